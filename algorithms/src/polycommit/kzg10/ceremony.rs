@@ -0,0 +1,524 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::UniversalParams;
+use crate::msm::VariableBase;
+use snarkvm_curves::{AffineCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{Field, PrimeField, Zero};
+use snarkvm_parameters::testnet3::PowersOfG;
+use snarkvm_utilities::{
+    cfg_chunks_mut,
+    io::{Result as IoResult, Write},
+    rand::Uniform,
+    serialize::{CanonicalDeserialize, CanonicalSerialize},
+};
+
+use anyhow::{anyhow, ensure, Result};
+use core::ops::Mul;
+use rand::{CryptoRng, Rng};
+use rand_chacha::{rand_core::SeedableRng, ChaChaRng};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, BTreeSet};
+#[cfg(not(target_family = "wasm"))]
+use std::path::Path;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The maximum number of powers of beta G, which is the number of powers in the hard-coded SRS.
+const MAX_NUM_POWERS: usize = 1 << 28;
+/// The number of powers of beta G in `powers-of-beta-15.usrs` and `shifted-powers-of-beta-15.usrs`.
+const NUM_POWERS_15: usize = 1 << 15;
+/// The hiding bound supported by the powers of beta * gamma G, which is the hiding bound used in Marlin.
+const HIDING_BOUND: usize = 1;
+/// The number of powers of beta G that are updated together in a contribution.
+const CHUNK_SIZE: usize = 1 << 12;
+/// The domain separator for hashing to G2.
+const DOMAIN: &[u8] = b"snarkVM KZG10 powers of tau";
+/// The personalization for the proof of knowledge of the contribution to `beta`.
+const TAU: &[u8] = b"tau";
+/// The personalization for the proof of knowledge of the contribution to `gamma`.
+const DELTA: &[u8] = b"delta";
+
+/// The state of a powers-of-tau ceremony for the KZG10 universal SRS, after zero or more contributions.
+///
+/// The state consists of powers of a trapdoor `beta`, and of a trapdoor `gamma` (for hiding commitments).
+/// Each contribution multiplies `beta` by a fresh secret `tau`, and `gamma` by a fresh secret `delta`,
+/// so neither trapdoor is known as long as a single participant discards their secrets.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Accumulator<E: PairingEngine> {
+    /// Group elements of the form `{ \beta^i G }`, where `i` ranges from 0 to `num_powers - 1`.
+    powers_of_beta_g: Vec<E::G1Affine>,
+    /// Group elements of the form `{ \beta^i \gamma G }`, for each `i` that `trim` requires.
+    powers_of_beta_times_gamma_g: BTreeMap<usize, E::G1Affine>,
+    /// Group elements of the form `{ \beta^{-(max_degree - d)} H }`, for each supported degree bound `d`.
+    negative_powers_of_beta_h: BTreeMap<usize, E::G2Affine>,
+    /// beta * H
+    beta_h: E::G2Affine,
+    /// gamma * H, which is only used to verify the powers of beta * gamma G.
+    gamma_h: E::G2Affine,
+}
+
+/// A proof that a contribution was computed from the previous accumulator, with secrets known to the participant.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct ContributionProof<E: PairingEngine> {
+    /// The proof of knowledge of `tau`, the contribution to `beta`.
+    tau: ProofOfKnowledge<E>,
+    /// The proof of knowledge of `delta`, the contribution to `gamma`.
+    delta: ProofOfKnowledge<E>,
+}
+
+/// A proof of knowledge of a secret `x`, bound to the digest of the accumulator it is applied to.
+/// It consists of `s G` for a random `s`, `x s G`, and `x R`, where `R` is the hash of
+/// the digest, `s G`, and `x s G` to G2.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+struct ProofOfKnowledge<E: PairingEngine> {
+    s_g: E::G1Affine,
+    x_s_g: E::G1Affine,
+    x_r: E::G2Affine,
+}
+
+impl<E: PairingEngine> Accumulator<E> {
+    /// Initializes a ceremony for an SRS with `num_powers` powers of beta G, which must be a power of two.
+    /// Initially, `beta` and `gamma` are both one, so the SRS is only secure after a contribution.
+    pub fn new(num_powers: usize) -> Result<Self> {
+        ensure!(num_powers.is_power_of_two() && num_powers >= 2, "The number of powers must be a power of two");
+        ensure!(num_powers <= MAX_NUM_POWERS, "The number of powers must be at most 2^28");
+
+        let g = E::G1Affine::prime_subgroup_generator();
+        let h = E::G2Affine::prime_subgroup_generator();
+        Ok(Self {
+            powers_of_beta_g: vec![g; num_powers],
+            powers_of_beta_times_gamma_g: gamma_indices(num_powers).into_iter().map(|i| (i, g)).collect(),
+            negative_powers_of_beta_h: degree_bounds(num_powers).map(|d| (d, h)).collect(),
+            beta_h: h,
+            gamma_h: h,
+        })
+    }
+
+    /// Returns the number of powers of beta G.
+    pub fn num_powers(&self) -> usize {
+        self.powers_of_beta_g.len()
+    }
+
+    /// Returns the maximum degree supported by the SRS.
+    pub fn max_degree(&self) -> usize {
+        self.num_powers() - 1
+    }
+
+    /// Returns the SHA-256 digest of the accumulator, to which the next contribution is bound.
+    pub fn digest(&self) -> Result<[u8; 32]> {
+        let mut hasher = HashWriter(Sha256::new());
+        self.serialize_uncompressed(&mut hasher)?;
+        Ok(hasher.0.finalize().into())
+    }
+
+    /// Applies a contribution with fresh secrets sampled from `rng`, and returns the resulting accumulator,
+    /// along with a proof that it was computed from `self`. The participant must discard `rng` afterwards.
+    pub fn contribute<R: Rng + CryptoRng>(&self, rng: &mut R) -> Result<(Self, ContributionProof<E>)> {
+        let digest = self.digest()?;
+
+        // Sample the secrets, and prove knowledge of them.
+        let (tau, delta) = (E::Fr::rand(rng), E::Fr::rand(rng));
+        ensure!(!tau.is_zero() && !delta.is_zero(), "Sampled a zero contribution");
+        let proof = ContributionProof {
+            tau: ProofOfKnowledge::new(&digest, TAU, tau, rng)?,
+            delta: ProofOfKnowledge::new(&digest, DELTA, delta, rng)?,
+        };
+        let tau_inverse = tau.inverse().ok_or_else(|| anyhow!("Failed to invert the contribution"))?;
+        let max_degree = self.max_degree();
+
+        // Multiply each power of beta G by the corresponding power of tau.
+        let mut powers_of_beta_g = self.powers_of_beta_g.clone();
+        cfg_chunks_mut!(powers_of_beta_g, CHUNK_SIZE).enumerate().for_each(|(i, chunk)| {
+            let mut power_of_tau = tau.pow([(i * CHUNK_SIZE) as u64]);
+            let mut powers = chunk
+                .iter()
+                .map(|power| {
+                    let power = power.mul(power_of_tau);
+                    power_of_tau *= tau;
+                    power
+                })
+                .collect::<Vec<_>>();
+            E::G1Projective::batch_normalization(&mut powers);
+            chunk.iter_mut().zip(powers).for_each(|(power, new_power)| *power = new_power.into());
+        });
+
+        // Multiply each power of beta * gamma G by the corresponding power of tau, and by delta.
+        let powers_of_beta_times_gamma_g = self
+            .powers_of_beta_times_gamma_g
+            .iter()
+            .map(|(i, power)| (*i, power.mul(tau.pow([*i as u64]) * delta).to_affine()))
+            .collect();
+
+        // Multiply each negative power of beta H by the corresponding negative power of tau.
+        let negative_powers_of_beta_h = self
+            .negative_powers_of_beta_h
+            .iter()
+            .map(|(d, power)| (*d, power.mul(tau_inverse.pow([(max_degree - d) as u64])).to_affine()))
+            .collect();
+
+        let accumulator = Self {
+            powers_of_beta_g,
+            powers_of_beta_times_gamma_g,
+            negative_powers_of_beta_h,
+            beta_h: self.beta_h.mul(tau).to_affine(),
+            gamma_h: self.gamma_h.mul(delta).to_affine(),
+        };
+        Ok((accumulator, proof))
+    }
+
+    /// Verifies that `next` is the result of applying the contribution with the given proof to `self`,
+    /// and that `next` is well-formed. The `rng` batches the pairing checks, and must not be known to the participant.
+    pub fn verify_contribution<R: Rng>(&self, next: &Self, proof: &ContributionProof<E>, rng: &mut R) -> Result<()> {
+        ensure!(next.num_powers() == self.num_powers(), "The contribution has a different number of powers");
+        let digest = self.digest()?;
+
+        // Ensure `beta` was multiplied by the secret in the proof of knowledge of `tau`.
+        let (r, tau_r) = proof.tau.verify(&digest, TAU)?;
+        ensure!(
+            same_ratio::<E>((self.powers_of_beta_g[1], next.powers_of_beta_g[1]), (r, tau_r)),
+            "The contribution to beta does not match its proof"
+        );
+
+        // Ensure `gamma` was multiplied by the secret in the proof of knowledge of `delta`.
+        let (r, delta_r) = proof.delta.verify(&digest, DELTA)?;
+        let (gamma_g, next_gamma_g) =
+            (self.powers_of_beta_times_gamma_g.get(&0), next.powers_of_beta_times_gamma_g.get(&0));
+        let (gamma_g, next_gamma_g) = gamma_g.zip(next_gamma_g).ok_or_else(|| anyhow!("Missing gamma * G"))?;
+        ensure!(
+            same_ratio::<E>((*gamma_g, *next_gamma_g), (r, delta_r)),
+            "The contribution to gamma does not match its proof"
+        );
+
+        // Ensure the resulting SRS is well-formed.
+        next.verify(rng)
+    }
+
+    /// Verifies that the accumulator is a well-formed SRS, i.e. that its elements are powers of the same
+    /// `beta` and `gamma`. The `rng` batches the pairing checks, and must not be known to the participants.
+    pub fn verify<R: Rng>(&self, rng: &mut R) -> Result<()> {
+        let num_powers = self.num_powers();
+        ensure!(num_powers.is_power_of_two() && num_powers >= 2, "The number of powers must be a power of two");
+        ensure!(num_powers <= MAX_NUM_POWERS, "The number of powers must be at most 2^28");
+        ensure!(
+            self.powers_of_beta_times_gamma_g.keys().copied().eq(gamma_indices(num_powers)),
+            "The SRS has an unexpected set of powers of beta * gamma G"
+        );
+        ensure!(
+            self.negative_powers_of_beta_h.keys().copied().eq(degree_bounds(num_powers)),
+            "The SRS has an unexpected set of negative powers of beta H"
+        );
+
+        let g = E::G1Affine::prime_subgroup_generator();
+        let h = E::G2Affine::prime_subgroup_generator();
+        let powers_of_beta_g = &self.powers_of_beta_g;
+        let gamma_g = self.powers_of_beta_times_gamma_g[&0];
+        ensure!(powers_of_beta_g[0] == g, "The first power of beta G must be the generator");
+        ensure!(!powers_of_beta_g[1].is_zero() && !self.beta_h.is_zero(), "The SRS contains a zero power of beta");
+        ensure!(!gamma_g.is_zero() && !self.gamma_h.is_zero(), "The SRS contains a zero power of gamma");
+
+        // Ensure beta H is consistent with the powers of beta G.
+        ensure!(same_ratio::<E>((g, powers_of_beta_g[1]), (h, self.beta_h)), "Beta H is inconsistent");
+
+        // Ensure each power of beta G is beta times the previous power, using a random linear combination.
+        let scalars = (1..num_powers).map(|_| E::Fr::rand(rng).to_bigint()).collect::<Vec<_>>();
+        let lhs = VariableBase::msm(&powers_of_beta_g[..num_powers - 1], &scalars).to_affine();
+        let rhs = VariableBase::msm(&powers_of_beta_g[1..], &scalars).to_affine();
+        ensure!(same_ratio::<E>((lhs, rhs), (h, self.beta_h)), "The powers of beta G are inconsistent");
+
+        // Ensure each power of beta * gamma G is gamma times the corresponding power of beta G,
+        // using a random linear combination.
+        let (bases, powers_of_beta_times_gamma_g): (Vec<_>, Vec<_>) = self
+            .powers_of_beta_times_gamma_g
+            .range(..num_powers)
+            .map(|(i, power)| (powers_of_beta_g[*i], *power))
+            .unzip();
+        let scalars = (0..bases.len()).map(|_| E::Fr::rand(rng).to_bigint()).collect::<Vec<_>>();
+        let lhs = VariableBase::msm(&bases, &scalars).to_affine();
+        let rhs = VariableBase::msm(&powers_of_beta_times_gamma_g, &scalars).to_affine();
+        ensure!(same_ratio::<E>((lhs, rhs), (h, self.gamma_h)), "The powers of beta * gamma G are inconsistent");
+
+        // Ensure the power of beta * gamma G beyond the maximum degree is beta times the previous power.
+        for (i, power) in self.powers_of_beta_times_gamma_g.range(num_powers..) {
+            let previous = self.powers_of_beta_times_gamma_g.get(&(i - 1));
+            let previous = previous.ok_or_else(|| anyhow!("Missing the power of beta * gamma G preceding {i}"))?;
+            ensure!(
+                same_ratio::<E>((*previous, *power), (h, self.beta_h)),
+                "The powers of beta * gamma G are inconsistent"
+            );
+        }
+
+        // Ensure each negative power of beta H is the inverse of the corresponding power of beta G.
+        let g_h = E::pairing(g, h);
+        for (d, power) in &self.negative_powers_of_beta_h {
+            ensure!(
+                E::pairing(powers_of_beta_g[self.max_degree() - d], *power) == g_h,
+                "The negative power of beta H for degree bound {d} is inconsistent"
+            );
+        }
+        Ok(())
+    }
+
+    /// Verifies a chain of contributions, where `accumulators[0]` is the initial accumulator, and each
+    /// `accumulators[i + 1]` is the result of applying the contribution with proof `proofs[i]` to `accumulators[i]`.
+    pub fn verify_chain<R: Rng>(accumulators: &[Self], proofs: &[ContributionProof<E>], rng: &mut R) -> Result<()> {
+        ensure!(!proofs.is_empty(), "The chain must contain at least one contribution");
+        ensure!(accumulators.len() == proofs.len() + 1, "The chain must contain one more accumulator than proofs");
+        ensure!(
+            accumulators[0] == Self::new(accumulators[0].num_powers())?,
+            "The chain must start from the initial accumulator"
+        );
+        for (accumulators, proof) in accumulators.windows(2).zip(proofs) {
+            accumulators[0].verify_contribution(&accumulators[1], proof, rng)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the universal parameters defined by the accumulator.
+    pub fn to_universal_params(&self) -> Result<UniversalParams<E>> {
+        let powers = PowersOfG::new(
+            self.powers_of_beta_g.clone(),
+            self.powers_of_beta_times_gamma_g.clone(),
+            self.negative_powers_of_beta_h.clone(),
+            self.beta_h,
+        )?;
+        Ok(UniversalParams::from_powers(powers, degree_bounds(self.num_powers()).collect()))
+    }
+
+    /// Writes the SRS to the given directory, as the `.usrs` files that `SonicKZG10::load_srs` consumes,
+    /// along with their `.metadata`. In detail, the files are:
+    /// * `powers-of-beta-15.usrs`, with the first `2^15` powers of beta G,
+    /// * `powers-of-beta-{k}.usrs`, with the powers from `2^(k - 1)` to `2^k`, for `15 < k <= log2(num_powers)`,
+    /// * `shifted-powers-of-beta-15.usrs`, with the last `2^15` powers of beta G,
+    /// * `shifted-powers-of-beta-{k}.usrs`, with the powers from `num_powers - 2^k` to `num_powers - 2^(k - 1)`,
+    ///   for `15 < k < log2(num_powers)`, and
+    /// * `powers-of-beta-gamma.usrs`, `neg-powers-of-beta.usrs`, and `beta-h.usrs`.
+    ///
+    /// Note: The loader downloads shifted powers relative to `2^28` powers, so only an SRS with `2^28` powers
+    /// can replace the hard-coded SRS.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn write_usrs<P: AsRef<Path>>(&self, directory: P) -> Result<()> {
+        let num_powers = self.num_powers();
+        ensure!(num_powers >= NUM_POWERS_15, "The SRS must contain at least 2^15 powers to be exported");
+        let log_num_powers = num_powers.trailing_zeros() as usize;
+
+        let directory = directory.as_ref();
+        std::fs::create_dir_all(directory)?;
+
+        // Write the powers of beta G.
+        let powers_of_beta_g = &self.powers_of_beta_g;
+        write_usrs_file(directory, "powers-of-beta-15", &powers_of_beta_g[..NUM_POWERS_15])?;
+        for k in 16..=log_num_powers {
+            write_usrs_file(directory, &format!("powers-of-beta-{k}"), &powers_of_beta_g[(1 << (k - 1))..(1 << k)])?;
+        }
+
+        // Write the shifted powers of beta G.
+        write_usrs_file(directory, "shifted-powers-of-beta-15", &powers_of_beta_g[(num_powers - NUM_POWERS_15)..])?;
+        for k in 16..log_num_powers {
+            let range = (num_powers - (1 << k))..(num_powers - (1 << (k - 1)));
+            write_usrs_file(directory, &format!("shifted-powers-of-beta-{k}"), &powers_of_beta_g[range])?;
+        }
+
+        // Write the remaining elements.
+        write_usrs_file(directory, "powers-of-beta-gamma", &self.powers_of_beta_times_gamma_g)?;
+        write_usrs_file(directory, "neg-powers-of-beta", &self.negative_powers_of_beta_h)?;
+        write_usrs_file(directory, "beta-h", &self.beta_h)
+    }
+}
+
+impl<E: PairingEngine> ProofOfKnowledge<E> {
+    /// Returns a proof of knowledge of `x`, bound to the given digest.
+    fn new<R: Rng + CryptoRng>(digest: &[u8; 32], personalization: &[u8], x: E::Fr, rng: &mut R) -> Result<Self> {
+        let s_g = E::G1Projective::rand(rng).to_affine();
+        let x_s_g = s_g.mul(x).to_affine();
+        let r = hash_to_g2::<E>(digest, personalization, &s_g, &x_s_g)?;
+        Ok(Self { s_g, x_s_g, x_r: r.mul(x).to_affine() })
+    }
+
+    /// Verifies the proof, and returns `(R, x R)`, which can be used to check that two elements of G1
+    /// differ by the secret `x`.
+    fn verify(&self, digest: &[u8; 32], personalization: &[u8]) -> Result<(E::G2Affine, E::G2Affine)> {
+        ensure!(!self.s_g.is_zero() && !self.x_s_g.is_zero(), "The proof of knowledge contains the identity");
+        let r = hash_to_g2::<E>(digest, personalization, &self.s_g, &self.x_s_g)?;
+        ensure!(same_ratio::<E>((self.s_g, self.x_s_g), (r, self.x_r)), "The proof of knowledge is invalid");
+        Ok((r, self.x_r))
+    }
+}
+
+/// Returns the degree bounds supported by an SRS with `num_powers` powers, which are the degree bounds
+/// of the form `2^k - 2` used in Marlin, for `2^k < num_powers`.
+fn degree_bounds(num_powers: usize) -> impl Iterator<Item = usize> {
+    (1..num_powers.trailing_zeros()).map(|k| (1 << k) - 2)
+}
+
+/// Returns the indices of the powers of beta * gamma G that `trim` requires, i.e. the first `HIDING_BOUND + 2`
+/// powers, and the first `HIDING_BOUND + 2` powers from the shift of each degree bound, up to `num_powers`.
+fn gamma_indices(num_powers: usize) -> BTreeSet<usize> {
+    let max_degree = num_powers - 1;
+    core::iter::once(0)
+        .chain(degree_bounds(num_powers).map(|d| max_degree - d))
+        .flat_map(|shift| shift..=(shift + HIDING_BOUND + 1))
+        .filter(|i| *i <= num_powers)
+        .collect()
+}
+
+/// Returns `true` if `(a, b)` in G1 and `(c, d)` in G2 have the same ratio, i.e. `b = x a` and `d = x c` for some `x`.
+fn same_ratio<E: PairingEngine>((a, b): (E::G1Affine, E::G1Affine), (c, d): (E::G2Affine, E::G2Affine)) -> bool {
+    E::pairing(a, d) == E::pairing(b, c)
+}
+
+/// Hashes the given digest and elements of a proof of knowledge to an element of G2 with an unknown discrete logarithm.
+fn hash_to_g2<E: PairingEngine>(
+    digest: &[u8; 32],
+    personalization: &[u8],
+    s_g: &E::G1Affine,
+    x_s_g: &E::G1Affine,
+) -> Result<E::G2Affine> {
+    let mut hasher = HashWriter(Sha256::new());
+    hasher.0.update(DOMAIN);
+    hasher.0.update(personalization);
+    hasher.0.update(digest);
+    s_g.serialize_uncompressed(&mut hasher)?;
+    x_s_g.serialize_uncompressed(&mut hasher)?;
+    // Sample the element by its x-coordinate, so that its discrete logarithm is unknown.
+    let rng = &mut ChaChaRng::from_seed(hasher.0.finalize().into());
+    Ok(E::G2Projective::rand(rng).to_affine())
+}
+
+/// Writes `value` to `{name}.usrs` in the given directory, along with its checksum and size in `{name}.metadata`.
+#[cfg(not(target_family = "wasm"))]
+fn write_usrs_file<T: CanonicalSerialize + ?Sized>(directory: &Path, name: &str, value: &T) -> Result<()> {
+    let mut bytes = Vec::with_capacity(value.uncompressed_size());
+    value.serialize_uncompressed(&mut bytes)?;
+    let checksum = hex::encode(Sha256::digest(&bytes));
+    let metadata = format!("{{\n  \"checksum\": \"{checksum}\",\n  \"size\": {}\n}}", bytes.len());
+    std::fs::write(directory.join(format!("{name}.usrs")), bytes)?;
+    std::fs::write(directory.join(format!("{name}.metadata")), metadata)?;
+    Ok(())
+}
+
+/// A writer that feeds its bytes into a SHA-256 hasher.
+struct HashWriter(Sha256);
+
+impl Write for HashWriter {
+    fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+        self.0.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> IoResult<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fft::DensePolynomial, polycommit::kzg10::KZG10};
+    use snarkvm_curves::bls12_377::{Bls12_377, G1Affine, G2Affine};
+    use snarkvm_parameters::testnet3::{Gamma, NegBeta};
+    use snarkvm_utilities::rand::TestRng;
+
+    use std::sync::atomic::AtomicBool;
+
+    type CurrentAccumulator = Accumulator<Bls12_377>;
+
+    #[test]
+    fn test_ceremony() {
+        let rng = &mut TestRng::default();
+        let num_powers = 1 << 6;
+
+        // Run a ceremony with three contributions.
+        let mut accumulators = vec![CurrentAccumulator::new(num_powers).unwrap()];
+        let mut proofs = vec![];
+        for _ in 0..3 {
+            let (accumulator, proof) = accumulators.last().unwrap().contribute(rng).unwrap();
+            accumulators.push(accumulator);
+            proofs.push(proof);
+        }
+        CurrentAccumulator::verify_chain(&accumulators, &proofs, rng).unwrap();
+
+        // Ensure a contribution is rejected with the proof of another contribution.
+        assert!(accumulators[1].verify_contribution(&accumulators[2], &proofs[2], rng).is_err());
+        // Ensure a contribution is rejected if it skips its predecessor.
+        assert!(accumulators[0].verify_contribution(&accumulators[2], &proofs[1], rng).is_err());
+        // Ensure a contribution is rejected if one of its powers is tampered with.
+        let mut tampered = accumulators[3].clone();
+        tampered.powers_of_beta_g.swap(5, 6);
+        assert!(accumulators[2].verify_contribution(&tampered, &proofs[2], rng).is_err());
+        // Ensure a chain without contributions is rejected.
+        assert!(CurrentAccumulator::verify_chain(&accumulators[..1], &[], rng).is_err());
+
+        // Ensure the resulting SRS commits to and opens hiding polynomials.
+        let pp = accumulators[3].to_universal_params().unwrap();
+        assert_eq!(pp.max_degree(), num_powers - 1);
+        let hiding_bound = Some(1);
+        let (ck, vk) = KZG10::trim(&pp, pp.max_degree(), hiding_bound);
+        let p = DensePolynomial::rand(pp.max_degree(), rng);
+        let (comm, rand) =
+            KZG10::<Bls12_377>::commit(&ck, &(&p).into(), hiding_bound, &AtomicBool::new(false), Some(rng)).unwrap();
+        let point = <Bls12_377 as PairingEngine>::Fr::rand(rng);
+        let proof = KZG10::<Bls12_377>::open(&ck, &p, point, &rand).unwrap();
+        assert!(KZG10::<Bls12_377>::check(&vk, &comm, point, p.evaluate(point), &proof).unwrap());
+    }
+
+    #[test]
+    fn test_write_usrs() {
+        let num_powers = 1 << 16;
+        let accumulator = CurrentAccumulator::new(num_powers).unwrap();
+
+        let directory = std::env::temp_dir().join(format!("snarkvm_kzg10_ceremony_{}", std::process::id()));
+        accumulator.write_usrs(&directory).unwrap();
+
+        // Ensure the powers are written in the format of the hard-coded SRS.
+        for (name, range) in [
+            ("powers-of-beta-15", 0..NUM_POWERS_15),
+            ("powers-of-beta-16", NUM_POWERS_15..num_powers),
+            ("shifted-powers-of-beta-15", (num_powers - NUM_POWERS_15)..num_powers),
+        ] {
+            let bytes = std::fs::read(directory.join(format!("{name}.usrs"))).unwrap();
+            let powers = Vec::<G1Affine>::deserialize_uncompressed_unchecked(&*bytes).unwrap();
+            assert_eq!(powers, accumulator.powers_of_beta_g[range]);
+
+            // Ensure the metadata matches the file.
+            let metadata = std::fs::read_to_string(directory.join(format!("{name}.metadata"))).unwrap();
+            let metadata: serde_json::Value = serde_json::from_str(&metadata).unwrap();
+            assert_eq!(metadata["size"], bytes.len());
+            assert_eq!(metadata["checksum"], hex::encode(Sha256::digest(&bytes)));
+        }
+        let bytes = std::fs::read(directory.join("neg-powers-of-beta.usrs")).unwrap();
+        let negative_powers_of_beta_h = BTreeMap::<usize, G2Affine>::deserialize_uncompressed_unchecked(&*bytes);
+        assert_eq!(negative_powers_of_beta_h.unwrap(), accumulator.negative_powers_of_beta_h);
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_hard_coded_layout() {
+        // Ensure an SRS with 2^28 powers supports the degree bounds of the hard-coded SRS.
+        let bytes = NegBeta::load_bytes().unwrap();
+        let negative_powers_of_beta_h = BTreeMap::<usize, G2Affine>::deserialize_uncompressed_unchecked(&*bytes);
+        assert!(negative_powers_of_beta_h.unwrap().keys().copied().eq(degree_bounds(MAX_NUM_POWERS)));
+
+        // Ensure it contains each power of beta * gamma G that `trim` requires from the hard-coded SRS.
+        let bytes = Gamma::load_bytes().unwrap();
+        let powers_of_beta_times_gamma_g = BTreeMap::<usize, G1Affine>::deserialize_uncompressed_unchecked(&*bytes);
+        let powers_of_beta_times_gamma_g = powers_of_beta_times_gamma_g.unwrap();
+        let expected = powers_of_beta_times_gamma_g.keys().copied().filter(|i| *i <= MAX_NUM_POWERS);
+        assert!(expected.eq(gamma_indices(MAX_NUM_POWERS)));
+    }
+}
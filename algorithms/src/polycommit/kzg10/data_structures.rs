@@ -16,26 +16,34 @@
 
 use crate::{
     fft::{DensePolynomial, EvaluationDomain},
+    msm::FixedBase,
+    polycommit::kzg10::KZGDegreeBounds,
     AlgebraicSponge,
 };
 use snarkvm_curves::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
-use snarkvm_fields::{ConstraintFieldError, PrimeField, ToConstraintField, Zero};
+use snarkvm_fields::{ConstraintFieldError, Field, One, PrimeField, ToConstraintField, Zero};
 use snarkvm_parameters::testnet3::PowersOfG;
 use snarkvm_utilities::{
     borrow::Cow,
     error,
     io::{Read, Write},
+    rand::Uniform,
     serialize::{CanonicalDeserialize, CanonicalSerialize},
     FromBytes,
+    MappedSlice,
     ToBytes,
     ToMinimalBits,
 };
+#[cfg(not(target_family = "wasm"))]
+use snarkvm_utilities::{MappedReader, ZeroCopyWriter};
 
-use anyhow::Result;
-use core::ops::{Add, AddAssign};
+use anyhow::{ensure, Result};
+use core::ops::{Add, AddAssign, Mul};
 use parking_lot::RwLock;
 use rand_core::RngCore;
 use std::{collections::BTreeMap, io, ops::Range, sync::Arc};
+#[cfg(not(target_family = "wasm"))]
+use std::{fs::File, path::Path};
 
 /// `UniversalParams` are the universal parameters for the KZG10 scheme.
 #[derive(Clone, Debug)]
@@ -66,6 +74,98 @@ impl<E: PairingEngine> UniversalParams<E> {
         Ok(Self { powers, h, supported_degree_bounds, prepared_h, prepared_beta_h })
     }
 
+    /// Samples new universal parameters that support polynomials up to degree `max_degree`.
+    ///
+    /// Note: The trapdoor is sampled from the given RNG, so the resulting parameters are only
+    /// as secure as the RNG is secret. This is intended for local development and testing.
+    pub fn setup<R: RngCore>(max_degree: usize, rng: &mut R) -> Result<Self> {
+        anyhow::ensure!(max_degree >= 1, "The maximum degree of the SRS must be at least 1");
+
+        // Sample the trapdoor and the generators.
+        let beta = E::Fr::rand(rng);
+        let g = E::G1Projective::rand(rng);
+        let gamma_g = E::G1Projective::rand(rng);
+        let h = E::G2Affine::prime_subgroup_generator();
+
+        // Compute the powers of beta, including one additional power for the hiding powers.
+        let mut powers_of_beta = Vec::with_capacity(max_degree + 2);
+        let mut current = E::Fr::one();
+        for _ in 0..(max_degree + 2) {
+            powers_of_beta.push(current);
+            current *= &beta;
+        }
+
+        // Compute the powers of beta * G, and the powers of beta * gamma * G.
+        let scalar_bits = E::Fr::size_in_bits();
+        let window_size = FixedBase::get_mul_window_size(max_degree + 2);
+        let g_table = FixedBase::get_window_table(scalar_bits, window_size, g);
+        let powers_of_beta_g = FixedBase::msm(scalar_bits, window_size, &g_table, &powers_of_beta[..=max_degree]);
+        let gamma_g_table = FixedBase::get_window_table(scalar_bits, window_size, gamma_g);
+        let powers_of_beta_times_gamma_g = FixedBase::msm(scalar_bits, window_size, &gamma_g_table, &powers_of_beta);
+
+        let powers_of_beta_g = E::G1Projective::batch_normalization_into_affine(powers_of_beta_g);
+        let powers_of_beta_times_gamma_g =
+            E::G1Projective::batch_normalization_into_affine(powers_of_beta_times_gamma_g)
+                .into_iter()
+                .enumerate()
+                .collect();
+
+        // Compute the negative powers of beta * H, for each degree bound used in Marlin.
+        let supported_degree_bounds = KZGDegreeBounds::Marlin.get_list::<E::Fr>(max_degree);
+        let mut negative_powers_of_beta_h = BTreeMap::new();
+        for degree_bound in &supported_degree_bounds {
+            let shift = powers_of_beta[max_degree - degree_bound]
+                .inverse()
+                .ok_or_else(|| anyhow::anyhow!("Failed to invert a power of beta"))?;
+            negative_powers_of_beta_h.insert(*degree_bound, h.mul(shift).to_affine());
+        }
+        let beta_h = h.mul(beta).to_affine();
+
+        // Initialize the powers.
+        let powers = PowersOfG::new(powers_of_beta_g, powers_of_beta_times_gamma_g, negative_powers_of_beta_h, beta_h)?;
+        Ok(Self::from_powers(powers, supported_degree_bounds))
+    }
+
+    /// Initializes universal parameters from the given powers, where `h` is the generator of G2.
+    pub(super) fn from_powers(powers: PowersOfG<E>, supported_degree_bounds: Vec<usize>) -> Self {
+        let h = E::G2Affine::prime_subgroup_generator();
+        let prepared_h = h.prepare();
+        let prepared_beta_h = powers.beta_h().prepare();
+        let powers = Arc::new(RwLock::new(powers));
+
+        Self { powers, h, supported_degree_bounds, prepared_h, prepared_beta_h }
+    }
+
+    /// Writes the universal parameters to the file at the given path, in the zero-copy layout.
+    /// The file can then be memory-mapped with `load_mapped`.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn write_mapped<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let mut writer = ZeroCopyWriter::new(io::BufWriter::new(File::create(path)?))?;
+        self.powers.write().write_mapped(&mut writer)?;
+        self.h.serialize_uncompressed(&mut writer)?;
+        self.supported_degree_bounds.serialize_uncompressed(&mut writer)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Loads universal parameters from the file at the given path, which was written by `write_mapped`.
+    /// The powers of beta G are memory-mapped rather than read into memory,
+    /// and each degree of them is validated when it is first requested, e.g. by `trim`.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn load_mapped<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let mut reader = MappedReader::open(path)?;
+        let powers = PowersOfG::<E>::read_mapped(&mut reader)?;
+        let h = E::G2Affine::deserialize_uncompressed(&mut reader)?;
+        let supported_degree_bounds = Vec::deserialize_uncompressed(&mut reader)?;
+        ensure!(reader.is_empty(), "The universal parameters contain trailing bytes");
+
+        let prepared_h = h.prepare();
+        let prepared_beta_h = powers.beta_h().prepare();
+        let powers = Arc::new(RwLock::new(powers));
+
+        Ok(Self { powers, h, supported_degree_bounds, prepared_h, prepared_beta_h })
+    }
+
     pub fn download_powers_for(&self, range: Range<usize>) -> Result<()> {
         self.powers.write().download_powers_for(range)
     }
@@ -80,8 +180,10 @@ impl<E: PairingEngine> UniversalParams<E> {
         self.powers.write().power_of_beta_g(which_power)
     }
 
-    pub fn powers_of_beta_g(&self, lower: usize, upper: usize) -> Result<Vec<E::G1Affine>> {
-        Ok(self.powers.write().powers_of_beta_g(lower..upper)?.to_vec())
+    /// Returns the powers of beta G from `lower` to `upper`.
+    /// If the parameters are memory-mapped, the powers are not copied out of the file.
+    pub fn powers_of_beta_g(&self, lower: usize, upper: usize) -> Result<MappedSlice<E::G1Affine>> {
+        self.powers.write().powers_of_beta_g(lower..upper)
     }
 
     pub fn powers_of_beta_times_gamma_g(&self) -> Arc<BTreeMap<usize, E::G1Affine>> {
@@ -42,6 +42,9 @@ use rand_core::RngCore;
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
+mod ceremony;
+pub use ceremony::*;
+
 mod data_structures;
 pub use data_structures::*;
 
@@ -99,6 +102,11 @@ impl<E: PairingEngine> KZG10<E> {
         Ok(params)
     }
 
+    /// Samples new public parameters for the maximum degree `degree`, without loading the hard-coded SRS.
+    pub fn setup<R: RngCore>(max_degree: usize, rng: &mut R) -> Result<UniversalParams<E>, PCError> {
+        UniversalParams::setup(max_degree, rng).map_err(Into::into)
+    }
+
     /// Outputs a commitment to `polynomial`.
     pub fn commit(
         powers: &Powers<E>,
@@ -548,6 +556,26 @@ mod tests {
         assert_eq!(&pp_bytes, &pp_recovered_bytes);
     }
 
+    #[test]
+    fn test_kzg10_universal_params_mapped() {
+        let rng = &mut TestRng::default();
+        let degree = 100;
+        let pp = KZG_Bls12_377::setup(degree, rng).unwrap();
+
+        // Write the parameters in the zero-copy layout, and map them back in.
+        let path = std::env::temp_dir().join(format!("snarkvm_kzg10_mapped_srs_{}", std::process::id()));
+        pp.write_mapped(&path).unwrap();
+        let pp_mapped = UniversalParams::<Bls12_377>::load_mapped(&path).unwrap();
+
+        let powers = pp_mapped.powers_of_beta_g(0, degree + 1).unwrap();
+        assert!(powers.is_mapped());
+        assert_eq!(&*powers, &*pp.powers_of_beta_g(0, degree + 1).unwrap());
+        assert_eq!(pp_mapped.max_degree(), degree);
+        assert_eq!(pp_mapped.to_bytes_le().unwrap(), pp.to_bytes_le().unwrap());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
     fn end_to_end_test_template<E: PairingEngine>() -> Result<(), PCError> {
         let rng = &mut TestRng::default();
         for _ in 0..100 {
@@ -574,6 +602,26 @@ mod tests {
         Ok(())
     }
 
+    fn setup_test_template<E: PairingEngine>() -> Result<(), PCError> {
+        let rng = &mut TestRng::default();
+        for degree in [2, 15, 100] {
+            let pp = KZG10::<E>::setup(degree, rng)?;
+            assert_eq!(pp.max_degree(), degree);
+            // Ensure the parameters cannot be extended beyond the maximum degree.
+            assert!(pp.powers_of_beta_g(0, degree + 2).is_err());
+
+            let hiding_bound = Some(1);
+            let (ck, vk) = KZG10::trim(&pp, degree, hiding_bound);
+            let p = DensePolynomial::rand(degree, rng);
+            let (comm, rand) = KZG10::<E>::commit(&ck, &(&p).into(), hiding_bound, &AtomicBool::new(false), Some(rng))?;
+            let point = E::Fr::rand(rng);
+            let value = p.evaluate(point);
+            let proof = KZG10::<E>::open(&ck, &p, point, &rand)?;
+            assert!(KZG10::<E>::check(&vk, &comm, point, value, &proof)?);
+        }
+        Ok(())
+    }
+
     fn linear_polynomial_test_template<E: PairingEngine>() -> Result<(), PCError> {
         let rng = &mut TestRng::default();
         for _ in 0..100 {
@@ -637,6 +685,11 @@ mod tests {
         end_to_end_test_template::<Bls12_377>().expect("test failed for bls12-377");
     }
 
+    #[test]
+    fn test_setup() {
+        setup_test_template::<Bls12_377>().expect("test failed for bls12-377");
+    }
+
     #[test]
     fn test_linear_polynomial() {
         linear_polynomial_test_template::<Bls12_377>().expect("test failed for bls12-377");
@@ -17,8 +17,10 @@
 use crate::{crypto_hash::sha256::sha256, fft::EvaluationDomain, polycommit::kzg10, Prepare};
 use hashbrown::HashMap;
 use snarkvm_curves::{PairingCurve, PairingEngine, ProjectiveCurve};
-use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField};
-use snarkvm_utilities::{error, serialize::*, FromBytes, ToBytes};
+use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField, Zero};
+use snarkvm_utilities::{error, serialize::*, FromBytes, MappedSlice, ToBytes};
+#[cfg(not(target_family = "wasm"))]
+use snarkvm_utilities::{MappedReader, ZeroCopyWriter};
 
 use std::{
     borrow::{Borrow, Cow},
@@ -61,17 +63,17 @@ impl<E: PairingEngine> Prepare for Commitment<E> {
 #[derive(Clone, Debug, Default, Hash, CanonicalSerialize, CanonicalDeserialize, PartialEq, Eq)]
 pub struct CommitterKey<E: PairingEngine> {
     /// The key used to commit to polynomials.
-    pub powers_of_beta_g: Vec<E::G1Affine>,
+    pub powers_of_beta_g: MappedSlice<E::G1Affine>,
 
     /// The key used to commit to polynomials in Lagrange basis.
-    pub lagrange_bases_at_beta_g: BTreeMap<usize, Vec<E::G1Affine>>,
+    pub lagrange_bases_at_beta_g: BTreeMap<usize, MappedSlice<E::G1Affine>>,
 
     /// The key used to commit to hiding polynomials.
     pub powers_of_beta_times_gamma_g: Vec<E::G1Affine>,
 
     /// The powers used to commit to shifted polynomials.
     /// This is `None` if `self` does not support enforcing any degree bounds.
-    pub shifted_powers_of_beta_g: Option<Vec<E::G1Affine>>,
+    pub shifted_powers_of_beta_g: Option<MappedSlice<E::G1Affine>>,
 
     /// The powers used to commit to shifted hiding polynomials.
     /// This is `None` if `self` does not support enforcing any degree bounds.
@@ -106,7 +108,7 @@ impl<E: PairingEngine> FromBytes for CommitterKey<E> {
                 let power: E::G1Affine = FromBytes::read_le(&mut reader)?;
                 basis.push(power);
             }
-            lagrange_bases_at_beta_g.insert(size as usize, basis);
+            lagrange_bases_at_beta_g.insert(size as usize, basis.into());
         }
 
         // Deserialize `powers_of_beta_times_gamma_g`.
@@ -128,7 +130,7 @@ impl<E: PairingEngine> FromBytes for CommitterKey<E> {
                     shifted_powers_of_beta_g.push(shifted_power);
                 }
 
-                Some(shifted_powers_of_beta_g)
+                Some(MappedSlice::from(shifted_powers_of_beta_g))
             }
             false => None,
         };
@@ -211,7 +213,7 @@ impl<E: PairingEngine> FromBytes for CommitterKey<E> {
         }
 
         Ok(Self {
-            powers_of_beta_g,
+            powers_of_beta_g: powers_of_beta_g.into(),
             lagrange_bases_at_beta_g,
             powers_of_beta_times_gamma_g,
             shifted_powers_of_beta_g,
@@ -348,7 +350,7 @@ impl<E: PairingEngine> CommitterKey<E> {
     /// KZG10 construction.
     pub fn lagrange_basis(&self, domain: EvaluationDomain<E::Fr>) -> Option<kzg10::LagrangeBasis<E>> {
         self.lagrange_bases_at_beta_g.get(&domain.size()).map(|basis| kzg10::LagrangeBasis {
-            lagrange_basis_at_beta_g: Cow::Borrowed(basis),
+            lagrange_basis_at_beta_g: Cow::Borrowed(basis.as_slice()),
             powers_of_beta_times_gamma_g: Cow::Borrowed(&self.powers_of_beta_times_gamma_g),
             domain,
         })
@@ -356,6 +358,75 @@ impl<E: PairingEngine> CommitterKey<E> {
 }
 
 impl<E: PairingEngine> CommitterKey<E> {
+    /// Writes the committer key in the zero-copy layout, so that it can be memory-mapped with `read_mapped`.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn write_mapped<W: Write>(&self, writer: &mut ZeroCopyWriter<W>) -> io::Result<()> {
+        // Serialize `powers_of_beta_g`.
+        writer.write_slice(&self.powers_of_beta_g)?;
+
+        // Serialize `lagrange_bases_at_beta_g`.
+        (self.lagrange_bases_at_beta_g.len() as u32).write_le(&mut *writer)?;
+        for (size, basis) in &self.lagrange_bases_at_beta_g {
+            (*size as u32).write_le(&mut *writer)?;
+            writer.write_slice(basis)?;
+        }
+
+        // Serialize `shifted_powers_of_beta_g`.
+        self.shifted_powers_of_beta_g.is_some().write_le(&mut *writer)?;
+        if let Some(shifted_powers_of_beta_g) = &self.shifted_powers_of_beta_g {
+            writer.write_slice(shifted_powers_of_beta_g)?;
+        }
+
+        // Serialize the remaining fields, which are small.
+        self.powers_of_beta_times_gamma_g.serialize_uncompressed(&mut *writer)?;
+        self.shifted_powers_of_beta_times_gamma_g.serialize_uncompressed(&mut *writer)?;
+        self.enforced_degree_bounds.serialize_uncompressed(&mut *writer)?;
+        (self.max_degree as u64).write_le(&mut *writer)
+    }
+
+    /// Reads the committer key from a memory-mapped file in the zero-copy layout.
+    /// The group elements are validated once, and are not copied out of the file.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn read_mapped(reader: &mut MappedReader) -> io::Result<Self> {
+        // Deserialize `powers_of_beta_g`.
+        let powers_of_beta_g = reader.read_slice()?;
+
+        // Deserialize `lagrange_bases_at_beta_g`.
+        let num_lagrange_bases: u32 = FromBytes::read_le(&mut *reader)?;
+        let mut lagrange_bases_at_beta_g = BTreeMap::new();
+        for _ in 0..num_lagrange_bases {
+            let size: u32 = FromBytes::read_le(&mut *reader)?;
+            let basis = reader.read_slice::<E::G1Affine>()?;
+            if basis.len() != size as usize {
+                return Err(error("Mismatching Lagrange basis size"));
+            }
+            lagrange_bases_at_beta_g.insert(size as usize, basis);
+        }
+
+        // Deserialize `shifted_powers_of_beta_g`.
+        let has_shifted_powers_of_beta_g: bool = FromBytes::read_le(&mut *reader)?;
+        let shifted_powers_of_beta_g = match has_shifted_powers_of_beta_g {
+            true => Some(reader.read_slice()?),
+            false => None,
+        };
+
+        // Deserialize the remaining fields.
+        let powers_of_beta_times_gamma_g = CanonicalDeserialize::deserialize_uncompressed(&mut *reader)?;
+        let shifted_powers_of_beta_times_gamma_g = CanonicalDeserialize::deserialize_uncompressed(&mut *reader)?;
+        let enforced_degree_bounds = CanonicalDeserialize::deserialize_uncompressed(&mut *reader)?;
+        let max_degree: u64 = FromBytes::read_le(&mut *reader)?;
+
+        Ok(Self {
+            powers_of_beta_g,
+            lagrange_bases_at_beta_g,
+            powers_of_beta_times_gamma_g,
+            shifted_powers_of_beta_g,
+            shifted_powers_of_beta_times_gamma_g,
+            enforced_degree_bounds,
+            max_degree: max_degree as usize,
+        })
+    }
+
     pub fn max_degree(&self) -> usize {
         self.max_degree
     }
@@ -424,6 +495,16 @@ impl<E: PairingEngine> VerifierKey<E> {
     pub fn supported_degree(&self) -> usize {
         self.supported_degree
     }
+
+    /// Returns `true` if `self` and `other` were trimmed from the same universal parameters,
+    /// in which case they agree on the G2 element for every degree bound they both support.
+    pub fn shares_srs_with(&self, other: &Self) -> bool {
+        self.max_degree == other.max_degree
+            && self.vk.g == other.vk.g
+            && self.vk.gamma_g == other.vk.gamma_g
+            && self.vk.h == other.vk.h
+            && self.vk.beta_h == other.vk.beta_h
+    }
 }
 
 impl<E: PairingEngine> ToConstraintField<E::Fq> for VerifierKey<E> {
@@ -781,3 +862,43 @@ impl<E: PairingEngine> ToBytes for BatchLCProof<E> {
         CanonicalSerialize::serialize_compressed(self, &mut writer).map_err(|_| error("could not serialize struct"))
     }
 }
+
+/// The pairing equation left over from a batch check, before it is checked.
+/// Equations from several batch checks can be randomly combined and checked at once.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PairingCheck<E: PairingEngine> {
+    /// The combined commitments, grouped by their degree bound.
+    pub(super) combined_comms: BTreeMap<Option<usize>, E::G1Projective>,
+    /// The combined witnesses, to be paired with `beta_h`.
+    pub(super) combined_witness: E::G1Projective,
+    /// The combined witnesses adjusted by the evaluation points and values, to be paired with `h`.
+    pub(super) combined_adjusted_witness: E::G1Projective,
+}
+
+impl<E: PairingEngine> Default for PairingCheck<E> {
+    fn default() -> Self {
+        Self {
+            combined_comms: BTreeMap::new(),
+            combined_witness: E::G1Projective::zero(),
+            combined_adjusted_witness: E::G1Projective::zero(),
+        }
+    }
+}
+
+impl<E: PairingEngine> PairingCheck<E> {
+    /// Multiplies every term of the equation by `scalar`.
+    pub(super) fn scale(&mut self, scalar: E::Fr) {
+        self.combined_comms.values_mut().for_each(|comm| *comm *= scalar);
+        self.combined_witness *= scalar;
+        self.combined_adjusted_witness *= scalar;
+    }
+
+    /// Adds the terms of `other` to the terms of `self`.
+    pub(super) fn merge(&mut self, other: Self) {
+        for (degree_bound, comm) in other.combined_comms {
+            *self.combined_comms.entry(degree_bound).or_insert_with(E::G1Projective::zero) += comm;
+        }
+        self.combined_witness += other.combined_witness;
+        self.combined_adjusted_witness += other.combined_adjusted_witness;
+    }
+}
@@ -24,6 +24,7 @@ use hashbrown::HashMap;
 use itertools::Itertools;
 use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
 use snarkvm_fields::{One, Zero};
+use snarkvm_utilities::rand::Uniform;
 
 use core::{
     convert::TryInto,
@@ -102,7 +103,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
                     max_degree - lowest_shift_degree + 1
                 ));
 
-                let shifted_powers_of_beta_g = pp.powers_of_beta_g(lowest_shift_degree, pp.max_degree() + 1)?.to_vec();
+                let shifted_powers_of_beta_g = pp.powers_of_beta_g(lowest_shift_degree, pp.max_degree() + 1)?;
                 let mut shifted_powers_of_beta_times_gamma_g = BTreeMap::new();
                 // Also add degree 0.
                 for degree_bound in enforced_degree_bounds {
@@ -125,7 +126,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             (None, None)
         };
 
-        let powers_of_beta_g = pp.powers_of_beta_g(0, supported_degree + 1)?.to_vec();
+        let powers_of_beta_g = pp.powers_of_beta_g(0, supported_degree + 1)?;
         let powers_of_beta_times_gamma_g = (0..=(supported_hiding_bound + 1))
             .map(|i| {
                 pp.powers_of_beta_times_gamma_g()
@@ -147,7 +148,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             let domain = crate::fft::EvaluationDomain::new(size).unwrap();
             let lagrange_basis_at_beta_g = pp.lagrange_basis(domain)?;
             assert!(lagrange_basis_at_beta_g.len().is_power_of_two());
-            lagrange_bases_at_beta_g.insert(domain.size(), lagrange_basis_at_beta_g);
+            lagrange_bases_at_beta_g.insert(domain.size(), lagrange_basis_at_beta_g.into());
             end_timer!(lagrange_time);
         }
 
@@ -416,6 +417,23 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         proof: &BatchProof<E>,
         fs_rng: &mut S,
     ) -> Result<bool, PCError>
+    where
+        Commitment<E>: 'a,
+    {
+        let check = Self::batch_check_elems(vk, commitments, query_set, values, proof, fs_rng)?;
+        Self::check_elems(check, vk)
+    }
+
+    /// Performs the checks of `batch_check` that do not need a pairing,
+    /// and returns the pairing equation that remains to be checked.
+    pub fn batch_check_elems<'a>(
+        vk: &VerifierKey<E>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        values: &Evaluations<E::Fr>,
+        proof: &BatchProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<PairingCheck<E>, PCError>
     where
         Commitment<E>: 'a,
     {
@@ -436,9 +454,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
 
         let mut randomizer = E::Fr::one();
 
-        let mut combined_comms = BTreeMap::new();
-        let mut combined_witness = E::G1Projective::zero();
-        let mut combined_adjusted_witness = E::G1Projective::zero();
+        let mut check = PairingCheck::default();
 
         for ((_query_name, (query, labels)), p) in query_to_labels_map.into_iter().zip_eq(&proof.0) {
             let mut comms_to_combine: Vec<&'_ LabeledCommitment<_>> = Vec::new();
@@ -456,9 +472,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             }
 
             Self::accumulate_elems(
-                &mut combined_comms,
-                &mut combined_witness,
-                &mut combined_adjusted_witness,
+                &mut check,
                 vk,
                 comms_to_combine.into_iter(),
                 *query,
@@ -471,9 +485,8 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             randomizer = fs_rng.squeeze_short_nonnative_field_element::<E::Fr>();
         }
 
-        let result = Self::check_elems(combined_comms, combined_witness, combined_adjusted_witness, vk);
         end_timer!(batch_check_time);
-        result
+        Ok(check)
     }
 
     pub fn open_combinations<'a>(
@@ -565,6 +578,32 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         proof: &BatchLCProof<E>,
         fs_rng: &mut S,
     ) -> Result<bool, PCError>
+    where
+        Commitment<E>: 'a,
+    {
+        let check = Self::check_combinations_elems(
+            vk,
+            linear_combinations,
+            commitments,
+            query_set,
+            evaluations,
+            proof,
+            fs_rng,
+        )?;
+        Self::check_elems(check, vk)
+    }
+
+    /// Performs the checks of `check_combinations` that do not need a pairing,
+    /// and returns the pairing equation that remains to be checked.
+    pub fn check_combinations_elems<'a>(
+        vk: &VerifierKey<E>,
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<E::Fr>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        evaluations: &Evaluations<E::Fr>,
+        proof: &BatchLCProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<PairingCheck<E>, PCError>
     where
         Commitment<E>: 'a,
     {
@@ -620,7 +659,39 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             .collect::<Vec<_>>();
         end_timer!(combined_comms_norm_time);
 
-        Self::batch_check(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
+        Self::batch_check_elems(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
+    }
+
+    /// Checks the pairing equations returned by `batch_check_elems` or `check_combinations_elems`
+    /// with a single multi-pairing, after combining them with random scalars from `rng`.
+    /// Equations under verifier keys trimmed from the same universal parameters are merged.
+    pub fn check_elems_batch<'a, R: RngCore>(
+        checks: impl IntoIterator<Item = (&'a VerifierKey<E>, PairingCheck<E>)>,
+        rng: &mut R,
+    ) -> Result<bool, PCError> {
+        let check_time = start_timer!(|| "Checking pairing equations in batch");
+        let mut merged: Vec<(Vec<&VerifierKey<E>>, PairingCheck<E>)> = Vec::new();
+        for (i, (vk, mut check)) in checks.into_iter().enumerate() {
+            if i > 0 {
+                check.scale(E::Fr::rand(rng));
+            }
+            match merged.iter_mut().find(|(vks, _)| vks[0].shares_srs_with(vk)) {
+                Some((vks, merged_check)) => {
+                    vks.push(vk);
+                    merged_check.merge(check);
+                }
+                None => merged.push((vec![vk], check)),
+            }
+        }
+
+        let mut g1_projective_elems = Vec::new();
+        let mut g2_prepared_elems = Vec::new();
+        for (vks, check) in merged {
+            Self::pairing_elems(check, &vks, &mut g1_projective_elems, &mut g2_prepared_elems)?;
+        }
+        let is_one = Self::product_of_pairings(g1_projective_elems, &g2_prepared_elems);
+        end_timer!(check_time);
+        Ok(is_one)
     }
 }
 
@@ -660,9 +731,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
 impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
     #[allow(clippy::too_many_arguments)]
     fn accumulate_elems<'a>(
-        combined_comms: &mut BTreeMap<Option<usize>, E::G1Projective>,
-        combined_witness: &mut E::G1Projective,
-        combined_adjusted_witness: &mut E::G1Projective,
+        check: &mut PairingCheck<E>,
         vk: &VerifierKey<E>,
         commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
         point: E::Fr,
@@ -690,7 +759,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             let comm_with_challenge: E::G1Projective = comm.0.mul(coeff);
 
             // Accumulate values in the BTreeMap
-            *combined_comms.entry(degree_bound).or_insert_with(E::G1Projective::zero) += &comm_with_challenge;
+            *check.combined_comms.entry(degree_bound).or_insert_with(E::G1Projective::zero) += &comm_with_challenge;
             end_timer!(acc_timer);
         }
 
@@ -701,31 +770,42 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             bases.push(vk.vk.gamma_g);
             coeffs.push(random_v);
         }
-        *combined_witness += if let Some(randomizer) = randomizer {
+        check.combined_witness += if let Some(randomizer) = randomizer {
             coeffs.iter_mut().for_each(|c| *c *= randomizer);
             proof.w.mul(randomizer)
         } else {
             proof.w.to_projective()
         };
         let coeffs = coeffs.into_iter().map(|c| c.into()).collect::<Vec<_>>();
-        *combined_adjusted_witness += VariableBase::msm(&bases, &coeffs);
+        check.combined_adjusted_witness += VariableBase::msm(&bases, &coeffs);
         end_timer!(acc_time);
     }
 
-    #[allow(clippy::type_complexity)]
-    fn check_elems(
-        combined_comms: BTreeMap<Option<usize>, E::G1Projective>,
-        combined_witness: E::G1Projective,
-        combined_adjusted_witness: E::G1Projective,
-        vk: &VerifierKey<E>,
-    ) -> Result<bool, PCError> {
+    /// Checks the pairing equation returned by `batch_check_elems` or `check_combinations_elems`.
+    pub fn check_elems(check: PairingCheck<E>, vk: &VerifierKey<E>) -> Result<bool, PCError> {
         let check_time = start_timer!(|| "Checking elems");
-        let mut g1_projective_elems = Vec::with_capacity(combined_comms.len() + 2);
-        let mut g2_prepared_elems = Vec::with_capacity(combined_comms.len() + 2);
+        let mut g1_projective_elems = Vec::with_capacity(check.combined_comms.len() + 2);
+        let mut g2_prepared_elems = Vec::with_capacity(check.combined_comms.len() + 2);
+        Self::pairing_elems(check, &[vk], &mut g1_projective_elems, &mut g2_prepared_elems)?;
+        let is_one = Self::product_of_pairings(g1_projective_elems, &g2_prepared_elems);
+        end_timer!(check_time);
+        Ok(is_one)
+    }
 
-        for (degree_bound, comm) in combined_comms.into_iter() {
+    /// Appends the pairing inputs of `check` to `g1_projective_elems` and `g2_prepared_elems`,
+    /// looking up the shift power of each degree bound in any of `vks`.
+    fn pairing_elems(
+        check: PairingCheck<E>,
+        vks: &[&VerifierKey<E>],
+        g1_projective_elems: &mut Vec<E::G1Projective>,
+        g2_prepared_elems: &mut Vec<<E::G2Affine as PairingCurve>::Prepared>,
+    ) -> Result<(), PCError> {
+        let vk = vks[0];
+        for (degree_bound, comm) in check.combined_comms.into_iter() {
             let shift_power = if let Some(degree_bound) = degree_bound {
-                vk.get_prepared_shift_power(degree_bound).ok_or(PCError::UnsupportedDegreeBound(degree_bound))?
+                vks.iter()
+                    .find_map(|vk| vk.get_prepared_shift_power(degree_bound))
+                    .ok_or(PCError::UnsupportedDegreeBound(degree_bound))?
             } else {
                 vk.vk.prepared_h.clone()
             };
@@ -734,21 +814,25 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             g2_prepared_elems.push(shift_power);
         }
 
-        g1_projective_elems.push(-combined_adjusted_witness);
+        g1_projective_elems.push(-check.combined_adjusted_witness);
         g2_prepared_elems.push(vk.vk.prepared_h.clone());
 
-        g1_projective_elems.push(-combined_witness);
+        g1_projective_elems.push(-check.combined_witness);
         g2_prepared_elems.push(vk.vk.prepared_beta_h.clone());
+        Ok(())
+    }
 
+    fn product_of_pairings(
+        g1_projective_elems: Vec<E::G1Projective>,
+        g2_prepared_elems: &[<E::G2Affine as PairingCurve>::Prepared],
+    ) -> bool {
         let g1_prepared_elems_iter = E::G1Projective::batch_normalization_into_affine(g1_projective_elems)
             .into_iter()
             .map(|a| a.prepare())
             .collect::<Vec<_>>();
 
         let g1_g2_prepared = g1_prepared_elems_iter.iter().zip_eq(g2_prepared_elems.iter());
-        let is_one: bool = E::product_of_pairings(g1_g2_prepared).is_one();
-        end_timer!(check_time);
-        Ok(is_one)
+        E::product_of_pairings(g1_g2_prepared).is_one()
     }
 }
 
@@ -20,13 +20,18 @@ use crate::{
 };
 use snarkvm_curves::PairingEngine;
 use snarkvm_utilities::{
+    error,
     io::{self, Read, Write},
     serialize::*,
     FromBytes,
     ToBytes,
 };
+#[cfg(not(target_family = "wasm"))]
+use snarkvm_utilities::{MappedReader, ZeroCopyWriter};
 
 use std::sync::Arc;
+#[cfg(not(target_family = "wasm"))]
+use std::{fs::File, path::Path};
 
 /// Proving key for a specific circuit (i.e., R1CS matrices).
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -41,6 +46,48 @@ pub struct CircuitProvingKey<E: PairingEngine, MM: MarlinMode> {
     pub committer_key: Arc<sonic_pc::CommitterKey<E>>,
 }
 
+impl<E: PairingEngine, MM: MarlinMode> CircuitProvingKey<E, MM> {
+    /// Writes the proving key to the file at the given path, in the zero-copy layout.
+    /// The file can then be memory-mapped with `load_mapped`.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn write_mapped<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut writer = ZeroCopyWriter::new(io::BufWriter::new(File::create(path)?))?;
+        CanonicalSerialize::serialize_compressed(&self.circuit_verifying_key, &mut writer)?;
+        CanonicalSerialize::serialize_compressed(&self.circuit_commitment_randomness, &mut writer)?;
+        CanonicalSerialize::serialize_compressed(&self.circuit, &mut writer)?;
+        self.committer_key.write_mapped(&mut writer)?;
+        writer.flush()
+    }
+
+    /// Loads a proving key from the file at the given path, which was written by `write_mapped`.
+    /// The committer key is memory-mapped rather than read into memory, and is validated once, on load.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn load_mapped<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::read_mapped(MappedReader::open(path)?)
+    }
+
+    /// Loads a proving key from the file at the given path, which was written by `write_mapped`,
+    /// and was already validated by an earlier `load_mapped`. The committer key is not revalidated.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn load_mapped_prevalidated<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::read_mapped(MappedReader::open_prevalidated(path)?)
+    }
+
+    /// Reads a proving key from the given reader, over a file in the zero-copy layout.
+    #[cfg(not(target_family = "wasm"))]
+    fn read_mapped(mut reader: MappedReader) -> io::Result<Self> {
+        let circuit_verifying_key = CanonicalDeserialize::deserialize_compressed(&mut reader)?;
+        let circuit_commitment_randomness = CanonicalDeserialize::deserialize_compressed(&mut reader)?;
+        let circuit = CanonicalDeserialize::deserialize_compressed(&mut reader)?;
+        let committer_key = Arc::new(sonic_pc::CommitterKey::read_mapped(&mut reader)?);
+        if !reader.is_empty() {
+            return Err(error("The proving key contains trailing bytes"));
+        }
+
+        Ok(Self { circuit_verifying_key, circuit_commitment_randomness, circuit, committer_key })
+    }
+}
+
 impl<E: PairingEngine, MM: MarlinMode> ToBytes for CircuitProvingKey<E, MM> {
     fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
         CanonicalSerialize::serialize_compressed(&self.circuit_verifying_key, &mut writer)?;
@@ -0,0 +1,103 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{snark::marlin::Proof, SNARKError};
+
+use snarkvm_curves::PairingEngine;
+use snarkvm_utilities::{
+    error,
+    io::{self, Read, Write},
+    serialize::*,
+    FromBytes,
+    ToBytes,
+};
+
+/// The zkSNARK proofs for batches of instances across several circuits.
+///
+/// The per-circuit proofs share a single Fiat-Shamir transcript,
+/// so they are only valid when verified together, in the order they were proven.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HeterogeneousProofs<E: PairingEngine> {
+    /// The proof for each circuit, in the order the circuits were given to the prover.
+    proofs: Vec<Proof<E>>,
+}
+
+impl<E: PairingEngine> HeterogeneousProofs<E> {
+    /// Construct new heterogeneous proofs.
+    pub fn new(proofs: Vec<Proof<E>>) -> Result<Self, SNARKError> {
+        if proofs.is_empty() {
+            return Err(SNARKError::EmptyBatch);
+        }
+        Ok(Self { proofs })
+    }
+
+    /// Returns the number of circuits being proven.
+    pub fn num_circuits(&self) -> usize {
+        self.proofs.len()
+    }
+
+    /// Returns the proof for each circuit.
+    pub fn proofs(&self) -> &[Proof<E>] {
+        &self.proofs
+    }
+
+    /// Returns the proof for each circuit, consuming `self`.
+    pub fn into_proofs(self) -> Vec<Proof<E>> {
+        self.proofs
+    }
+}
+
+impl<E: PairingEngine> CanonicalSerialize for HeterogeneousProofs<E> {
+    fn serialize_with_mode<W: Write>(&self, mut writer: W, compress: Compress) -> Result<(), SerializationError> {
+        CanonicalSerialize::serialize_with_mode(&self.proofs, &mut writer, compress)
+    }
+
+    fn serialized_size(&self, mode: Compress) -> usize {
+        CanonicalSerialize::serialized_size(&self.proofs, mode)
+    }
+}
+
+impl<E: PairingEngine> Valid for HeterogeneousProofs<E> {
+    fn check(&self) -> Result<(), SerializationError> {
+        if self.proofs.is_empty() {
+            return Err(SerializationError::InvalidData);
+        }
+        self.proofs.check()
+    }
+}
+
+impl<E: PairingEngine> CanonicalDeserialize for HeterogeneousProofs<E> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let proofs: Vec<Proof<E>> = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        Self::new(proofs).map_err(|_| SerializationError::InvalidData)
+    }
+}
+
+impl<E: PairingEngine> ToBytes for HeterogeneousProofs<E> {
+    fn write_le<W: Write>(&self, mut w: W) -> io::Result<()> {
+        Self::serialize_compressed(self, &mut w).map_err(|_| error("could not serialize HeterogeneousProofs"))
+    }
+}
+
+impl<E: PairingEngine> FromBytes for HeterogeneousProofs<E> {
+    fn read_le<R: Read>(mut r: R) -> io::Result<Self> {
+        Self::deserialize_compressed(&mut r).map_err(|_| error("could not deserialize HeterogeneousProofs"))
+    }
+}
@@ -34,6 +34,10 @@ pub use prepared_circuit_verifying_key::*;
 pub(super) mod proof;
 pub use proof::*;
 
+/// The Marlin zkSNARK proofs for batches across several circuits.
+pub(super) mod heterogeneous_proofs;
+pub use heterogeneous_proofs::*;
+
 /// The Marlin universal SRS.
 pub(super) mod universal_srs;
 pub use universal_srs::*;
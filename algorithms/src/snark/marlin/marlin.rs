@@ -16,7 +16,15 @@
 
 use crate::{
     fft::EvaluationDomain,
-    polycommit::sonic_pc::{Commitment, Evaluations, LabeledCommitment, QuerySet, Randomness, SonicKZG10},
+    polycommit::sonic_pc::{
+        Commitment,
+        Evaluations,
+        LabeledCommitment,
+        PairingCheck,
+        QuerySet,
+        Randomness,
+        SonicKZG10,
+    },
     snark::marlin::{
         ahp::{AHPError, AHPForR1CS, EvaluationsProvider},
         proof,
@@ -24,8 +32,10 @@ use crate::{
         witness_label,
         CircuitProvingKey,
         CircuitVerifyingKey,
+        HeterogeneousProofs,
         MarlinError,
         MarlinMode,
+        PreparedCircuitVerifyingKey,
         Proof,
         UniversalSRS,
     },
@@ -138,270 +148,133 @@ impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, MM: MarlinMode> MarlinSNAR
         Ok((circuit_proving_key, circuit_verifying_key))
     }
 
-    fn terminate(terminator: &AtomicBool) -> Result<(), MarlinError> {
-        if terminator.load(Ordering::Relaxed) { Err(MarlinError::Terminated) } else { Ok(()) }
-    }
-
-    fn init_sponge(
-        fs_parameters: &FS::Parameters,
-        batch_size: usize,
-        circuit_commitments: &[crate::polycommit::sonic_pc::Commitment<E>],
-        inputs: &[Vec<E::Fr>],
-    ) -> FS {
-        let mut sponge = FS::new_with_parameters(fs_parameters);
-        sponge.absorb_bytes(&to_bytes_le![&Self::PROTOCOL_NAME].unwrap());
-        sponge.absorb_bytes(&batch_size.to_le_bytes());
-        sponge.absorb_native_field_elements(circuit_commitments);
-        for input in inputs {
-            sponge.absorb_nonnative_field_elements(input.iter().copied());
-        }
-        sponge
-    }
-
-    fn init_sponge_for_certificate(
+    /// Returns a proof for each of the given batches of circuits, each proven under its own proving key.
+    pub fn prove_heterogeneous_batch<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
         fs_parameters: &FS::Parameters,
-        circuit_commitments: &[crate::polycommit::sonic_pc::Commitment<E>],
-    ) -> FS {
-        let mut sponge = FS::new_with_parameters(fs_parameters);
-        sponge.absorb_bytes(&to_bytes_le![&Self::PROTOCOL_NAME].unwrap());
-        sponge.absorb_native_field_elements(circuit_commitments);
-        sponge
-    }
-
-    fn absorb_labeled_with_msg(
-        comms: &[LabeledCommitment<Commitment<E>>],
-        message: &prover::ThirdMessage<E::Fr>,
-        sponge: &mut FS,
-    ) {
-        let commitments: Vec<_> = comms.iter().map(|c| *c.commitment()).collect();
-        Self::absorb_with_msg(&commitments, message, sponge)
-    }
-
-    fn absorb_labeled(comms: &[LabeledCommitment<Commitment<E>>], sponge: &mut FS) {
-        let commitments: Vec<_> = comms.iter().map(|c| *c.commitment()).collect();
-        Self::absorb(&commitments, sponge);
-    }
-
-    fn absorb(commitments: &[Commitment<E>], sponge: &mut FS) {
-        let sponge_time = start_timer!(|| "Absorbing commitments");
-        sponge.absorb_native_field_elements(commitments);
-        end_timer!(sponge_time);
-    }
-
-    fn absorb_with_msg(commitments: &[Commitment<E>], msg: &prover::ThirdMessage<E::Fr>, sponge: &mut FS) {
-        let sponge_time = start_timer!(|| "Absorbing commitments and message");
-        Self::absorb(commitments, sponge);
-        sponge.absorb_nonnative_field_elements([msg.sum_a, msg.sum_b, msg.sum_c]);
-        end_timer!(sponge_time);
-    }
-}
-
-impl<E: PairingEngine, FS, MM> SNARK for MarlinSNARK<E, FS, MM>
-where
-    E::Fr: PrimeField,
-    E::Fq: PrimeField,
-    FS: AlgebraicSponge<E::Fq, 2>,
-    MM: MarlinMode,
-{
-    type BaseField = E::Fq;
-    type Certificate = Certificate<E>;
-    type FSParameters = FS::Parameters;
-    type FiatShamirRng = FS;
-    type Proof = Proof<E>;
-    type ProvingKey = CircuitProvingKey<E, MM>;
-    type ScalarField = E::Fr;
-    type UniversalSetupConfig = usize;
-    type UniversalSetupParameters = UniversalSRS<E>;
-    type VerifierInput = [E::Fr];
-    type VerifyingKey = CircuitVerifyingKey<E, MM>;
-
-    fn universal_setup(max_degree: &Self::UniversalSetupConfig) -> Result<Self::UniversalSetupParameters, SNARKError> {
-        let setup_time = start_timer!(|| { format!("Marlin::UniversalSetup with max_degree {}", max_degree,) });
-
-        let srs = SonicKZG10::<E, FS>::load_srs(*max_degree).map_err(Into::into);
-        end_timer!(setup_time);
-        srs
-    }
-
-    fn setup<C: ConstraintSynthesizer<E::Fr>>(
-        circuit: &C,
-        srs: &mut SRS<Self::UniversalSetupParameters>,
-    ) -> Result<(Self::ProvingKey, Self::VerifyingKey), SNARKError> {
-        match srs {
-            SRS::CircuitSpecific => Self::circuit_specific_setup(circuit),
-            SRS::Universal(srs) => Self::circuit_setup(srs, circuit),
-        }
-        .map_err(SNARKError::from)
-    }
-
-    fn prove_vk(
-        fs_parameters: &Self::FSParameters,
-        verifying_key: &Self::VerifyingKey,
-        proving_key: &Self::ProvingKey,
-    ) -> Result<Self::Certificate, SNARKError> {
-        // Initialize sponge
-        let mut sponge = Self::init_sponge_for_certificate(fs_parameters, &verifying_key.circuit_commitments);
-        // Compute challenges for linear combination, and the point to evaluate the polynomials at.
-        // The linear combination requires `num_polynomials - 1` coefficients
-        // (since the first coeff is 1), and so we squeeze out `num_polynomials` points.
-        let mut challenges = sponge.squeeze_nonnative_field_elements(verifying_key.circuit_commitments.len());
-        let point = challenges.pop().unwrap();
-        let one = E::Fr::one();
-        let linear_combination_challenges = core::iter::once(&one).chain(challenges.iter());
-
-        // We will construct a linear combination and provide a proof of evaluation of the lc at `point`.
-        let mut lc = crate::polycommit::sonic_pc::LinearCombination::empty("circuit_check");
-        for (poly, &c) in proving_key.circuit.iter().zip(linear_combination_challenges) {
-            lc.add(c, poly.label());
-        }
-
-        let query_set = QuerySet::from_iter([("circuit_check".into(), ("challenge".into(), point))]);
-        let commitments = verifying_key
-            .iter()
-            .cloned()
-            .zip_eq(AHPForR1CS::<E::Fr, MM>::index_polynomial_info().values())
-            .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
-            .collect::<Vec<_>>();
-
-        let certificate = SonicKZG10::<E, FS>::open_combinations(
-            &proving_key.committer_key,
-            &[lc],
-            proving_key.circuit.iter(),
-            &commitments,
-            &query_set,
-            &proving_key.circuit_commitment_randomness.clone(),
-            &mut sponge,
-        )?;
-
-        Ok(Self::Certificate::new(certificate))
-    }
-
-    fn verify_vk<C: ConstraintSynthesizer<Self::ScalarField>>(
-        fs_parameters: &Self::FSParameters,
-        circuit: &C,
-        verifying_key: &Self::VerifyingKey,
-        certificate: &Self::Certificate,
-    ) -> Result<bool, SNARKError> {
-        let info = AHPForR1CS::<E::Fr, MM>::index_polynomial_info();
-        // Initialize sponge.
-        let mut sponge = Self::init_sponge_for_certificate(fs_parameters, &verifying_key.circuit_commitments);
-        // Compute challenges for linear combination, and the point to evaluate the polynomials at.
-        // The linear combination requires `num_polynomials - 1` coefficients
-        // (since the first coeff is 1), and so we squeeze out `num_polynomials` points.
-        let mut challenges = sponge.squeeze_nonnative_field_elements(verifying_key.circuit_commitments.len());
-        let point = challenges.pop().unwrap();
-
-        let evaluations_at_point = AHPForR1CS::<E::Fr, MM>::evaluate_index_polynomials(circuit, point)?;
-        let one = E::Fr::one();
-        let linear_combination_challenges = core::iter::once(&one).chain(challenges.iter());
-
-        // We will construct a linear combination and provide a proof of evaluation of the lc at `point`.
-        let mut lc = crate::polycommit::sonic_pc::LinearCombination::empty("circuit_check");
-        let mut evaluation = E::Fr::zero();
-        for ((label, &c), eval) in info.keys().zip_eq(linear_combination_challenges).zip_eq(evaluations_at_point) {
-            lc.add(c, label.as_str());
-            evaluation += c * eval;
-        }
-
-        let query_set = QuerySet::from_iter([("circuit_check".into(), ("challenge".into(), point))]);
-        let commitments = verifying_key
-            .iter()
-            .cloned()
-            .zip_eq(info.values())
-            .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
-            .collect::<Vec<_>>();
-        let evaluations = Evaluations::from_iter([(("circuit_check".into(), point), evaluation)]);
-
-        SonicKZG10::<E, FS>::check_combinations(
-            &verifying_key.verifier_key,
-            &[lc],
-            &commitments,
-            &query_set,
-            &evaluations,
-            &certificate.pc_proof,
-            &mut sponge,
+        keys_to_constraints: &[(&CircuitProvingKey<E, MM>, &[C])],
+        zk_rng: &mut R,
+    ) -> Result<HeterogeneousProofs<E>, SNARKError> {
+        Self::prove_heterogeneous_batch_with_terminator(
+            fs_parameters,
+            keys_to_constraints,
+            &AtomicBool::new(false),
+            zk_rng,
         )
-        .map_err(Into::into)
     }
 
-    #[allow(clippy::only_used_in_recursion)]
-    fn prove_batch_with_terminator<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
-        fs_parameters: &Self::FSParameters,
-        circuit_proving_key: &CircuitProvingKey<E, MM>,
-        circuits: &[C],
+    /// Returns a proof for each of the given batches of circuits, each proven under its own proving key.
+    /// The AHP rounds and the polynomial commitment openings of all circuits share one Fiat-Shamir transcript.
+    pub fn prove_heterogeneous_batch_with_terminator<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        fs_parameters: &FS::Parameters,
+        keys_to_constraints: &[(&CircuitProvingKey<E, MM>, &[C])],
         terminator: &AtomicBool,
         zk_rng: &mut R,
-    ) -> Result<Self::Proof, SNARKError> {
-        let prover_time = start_timer!(|| "Marlin::Prover");
-        let batch_size = circuits.len();
-        if batch_size == 0 {
+    ) -> Result<HeterogeneousProofs<E>, SNARKError> {
+        let prover_time = start_timer!(|| format!("Marlin::Prover with {} circuits", keys_to_constraints.len()));
+        if keys_to_constraints.is_empty() || keys_to_constraints.iter().any(|(_, circuits)| circuits.is_empty()) {
             return Err(SNARKError::EmptyBatch);
         }
 
         Self::terminate(terminator)?;
 
-        let prover_state = AHPForR1CS::<_, MM>::init_prover(&circuit_proving_key.circuit, circuits)?;
-        let public_input = prover_state.public_inputs();
-        let padded_public_input = prover_state.padded_public_inputs();
-        assert_eq!(prover_state.batch_size, batch_size);
+        let prover_states = keys_to_constraints
+            .iter()
+            .map(|(circuit_proving_key, circuits)| {
+                AHPForR1CS::<_, MM>::init_prover(&circuit_proving_key.circuit, circuits)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let public_inputs: Vec<_> = prover_states.iter().map(|state| state.public_inputs()).collect();
+        let padded_public_inputs: Vec<_> = prover_states.iter().map(|state| state.padded_public_inputs()).collect();
+        let batch_sizes: Vec<_> = prover_states.iter().map(|state| state.batch_size).collect();
 
         let mut sponge = Self::init_sponge(
             fs_parameters,
-            batch_size,
-            &circuit_proving_key.circuit_verifying_key.circuit_commitments,
-            &padded_public_input,
+            keys_to_constraints
+                .iter()
+                .map(|(circuit_proving_key, _)| &circuit_proving_key.circuit_verifying_key.circuit_commitments[..])
+                .zip_eq(&padded_public_inputs),
         );
 
         // --------------------------------------------------------------------
         // First round
 
         Self::terminate(terminator)?;
-        let mut prover_state = AHPForR1CS::<_, MM>::prover_first_round(prover_state, zk_rng)?;
+        let mut prover_states = prover_states
+            .into_iter()
+            .map(|state| AHPForR1CS::<_, MM>::prover_first_round(state, zk_rng))
+            .collect::<Result<Vec<_>, _>>()?;
         Self::terminate(terminator)?;
 
         let first_round_comm_time = start_timer!(|| "Committing to first round polys");
-        let (first_commitments, first_commitment_randomnesses) = {
+        let mut first_commitments = Vec::with_capacity(keys_to_constraints.len());
+        let mut first_commitment_randomnesses = Vec::with_capacity(keys_to_constraints.len());
+        for ((circuit_proving_key, _), prover_state) in keys_to_constraints.iter().zip_eq(&mut prover_states) {
             let first_round_oracles = Arc::get_mut(prover_state.first_round_oracles.as_mut().unwrap()).unwrap();
-            SonicKZG10::<E, FS>::commit(
+            let (commitments, randomnesses) = SonicKZG10::<E, FS>::commit(
                 &circuit_proving_key.committer_key,
                 first_round_oracles.iter_for_commit(),
                 Some(zk_rng),
-            )?
-        };
+            )?;
+            first_commitments.push(commitments);
+            first_commitment_randomnesses.push(randomnesses);
+        }
         end_timer!(first_round_comm_time);
 
-        Self::absorb_labeled(&first_commitments, &mut sponge);
+        first_commitments.iter().for_each(|commitments| Self::absorb_labeled(commitments, &mut sponge));
         Self::terminate(terminator)?;
 
-        let (verifier_first_message, verifier_state) = AHPForR1CS::<_, MM>::verifier_first_round(
-            circuit_proving_key.circuit_verifying_key.circuit_info,
-            batch_size,
-            &mut sponge,
-        )?;
+        let (verifier_first_msgs, verifier_states): (Vec<_>, Vec<_>) = keys_to_constraints
+            .iter()
+            .zip_eq(&batch_sizes)
+            .map(|((circuit_proving_key, _), batch_size)| {
+                AHPForR1CS::<_, MM>::verifier_first_round(
+                    circuit_proving_key.circuit_verifying_key.circuit_info,
+                    *batch_size,
+                    &mut sponge,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .unzip();
         // --------------------------------------------------------------------
 
         // --------------------------------------------------------------------
         // Second round
 
         Self::terminate(terminator)?;
-        let (second_oracles, prover_state) =
-            AHPForR1CS::<_, MM>::prover_second_round(&verifier_first_message, prover_state, zk_rng);
+        let (second_oracles, prover_states): (Vec<_>, Vec<_>) = prover_states
+            .into_iter()
+            .zip_eq(&verifier_first_msgs)
+            .map(|(state, msg)| AHPForR1CS::<_, MM>::prover_second_round(msg, state, zk_rng))
+            .unzip();
         Self::terminate(terminator)?;
 
         let second_round_comm_time = start_timer!(|| "Committing to second round polys");
-        let (second_commitments, second_commitment_randomnesses) = SonicKZG10::<E, FS>::commit_with_terminator(
-            &circuit_proving_key.committer_key,
-            second_oracles.iter().map(Into::into),
-            terminator,
-            Some(zk_rng),
-        )?;
+        let (second_commitments, second_commitment_randomnesses): (Vec<_>, Vec<_>) = keys_to_constraints
+            .iter()
+            .zip_eq(&second_oracles)
+            .map(|((circuit_proving_key, _), oracles)| {
+                SonicKZG10::<E, FS>::commit_with_terminator(
+                    &circuit_proving_key.committer_key,
+                    oracles.iter().map(Into::into),
+                    terminator,
+                    Some(zk_rng),
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .unzip();
         end_timer!(second_round_comm_time);
 
-        Self::absorb_labeled(&second_commitments, &mut sponge);
+        second_commitments.iter().for_each(|commitments| Self::absorb_labeled(commitments, &mut sponge));
         Self::terminate(terminator)?;
 
-        let (verifier_second_msg, verifier_state) =
-            AHPForR1CS::<_, MM>::verifier_second_round(verifier_state, &mut sponge)?;
+        let (verifier_second_msgs, verifier_states): (Vec<_>, Vec<_>) = verifier_states
+            .into_iter()
+            .map(|state| AHPForR1CS::<_, MM>::verifier_second_round(state, &mut sponge))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .unzip();
         // --------------------------------------------------------------------
 
         // --------------------------------------------------------------------
@@ -409,23 +282,45 @@ where
 
         Self::terminate(terminator)?;
 
-        let (prover_third_message, third_oracles, prover_state) =
-            AHPForR1CS::<_, MM>::prover_third_round(&verifier_second_msg, prover_state, zk_rng)?;
+        let mut prover_third_messages = Vec::with_capacity(keys_to_constraints.len());
+        let mut third_oracles = Vec::with_capacity(keys_to_constraints.len());
+        let mut next_prover_states = Vec::with_capacity(keys_to_constraints.len());
+        for (state, msg) in prover_states.into_iter().zip_eq(&verifier_second_msgs) {
+            let (prover_message, oracles, state) = AHPForR1CS::<_, MM>::prover_third_round(msg, state, zk_rng)?;
+            prover_third_messages.push(prover_message);
+            third_oracles.push(oracles);
+            next_prover_states.push(state);
+        }
+        let prover_states = next_prover_states;
         Self::terminate(terminator)?;
 
         let third_round_comm_time = start_timer!(|| "Committing to third round polys");
-        let (third_commitments, third_commitment_randomnesses) = SonicKZG10::<E, FS>::commit_with_terminator(
-            &circuit_proving_key.committer_key,
-            third_oracles.iter().map(Into::into),
-            terminator,
-            Some(zk_rng),
-        )?;
+        let (third_commitments, third_commitment_randomnesses): (Vec<_>, Vec<_>) = keys_to_constraints
+            .iter()
+            .zip_eq(&third_oracles)
+            .map(|((circuit_proving_key, _), oracles)| {
+                SonicKZG10::<E, FS>::commit_with_terminator(
+                    &circuit_proving_key.committer_key,
+                    oracles.iter().map(Into::into),
+                    terminator,
+                    Some(zk_rng),
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .unzip();
         end_timer!(third_round_comm_time);
 
-        Self::absorb_labeled_with_msg(&third_commitments, &prover_third_message, &mut sponge);
+        for (commitments, prover_message) in third_commitments.iter().zip_eq(&prover_third_messages) {
+            Self::absorb_labeled_with_msg(commitments, prover_message, &mut sponge);
+        }
 
-        let (verifier_third_msg, verifier_state) =
-            AHPForR1CS::<_, MM>::verifier_third_round(verifier_state, &mut sponge)?;
+        let (verifier_third_msgs, verifier_states): (Vec<_>, Vec<_>) = verifier_states
+            .into_iter()
+            .map(|state| AHPForR1CS::<_, MM>::verifier_third_round(state, &mut sponge))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .unzip();
         // --------------------------------------------------------------------
 
         // --------------------------------------------------------------------
@@ -433,219 +328,467 @@ where
 
         Self::terminate(terminator)?;
 
-        let first_round_oracles = Arc::clone(prover_state.first_round_oracles.as_ref().unwrap());
-        let fourth_oracles = AHPForR1CS::<_, MM>::prover_fourth_round(&verifier_third_msg, prover_state, zk_rng)?;
+        let first_round_oracles: Vec<_> =
+            prover_states.iter().map(|state| Arc::clone(state.first_round_oracles.as_ref().unwrap())).collect();
+        let fourth_oracles = prover_states
+            .into_iter()
+            .zip_eq(&verifier_third_msgs)
+            .map(|(state, msg)| AHPForR1CS::<_, MM>::prover_fourth_round(msg, state, zk_rng))
+            .collect::<Result<Vec<_>, _>>()?;
         Self::terminate(terminator)?;
 
         let fourth_round_comm_time = start_timer!(|| "Committing to fourth round polys");
-        let (fourth_commitments, fourth_commitment_randomnesses) = SonicKZG10::<E, FS>::commit_with_terminator(
-            &circuit_proving_key.committer_key,
-            fourth_oracles.iter().map(Into::into),
-            terminator,
-            Some(zk_rng),
-        )?;
+        let (fourth_commitments, fourth_commitment_randomnesses): (Vec<_>, Vec<_>) = keys_to_constraints
+            .iter()
+            .zip_eq(&fourth_oracles)
+            .map(|((circuit_proving_key, _), oracles)| {
+                SonicKZG10::<E, FS>::commit_with_terminator(
+                    &circuit_proving_key.committer_key,
+                    oracles.iter().map(Into::into),
+                    terminator,
+                    Some(zk_rng),
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .unzip();
         end_timer!(fourth_round_comm_time);
 
-        Self::absorb_labeled(&fourth_commitments, &mut sponge);
+        fourth_commitments.iter().for_each(|commitments| Self::absorb_labeled(commitments, &mut sponge));
 
-        let verifier_state = AHPForR1CS::<_, MM>::verifier_fourth_round(verifier_state, &mut sponge)?;
+        let verifier_states = verifier_states
+            .into_iter()
+            .map(|state| AHPForR1CS::<_, MM>::verifier_fourth_round(state, &mut sponge))
+            .collect::<Result<Vec<_>, _>>()?;
         // --------------------------------------------------------------------
 
         Self::terminate(terminator)?;
 
-        // Gather prover polynomials in one vector.
-        let polynomials: Vec<_> = circuit_proving_key
-            .circuit
-            .iter() // 12 items
-            .chain(first_round_oracles.iter_for_open()) // 3 * batch_size + (MM::ZK as usize) items
-            .chain(second_oracles.iter())// 2 items
-            .chain(third_oracles.iter())// 3 items
-            .chain(fourth_oracles.iter())// 1 item
-            .collect();
+        // Evaluate the linear combinations of each circuit over its query set.
+        let mut openings = Vec::with_capacity(keys_to_constraints.len());
+        for (i, ((circuit_proving_key, _), verifier_state)) in
+            keys_to_constraints.iter().zip_eq(verifier_states).enumerate()
+        {
+            // Gather prover polynomials in one vector.
+            let polynomials: Vec<_> = circuit_proving_key
+                .circuit
+                .iter() // 12 items
+                .chain(first_round_oracles[i].iter_for_open()) // 3 * batch_size + (MM::ZK as usize) items
+                .chain(second_oracles[i].iter())// 2 items
+                .chain(third_oracles[i].iter())// 3 items
+                .chain(fourth_oracles[i].iter())// 1 item
+                .collect();
+
+            // Compute the AHP verifier's query set.
+            let (query_set, verifier_state) = AHPForR1CS::<_, MM>::verifier_query_set(verifier_state);
+            let lc_s = AHPForR1CS::<_, MM>::construct_linear_combinations(
+                &public_inputs[i],
+                &polynomials,
+                &prover_third_messages[i],
+                &verifier_state,
+            )?;
 
-        Self::terminate(terminator)?;
+            Self::terminate(terminator)?;
 
-        // Gather commitments in one vector.
-        let witness_commitments = first_commitments.chunks_exact(3);
-        let mask_poly = MM::ZK.then(|| *witness_commitments.remainder()[0].commitment());
-        let witness_commitments = witness_commitments
-            .map(|c| proof::WitnessCommitments {
-                w: *c[0].commitment(),
-                z_a: *c[1].commitment(),
-                z_b: *c[2].commitment(),
-            })
-            .collect();
-        #[rustfmt::skip]
-        let commitments = proof::Commitments {
-            witness_commitments,
-            mask_poly,
+            let eval_time = start_timer!(|| "Evaluating linear combinations over query set");
+            let mut evaluations = std::collections::BTreeMap::new();
+            for (label, (_, point)) in query_set.to_set() {
+                if !AHPForR1CS::<E::Fr, MM>::LC_WITH_ZERO_EVAL.contains(&label.as_str()) {
+                    let lc = lc_s.get(&label).ok_or_else(|| AHPError::MissingEval(label.to_string()))?;
+                    let evaluation = polynomials.get_lc_eval(lc, point)?;
+                    evaluations.insert(label, evaluation);
+                }
+            }
 
-            g_1: *second_commitments[0].commitment(),
-            h_1: *second_commitments[1].commitment(),
+            let evaluations = proof::Evaluations::from_map(&evaluations, batch_sizes[i]);
+            end_timer!(eval_time);
 
+            openings.push((polynomials, query_set, lc_s, evaluations));
+        }
 
-            g_a: *third_commitments[0].commitment(),
-            g_b: *third_commitments[1].commitment(),
-            g_c: *third_commitments[2].commitment(),
+        Self::terminate(terminator)?;
 
-            h_2: *fourth_commitments[0].commitment(),
-        };
+        for (_, _, _, evaluations) in &openings {
+            sponge.absorb_nonnative_field_elements(evaluations.to_field_elements());
+        }
 
-        let labeled_commitments: Vec<_> = circuit_proving_key
-            .circuit_verifying_key
-            .iter()
-            .cloned()
-            .zip_eq(AHPForR1CS::<E::Fr, MM>::index_polynomial_info().values())
-            .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
-            .chain(first_commitments.into_iter())
-            .chain(second_commitments.into_iter())
-            .chain(third_commitments.into_iter())
-            .chain(fourth_commitments.into_iter())
-            .collect();
+        let mut proofs = Vec::with_capacity(keys_to_constraints.len());
+        for (i, ((circuit_proving_key, _), (polynomials, query_set, lc_s, evaluations))) in
+            keys_to_constraints.iter().zip_eq(openings).enumerate()
+        {
+            // Gather commitments in one vector.
+            let witness_commitments = first_commitments[i].chunks_exact(3);
+            let mask_poly = MM::ZK.then(|| *witness_commitments.remainder()[0].commitment());
+            let witness_commitments = witness_commitments
+                .map(|c| proof::WitnessCommitments {
+                    w: *c[0].commitment(),
+                    z_a: *c[1].commitment(),
+                    z_b: *c[2].commitment(),
+                })
+                .collect();
+            #[rustfmt::skip]
+            let commitments = proof::Commitments {
+                witness_commitments,
+                mask_poly,
 
-        // Gather commitment randomness together.
-        let commitment_randomnesses: Vec<Randomness<E>> = circuit_proving_key
-            .circuit_commitment_randomness
-            .clone()
-            .into_iter()
-            .chain(first_commitment_randomnesses)
-            .chain(second_commitment_randomnesses)
-            .chain(third_commitment_randomnesses)
-            .chain(fourth_commitment_randomnesses)
-            .collect();
+                g_1: *second_commitments[i][0].commitment(),
+                h_1: *second_commitments[i][1].commitment(),
 
-        if !MM::ZK {
-            let empty_randomness = Randomness::<E>::empty();
-            assert!(commitment_randomnesses.iter().all(|r| r == &empty_randomness));
-        }
 
-        // Compute the AHP verifier's query set.
-        let (query_set, verifier_state) = AHPForR1CS::<_, MM>::verifier_query_set(verifier_state);
-        let lc_s = AHPForR1CS::<_, MM>::construct_linear_combinations(
-            &public_input,
-            &polynomials,
-            &prover_third_message,
-            &verifier_state,
-        )?;
+                g_a: *third_commitments[i][0].commitment(),
+                g_b: *third_commitments[i][1].commitment(),
+                g_c: *third_commitments[i][2].commitment(),
 
-        Self::terminate(terminator)?;
+                h_2: *fourth_commitments[i][0].commitment(),
+            };
 
-        let eval_time = start_timer!(|| "Evaluating linear combinations over query set");
-        let mut evaluations = std::collections::BTreeMap::new();
-        for (label, (_, point)) in query_set.to_set() {
-            if !AHPForR1CS::<E::Fr, MM>::LC_WITH_ZERO_EVAL.contains(&label.as_str()) {
-                let lc = lc_s.get(&label).ok_or_else(|| AHPError::MissingEval(label.to_string()))?;
-                let evaluation = polynomials.get_lc_eval(lc, point)?;
-                evaluations.insert(label, evaluation);
+            let labeled_commitments: Vec<_> = circuit_proving_key
+                .circuit_verifying_key
+                .iter()
+                .cloned()
+                .zip_eq(AHPForR1CS::<E::Fr, MM>::index_polynomial_info().values())
+                .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
+                .chain(first_commitments[i].iter().cloned())
+                .chain(second_commitments[i].iter().cloned())
+                .chain(third_commitments[i].iter().cloned())
+                .chain(fourth_commitments[i].iter().cloned())
+                .collect();
+
+            // Gather commitment randomness together.
+            let commitment_randomnesses: Vec<Randomness<E>> = circuit_proving_key
+                .circuit_commitment_randomness
+                .iter()
+                .chain(&first_commitment_randomnesses[i])
+                .chain(&second_commitment_randomnesses[i])
+                .chain(&third_commitment_randomnesses[i])
+                .chain(&fourth_commitment_randomnesses[i])
+                .cloned()
+                .collect();
+
+            if !MM::ZK {
+                let empty_randomness = Randomness::<E>::empty();
+                assert!(commitment_randomnesses.iter().all(|r| r == &empty_randomness));
             }
-        }
-
-        let evaluations = proof::Evaluations::from_map(&evaluations, batch_size);
-        end_timer!(eval_time);
 
-        Self::terminate(terminator)?;
-
-        sponge.absorb_nonnative_field_elements(evaluations.to_field_elements());
+            // Open the linear combinations of this circuit, continuing the shared transcript.
+            let pc_proof = SonicKZG10::<E, FS>::open_combinations(
+                &circuit_proving_key.committer_key,
+                lc_s.values(),
+                polynomials,
+                &labeled_commitments,
+                &query_set.to_set(),
+                &commitment_randomnesses,
+                &mut sponge,
+            )?;
 
-        let pc_proof = SonicKZG10::<E, FS>::open_combinations(
-            &circuit_proving_key.committer_key,
-            lc_s.values(),
-            polynomials,
-            &labeled_commitments,
-            &query_set.to_set(),
-            &commitment_randomnesses,
-            &mut sponge,
-        )?;
+            Self::terminate(terminator)?;
 
-        Self::terminate(terminator)?;
+            let proof =
+                Proof::<E>::new(batch_sizes[i], commitments, evaluations, prover_third_messages[i].clone(), pc_proof)?;
+            assert_eq!(proof.pc_proof.is_hiding(), MM::ZK);
+            proofs.push(proof);
+        }
 
-        let proof = Proof::<E>::new(batch_size, commitments, evaluations, prover_third_message, pc_proof)?;
-        assert_eq!(proof.pc_proof.is_hiding(), MM::ZK);
+        let proofs = HeterogeneousProofs::new(proofs)?;
 
         #[cfg(debug_assertions)]
-        if !Self::verify_batch(fs_parameters, &circuit_proving_key.circuit_verifying_key, &public_input, &proof)? {
-            println!("Invalid proof")
+        {
+            let keys_to_inputs: Vec<_> = keys_to_constraints
+                .iter()
+                .zip_eq(&public_inputs)
+                .map(|((circuit_proving_key, _), inputs)| (&circuit_proving_key.circuit_verifying_key, &inputs[..]))
+                .collect();
+            if !Self::verify_heterogeneous_batch(fs_parameters, &keys_to_inputs, &proofs)? {
+                println!("Invalid proofs")
+            }
         }
         end_timer!(prover_time);
 
-        Ok(proof)
+        Ok(proofs)
     }
 
-    fn verify_batch_prepared<B: Borrow<Self::VerifierInput>>(
-        fs_parameters: &Self::FSParameters,
-        prepared_verifying_key: &<Self::VerifyingKey as Prepare>::Prepared,
-        public_inputs: &[B],
-        proof: &Self::Proof,
+    /// Returns `true` if the given proofs are valid for the batches of public inputs under each verifying key.
+    pub fn verify_heterogeneous_batch<B: Borrow<[E::Fr]>>(
+        fs_parameters: &FS::Parameters,
+        keys_to_inputs: &[(&CircuitVerifyingKey<E, MM>, &[B])],
+        proofs: &HeterogeneousProofs<E>,
     ) -> Result<bool, SNARKError> {
-        let circuit_verifying_key = &prepared_verifying_key.orig_vk;
-        if public_inputs.is_empty() {
+        let preparation_time = start_timer!(|| "Preparing vks");
+        let prepared_verifying_keys: Vec<_> = keys_to_inputs.iter().map(|(vk, _)| vk.prepare()).collect();
+        end_timer!(preparation_time);
+        let keys_to_inputs: Vec<_> =
+            prepared_verifying_keys.iter().zip_eq(keys_to_inputs).map(|(vk, (_, inputs))| (vk, *inputs)).collect();
+        Self::verify_heterogeneous_batch_prepared(fs_parameters, &keys_to_inputs, proofs)
+    }
+
+    /// Returns `true` if the given proofs are valid for the batches of public inputs under each prepared verifying key.
+    pub fn verify_heterogeneous_batch_prepared<B: Borrow<[E::Fr]>>(
+        fs_parameters: &FS::Parameters,
+        keys_to_inputs: &[(&PreparedCircuitVerifyingKey<E, MM>, &[B])],
+        proofs: &HeterogeneousProofs<E>,
+    ) -> Result<bool, SNARKError> {
+        Self::verify_proofs_prepared(fs_parameters, keys_to_inputs, proofs.proofs())
+    }
+
+    /// Returns `true` if every proof is valid for its batch of public inputs under its prepared verifying key,
+    /// and every certificate is valid for its circuit under its verifying key.
+    /// Unlike a heterogeneous batch, each proof has its own Fiat-Shamir transcript,
+    /// but the KZG opening checks of all proofs and certificates are combined into a single multi-pairing.
+    #[allow(clippy::type_complexity)]
+    pub fn verify_many_prepared<B: Borrow<[E::Fr]>, C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        fs_parameters: &FS::Parameters,
+        instances: &[(&PreparedCircuitVerifyingKey<E, MM>, &[B], &Proof<E>)],
+        certificates: &[(&C, &CircuitVerifyingKey<E, MM>, &Certificate<E>)],
+        rng: &mut R,
+    ) -> Result<bool, SNARKError> {
+        if instances.is_empty() && certificates.is_empty() {
             return Err(SNARKError::EmptyBatch);
         }
-
-        if public_inputs.len() != proof.batch_size()? {
-            return Err(SNARKError::BatchSizeMismatch);
+        let verifier_time = start_timer!(|| format!(
+            "Marlin::VerifyMany with {} proofs and {} certificates",
+            instances.len(),
+            certificates.len()
+        ));
+        let mut checks = Vec::with_capacity(instances.len() + certificates.len());
+        for (prepared_verifying_key, inputs, proof) in instances {
+            match Self::pairing_checks_prepared(
+                fs_parameters,
+                &[(prepared_verifying_key, inputs)],
+                std::slice::from_ref(proof),
+            )? {
+                Some(pairing_checks) => {
+                    let verifier_key = &prepared_verifying_key.orig_vk.verifier_key;
+                    checks.extend(pairing_checks.into_iter().map(|pairing_check| (verifier_key, pairing_check)))
+                }
+                None => return Ok(false),
+            }
+        }
+        for (circuit, verifying_key, certificate) in certificates {
+            let pairing_check = Self::certificate_pairing_check(fs_parameters, *circuit, verifying_key, certificate)?;
+            checks.push((&verifying_key.verifier_key, pairing_check));
         }
+        let evaluations_are_correct = SonicKZG10::<E, FS>::check_elems_batch(checks, rng)?;
+        end_timer!(verifier_time, || format!(" SonicKZG10::CheckMany: {evaluations_are_correct}"));
+        Ok(evaluations_are_correct)
+    }
 
-        let comms = &proof.commitments;
-        let proof_has_correct_zk_mode = if MM::ZK {
-            proof.pc_proof.is_hiding() & comms.mask_poly.is_some()
-        } else {
-            !proof.pc_proof.is_hiding() & comms.mask_poly.is_none()
-        };
-        if !proof_has_correct_zk_mode {
-            eprintln!(
-                "Found `mask_poly` in the first round when not expected, or proof has incorrect hiding mode ({})",
-                proof.pc_proof.is_hiding()
-            );
-            return Ok(false);
+    /// Returns the index of the first proof or certificate that is invalid, or `None` if all of them are valid.
+    /// The certificates are indexed after the proofs.
+    /// The proofs and certificates are first checked together with `verify_many_prepared`,
+    /// and are only checked one at a time to locate the failure if that check fails.
+    #[allow(clippy::type_complexity)]
+    pub fn find_invalid_proof_prepared<B: Borrow<[E::Fr]>, C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        fs_parameters: &FS::Parameters,
+        instances: &[(&PreparedCircuitVerifyingKey<E, MM>, &[B], &Proof<E>)],
+        certificates: &[(&C, &CircuitVerifyingKey<E, MM>, &Certificate<E>)],
+        rng: &mut R,
+    ) -> Option<usize> {
+        if (instances.is_empty() && certificates.is_empty())
+            || matches!(Self::verify_many_prepared(fs_parameters, instances, certificates, rng), Ok(true))
+        {
+            return None;
         }
+        let invalid_proof = instances.iter().position(|(prepared_verifying_key, inputs, proof)| {
+            !matches!(
+                Self::verify_proofs_prepared(
+                    fs_parameters,
+                    &[(prepared_verifying_key, inputs)],
+                    std::slice::from_ref(proof)
+                ),
+                Ok(true)
+            )
+        });
+        invalid_proof.or_else(|| {
+            let invalid_certificate = certificates.iter().position(|(circuit, verifying_key, certificate)| {
+                !matches!(Self::verify_certificate(fs_parameters, *circuit, verifying_key, certificate), Ok(true))
+            });
+            invalid_certificate.map(|index| instances.len() + index)
+        })
+    }
+
+    /// Returns `true` if the given certificate is valid for the circuit under the verifying key.
+    fn verify_certificate<C: ConstraintSynthesizer<E::Fr>>(
+        fs_parameters: &FS::Parameters,
+        circuit: &C,
+        verifying_key: &CircuitVerifyingKey<E, MM>,
+        certificate: &Certificate<E>,
+    ) -> Result<bool, SNARKError> {
+        let pairing_check = Self::certificate_pairing_check(fs_parameters, circuit, verifying_key, certificate)?;
+        SonicKZG10::<E, FS>::check_elems(pairing_check, &verifying_key.verifier_key).map_err(Into::into)
+    }
 
-        let batch_size = public_inputs.len();
-        let verifier_time = start_timer!(|| format!("Marlin::Verify with batch size {batch_size}"));
+    /// Runs the verifier of the given certificate up to the KZG pairing check,
+    /// and returns the pairing equation that remains to be checked.
+    fn certificate_pairing_check<C: ConstraintSynthesizer<E::Fr>>(
+        fs_parameters: &FS::Parameters,
+        circuit: &C,
+        verifying_key: &CircuitVerifyingKey<E, MM>,
+        certificate: &Certificate<E>,
+    ) -> Result<PairingCheck<E>, SNARKError> {
+        let info = AHPForR1CS::<E::Fr, MM>::index_polynomial_info();
+        // Initialize sponge.
+        let mut sponge = Self::init_sponge_for_certificate(fs_parameters, &verifying_key.circuit_commitments);
+        // Compute challenges for linear combination, and the point to evaluate the polynomials at.
+        // The linear combination requires `num_polynomials - 1` coefficients
+        // (since the first coeff is 1), and so we squeeze out `num_polynomials` points.
+        let mut challenges = sponge.squeeze_nonnative_field_elements(verifying_key.circuit_commitments.len());
+        let point = challenges.pop().unwrap();
 
-        let first_round_info = AHPForR1CS::<E::Fr, MM>::first_round_polynomial_info(batch_size);
-        let mut first_commitments = comms
-            .witness_commitments
+        let evaluations_at_point = AHPForR1CS::<E::Fr, MM>::evaluate_index_polynomials(circuit, point)?;
+        let one = E::Fr::one();
+        let linear_combination_challenges = core::iter::once(&one).chain(challenges.iter());
+
+        // We will construct a linear combination and provide a proof of evaluation of the lc at `point`.
+        let mut lc = crate::polycommit::sonic_pc::LinearCombination::empty("circuit_check");
+        let mut evaluation = E::Fr::zero();
+        for ((label, &c), eval) in info.keys().zip_eq(linear_combination_challenges).zip_eq(evaluations_at_point) {
+            lc.add(c, label.as_str());
+            evaluation += c * eval;
+        }
+
+        let query_set = QuerySet::from_iter([("circuit_check".into(), ("challenge".into(), point))]);
+        let commitments = verifying_key
             .iter()
-            .enumerate()
-            .flat_map(|(i, c)| {
-                [
-                    LabeledCommitment::new_with_info(&first_round_info[&witness_label("w", i)], c.w),
-                    LabeledCommitment::new_with_info(&first_round_info[&witness_label("z_a", i)], c.z_a),
-                    LabeledCommitment::new_with_info(&first_round_info[&witness_label("z_b", i)], c.z_b),
-                ]
-            })
+            .cloned()
+            .zip_eq(info.values())
+            .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
             .collect::<Vec<_>>();
-        if MM::ZK {
-            first_commitments.push(LabeledCommitment::new_with_info(
-                first_round_info.get("mask_poly").unwrap(),
-                comms.mask_poly.unwrap(),
-            ));
+        let evaluations = Evaluations::from_iter([(("circuit_check".into(), point), evaluation)]);
+
+        SonicKZG10::<E, FS>::check_combinations_elems(
+            &verifying_key.verifier_key,
+            &[lc],
+            &commitments,
+            &query_set,
+            &evaluations,
+            &certificate.pc_proof,
+            &mut sponge,
+        )
+        .map_err(Into::into)
+    }
+
+    /// Returns `true` if the given proofs, which share one Fiat-Shamir transcript, are valid
+    /// for the batches of public inputs under each prepared verifying key.
+    fn verify_proofs_prepared<B: Borrow<[E::Fr]>>(
+        fs_parameters: &FS::Parameters,
+        keys_to_inputs: &[(&PreparedCircuitVerifyingKey<E, MM>, &[B])],
+        proofs: &[Proof<E>],
+    ) -> Result<bool, SNARKError> {
+        let verifier_time = start_timer!(|| format!("Marlin::Verify with {} circuits", keys_to_inputs.len()));
+        let pairing_checks = match Self::pairing_checks_prepared(fs_parameters, keys_to_inputs, proofs)? {
+            Some(pairing_checks) => pairing_checks,
+            None => return Ok(false),
+        };
+
+        let mut evaluations_are_correct = true;
+        for ((prepared_verifying_key, _), pairing_check) in keys_to_inputs.iter().zip_eq(pairing_checks) {
+            evaluations_are_correct &=
+                SonicKZG10::<E, FS>::check_elems(pairing_check, &prepared_verifying_key.orig_vk.verifier_key)?;
+        }
+
+        if !evaluations_are_correct {
+            #[cfg(debug_assertions)]
+            eprintln!("SonicKZG10::Check failed");
         }
+        end_timer!(verifier_time, || format!(
+            " SonicKZG10::Check for AHP Verifier linear equations: {}",
+            evaluations_are_correct
+        ));
+        Ok(evaluations_are_correct)
+    }
 
-        let second_round_info =
-            AHPForR1CS::<E::Fr, MM>::second_round_polynomial_info(&circuit_verifying_key.circuit_info);
-        let second_commitments = [
-            LabeledCommitment::new_with_info(&second_round_info["g_1"], comms.g_1),
-            LabeledCommitment::new_with_info(&second_round_info["h_1"], comms.h_1),
-        ];
-
-        let third_round_info =
-            AHPForR1CS::<E::Fr, MM>::third_round_polynomial_info(&circuit_verifying_key.circuit_info);
-        let third_commitments = [
-            LabeledCommitment::new_with_info(&third_round_info["g_a"], comms.g_a),
-            LabeledCommitment::new_with_info(&third_round_info["g_b"], comms.g_b),
-            LabeledCommitment::new_with_info(&third_round_info["g_c"], comms.g_c),
-        ];
-
-        let fourth_round_info = AHPForR1CS::<E::Fr, MM>::fourth_round_polynomial_info();
-        let fourth_commitments = [LabeledCommitment::new_with_info(&fourth_round_info["h_2"], comms.h_2)];
-
-        let input_domain =
-            EvaluationDomain::<E::Fr>::new(circuit_verifying_key.circuit_info.num_public_inputs).unwrap();
-
-        let (padded_public_inputs, public_inputs): (Vec<_>, Vec<_>) = {
-            public_inputs
+    /// Runs the verifier of the given proofs, which share one Fiat-Shamir transcript, up to the KZG pairing checks,
+    /// and returns the pairing equation of each circuit. Returns `None` if a proof has the wrong zero-knowledge mode.
+    fn pairing_checks_prepared<B: Borrow<[E::Fr]>>(
+        fs_parameters: &FS::Parameters,
+        keys_to_inputs: &[(&PreparedCircuitVerifyingKey<E, MM>, &[B])],
+        proofs: &[Proof<E>],
+    ) -> Result<Option<Vec<PairingCheck<E>>>, SNARKError> {
+        if keys_to_inputs.is_empty() || keys_to_inputs.iter().any(|(_, inputs)| inputs.is_empty()) {
+            return Err(SNARKError::EmptyBatch);
+        }
+        if keys_to_inputs.len() != proofs.len() {
+            return Err(SNARKError::BatchSizeMismatch);
+        }
+        for ((_, inputs), proof) in keys_to_inputs.iter().zip_eq(proofs) {
+            if inputs.len() != proof.batch_size()? {
+                return Err(SNARKError::BatchSizeMismatch);
+            }
+        }
+
+        let proof_has_correct_zk_mode = proofs.iter().all(|proof| {
+            let proof_has_correct_zk_mode = if MM::ZK {
+                proof.pc_proof.is_hiding() & proof.commitments.mask_poly.is_some()
+            } else {
+                !proof.pc_proof.is_hiding() & proof.commitments.mask_poly.is_none()
+            };
+            if !proof_has_correct_zk_mode {
+                eprintln!(
+                    "Found `mask_poly` in the first round when not expected, or proof has incorrect hiding mode ({})",
+                    proof.pc_proof.is_hiding()
+                );
+            }
+            proof_has_correct_zk_mode
+        });
+        if !proof_has_correct_zk_mode {
+            return Ok(None);
+        }
+
+        let mut first_commitments = Vec::with_capacity(keys_to_inputs.len());
+        let mut second_commitments = Vec::with_capacity(keys_to_inputs.len());
+        let mut third_commitments = Vec::with_capacity(keys_to_inputs.len());
+        let mut fourth_commitments = Vec::with_capacity(keys_to_inputs.len());
+        let mut padded_public_inputs = Vec::with_capacity(keys_to_inputs.len());
+        let mut public_inputs = Vec::with_capacity(keys_to_inputs.len());
+
+        for ((prepared_verifying_key, inputs), proof) in keys_to_inputs.iter().zip_eq(proofs) {
+            let circuit_verifying_key = &prepared_verifying_key.orig_vk;
+            let batch_size = inputs.len();
+            let comms = &proof.commitments;
+
+            let first_round_info = AHPForR1CS::<E::Fr, MM>::first_round_polynomial_info(batch_size);
+            let mut commitments = comms
+                .witness_commitments
+                .iter()
+                .enumerate()
+                .flat_map(|(i, c)| {
+                    [
+                        LabeledCommitment::new_with_info(&first_round_info[&witness_label("w", i)], c.w),
+                        LabeledCommitment::new_with_info(&first_round_info[&witness_label("z_a", i)], c.z_a),
+                        LabeledCommitment::new_with_info(&first_round_info[&witness_label("z_b", i)], c.z_b),
+                    ]
+                })
+                .collect::<Vec<_>>();
+            if MM::ZK {
+                commitments.push(LabeledCommitment::new_with_info(
+                    first_round_info.get("mask_poly").unwrap(),
+                    comms.mask_poly.unwrap(),
+                ));
+            }
+            first_commitments.push(commitments);
+
+            let second_round_info =
+                AHPForR1CS::<E::Fr, MM>::second_round_polynomial_info(&circuit_verifying_key.circuit_info);
+            second_commitments.push([
+                LabeledCommitment::new_with_info(&second_round_info["g_1"], comms.g_1),
+                LabeledCommitment::new_with_info(&second_round_info["h_1"], comms.h_1),
+            ]);
+
+            let third_round_info =
+                AHPForR1CS::<E::Fr, MM>::third_round_polynomial_info(&circuit_verifying_key.circuit_info);
+            third_commitments.push([
+                LabeledCommitment::new_with_info(&third_round_info["g_a"], comms.g_a),
+                LabeledCommitment::new_with_info(&third_round_info["g_b"], comms.g_b),
+                LabeledCommitment::new_with_info(&third_round_info["g_c"], comms.g_c),
+            ]);
+
+            let fourth_round_info = AHPForR1CS::<E::Fr, MM>::fourth_round_polynomial_info();
+            fourth_commitments.push([LabeledCommitment::new_with_info(&fourth_round_info["h_2"], comms.h_2)]);
+
+            let input_domain =
+                EvaluationDomain::<E::Fr>::new(circuit_verifying_key.circuit_info.num_public_inputs).unwrap();
+
+            let (padded, unpadded): (Vec<_>, Vec<_>) = inputs
                 .iter()
                 .map(|input| {
                     let input = input.borrow().to_field_elements().unwrap();
@@ -658,114 +801,315 @@ where
                     let unformatted = prover::ConstraintSystem::unformat_public_input(&new_input);
                     (new_input, unformatted)
                 })
-                .unzip()
-        };
+                .unzip();
+            padded_public_inputs.push(padded);
+            public_inputs.push(unpadded);
+        }
 
         let mut sponge = Self::init_sponge(
             fs_parameters,
-            batch_size,
-            &circuit_verifying_key.circuit_commitments,
-            &padded_public_inputs,
+            keys_to_inputs
+                .iter()
+                .map(|(prepared_verifying_key, _)| &prepared_verifying_key.orig_vk.circuit_commitments[..])
+                .zip_eq(&padded_public_inputs),
         );
 
         // --------------------------------------------------------------------
         // First round
         let first_round_time = start_timer!(|| "First round");
-        Self::absorb_labeled(&first_commitments, &mut sponge);
-        let (_, verifier_state) =
-            AHPForR1CS::<_, MM>::verifier_first_round(circuit_verifying_key.circuit_info, batch_size, &mut sponge)?;
+        first_commitments.iter().for_each(|commitments| Self::absorb_labeled(commitments, &mut sponge));
+        let verifier_states = keys_to_inputs
+            .iter()
+            .map(|(prepared_verifying_key, inputs)| {
+                let circuit_info = prepared_verifying_key.orig_vk.circuit_info;
+                let (_, verifier_state) =
+                    AHPForR1CS::<_, MM>::verifier_first_round(circuit_info, inputs.len(), &mut sponge)?;
+                Ok(verifier_state)
+            })
+            .collect::<Result<Vec<_>, AHPError>>()?;
         end_timer!(first_round_time);
         // --------------------------------------------------------------------
 
         // --------------------------------------------------------------------
         // Second round
         let second_round_time = start_timer!(|| "Second round");
-        Self::absorb_labeled(&second_commitments, &mut sponge);
-        let (_, verifier_state) = AHPForR1CS::<_, MM>::verifier_second_round(verifier_state, &mut sponge)?;
+        second_commitments.iter().for_each(|commitments| Self::absorb_labeled(commitments, &mut sponge));
+        let verifier_states = verifier_states
+            .into_iter()
+            .map(|state| Ok(AHPForR1CS::<_, MM>::verifier_second_round(state, &mut sponge)?.1))
+            .collect::<Result<Vec<_>, AHPError>>()?;
         end_timer!(second_round_time);
         // --------------------------------------------------------------------
 
         // --------------------------------------------------------------------
         // Third round
         let third_round_time = start_timer!(|| "Third round");
-
-        Self::absorb_labeled_with_msg(&third_commitments, &proof.msg, &mut sponge);
-        let (_, verifier_state) = AHPForR1CS::<_, MM>::verifier_third_round(verifier_state, &mut sponge)?;
+        for (commitments, proof) in third_commitments.iter().zip_eq(proofs) {
+            Self::absorb_labeled_with_msg(commitments, &proof.msg, &mut sponge);
+        }
+        let verifier_states = verifier_states
+            .into_iter()
+            .map(|state| Ok(AHPForR1CS::<_, MM>::verifier_third_round(state, &mut sponge)?.1))
+            .collect::<Result<Vec<_>, AHPError>>()?;
         end_timer!(third_round_time);
         // --------------------------------------------------------------------
 
         // --------------------------------------------------------------------
         // Fourth round
         let fourth_round_time = start_timer!(|| "Fourth round");
-
-        Self::absorb_labeled(&fourth_commitments, &mut sponge);
-        let verifier_state = AHPForR1CS::<_, MM>::verifier_fourth_round(verifier_state, &mut sponge)?;
+        fourth_commitments.iter().for_each(|commitments| Self::absorb_labeled(commitments, &mut sponge));
+        let verifier_states = verifier_states
+            .into_iter()
+            .map(|state| AHPForR1CS::<_, MM>::verifier_fourth_round(state, &mut sponge))
+            .collect::<Result<Vec<_>, AHPError>>()?;
         end_timer!(fourth_round_time);
         // --------------------------------------------------------------------
 
-        // Collect degree bounds for commitments. Indexed polynomials have *no*
-        // degree bounds because we know the committed index polynomial has the
-        // correct degree.
+        for proof in proofs {
+            sponge.absorb_nonnative_field_elements(proof.evaluations.to_field_elements());
+        }
 
-        // Gather commitments in one vector.
-        let commitments: Vec<_> = circuit_verifying_key
-            .iter()
-            .cloned()
-            .zip_eq(AHPForR1CS::<E::Fr, MM>::index_polynomial_info().values())
-            .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
-            .chain(first_commitments)
-            .chain(second_commitments)
-            .chain(third_commitments)
-            .chain(fourth_commitments)
-            .collect();
+        let mut pairing_checks = Vec::with_capacity(keys_to_inputs.len());
+        for (i, (((prepared_verifying_key, _), proof), verifier_state)) in
+            keys_to_inputs.iter().zip_eq(proofs).zip_eq(verifier_states).enumerate()
+        {
+            let circuit_verifying_key = &prepared_verifying_key.orig_vk;
 
-        let query_set_time = start_timer!(|| "Constructing query set");
-        let (query_set, verifier_state) = AHPForR1CS::<_, MM>::verifier_query_set(verifier_state);
-        end_timer!(query_set_time);
+            // Collect degree bounds for commitments. Indexed polynomials have *no*
+            // degree bounds because we know the committed index polynomial has the
+            // correct degree.
 
-        sponge.absorb_nonnative_field_elements(proof.evaluations.to_field_elements());
+            // Gather commitments in one vector.
+            let commitments: Vec<_> = circuit_verifying_key
+                .iter()
+                .cloned()
+                .zip_eq(AHPForR1CS::<E::Fr, MM>::index_polynomial_info().values())
+                .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
+                .chain(first_commitments[i].iter().cloned())
+                .chain(second_commitments[i].iter().cloned())
+                .chain(third_commitments[i].iter().cloned())
+                .chain(fourth_commitments[i].iter().cloned())
+                .collect();
+
+            let query_set_time = start_timer!(|| "Constructing query set");
+            let (query_set, verifier_state) = AHPForR1CS::<_, MM>::verifier_query_set(verifier_state);
+            end_timer!(query_set_time);
+
+            let mut evaluations = Evaluations::new();
+
+            for (label, (_point_name, q)) in query_set.to_set() {
+                if AHPForR1CS::<E::Fr, MM>::LC_WITH_ZERO_EVAL.contains(&label.as_ref()) {
+                    evaluations.insert((label, q), E::Fr::zero());
+                } else {
+                    let eval = proof.evaluations.get(&label).ok_or_else(|| AHPError::MissingEval(label.clone()))?;
+                    evaluations.insert((label, q), eval);
+                }
+            }
 
-        let mut evaluations = Evaluations::new();
+            let lc_time = start_timer!(|| "Constructing linear combinations");
+            let lc_s = AHPForR1CS::<_, MM>::construct_linear_combinations(
+                &public_inputs[i],
+                &evaluations,
+                &proof.msg,
+                &verifier_state,
+            )?;
+            end_timer!(lc_time);
+
+            // Check the linear combinations of this circuit, continuing the shared transcript.
+            let pc_time = start_timer!(|| "Checking linear combinations with PC");
+            pairing_checks.push(SonicKZG10::<E, FS>::check_combinations_elems(
+                &circuit_verifying_key.verifier_key,
+                lc_s.values(),
+                &commitments,
+                &query_set.to_set(),
+                &evaluations,
+                &proof.pc_proof,
+                &mut sponge,
+            )?);
+            end_timer!(pc_time);
+        }
 
-        for (label, (_point_name, q)) in query_set.to_set() {
-            if AHPForR1CS::<E::Fr, MM>::LC_WITH_ZERO_EVAL.contains(&label.as_ref()) {
-                evaluations.insert((label, q), E::Fr::zero());
-            } else {
-                let eval = proof.evaluations.get(&label).ok_or_else(|| AHPError::MissingEval(label.clone()))?;
-                evaluations.insert((label, q), eval);
+        Ok(Some(pairing_checks))
+    }
+
+    fn terminate(terminator: &AtomicBool) -> Result<(), MarlinError> {
+        if terminator.load(Ordering::Relaxed) {
+            Err(MarlinError::Terminated)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn init_sponge<'a>(
+        fs_parameters: &FS::Parameters,
+        circuits_to_inputs: impl IntoIterator<Item = (&'a [Commitment<E>], &'a Vec<Vec<E::Fr>>)>,
+    ) -> FS {
+        let mut sponge = FS::new_with_parameters(fs_parameters);
+        sponge.absorb_bytes(&to_bytes_le![&Self::PROTOCOL_NAME].unwrap());
+        for (circuit_commitments, inputs) in circuits_to_inputs {
+            sponge.absorb_bytes(&inputs.len().to_le_bytes());
+            sponge.absorb_native_field_elements(circuit_commitments);
+            for input in inputs {
+                sponge.absorb_nonnative_field_elements(input.iter().copied());
             }
         }
+        sponge
+    }
 
-        let lc_time = start_timer!(|| "Constructing linear combinations");
-        let lc_s = AHPForR1CS::<_, MM>::construct_linear_combinations(
-            &public_inputs,
-            &evaluations,
-            &proof.msg,
-            &verifier_state,
-        )?;
-        end_timer!(lc_time);
+    fn init_sponge_for_certificate(
+        fs_parameters: &FS::Parameters,
+        circuit_commitments: &[crate::polycommit::sonic_pc::Commitment<E>],
+    ) -> FS {
+        let mut sponge = FS::new_with_parameters(fs_parameters);
+        sponge.absorb_bytes(&to_bytes_le![&Self::PROTOCOL_NAME].unwrap());
+        sponge.absorb_native_field_elements(circuit_commitments);
+        sponge
+    }
+
+    fn absorb_labeled_with_msg(
+        comms: &[LabeledCommitment<Commitment<E>>],
+        message: &prover::ThirdMessage<E::Fr>,
+        sponge: &mut FS,
+    ) {
+        let commitments: Vec<_> = comms.iter().map(|c| *c.commitment()).collect();
+        Self::absorb_with_msg(&commitments, message, sponge)
+    }
+
+    fn absorb_labeled(comms: &[LabeledCommitment<Commitment<E>>], sponge: &mut FS) {
+        let commitments: Vec<_> = comms.iter().map(|c| *c.commitment()).collect();
+        Self::absorb(&commitments, sponge);
+    }
+
+    fn absorb(commitments: &[Commitment<E>], sponge: &mut FS) {
+        let sponge_time = start_timer!(|| "Absorbing commitments");
+        sponge.absorb_native_field_elements(commitments);
+        end_timer!(sponge_time);
+    }
+
+    fn absorb_with_msg(commitments: &[Commitment<E>], msg: &prover::ThirdMessage<E::Fr>, sponge: &mut FS) {
+        let sponge_time = start_timer!(|| "Absorbing commitments and message");
+        Self::absorb(commitments, sponge);
+        sponge.absorb_nonnative_field_elements([msg.sum_a, msg.sum_b, msg.sum_c]);
+        end_timer!(sponge_time);
+    }
+}
+
+impl<E: PairingEngine, FS, MM> SNARK for MarlinSNARK<E, FS, MM>
+where
+    E::Fr: PrimeField,
+    E::Fq: PrimeField,
+    FS: AlgebraicSponge<E::Fq, 2>,
+    MM: MarlinMode,
+{
+    type BaseField = E::Fq;
+    type Certificate = Certificate<E>;
+    type FSParameters = FS::Parameters;
+    type FiatShamirRng = FS;
+    type Proof = Proof<E>;
+    type ProvingKey = CircuitProvingKey<E, MM>;
+    type ScalarField = E::Fr;
+    type UniversalSetupConfig = usize;
+    type UniversalSetupParameters = UniversalSRS<E>;
+    type VerifierInput = [E::Fr];
+    type VerifyingKey = CircuitVerifyingKey<E, MM>;
+
+    fn universal_setup(max_degree: &Self::UniversalSetupConfig) -> Result<Self::UniversalSetupParameters, SNARKError> {
+        let setup_time = start_timer!(|| { format!("Marlin::UniversalSetup with max_degree {}", max_degree,) });
+
+        let srs = SonicKZG10::<E, FS>::load_srs(*max_degree).map_err(Into::into);
+        end_timer!(setup_time);
+        srs
+    }
+
+    fn setup<C: ConstraintSynthesizer<E::Fr>>(
+        circuit: &C,
+        srs: &mut SRS<Self::UniversalSetupParameters>,
+    ) -> Result<(Self::ProvingKey, Self::VerifyingKey), SNARKError> {
+        match srs {
+            SRS::CircuitSpecific => Self::circuit_specific_setup(circuit),
+            SRS::Universal(srs) => Self::circuit_setup(srs, circuit),
+        }
+        .map_err(SNARKError::from)
+    }
+
+    fn prove_vk(
+        fs_parameters: &Self::FSParameters,
+        verifying_key: &Self::VerifyingKey,
+        proving_key: &Self::ProvingKey,
+    ) -> Result<Self::Certificate, SNARKError> {
+        // Initialize sponge
+        let mut sponge = Self::init_sponge_for_certificate(fs_parameters, &verifying_key.circuit_commitments);
+        // Compute challenges for linear combination, and the point to evaluate the polynomials at.
+        // The linear combination requires `num_polynomials - 1` coefficients
+        // (since the first coeff is 1), and so we squeeze out `num_polynomials` points.
+        let mut challenges = sponge.squeeze_nonnative_field_elements(verifying_key.circuit_commitments.len());
+        let point = challenges.pop().unwrap();
+        let one = E::Fr::one();
+        let linear_combination_challenges = core::iter::once(&one).chain(challenges.iter());
+
+        // We will construct a linear combination and provide a proof of evaluation of the lc at `point`.
+        let mut lc = crate::polycommit::sonic_pc::LinearCombination::empty("circuit_check");
+        for (poly, &c) in proving_key.circuit.iter().zip(linear_combination_challenges) {
+            lc.add(c, poly.label());
+        }
+
+        let query_set = QuerySet::from_iter([("circuit_check".into(), ("challenge".into(), point))]);
+        let commitments = verifying_key
+            .iter()
+            .cloned()
+            .zip_eq(AHPForR1CS::<E::Fr, MM>::index_polynomial_info().values())
+            .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
+            .collect::<Vec<_>>();
 
-        let pc_time = start_timer!(|| "Checking linear combinations with PC");
-        let evaluations_are_correct = SonicKZG10::<E, FS>::check_combinations(
-            &circuit_verifying_key.verifier_key,
-            lc_s.values(),
+        let certificate = SonicKZG10::<E, FS>::open_combinations(
+            &proving_key.committer_key,
+            &[lc],
+            proving_key.circuit.iter(),
             &commitments,
-            &query_set.to_set(),
-            &evaluations,
-            &proof.pc_proof,
+            &query_set,
+            &proving_key.circuit_commitment_randomness.clone(),
             &mut sponge,
         )?;
-        end_timer!(pc_time);
 
-        if !evaluations_are_correct {
-            #[cfg(debug_assertions)]
-            eprintln!("SonicKZG10::Check failed");
-        }
-        end_timer!(verifier_time, || format!(
-            " SonicKZG10::Check for AHP Verifier linear equations: {}",
-            evaluations_are_correct & proof_has_correct_zk_mode
-        ));
-        Ok(evaluations_are_correct & proof_has_correct_zk_mode)
+        Ok(Self::Certificate::new(certificate))
+    }
+
+    fn verify_vk<C: ConstraintSynthesizer<Self::ScalarField>>(
+        fs_parameters: &Self::FSParameters,
+        circuit: &C,
+        verifying_key: &Self::VerifyingKey,
+        certificate: &Self::Certificate,
+    ) -> Result<bool, SNARKError> {
+        Self::verify_certificate(fs_parameters, circuit, verifying_key, certificate)
+    }
+
+    fn prove_batch_with_terminator<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        fs_parameters: &Self::FSParameters,
+        circuit_proving_key: &CircuitProvingKey<E, MM>,
+        circuits: &[C],
+        terminator: &AtomicBool,
+        zk_rng: &mut R,
+    ) -> Result<Self::Proof, SNARKError> {
+        let proofs = Self::prove_heterogeneous_batch_with_terminator(
+            fs_parameters,
+            &[(circuit_proving_key, circuits)],
+            terminator,
+            zk_rng,
+        )?;
+        Ok(proofs.into_proofs().remove(0))
+    }
+
+    fn verify_batch_prepared<B: Borrow<Self::VerifierInput>>(
+        fs_parameters: &Self::FSParameters,
+        prepared_verifying_key: &<Self::VerifyingKey as Prepare>::Prepared,
+        public_inputs: &[B],
+        proof: &Self::Proof,
+    ) -> Result<bool, SNARKError> {
+        Self::verify_proofs_prepared(
+            fs_parameters,
+            &[(prepared_verifying_key, public_inputs)],
+            std::slice::from_ref(proof),
+        )
     }
 }
 
@@ -781,7 +1125,7 @@ pub mod test {
     use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
     use snarkvm_fields::Field;
     use snarkvm_r1cs::{ConstraintSystem, SynthesisError};
-    use snarkvm_utilities::{TestRng, Uniform};
+    use snarkvm_utilities::{FromBytes, TestRng, Uniform};
 
     use core::ops::MulAssign;
 
@@ -854,4 +1198,132 @@ pub mod test {
             );
         }
     }
+
+    #[test]
+    fn marlin_heterogeneous_batch_test() {
+        let mut rng = TestRng::default();
+
+        // Construct two different circuits, with a batch of instances for each.
+        let sample_circuit = |num_constraints: usize, num_variables: usize, rng: &mut TestRng| {
+            let a = Fr::rand(rng);
+            let b = Fr::rand(rng);
+            (Circuit { a: Some(a), b: Some(b), num_constraints, num_variables }, a * b)
+        };
+        let (circuits_a, inputs_a): (Vec<_>, Vec<_>) = (0..2).map(|_| sample_circuit(100, 25, &mut rng)).unzip();
+        let (circuits_b, inputs_b): (Vec<_>, Vec<_>) = (0..3).map(|_| sample_circuit(300, 50, &mut rng)).unzip();
+        let inputs_a: Vec<_> = inputs_a.into_iter().map(|c| vec![c]).collect();
+        let inputs_b: Vec<_> = inputs_b.into_iter().map(|c| vec![c]).collect();
+
+        // Generate the circuit parameters from one universal SRS.
+        let max_degree = AHPForR1CS::<Fr, MarlinHidingMode>::index(&circuits_b[0]).unwrap().max_degree();
+        let universal_srs = TestSNARK::universal_setup(&max_degree).unwrap();
+        let (pk_a, vk_a) = TestSNARK::circuit_setup(&universal_srs, &circuits_a[0]).unwrap();
+        let (pk_b, vk_b) = TestSNARK::circuit_setup(&universal_srs, &circuits_b[0]).unwrap();
+
+        let fs_parameters = FS::sample_parameters();
+
+        // Prove both batches under a shared transcript.
+        let proofs = TestSNARK::prove_heterogeneous_batch(
+            &fs_parameters,
+            &[(&pk_a, &circuits_a[..]), (&pk_b, &circuits_b[..])],
+            &mut rng,
+        )
+        .unwrap();
+        assert_eq!(proofs.num_circuits(), 2);
+
+        // Ensure the proofs verify.
+        let keys_to_inputs = [(&vk_a, &inputs_a[..]), (&vk_b, &inputs_b[..])];
+        assert!(TestSNARK::verify_heterogeneous_batch(&fs_parameters, &keys_to_inputs, &proofs).unwrap());
+
+        // Ensure the proofs do not verify on the wrong inputs.
+        let wrong_inputs_b: Vec<_> = inputs_b.iter().map(|input| vec![input[0] + Fr::one()]).collect();
+        let keys_to_wrong_inputs = [(&vk_a, &inputs_a[..]), (&vk_b, &wrong_inputs_b[..])];
+        assert!(!TestSNARK::verify_heterogeneous_batch(&fs_parameters, &keys_to_wrong_inputs, &proofs).unwrap());
+
+        // Ensure the per-circuit proofs do not verify outside the shared transcript.
+        let proof_a = &proofs.proofs()[0];
+        assert!(!TestSNARK::verify_batch(&fs_parameters, &vk_a, &inputs_a, proof_a).unwrap());
+
+        // Ensure the proofs round-trip through their byte representation.
+        let bytes = proofs.to_bytes_le().unwrap();
+        assert_eq!(proofs, HeterogeneousProofs::read_le(&bytes[..]).unwrap());
+    }
+
+    #[test]
+    fn marlin_verify_many_test() {
+        let mut rng = TestRng::default();
+
+        // Construct instances of two different circuits.
+        let sample_circuit = |num_constraints: usize, num_variables: usize, rng: &mut TestRng| {
+            let a = Fr::rand(rng);
+            let b = Fr::rand(rng);
+            (Circuit { a: Some(a), b: Some(b), num_constraints, num_variables }, vec![a * b])
+        };
+        let circuits: Vec<_> = (0..4)
+            .map(|i| if i % 2 == 0 { sample_circuit(100, 25, &mut rng) } else { sample_circuit(300, 50, &mut rng) })
+            .collect();
+
+        // Generate the circuit parameters from one universal SRS.
+        let max_degree = AHPForR1CS::<Fr, MarlinHidingMode>::index(&circuits[1].0).unwrap().max_degree();
+        let universal_srs = TestSNARK::universal_setup(&max_degree).unwrap();
+        let (pk_a, vk_a) = TestSNARK::circuit_setup(&universal_srs, &circuits[0].0).unwrap();
+        let (pk_b, vk_b) = TestSNARK::circuit_setup(&universal_srs, &circuits[1].0).unwrap();
+
+        let fs_parameters = FS::sample_parameters();
+
+        // Certify the first circuit.
+        let certificate = TestSNARK::prove_vk(&fs_parameters, &vk_a, &pk_a).unwrap();
+        let mut certificates = [(&circuits[0].0, &vk_a, &certificate)];
+        let (vk_a, vk_b) = (vk_a.prepare(), vk_b.prepare());
+
+        // Prove each instance on its own.
+        let proofs: Vec<_> = circuits
+            .iter()
+            .enumerate()
+            .map(|(i, (circuit, _))| {
+                let proving_key = if i % 2 == 0 { &pk_a } else { &pk_b };
+                TestSNARK::prove(&fs_parameters, proving_key, circuit, &mut rng).unwrap()
+            })
+            .collect();
+
+        // Ensure the proofs verify together.
+        let mut instances: Vec<_> = circuits
+            .iter()
+            .zip_eq(&proofs)
+            .enumerate()
+            .map(|(i, ((_, inputs), proof))| {
+                let verifying_key = if i % 2 == 0 { &vk_a } else { &vk_b };
+                (verifying_key, std::slice::from_ref(inputs), proof)
+            })
+            .collect();
+        assert!(TestSNARK::verify_many_prepared(&fs_parameters, &instances, &certificates, &mut rng).unwrap());
+        assert_eq!(TestSNARK::find_invalid_proof_prepared(&fs_parameters, &instances, &certificates, &mut rng), None);
+
+        // Ensure a certificate for the wrong circuit is located after the proofs.
+        certificates[0].0 = &circuits[1].0;
+        assert!(!TestSNARK::verify_many_prepared(&fs_parameters, &instances, &certificates, &mut rng).unwrap_or(false));
+        assert_eq!(
+            TestSNARK::find_invalid_proof_prepared(&fs_parameters, &instances, &certificates, &mut rng),
+            Some(4)
+        );
+        certificates[0].0 = &circuits[0].0;
+
+        // Ensure an invalid proof is located.
+        let wrong_inputs = vec![circuits[2].1[0] + Fr::one()];
+        instances[2].1 = std::slice::from_ref(&wrong_inputs);
+        assert!(!TestSNARK::verify_many_prepared(&fs_parameters, &instances, &certificates, &mut rng).unwrap());
+        assert_eq!(
+            TestSNARK::find_invalid_proof_prepared(&fs_parameters, &instances, &certificates, &mut rng),
+            Some(2)
+        );
+
+        // Ensure a proof under the wrong verifying key is located.
+        instances[2].1 = std::slice::from_ref(&circuits[2].1);
+        instances[3].0 = &vk_a;
+        assert!(!TestSNARK::verify_many_prepared(&fs_parameters, &instances, &certificates, &mut rng).unwrap_or(false));
+        assert_eq!(
+            TestSNARK::find_invalid_proof_prepared(&fs_parameters, &instances, &certificates, &mut rng),
+            Some(3)
+        );
+    }
 }
@@ -295,7 +295,7 @@ mod marlin_hiding {
     use super::*;
     use crate::{
         crypto_hash::PoseidonSponge,
-        snark::marlin::{ahp::AHPForR1CS, CircuitVerifyingKey, MarlinHidingMode, MarlinSNARK},
+        snark::marlin::{ahp::AHPForR1CS, CircuitProvingKey, CircuitVerifyingKey, MarlinHidingMode, MarlinSNARK},
     };
     use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
     use snarkvm_utilities::{
@@ -387,6 +387,34 @@ mod marlin_hiding {
         assert_eq!(index_vk, bincode::deserialize(&candidate_bytes[..]).unwrap());
     }
 
+    #[test]
+    fn test_mapped_proving_key() {
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, MarlinHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = MarlinInst::universal_setup(&max_degree).unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        let (a, b) = (Fr::rand(rng), Fr::rand(rng));
+        let circuit = Circuit { a: Some(a), b: Some(b), num_constraints: 100, num_variables: 25 };
+        let (index_pk, index_vk) = MarlinInst::circuit_setup(&universal_srs, &circuit).unwrap();
+
+        // Write the proving key in the zero-copy layout, and map it back in.
+        let path = std::env::temp_dir().join(format!("snarkvm_marlin_mapped_proving_key_{}", std::process::id()));
+        index_pk.write_mapped(&path).unwrap();
+        let mapped_pk = CircuitProvingKey::load_mapped(&path).unwrap();
+        assert!(mapped_pk.committer_key.powers_of_beta_g.is_mapped());
+        assert_eq!(index_pk, mapped_pk);
+
+        // Ensure the mapped proving key produces valid proofs.
+        let proof = MarlinInst::prove(&fs_parameters, &mapped_pk, &circuit, rng).unwrap();
+        let c = a * b;
+        let d = c * b;
+        assert!(MarlinInst::verify(&fs_parameters, &index_vk, [c, d], &proof).unwrap());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
     #[test]
     fn prove_and_verify_with_tall_matrix_big() {
         let num_constraints = 100;
@@ -509,3 +537,54 @@ mod marlin_hiding {
         assert!(MarlinInst::verify(&fs_parameters, &vk1, [c1, d1], &proof1).unwrap());
     }
 }
+
+mod marlin_bn254 {
+    use super::*;
+    use crate::{
+        crypto_hash::PoseidonSponge,
+        polycommit::kzg10::KZG10,
+        snark::marlin::{ahp::AHPForR1CS, MarlinHidingMode, MarlinNonHidingMode, MarlinSNARK},
+    };
+    use snarkvm_curves::bn254::{Bn254, Fq, Fr};
+    use snarkvm_utilities::rand::{TestRng, Uniform};
+
+    use core::ops::MulAssign;
+
+    type MarlinInst = MarlinSNARK<Bn254, FS, MarlinHidingMode>;
+    type MarlinPoswInst = MarlinSNARK<Bn254, FS, MarlinNonHidingMode>;
+    type FS = PoseidonSponge<Fq, 2, 1>;
+
+    macro_rules! impl_bn254_test {
+        ($test_name:ident, $marlin_inst:tt, $marlin_mode:tt) => {
+            #[test]
+            fn $test_name() {
+                let rng = &mut TestRng::default();
+
+                let max_degree = AHPForR1CS::<Fr, $marlin_mode>::max_degree(100, 25, 300).unwrap();
+                // The hard-coded SRS is over BLS12-377, so sample one for BN254.
+                let universal_srs = KZG10::<Bn254>::setup(max_degree, rng).unwrap();
+                let fs_parameters = FS::sample_parameters();
+
+                for (num_constraints, num_variables) in [(25, 25), (100, 25), (26, 25)] {
+                    let a = Fr::rand(rng);
+                    let b = Fr::rand(rng);
+                    let mut c = a;
+                    c.mul_assign(&b);
+                    let mut d = c;
+                    d.mul_assign(&b);
+
+                    let circuit = Circuit { a: Some(a), b: Some(b), num_constraints, num_variables };
+
+                    let (index_pk, index_vk) = $marlin_inst::circuit_setup(&universal_srs, &circuit).unwrap();
+                    let proof = $marlin_inst::prove(&fs_parameters, &index_pk, &circuit, rng).unwrap();
+
+                    assert!($marlin_inst::verify(&fs_parameters, &index_vk, [c, d], &proof).unwrap());
+                    assert!(!$marlin_inst::verify(&fs_parameters, &index_vk, [a, a], &proof).unwrap());
+                }
+            }
+        };
+    }
+
+    impl_bn254_test!(prove_and_verify_hiding, MarlinInst, MarlinHidingMode);
+    impl_bn254_test!(prove_and_verify_non_hiding, MarlinPoswInst, MarlinNonHidingMode);
+}
@@ -24,6 +24,7 @@ use crate::PrivateKey;
 use snarkvm_circuit_network::Aleo;
 use snarkvm_circuit_types::{environment::prelude::*, Address, Group, Scalar};
 
+#[derive(Clone)]
 pub struct ComputeKey<A: Aleo> {
     /// The signature public key `pk_sig` := G^sk_sig.
     pk_sig: Group<A>,
@@ -50,6 +51,16 @@ impl<A: Aleo> Inject for ComputeKey<A> {
     }
 }
 
+impl<A: Aleo> From<(Group<A>, Group<A>)> for ComputeKey<A> {
+    /// Derives the account compute key from a tuple `(pk_sig, pr_sig)`.
+    fn from((pk_sig, pr_sig): (Group<A>, Group<A>)) -> Self {
+        // Compute `sk_prf` := HashToScalar(G^sk_sig || G^r_sig).
+        let sk_prf = A::hash_to_scalar_psd4(&[pk_sig.to_x_coordinate(), pr_sig.to_x_coordinate()]);
+        // Output the compute key.
+        Self { pk_sig, pr_sig, sk_prf }
+    }
+}
+
 impl<A: Aleo> ComputeKey<A> {
     /// Returns the signature public key.
     pub const fn pk_sig(&self) -> &Group<A> {
@@ -0,0 +1,138 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+use super::*;
+
+impl<A: Aleo> ToBits for Signature<A> {
+    type Boolean = Boolean<A>;
+
+    /// Returns the little-endian bits of the signature.
+    fn to_bits_le(&self) -> Vec<Self::Boolean> {
+        let mut bits_le = Vec::new();
+        // Write the challenge and response bits.
+        bits_le.extend(self.challenge.to_bits_le());
+        bits_le.extend(self.response.to_bits_le());
+        // Write the compute key bits, as the x-coordinates of `pk_sig` and `pr_sig`.
+        bits_le.extend(self.compute_key.pk_sig().to_x_coordinate().to_bits_le());
+        bits_le.extend(self.compute_key.pr_sig().to_x_coordinate().to_bits_le());
+        bits_le
+    }
+
+    /// Returns the big-endian bits of the signature.
+    fn to_bits_be(&self) -> Vec<Self::Boolean> {
+        let mut bits_be = Vec::new();
+        // Write the challenge and response bits.
+        bits_be.extend(self.challenge.to_bits_be());
+        bits_be.extend(self.response.to_bits_be());
+        // Write the compute key bits, as the x-coordinates of `pk_sig` and `pr_sig`.
+        bits_be.extend(self.compute_key.pk_sig().to_x_coordinate().to_bits_be());
+        bits_be.extend(self.compute_key.pr_sig().to_x_coordinate().to_bits_be());
+        bits_be
+    }
+}
+
+impl<A: Aleo> FromBits for Signature<A> {
+    type Boolean = Boolean<A>;
+
+    /// Initializes a new signature from a list of little-endian bits.
+    fn from_bits_le(bits_le: &[Self::Boolean]) -> Self {
+        let (challenge, response, pk_sig, pr_sig) = split_bits::<A>(bits_le);
+        Self {
+            challenge: Scalar::from_bits_le(challenge),
+            response: Scalar::from_bits_le(response),
+            compute_key: ComputeKey::from((
+                Group::from_x_coordinate(Field::from_bits_le(pk_sig)),
+                Group::from_x_coordinate(Field::from_bits_le(pr_sig)),
+            )),
+        }
+    }
+
+    /// Initializes a new signature from a list of big-endian bits.
+    fn from_bits_be(bits_be: &[Self::Boolean]) -> Self {
+        let (challenge, response, pk_sig, pr_sig) = split_bits::<A>(bits_be);
+        Self {
+            challenge: Scalar::from_bits_be(challenge),
+            response: Scalar::from_bits_be(response),
+            compute_key: ComputeKey::from((
+                Group::from_x_coordinate(Field::from_bits_be(pk_sig)),
+                Group::from_x_coordinate(Field::from_bits_be(pr_sig)),
+            )),
+        }
+    }
+}
+
+/// Splits the signature bits into the challenge, response, `pk_sig`, and `pr_sig` bits.
+#[allow(clippy::type_complexity)]
+fn split_bits<A: Aleo>(bits: &[Boolean<A>]) -> (&[Boolean<A>], &[Boolean<A>], &[Boolean<A>], &[Boolean<A>]) {
+    let scalar_size_in_bits = A::ScalarField::size_in_bits();
+    let field_size_in_bits = A::BaseField::size_in_bits();
+
+    // Ensure the number of bits matches the signature size.
+    if bits.len() != 2 * scalar_size_in_bits + 2 * field_size_in_bits {
+        A::halt(format!("Invalid signature size: found {} bits", bits.len()))
+    }
+
+    let (challenge, bits) = bits.split_at(scalar_size_in_bits);
+    let (response, bits) = bits.split_at(scalar_size_in_bits);
+    let (pk_sig, pr_sig) = bits.split_at(field_size_in_bits);
+    (challenge, response, pk_sig, pr_sig)
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::{helpers::generate_account, Circuit};
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+
+    fn check_bits(mode: Mode) -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Generate a signature.
+            let (private_key, _compute_key, _view_key, _address) = generate_account()?;
+            let expected = console::Signature::sign(&private_key, &[Uniform::rand(rng)], rng)?;
+            let signature = Signature::<Circuit>::new(mode, expected);
+
+            // Ensure the bits match the console bits.
+            assert_eq!(expected.to_bits_le(), signature.to_bits_le().eject_value());
+            assert_eq!(expected.to_bits_be(), signature.to_bits_be().eject_value());
+
+            // Ensure the signature is recovered from its bits.
+            assert_eq!(expected, Signature::<Circuit>::from_bits_le(&signature.to_bits_le()).eject_value());
+            assert_eq!(expected, Signature::<Circuit>::from_bits_be(&signature.to_bits_be()).eject_value());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bits_constant() -> Result<()> {
+        check_bits(Mode::Constant)
+    }
+
+    #[test]
+    fn test_bits_public() -> Result<()> {
+        check_bits(Mode::Public)
+    }
+
+    #[test]
+    fn test_bits_private() -> Result<()> {
+        check_bits(Mode::Private)
+    }
+}
@@ -19,306 +19,319 @@ use crate::{helpers::Constraint, Mode, *};
 use core::{cell::RefCell, fmt};
 use std::rc::Rc;
 
-type Field = <console::Testnet3 as console::Environment>::Field;
-
-thread_local! {
-    pub(super) static CIRCUIT: Rc<RefCell<R1CS<Field>>> = Rc::new(RefCell::new(R1CS::new()));
-    pub(super) static IN_WITNESS: Rc<RefCell<bool>> = Rc::new(RefCell::new(false));
-    pub(super) static ZERO: LinearCombination<Field> = LinearCombination::zero();
-    pub(super) static ONE: LinearCombination<Field> = LinearCombination::one();
-}
+/// Implements a circuit environment for the given console network, backed by a thread-local R1CS.
+macro_rules! circuit_environment {
+    ($circuit:ident, $network:ty) => {
+        type Field = <$network as console::Environment>::Field;
+
+        thread_local! {
+            pub(super) static CIRCUIT: Rc<RefCell<R1CS<Field>>> = Rc::new(RefCell::new(R1CS::new()));
+            pub(super) static IN_WITNESS: Rc<RefCell<bool>> = Rc::new(RefCell::new(false));
+            pub(super) static ZERO: LinearCombination<Field> = LinearCombination::zero();
+            pub(super) static ONE: LinearCombination<Field> = LinearCombination::one();
+        }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
-pub struct Circuit;
+        #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+        pub struct $circuit;
 
-impl Environment for Circuit {
-    type Affine = <console::Testnet3 as console::Environment>::Affine;
-    type BaseField = Field;
-    type Network = console::Testnet3;
-    type ScalarField = <console::Testnet3 as console::Environment>::Scalar;
+        impl Environment for $circuit {
+            type Affine = <$network as console::Environment>::Affine;
+            type BaseField = Field;
+            type Network = $network;
+            type ScalarField = <$network as console::Environment>::Scalar;
 
-    /// Returns the `zero` constant.
-    fn zero() -> LinearCombination<Self::BaseField> {
-        ZERO.with(|zero| zero.clone())
-    }
+            /// Returns the `zero` constant.
+            fn zero() -> LinearCombination<Self::BaseField> {
+                ZERO.with(|zero| zero.clone())
+            }
 
-    /// Returns the `one` constant.
-    fn one() -> LinearCombination<Self::BaseField> {
-        ONE.with(|one| one.clone())
-    }
+            /// Returns the `one` constant.
+            fn one() -> LinearCombination<Self::BaseField> {
+                ONE.with(|one| one.clone())
+            }
 
-    /// Returns a new variable of the given mode and value.
-    fn new_variable(mode: Mode, value: Self::BaseField) -> Variable<Self::BaseField> {
-        IN_WITNESS.with(|in_witness| {
-            // Ensure we are not in witness mode.
-            if !(*(**in_witness).borrow()) {
-                CIRCUIT.with(|circuit| match mode {
-                    Mode::Constant => (**circuit).borrow_mut().new_constant(value),
-                    Mode::Public => (**circuit).borrow_mut().new_public(value),
-                    Mode::Private => (**circuit).borrow_mut().new_private(value),
+            /// Returns a new variable of the given mode and value.
+            fn new_variable(mode: Mode, value: Self::BaseField) -> Variable<Self::BaseField> {
+                IN_WITNESS.with(|in_witness| {
+                    // Ensure we are not in witness mode.
+                    if !(*(**in_witness).borrow()) {
+                        CIRCUIT.with(|circuit| match mode {
+                            Mode::Constant => (**circuit).borrow_mut().new_constant(value),
+                            Mode::Public => (**circuit).borrow_mut().new_public(value),
+                            Mode::Private => (**circuit).borrow_mut().new_private(value),
+                        })
+                    } else {
+                        Self::halt("Tried to initialize a new variable in witness mode")
+                    }
                 })
-            } else {
-                Self::halt("Tried to initialize a new variable in witness mode")
             }
-        })
-    }
 
-    /// Returns a new witness of the given mode and value.
-    fn new_witness<Fn: FnOnce() -> Output::Primitive, Output: Inject>(mode: Mode, logic: Fn) -> Output {
-        IN_WITNESS.with(|in_witness| {
-            // Set the entire environment to witness mode.
-            *(**in_witness).borrow_mut() = true;
+            /// Returns a new witness of the given mode and value.
+            fn new_witness<Fn: FnOnce() -> Output::Primitive, Output: Inject>(mode: Mode, logic: Fn) -> Output {
+                IN_WITNESS.with(|in_witness| {
+                    // Set the entire environment to witness mode.
+                    *(**in_witness).borrow_mut() = true;
 
-            // Run the logic.
-            let output = logic();
+                    // Run the logic.
+                    let output = logic();
 
-            // Return the entire environment from witness mode.
-            *(**in_witness).borrow_mut() = false;
+                    // Return the entire environment from witness mode.
+                    *(**in_witness).borrow_mut() = false;
 
-            Inject::new(mode, output)
-        })
-    }
+                    Inject::new(mode, output)
+                })
+            }
 
-    // /// Appends the given scope to the current environment.
-    // fn push_scope(name: &str) {
-    //     CIRCUIT.with(|circuit| {
-    //         // Set the entire environment to the new scope.
-    //         match Self::cs().push_scope(name) {
-    //             Ok(()) => (),
-    //             Err(error) => Self::halt(error),
-    //         }
-    //     })
-    // }
-    //
-    // /// Removes the given scope from the current environment.
-    // fn pop_scope(name: &str) {
-    //     CIRCUIT.with(|circuit| {
-    //         // Return the entire environment to the previous scope.
-    //         match Self::cs().pop_scope(name) {
-    //             Ok(scope) => {
-    //                 scope
-    //             }
-    //             Err(error) => Self::halt(error),
-    //         }
-    //     })
-    // }
-
-    /// Enters a new scope for the environment.
-    fn scope<S: Into<String>, Fn, Output>(name: S, logic: Fn) -> Output
-    where
-        Fn: FnOnce() -> Output,
-    {
-        IN_WITNESS.with(|in_witness| {
-            // Ensure we are not in witness mode.
-            if !(*(**in_witness).borrow()) {
-                CIRCUIT.with(|circuit| {
-                    // Set the entire environment to the new scope.
-                    let name = name.into();
-                    if let Err(error) = (**circuit).borrow_mut().push_scope(&name) {
-                        Self::halt(error)
+            // /// Appends the given scope to the current environment.
+            // fn push_scope(name: &str) {
+            //     CIRCUIT.with(|circuit| {
+            //         // Set the entire environment to the new scope.
+            //         match Self::cs().push_scope(name) {
+            //             Ok(()) => (),
+            //             Err(error) => Self::halt(error),
+            //         }
+            //     })
+            // }
+            //
+            // /// Removes the given scope from the current environment.
+            // fn pop_scope(name: &str) {
+            //     CIRCUIT.with(|circuit| {
+            //         // Return the entire environment to the previous scope.
+            //         match Self::cs().pop_scope(name) {
+            //             Ok(scope) => {
+            //                 scope
+            //             }
+            //             Err(error) => Self::halt(error),
+            //         }
+            //     })
+            // }
+
+            /// Enters a new scope for the environment.
+            fn scope<S: Into<String>, Fn, Output>(name: S, logic: Fn) -> Output
+            where
+                Fn: FnOnce() -> Output,
+            {
+                IN_WITNESS.with(|in_witness| {
+                    // Ensure we are not in witness mode.
+                    if !(*(**in_witness).borrow()) {
+                        CIRCUIT.with(|circuit| {
+                            // Set the entire environment to the new scope.
+                            let name = name.into();
+                            if let Err(error) = (**circuit).borrow_mut().push_scope(&name) {
+                                Self::halt(error)
+                            }
+
+                            // Run the logic.
+                            let output = logic();
+
+                            // Return the entire environment to the previous scope.
+                            if let Err(error) = (**circuit).borrow_mut().pop_scope(name) {
+                                Self::halt(error)
+                            }
+
+                            output
+                        })
+                    } else {
+                        Self::halt("Tried to initialize a new scope in witness mode")
                     }
+                })
+            }
 
-                    // Run the logic.
-                    let output = logic();
-
-                    // Return the entire environment to the previous scope.
-                    if let Err(error) = (**circuit).borrow_mut().pop_scope(name) {
-                        Self::halt(error)
+            /// Adds one constraint enforcing that `(A * B) == C`.
+            fn enforce<Fn, A, B, C>(constraint: Fn)
+            where
+                Fn: FnOnce() -> (A, B, C),
+                A: Into<LinearCombination<Self::BaseField>>,
+                B: Into<LinearCombination<Self::BaseField>>,
+                C: Into<LinearCombination<Self::BaseField>>,
+            {
+                IN_WITNESS.with(|in_witness| {
+                    // Ensure we are not in witness mode.
+                    if !(*(**in_witness).borrow()) {
+                        CIRCUIT.with(|circuit| {
+                            let (a, b, c) = constraint();
+                            let (a, b, c) = (a.into(), b.into(), c.into());
+
+                            // Ensure the constraint is not comprised of constants.
+                            match a.is_constant() && b.is_constant() && c.is_constant() {
+                                true => {
+                                    // Evaluate the constant constraint.
+                                    assert_eq!(
+                                        a.value() * b.value(),
+                                        c.value(),
+                                        "Constant constraint failed: ({} * {}) =?= {}",
+                                        a,
+                                        b,
+                                        c
+                                    );
+
+                                    // match self.counter.scope().is_empty() {
+                                    //     true => println!("Enforced constraint with constant terms: ({} * {}) =?= {}", a, b, c),
+                                    //     false => println!(
+                                    //         "Enforced constraint with constant terms ({}): ({} * {}) =?= {}",
+                                    //         self.counter.scope(), a, b, c
+                                    //     ),
+                                    // }
+                                }
+                                false => {
+                                    // Construct the constraint object.
+                                    let constraint = Constraint((**circuit).borrow().scope(), a, b, c);
+                                    // Append the constraint.
+                                    (**circuit).borrow_mut().enforce(constraint)
+                                }
+                            }
+                        });
                     }
-
-                    output
                 })
-            } else {
-                Self::halt("Tried to initialize a new scope in witness mode")
             }
-        })
-    }
 
-    /// Adds one constraint enforcing that `(A * B) == C`.
-    fn enforce<Fn, A, B, C>(constraint: Fn)
-    where
-        Fn: FnOnce() -> (A, B, C),
-        A: Into<LinearCombination<Self::BaseField>>,
-        B: Into<LinearCombination<Self::BaseField>>,
-        C: Into<LinearCombination<Self::BaseField>>,
-    {
-        IN_WITNESS.with(|in_witness| {
-            // Ensure we are not in witness mode.
-            if !(*(**in_witness).borrow()) {
-                CIRCUIT.with(|circuit| {
-                    let (a, b, c) = constraint();
-                    let (a, b, c) = (a.into(), b.into(), c.into());
-
-                    // Ensure the constraint is not comprised of constants.
-                    match a.is_constant() && b.is_constant() && c.is_constant() {
-                        true => {
-                            // Evaluate the constant constraint.
-                            assert_eq!(
-                                a.value() * b.value(),
-                                c.value(),
-                                "Constant constraint failed: ({} * {}) =?= {}",
-                                a,
-                                b,
-                                c
-                            );
-
-                            // match self.counter.scope().is_empty() {
-                            //     true => println!("Enforced constraint with constant terms: ({} * {}) =?= {}", a, b, c),
-                            //     false => println!(
-                            //         "Enforced constraint with constant terms ({}): ({} * {}) =?= {}",
-                            //         self.counter.scope(), a, b, c
-                            //     ),
-                            // }
-                        }
-                        false => {
-                            // Construct the constraint object.
-                            let constraint = Constraint((**circuit).borrow().scope(), a, b, c);
-                            // Append the constraint.
-                            (**circuit).borrow_mut().enforce(constraint)
-                        }
-                    }
-                });
+            /// Returns `true` if all constraints in the environment are satisfied.
+            fn is_satisfied() -> bool {
+                CIRCUIT.with(|circuit| (**circuit).borrow().is_satisfied())
             }
-        })
-    }
 
-    /// Returns `true` if all constraints in the environment are satisfied.
-    fn is_satisfied() -> bool {
-        CIRCUIT.with(|circuit| (**circuit).borrow().is_satisfied())
-    }
+            /// Returns `true` if all constraints in the current scope are satisfied.
+            fn is_satisfied_in_scope() -> bool {
+                CIRCUIT.with(|circuit| (**circuit).borrow().is_satisfied_in_scope())
+            }
 
-    /// Returns `true` if all constraints in the current scope are satisfied.
-    fn is_satisfied_in_scope() -> bool {
-        CIRCUIT.with(|circuit| (**circuit).borrow().is_satisfied_in_scope())
-    }
+            /// Returns the number of constants in the entire circuit.
+            fn num_constants() -> u64 {
+                CIRCUIT.with(|circuit| (**circuit).borrow().num_constants())
+            }
 
-    /// Returns the number of constants in the entire circuit.
-    fn num_constants() -> u64 {
-        CIRCUIT.with(|circuit| (**circuit).borrow().num_constants())
-    }
+            /// Returns the number of public variables in the entire circuit.
+            fn num_public() -> u64 {
+                CIRCUIT.with(|circuit| (**circuit).borrow().num_public())
+            }
 
-    /// Returns the number of public variables in the entire circuit.
-    fn num_public() -> u64 {
-        CIRCUIT.with(|circuit| (**circuit).borrow().num_public())
-    }
+            /// Returns the number of private variables in the entire circuit.
+            fn num_private() -> u64 {
+                CIRCUIT.with(|circuit| (**circuit).borrow().num_private())
+            }
 
-    /// Returns the number of private variables in the entire circuit.
-    fn num_private() -> u64 {
-        CIRCUIT.with(|circuit| (**circuit).borrow().num_private())
-    }
+            /// Returns the number of constraints in the entire circuit.
+            fn num_constraints() -> u64 {
+                CIRCUIT.with(|circuit| (**circuit).borrow().num_constraints())
+            }
 
-    /// Returns the number of constraints in the entire circuit.
-    fn num_constraints() -> u64 {
-        CIRCUIT.with(|circuit| (**circuit).borrow().num_constraints())
-    }
+            /// Returns the number of gates in the entire circuit.
+            fn num_gates() -> u64 {
+                CIRCUIT.with(|circuit| (**circuit).borrow().num_gates())
+            }
 
-    /// Returns the number of gates in the entire circuit.
-    fn num_gates() -> u64 {
-        CIRCUIT.with(|circuit| (**circuit).borrow().num_gates())
-    }
+            /// Returns the number of constants for the current scope.
+            fn num_constants_in_scope() -> u64 {
+                CIRCUIT.with(|circuit| (**circuit).borrow().num_constants_in_scope())
+            }
 
-    /// Returns the number of constants for the current scope.
-    fn num_constants_in_scope() -> u64 {
-        CIRCUIT.with(|circuit| (**circuit).borrow().num_constants_in_scope())
-    }
+            /// Returns the number of public variables for the current scope.
+            fn num_public_in_scope() -> u64 {
+                CIRCUIT.with(|circuit| (**circuit).borrow().num_public_in_scope())
+            }
 
-    /// Returns the number of public variables for the current scope.
-    fn num_public_in_scope() -> u64 {
-        CIRCUIT.with(|circuit| (**circuit).borrow().num_public_in_scope())
-    }
+            /// Returns the number of private variables for the current scope.
+            fn num_private_in_scope() -> u64 {
+                CIRCUIT.with(|circuit| (**circuit).borrow().num_private_in_scope())
+            }
 
-    /// Returns the number of private variables for the current scope.
-    fn num_private_in_scope() -> u64 {
-        CIRCUIT.with(|circuit| (**circuit).borrow().num_private_in_scope())
-    }
+            /// Returns the number of constraints for the current scope.
+            fn num_constraints_in_scope() -> u64 {
+                CIRCUIT.with(|circuit| (**circuit).borrow().num_constraints_in_scope())
+            }
 
-    /// Returns the number of constraints for the current scope.
-    fn num_constraints_in_scope() -> u64 {
-        CIRCUIT.with(|circuit| (**circuit).borrow().num_constraints_in_scope())
-    }
+            /// Returns the number of gates for the current scope.
+            fn num_gates_in_scope() -> u64 {
+                CIRCUIT.with(|circuit| (**circuit).borrow().num_gates_in_scope())
+            }
 
-    /// Returns the number of gates for the current scope.
-    fn num_gates_in_scope() -> u64 {
-        CIRCUIT.with(|circuit| (**circuit).borrow().num_gates_in_scope())
-    }
+            /// Halts the program from further synthesis, evaluation, and execution in the current environment.
+            fn halt<S: Into<String>, T>(message: S) -> T {
+                let error = message.into();
+                // eprintln!("{}", &error);
+                panic!("{}", &error)
+            }
 
-    /// Halts the program from further synthesis, evaluation, and execution in the current environment.
-    fn halt<S: Into<String>, T>(message: S) -> T {
-        let error = message.into();
-        // eprintln!("{}", &error);
-        panic!("{}", &error)
-    }
+            /// Replaces the R1CS of the active circuit environment, returning the previous R1CS.
+            fn replace_r1cs(r1cs: R1CS<Self::BaseField>) -> R1CS<Self::BaseField> {
+                CIRCUIT.with(|circuit| circuit.replace(r1cs))
+            }
 
-    /// TODO (howardwu): Abstraction - Refactor this into an appropriate design.
-    ///  Circuits should not have easy access to this during synthesis.
-    /// Returns the R1CS circuit, resetting the circuit.
-    fn inject_r1cs(r1cs: R1CS<Self::BaseField>) {
-        CIRCUIT.with(|circuit| {
-            // Ensure the circuit is empty before injecting.
-            assert_eq!(0, (**circuit).borrow().num_constants());
-            assert_eq!(1, (**circuit).borrow().num_public());
-            assert_eq!(0, (**circuit).borrow().num_private());
-            assert_eq!(0, (**circuit).borrow().num_constraints());
-            // Inject the R1CS instance.
-            let r1cs = circuit.replace(r1cs);
-            // Ensure the circuit that was replaced is empty.
-            assert_eq!(0, r1cs.num_constants());
-            assert_eq!(1, r1cs.num_public());
-            assert_eq!(0, r1cs.num_private());
-            assert_eq!(0, r1cs.num_constraints());
-        })
-    }
+            /// TODO (howardwu): Abstraction - Refactor this into an appropriate design.
+            ///  Circuits should not have easy access to this during synthesis.
+            /// Returns the R1CS circuit, resetting the circuit.
+            fn inject_r1cs(r1cs: R1CS<Self::BaseField>) {
+                CIRCUIT.with(|circuit| {
+                    // Ensure the circuit is empty before injecting.
+                    assert_eq!(0, (**circuit).borrow().num_constants());
+                    assert_eq!(1, (**circuit).borrow().num_public());
+                    assert_eq!(0, (**circuit).borrow().num_private());
+                    assert_eq!(0, (**circuit).borrow().num_constraints());
+                    // Inject the R1CS instance.
+                    let r1cs = circuit.replace(r1cs);
+                    // Ensure the circuit that was replaced is empty.
+                    assert_eq!(0, r1cs.num_constants());
+                    assert_eq!(1, r1cs.num_public());
+                    assert_eq!(0, r1cs.num_private());
+                    assert_eq!(0, r1cs.num_constraints());
+                })
+            }
 
-    /// TODO (howardwu): Abstraction - Refactor this into an appropriate design.
-    ///  Circuits should not have easy access to this during synthesis.
-    /// Returns the R1CS circuit, resetting the circuit.
-    fn eject_r1cs_and_reset() -> R1CS<Self::BaseField> {
-        CIRCUIT.with(|circuit| {
-            // Eject the R1CS instance.
-            let r1cs = circuit.replace(R1CS::<<Self as Environment>::BaseField>::new());
-            // Ensure the circuit is now empty.
-            assert_eq!(0, (**circuit).borrow().num_constants());
-            assert_eq!(1, (**circuit).borrow().num_public());
-            assert_eq!(0, (**circuit).borrow().num_private());
-            assert_eq!(0, (**circuit).borrow().num_constraints());
-            // Return the R1CS instance.
-            r1cs
-        })
-    }
+            /// TODO (howardwu): Abstraction - Refactor this into an appropriate design.
+            ///  Circuits should not have easy access to this during synthesis.
+            /// Returns the R1CS circuit, resetting the circuit.
+            fn eject_r1cs_and_reset() -> R1CS<Self::BaseField> {
+                CIRCUIT.with(|circuit| {
+                    // Eject the R1CS instance.
+                    let r1cs = circuit.replace(R1CS::<<Self as Environment>::BaseField>::new());
+                    // Ensure the circuit is now empty.
+                    assert_eq!(0, (**circuit).borrow().num_constants());
+                    assert_eq!(1, (**circuit).borrow().num_public());
+                    assert_eq!(0, (**circuit).borrow().num_private());
+                    assert_eq!(0, (**circuit).borrow().num_constraints());
+                    // Return the R1CS instance.
+                    r1cs
+                })
+            }
 
-    /// TODO (howardwu): Abstraction - Refactor this into an appropriate design.
-    ///  Circuits should not have easy access to this during synthesis.
-    /// Returns the R1CS assignment of the circuit, resetting the circuit.
-    fn eject_assignment_and_reset() -> Assignment<<Self::Network as console::Environment>::Field> {
-        CIRCUIT.with(|circuit| {
-            // Eject the R1CS instance.
-            let r1cs = circuit.replace(R1CS::<<Self as Environment>::BaseField>::new());
-            assert_eq!(0, (**circuit).borrow().num_constants());
-            assert_eq!(1, (**circuit).borrow().num_public());
-            assert_eq!(0, (**circuit).borrow().num_private());
-            assert_eq!(0, (**circuit).borrow().num_constraints());
-            // Convert the R1CS instance to an assignment.
-            Assignment::from(r1cs)
-        })
-    }
+            /// TODO (howardwu): Abstraction - Refactor this into an appropriate design.
+            ///  Circuits should not have easy access to this during synthesis.
+            /// Returns the R1CS assignment of the circuit, resetting the circuit.
+            fn eject_assignment_and_reset() -> Assignment<<Self::Network as console::Environment>::Field> {
+                CIRCUIT.with(|circuit| {
+                    // Eject the R1CS instance.
+                    let r1cs = circuit.replace(R1CS::<<Self as Environment>::BaseField>::new());
+                    assert_eq!(0, (**circuit).borrow().num_constants());
+                    assert_eq!(1, (**circuit).borrow().num_public());
+                    assert_eq!(0, (**circuit).borrow().num_private());
+                    assert_eq!(0, (**circuit).borrow().num_constraints());
+                    // Convert the R1CS instance to an assignment.
+                    Assignment::from(r1cs)
+                })
+            }
 
-    /// Clears the circuit and initializes an empty environment.
-    fn reset() {
-        CIRCUIT.with(|circuit| {
-            *(**circuit).borrow_mut() = R1CS::<<Self as Environment>::BaseField>::new();
-            assert_eq!(0, (**circuit).borrow().num_constants());
-            assert_eq!(1, (**circuit).borrow().num_public());
-            assert_eq!(0, (**circuit).borrow().num_private());
-            assert_eq!(0, (**circuit).borrow().num_constraints());
-        });
-    }
-}
+            /// Clears the circuit and initializes an empty environment.
+            fn reset() {
+                CIRCUIT.with(|circuit| {
+                    *(**circuit).borrow_mut() = R1CS::<<Self as Environment>::BaseField>::new();
+                    assert_eq!(0, (**circuit).borrow().num_constants());
+                    assert_eq!(1, (**circuit).borrow().num_public());
+                    assert_eq!(0, (**circuit).borrow().num_private());
+                    assert_eq!(0, (**circuit).borrow().num_constraints());
+                });
+            }
+        }
 
-impl fmt::Display for Circuit {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        CIRCUIT.with(|circuit| write!(f, "{}", (**circuit).borrow()))
-    }
+        impl fmt::Display for $circuit {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                CIRCUIT.with(|circuit| write!(f, "{}", (**circuit).borrow()))
+            }
+        }
+    };
 }
+pub(crate) use circuit_environment;
+
+circuit_environment!(Circuit, console::Testnet3);
 
 #[cfg(test)]
 mod tests {
@@ -355,6 +368,60 @@ mod tests {
         println!("{}", output);
     }
 
+    #[test]
+    fn test_circuit_enter() {
+        let _candidate = create_example_circuit::<Circuit>();
+        let count = Circuit::count();
+
+        {
+            // Enter a new environment.
+            let _environment = Circuit::enter();
+            assert_eq!((0, 1, 0, 0, 0), Circuit::count());
+            let _candidate = create_example_circuit::<Circuit>();
+            let nested_count = Circuit::count();
+
+            // Enter a nested environment, and eject its assignment.
+            let environment = Circuit::enter();
+            assert_eq!((0, 1, 0, 0, 0), Circuit::count());
+            let _candidate = create_example_circuit::<Circuit>();
+            let assignment = environment.eject_assignment();
+            assert_eq!(nested_count.1, assignment.num_public());
+            assert_eq!(nested_count.2, assignment.num_private());
+            assert_eq!(nested_count.3, assignment.num_constraints());
+
+            // Ensure the enclosing environment is restored.
+            assert_eq!(nested_count, Circuit::count());
+        }
+
+        // Ensure the original environment is restored.
+        assert_eq!(count, Circuit::count());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_circuit_enter_interleaved() {
+        let _candidate = create_example_circuit::<Circuit>();
+        let count = Circuit::count();
+
+        // Enter two environments, and exit the first one before the second one.
+        let first = Circuit::enter();
+        let _candidate = create_example_circuit::<Circuit>();
+        let second = Circuit::enter();
+        let _candidate = create_example_circuit::<Circuit>();
+        let second_count = Circuit::count();
+        let assignment = first.eject_assignment();
+        assert_eq!(second_count.2, assignment.num_private());
+
+        // Ensure the second environment is still active.
+        assert_eq!(second_count, Circuit::count());
+        drop(second);
+
+        // Ensure the original environment is restored.
+        assert_eq!(count, Circuit::count());
+        Circuit::reset();
+    }
+
     #[test]
     fn test_circuit_scope() {
         Circuit::scope("test_circuit_scope", || {
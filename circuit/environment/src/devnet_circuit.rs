@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{circuit::circuit_environment, helpers::Constraint, Mode, *};
+
+use core::{cell::RefCell, fmt};
+use std::rc::Rc;
+
+circuit_environment!(DevnetCircuit, console::Devnet);
+
+#[cfg(test)]
+mod tests {
+    use snarkvm_circuit::prelude::*;
+
+    /// Compute 2^EXPONENT - 1, in a purposefully constraint-inefficient manner for testing.
+    fn create_example_circuit<E: Environment>() -> Field<E> {
+        let one = snarkvm_console_types::Field::<E::Network>::one();
+        let two = one + one;
+
+        const EXPONENT: u64 = 64;
+
+        // Compute 2^EXPONENT - 1, in a purposefully constraint-inefficient manner for testing.
+        let mut candidate = Field::<E>::new(Mode::Public, one);
+        let mut accumulator = Field::new(Mode::Private, two);
+        for _ in 0..EXPONENT {
+            candidate += &accumulator;
+            accumulator *= Field::new(Mode::Private, two);
+        }
+
+        assert_eq!((accumulator - Field::one()).eject_value(), candidate.eject_value());
+        assert_eq!(2, E::num_public());
+        assert_eq!(2 * EXPONENT + 1, E::num_private());
+        assert_eq!(EXPONENT, E::num_constraints());
+        assert!(E::is_satisfied());
+
+        candidate
+    }
+
+    #[test]
+    fn test_circuit_is_separate() {
+        let _candidate = create_example_circuit::<DevnetCircuit>();
+        // Ensure the testnet circuit environment is unaffected.
+        assert_eq!((0, 1, 0, 0, 0), Circuit::count());
+        DevnetCircuit::reset();
+    }
+
+    #[test]
+    fn test_circuit_scope() {
+        DevnetCircuit::scope("test_circuit_scope", || {
+            assert_eq!(0, DevnetCircuit::num_constants());
+            assert_eq!(1, DevnetCircuit::num_public());
+            assert_eq!(0, DevnetCircuit::num_private());
+            assert_eq!(0, DevnetCircuit::num_constraints());
+
+            assert_eq!(0, DevnetCircuit::num_constants_in_scope());
+            assert_eq!(0, DevnetCircuit::num_public_in_scope());
+            assert_eq!(0, DevnetCircuit::num_private_in_scope());
+            assert_eq!(0, DevnetCircuit::num_constraints_in_scope());
+        })
+    }
+}
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{witness_mode, Assignment, Inject, LinearCombination, Mode, Variable, R1CS};
+use crate::{witness_mode, Assignment, EnvironmentGuard, Inject, LinearCombination, Mode, Variable, R1CS};
 use snarkvm_curves::AffineCurve;
 use snarkvm_fields::traits::*;
 
@@ -162,6 +162,20 @@ pub trait Environment: 'static + Copy + Clone + fmt::Debug + fmt::Display + Eq +
         <Self::Network as console::Environment>::halt(message)
     }
 
+    /// Enters a new, empty circuit environment, which is active until the returned guard is dropped.
+    /// Upon dropping the guard, its circuit environment is discarded, and the enclosing one is restored.
+    fn enter() -> EnvironmentGuard<Self> {
+        EnvironmentGuard::new()
+    }
+
+    /// Replaces the R1CS of the active circuit environment, returning the previous R1CS.
+    fn replace_r1cs(r1cs: R1CS<Self::BaseField>) -> R1CS<Self::BaseField> {
+        // Eject the active R1CS, and inject the given R1CS into the now-empty environment.
+        let previous = Self::eject_r1cs_and_reset();
+        Self::inject_r1cs(r1cs);
+        previous
+    }
+
     /// Returns the R1CS circuit, resetting the circuit.
     fn inject_r1cs(r1cs: R1CS<Self::BaseField>);
 
@@ -16,8 +16,13 @@
 
 use crate::Index;
 use snarkvm_fields::PrimeField;
+use snarkvm_utilities::{BigInteger, ToBytes};
 
 use indexmap::IndexMap;
+use std::{
+    collections::BTreeMap,
+    io::{Result as IoResult, Write},
+};
 
 #[derive(Clone, PartialEq, Eq, Hash)]
 enum AssignmentVariable<F: PrimeField> {
@@ -103,6 +108,162 @@ impl<F: PrimeField> Assignment<F> {
     }
 }
 
+impl<F: PrimeField> Assignment<F> {
+    /// Writes the constraint system in the iden3 `.r1cs` binary format (version 1).
+    ///
+    /// Wire `0` is the public variable for the constant `1`, followed by the remaining public variables,
+    /// and then the private variables. Constant terms are folded into the coefficient of wire `0`.
+    pub fn write_r1cs<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        let n8 = Self::field_size_in_bytes();
+
+        // Construct the header section.
+        let mut header = Vec::new();
+        header.extend_from_slice(&n8.to_le_bytes());
+        F::modulus().write_le(&mut header)?;
+        header.extend_from_slice(&self.num_wires().to_le_bytes());
+        // There are no public outputs, all public variables are public inputs, and the private variables are witness wires.
+        header.extend_from_slice(&0u32.to_le_bytes());
+        header.extend_from_slice(&(self.num_public() as u32 - 1).to_le_bytes());
+        header.extend_from_slice(&0u32.to_le_bytes());
+        header.extend_from_slice(&(self.num_wires() as u64).to_le_bytes());
+        header.extend_from_slice(&(self.num_constraints() as u32).to_le_bytes());
+
+        // Construct the constraints section.
+        let mut constraints = Vec::new();
+        for (a, b, c) in self.to_wire_constraints() {
+            for lc in [a, b, c] {
+                constraints.extend_from_slice(&(lc.len() as u32).to_le_bytes());
+                for (wire, coefficient) in lc {
+                    constraints.extend_from_slice(&wire.to_le_bytes());
+                    coefficient.to_bigint().write_le(&mut constraints)?;
+                }
+            }
+        }
+
+        // Construct the wire-to-label map section, where each wire is its own label.
+        let mut map = Vec::new();
+        for wire in 0..self.num_wires() {
+            map.extend_from_slice(&(wire as u64).to_le_bytes());
+        }
+
+        Self::write_sections(&mut writer, b"r1cs", 1, &[(1, header), (2, constraints), (3, map)])
+    }
+
+    /// Writes the witness assignment in the iden3 `.wtns` binary format (version 2).
+    /// The witness values are ordered by wire, as in `Self::write_r1cs`.
+    pub fn write_wtns<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        let n8 = Self::field_size_in_bytes();
+
+        // Construct the header section.
+        let mut header = Vec::new();
+        header.extend_from_slice(&n8.to_le_bytes());
+        F::modulus().write_le(&mut header)?;
+        header.extend_from_slice(&self.num_wires().to_le_bytes());
+
+        // Construct the witness section.
+        let mut witness = Vec::new();
+        for value in self.to_witness() {
+            value.to_bigint().write_le(&mut witness)?;
+        }
+
+        Self::write_sections(&mut writer, b"wtns", 2, &[(1, header), (2, witness)])
+    }
+
+    /// Returns the constraint system in the JSON format of `snarkjs r1cs export json`.
+    pub fn to_r1cs_json(&self) -> serde_json::Value {
+        let constraints = self
+            .to_wire_constraints()
+            .into_iter()
+            .map(|(a, b, c)| {
+                [a, b, c]
+                    .into_iter()
+                    .map(|lc| {
+                        lc.into_iter()
+                            .map(|(wire, coefficient)| (wire.to_string(), coefficient.to_string().into()))
+                            .collect::<serde_json::Map<_, _>>()
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        serde_json::json!({
+            "n8": Self::field_size_in_bytes(),
+            "prime": F::modulus().to_string(),
+            "nVars": self.num_wires(),
+            "nOutputs": 0,
+            "nPubInputs": self.num_public() - 1,
+            "nPrvInputs": 0,
+            "nLabels": self.num_wires(),
+            "nConstraints": self.num_constraints(),
+            "constraints": constraints,
+            "map": (0..self.num_wires()).collect::<Vec<_>>(),
+        })
+    }
+
+    /// Returns the witness assignment in the JSON format of `snarkjs wtns export json`.
+    pub fn to_wtns_json(&self) -> serde_json::Value {
+        self.to_witness().iter().map(|value| value.to_string()).collect()
+    }
+
+    /// Returns the witness values, ordered by wire.
+    pub fn to_witness(&self) -> Vec<F> {
+        self.public.values().chain(self.private.values()).cloned().collect()
+    }
+
+    /// Returns the number of wires, which is the sum of the public and private variables.
+    fn num_wires(&self) -> u32 {
+        (self.num_public() + self.num_private()) as u32
+    }
+
+    /// Returns the number of bytes used to encode a field element.
+    fn field_size_in_bytes() -> u32 {
+        (<F::BigInteger as BigInteger>::NUM_LIMBS * 8) as u32
+    }
+
+    /// Returns the constraints, with each linear combination as a sorted map from wire to nonzero coefficient.
+    #[allow(clippy::type_complexity)]
+    fn to_wire_constraints(&self) -> Vec<(BTreeMap<u32, F>, BTreeMap<u32, F>, BTreeMap<u32, F>)> {
+        let num_public = self.num_public();
+
+        let convert = |lc: &AssignmentLC<F>| {
+            let mut wires = BTreeMap::new();
+            // The constant term is a multiple of wire `0`, which is the constant `1`.
+            *wires.entry(0u32).or_insert_with(F::zero) += lc.constant;
+            for (variable, coefficient) in &lc.terms {
+                let (wire, coefficient) = match variable {
+                    AssignmentVariable::Constant(value) => (0, *value * coefficient),
+                    AssignmentVariable::Public(index) => (*index as u32, *coefficient),
+                    AssignmentVariable::Private(index) => ((num_public + index) as u32, *coefficient),
+                };
+                *wires.entry(wire).or_insert_with(F::zero) += coefficient;
+            }
+            // Remove the terms that cancelled out.
+            wires.retain(|_, coefficient| !coefficient.is_zero());
+            wires
+        };
+
+        self.constraints.iter().map(|(a, b, c)| (convert(a), convert(b), convert(c))).collect()
+    }
+
+    /// Writes the given sections in the iden3 binary container format.
+    fn write_sections<W: Write>(
+        writer: &mut W,
+        magic: &[u8; 4],
+        version: u32,
+        sections: &[(u32, Vec<u8>)],
+    ) -> IoResult<()> {
+        writer.write_all(magic)?;
+        writer.write_all(&version.to_le_bytes())?;
+        writer.write_all(&(sections.len() as u32).to_le_bytes())?;
+        for (section_type, section) in sections {
+            writer.write_all(&section_type.to_le_bytes())?;
+            writer.write_all(&(section.len() as u64).to_le_bytes())?;
+            writer.write_all(section)?;
+        }
+        Ok(())
+    }
+}
+
 impl<F: PrimeField> snarkvm_r1cs::ConstraintSynthesizer<F> for Assignment<F> {
     /// Synthesizes the constraints from the environment into a `snarkvm_r1cs`-compliant constraint system.
     fn generate_constraints<CS: snarkvm_r1cs::ConstraintSystem<F>>(
@@ -223,6 +384,7 @@ mod tests {
     use snarkvm_circuit::prelude::*;
     use snarkvm_curves::bls12_377::Fr;
     use snarkvm_r1cs::ConstraintSynthesizer;
+    use snarkvm_utilities::ToBytes;
 
     /// Compute 2^EXPONENT - 1, in a purposefully constraint-inefficient manner for testing.
     fn create_example_circuit<E: Environment>() -> Field<E> {
@@ -268,6 +430,64 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_export() {
+        let _candidate_output = create_example_circuit::<Circuit>();
+        let assignment = Circuit::eject_assignment_and_reset();
+
+        let num_wires = (assignment.num_public() + assignment.num_private()) as usize;
+        let num_constraints = assignment.num_constraints() as usize;
+
+        // Ensure the exported constraints are satisfied by the exported witness.
+        let witness = assignment.to_witness();
+        assert_eq!(num_wires, witness.len());
+        assert_eq!(Fr::one(), witness[0]);
+        let r1cs_json = assignment.to_r1cs_json();
+        let evaluate = |lc: &serde_json::Value| -> Fr {
+            lc.as_object()
+                .unwrap()
+                .iter()
+                .map(|(wire, coefficient)| {
+                    witness[wire.parse::<usize>().unwrap()] * Fr::from_str(coefficient.as_str().unwrap()).unwrap()
+                })
+                .sum()
+        };
+        let constraints = r1cs_json["constraints"].as_array().unwrap();
+        assert_eq!(num_constraints, constraints.len());
+        for constraint in constraints {
+            assert_eq!(evaluate(&constraint[0]) * evaluate(&constraint[1]), evaluate(&constraint[2]));
+        }
+
+        // Check the `.r1cs` header.
+        let mut r1cs = Vec::new();
+        assignment.write_r1cs(&mut r1cs).unwrap();
+        let read_u32 = |bytes: &[u8], offset: usize| u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap());
+        assert_eq!(b"r1cs", &r1cs[0..4]);
+        assert_eq!(1, read_u32(&r1cs, 4));
+        assert_eq!(3, read_u32(&r1cs, 8));
+        assert_eq!(1, read_u32(&r1cs, 12));
+        assert_eq!(32, read_u32(&r1cs, 24));
+        assert_eq!(num_wires as u32, read_u32(&r1cs, 60));
+        assert_eq!(1, read_u32(&r1cs, 68));
+        assert_eq!(num_constraints as u32, read_u32(&r1cs, 84));
+
+        // Check the `.wtns` layout.
+        let mut wtns = Vec::new();
+        assignment.write_wtns(&mut wtns).unwrap();
+        assert_eq!(b"wtns", &wtns[0..4]);
+        assert_eq!(2, read_u32(&wtns, 4));
+        assert_eq!(num_wires as u32, read_u32(&wtns, 60));
+        assert_eq!(12 + (12 + 40) + (12 + 32 * num_wires), wtns.len());
+        assert_eq!(Fr::one().to_bigint().to_bytes_le().unwrap(), wtns[76..108]);
+
+        // Check the JSON forms.
+        assert_eq!(num_wires, r1cs_json["nVars"].as_u64().unwrap() as usize);
+        assert_eq!(Fr::modulus().to_string(), r1cs_json["prime"]);
+        let wtns_json = assignment.to_wtns_json();
+        assert_eq!(num_wires, wtns_json.as_array().unwrap().len());
+        assert_eq!("1", wtns_json[0]);
+    }
+
     #[test]
     fn test_marlin() {
         let _candidate_output = create_example_circuit::<Circuit>();
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Circuit, LinearCombination, Variable, R1CS};
+use crate::{Circuit, DevnetCircuit, LinearCombination, Variable, R1CS};
 use snarkvm_curves::edwards_bls12::Fq;
 use snarkvm_fields::PrimeField;
 
@@ -36,6 +36,16 @@ impl snarkvm_r1cs::ConstraintSynthesizer<Fq> for Circuit {
     }
 }
 
+impl snarkvm_r1cs::ConstraintSynthesizer<Fq> for DevnetCircuit {
+    /// Synthesizes the constraints from the environment into a `snarkvm_r1cs`-compliant constraint system.
+    fn generate_constraints<CS: snarkvm_r1cs::ConstraintSystem<Fq>>(
+        &self,
+        cs: &mut CS,
+    ) -> Result<(), snarkvm_r1cs::SynthesisError> {
+        crate::devnet_circuit::CIRCUIT.with(|circuit| (*(**circuit).borrow()).generate_constraints(cs))
+    }
+}
+
 impl<F: PrimeField> R1CS<F> {
     /// Synthesizes the constraints from the environment into a `snarkvm_r1cs`-compliant constraint system.
     fn generate_constraints<CS: snarkvm_r1cs::ConstraintSystem<F>>(
@@ -0,0 +1,115 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Assignment, Environment, R1CS};
+
+use core::{
+    any::{Any, TypeId},
+    cell::{Cell, RefCell},
+    marker::PhantomData,
+};
+use std::collections::HashMap;
+
+/// The enclosing constraint systems saved by the guards on this thread, in the order the guards were created.
+/// Each entry pairs the ID of a guard with the constraint system that was active when the guard was created.
+type Frames<F> = Vec<(u64, R1CS<F>)>;
+
+thread_local! {
+    /// The saved frames for each console network on this thread, keyed by the type ID of the network.
+    /// Note: Circuit environments of the same console network share one constraint system per thread.
+    static FRAMES: RefCell<HashMap<TypeId, Box<dyn Any>>> = RefCell::new(HashMap::new());
+    /// The ID of the next guard on this thread.
+    static NEXT_ID: Cell<u64> = Cell::new(0);
+}
+
+/// A guard over a fresh circuit environment, which is created by `Environment::enter`.
+///
+/// While the guard is the most recently entered one, all synthesis for `E` on this thread is directed to its own
+/// constraint system. When the guard is dropped, its constraint system is discarded. If it was the active one,
+/// the enclosing constraint system is restored; otherwise, the guards entered after it now enclose its parent.
+/// As such, guards may be nested, and dropped in any order. Since each thread has its own constraint system,
+/// synthesis on separate threads is independent, and the guard is neither `Send` nor `Sync`.
+#[must_use = "the circuit environment is exited as soon as the guard is dropped"]
+pub struct EnvironmentGuard<E: Environment> {
+    /// The ID of the guard, which is `None` once the circuit environment is exited.
+    id: Option<u64>,
+    /// The constraint system is bound to the current thread, so the guard is neither `Send` nor `Sync`.
+    _phantom: PhantomData<*const E>,
+}
+
+impl<E: Environment> EnvironmentGuard<E> {
+    /// Enters a new, empty circuit environment, saving the enclosing one.
+    pub(crate) fn new() -> Self {
+        let id = NEXT_ID.with(|next_id| next_id.replace(next_id.get() + 1));
+        let parent = E::replace_r1cs(R1CS::new());
+        Self::with_frames(|frames| frames.push((id, parent)));
+        Self { id: Some(id), _phantom: PhantomData }
+    }
+
+    /// Exits the circuit environment, returning its R1CS and restoring the enclosing one.
+    pub fn eject_r1cs(mut self) -> R1CS<E::BaseField> {
+        match self.id.take() {
+            Some(id) => Self::exit(id),
+            None => E::halt("The circuit environment was already exited"),
+        }
+    }
+
+    /// Exits the circuit environment, returning its R1CS assignment and restoring the enclosing one.
+    pub fn eject_assignment(self) -> Assignment<<E::Network as console::Environment>::Field> {
+        Assignment::from(self.eject_r1cs())
+    }
+
+    /// Removes the frame of the guard with the given ID, returning the R1CS of its circuit environment.
+    fn exit(id: u64) -> R1CS<E::BaseField> {
+        Self::with_frames(|frames| {
+            let index = match frames.iter().position(|(frame_id, _)| *frame_id == id) {
+                Some(index) => index,
+                None => E::halt("The circuit environment is missing from this thread"),
+            };
+            let (_, parent) = frames.remove(index);
+            match frames.get_mut(index) {
+                // If the guard was entered after this one, its frame holds the R1CS of this environment,
+                // and it now encloses the parent of this environment.
+                Some((_, r1cs)) => core::mem::replace(r1cs, parent),
+                // Otherwise, this environment is active, so restore its parent.
+                None => E::replace_r1cs(parent),
+            }
+        })
+    }
+
+    /// Applies the given operation to the saved frames for `E` on this thread.
+    fn with_frames<T>(operation: impl FnOnce(&mut Frames<E::BaseField>) -> T) -> T {
+        FRAMES.with(|frames| {
+            let mut frames = frames.borrow_mut();
+            let frames = frames
+                .entry(TypeId::of::<E::Network>())
+                .or_insert_with(|| Box::new(Frames::<E::BaseField>::new()));
+            match frames.downcast_mut::<Frames<E::BaseField>>() {
+                Some(frames) => operation(frames),
+                None => E::halt("The circuit environment frames have a mismatched field"),
+            }
+        })
+    }
+}
+
+impl<E: Environment> Drop for EnvironmentGuard<E> {
+    /// Exits the circuit environment, discarding its R1CS.
+    fn drop(&mut self) {
+        if let Some(id) = self.id.take() {
+            Self::exit(id);
+        }
+    }
+}
@@ -28,6 +28,9 @@ pub(super) mod converter;
 pub mod count;
 pub use count::*;
 
+pub mod guard;
+pub use guard::*;
+
 pub(super) mod counter;
 pub(super) use counter::*;
 
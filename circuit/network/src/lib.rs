@@ -17,6 +17,9 @@
 #![forbid(unsafe_code)]
 #![allow(clippy::too_many_arguments)]
 
+pub mod devnet;
+pub use devnet::*;
+
 pub mod v0;
 pub use v0::*;
 
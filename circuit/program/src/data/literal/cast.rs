@@ -0,0 +1,290 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_circuit_types::integers::Integer;
+
+#[cfg(console)]
+impl<A: Aleo> Literal<A> {
+    /// Casts the literal into the given literal type.
+    /// This method halts if the value of the literal does not fit in the given literal type.
+    pub fn cast(&self, to_type: console::LiteralType) -> Result<Self> {
+        self.cast_internal(to_type, false)
+    }
+
+    /// Casts the literal into the given literal type, truncating the value if it does not fit.
+    /// Integers are truncated to their lower bits, and negative integers are cast as their two's complement.
+    pub fn cast_lossy(&self, to_type: console::LiteralType) -> Result<Self> {
+        self.cast_internal(to_type, true)
+    }
+
+    /// Casts the literal into the given literal type, truncating the value iff `is_lossy` is `true`.
+    fn cast_internal(&self, to_type: console::LiteralType, is_lossy: bool) -> Result<Self> {
+        use console::LiteralType;
+
+        // Ensure the cast is supported.
+        let from_type = self.to_type();
+        ensure!(from_type.is_castable_to(&to_type), "Cannot cast a '{from_type}' literal into a '{to_type}'");
+
+        // Casting a literal into its own type is a no-op.
+        if from_type == to_type {
+            return Ok(self.clone());
+        }
+
+        match (self, to_type) {
+            // Cast the address into its field representation (i.e. the x-coordinate).
+            (Self::Address(address), LiteralType::Field) => Ok(Self::Field(address.to_field())),
+            // Cast the field element into the group element with the given x-coordinate.
+            (Self::Field(field), LiteralType::Group) => Ok(Self::Group(Group::from_x_coordinate(field.clone()))),
+            // Cast the group element into its x-coordinate.
+            (Self::Group(group), LiteralType::Field) => Ok(Self::Field(group.to_x_coordinate())),
+            // Otherwise, cast the bits of the boolean, field element, or integer.
+            _ => {
+                // Retrieve the little-endian bits, and whether they are a two's complement representation.
+                let (bits_le, is_signed) = match self {
+                    Self::Boolean(boolean) => (vec![boolean.clone()], false),
+                    Self::Field(field) => match is_lossy {
+                        // If the cast is lossy, the bits must be the canonical representation of the field element.
+                        true => (to_canonical_bits_le(field), false),
+                        // If the cast is checked, the upper bits must be zero, so only the lower bits are extracted.
+                        false => {
+                            let num_bits = match to_type {
+                                LiteralType::I8 | LiteralType::U8 => 8,
+                                LiteralType::I16 | LiteralType::U16 => 16,
+                                LiteralType::I32 | LiteralType::U32 => 32,
+                                LiteralType::I64 | LiteralType::U64 => 64,
+                                LiteralType::I128 | LiteralType::U128 => 128,
+                                _ => bail!("Cannot cast a '{from_type}' literal into a '{to_type}'"),
+                            };
+                            (field.to_lower_bits_le(num_bits), false)
+                        }
+                    },
+                    Self::I8(integer) => (integer.to_bits_le(), true),
+                    Self::I16(integer) => (integer.to_bits_le(), true),
+                    Self::I32(integer) => (integer.to_bits_le(), true),
+                    Self::I64(integer) => (integer.to_bits_le(), true),
+                    Self::I128(integer) => (integer.to_bits_le(), true),
+                    Self::U8(integer) => (integer.to_bits_le(), false),
+                    Self::U16(integer) => (integer.to_bits_le(), false),
+                    Self::U32(integer) => (integer.to_bits_le(), false),
+                    Self::U64(integer) => (integer.to_bits_le(), false),
+                    Self::U128(integer) => (integer.to_bits_le(), false),
+                    _ => bail!("Cannot cast a '{from_type}' literal into a '{to_type}'"),
+                };
+
+                match to_type {
+                    LiteralType::Field => {
+                        // If the cast is checked, ensure the value is not negative.
+                        if !is_lossy && is_signed {
+                            if let Some(sign) = bits_le.last() {
+                                A::assert(!sign);
+                            }
+                        }
+                        Ok(Self::Field(Field::from_bits_le(&bits_le)))
+                    }
+                    LiteralType::I8 => Ok(Self::I8(cast_integer(&bits_le, is_signed, is_lossy))),
+                    LiteralType::I16 => Ok(Self::I16(cast_integer(&bits_le, is_signed, is_lossy))),
+                    LiteralType::I32 => Ok(Self::I32(cast_integer(&bits_le, is_signed, is_lossy))),
+                    LiteralType::I64 => Ok(Self::I64(cast_integer(&bits_le, is_signed, is_lossy))),
+                    LiteralType::I128 => Ok(Self::I128(cast_integer(&bits_le, is_signed, is_lossy))),
+                    LiteralType::U8 => Ok(Self::U8(cast_integer(&bits_le, is_signed, is_lossy))),
+                    LiteralType::U16 => Ok(Self::U16(cast_integer(&bits_le, is_signed, is_lossy))),
+                    LiteralType::U32 => Ok(Self::U32(cast_integer(&bits_le, is_signed, is_lossy))),
+                    LiteralType::U64 => Ok(Self::U64(cast_integer(&bits_le, is_signed, is_lossy))),
+                    LiteralType::U128 => Ok(Self::U128(cast_integer(&bits_le, is_signed, is_lossy))),
+                    _ => bail!("Cannot cast a '{from_type}' literal into a '{to_type}'"),
+                }
+            }
+        }
+    }
+}
+
+/// Casts the given little-endian bits into an integer of type `I`.
+/// The bits are sign-extended if `is_signed` is `true`, and zero-extended otherwise.
+/// If `is_lossy` is `false`, this method enforces that the value fits in the integer type.
+#[cfg(console)]
+fn cast_integer<A: Aleo, I: IntegerType>(bits_le: &[Boolean<A>], is_signed: bool, is_lossy: bool) -> Integer<A, I> {
+    // Retrieve the bit that extends the value beyond its given bits.
+    let extension = match (is_signed, bits_le.last()) {
+        (true, Some(sign)) => sign.clone(),
+        _ => Boolean::constant(false),
+    };
+
+    // Resize the bits to the size of the integer type.
+    let num_bits = I::BITS as usize;
+    let mut integer_bits_le = bits_le.iter().take(num_bits).cloned().collect::<Vec<_>>();
+    integer_bits_le.resize(num_bits, extension.clone());
+
+    // If the cast is checked, ensure the value fits in the integer type.
+    if !is_lossy {
+        // Retrieve the sign of the integer.
+        let sign = match I::is_signed() {
+            true => integer_bits_le[num_bits - 1].clone(),
+            false => Boolean::constant(false),
+        };
+        // Ensure the truncated bits and the extension bit all match the sign of the integer.
+        for bit in bits_le.iter().skip(num_bits).chain([&extension]) {
+            A::assert_eq(bit, &sign);
+        }
+    }
+
+    Integer::from_bits_le(&integer_bits_le)
+}
+
+/// Returns the little-endian bits of the given field element, enforcing that they are its canonical representation.
+#[cfg(console)]
+fn to_canonical_bits_le<A: Aleo>(field: &Field<A>) -> Vec<Boolean<A>> {
+    let bits_le = field.to_bits_le();
+
+    // Ensure the bits are less than or equal to the modulus minus one, from the LSB to the MSB.
+    let modulus_minus_one = (-Field::<A>::one()).to_bits_le();
+    let is_less_than_or_equal = bits_le.iter().zip_eq(modulus_minus_one).fold(
+        Boolean::constant(true),
+        |is_less_than_or_equal, (this, that)| match that.eject_value() {
+            true => (!this).bitor(&is_less_than_or_equal),
+            false => (!this).bitand(&is_less_than_or_equal),
+        },
+    );
+    A::assert(is_less_than_or_equal);
+
+    bits_le
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use console::{LiteralType, TestRng, Uniform};
+
+    use rand::Rng;
+
+    const ITERATIONS: u32 = 100;
+
+    fn check_cast(mode: Mode, expected: console::Literal<<Circuit as Environment>::Network>, to_type: LiteralType) {
+        let literal = Literal::<Circuit>::new(mode, expected.clone());
+
+        // Check the checked cast.
+        match expected.cast(to_type) {
+            Ok(expected) => {
+                Circuit::scope(format!("{mode} cast"), || {
+                    let candidate = literal.cast(to_type).unwrap();
+                    assert_eq!(expected, candidate.eject_value());
+                    assert!(Circuit::is_satisfied_in_scope());
+                });
+            }
+            Err(_) => match mode {
+                // Constant casts that do not fit are caught when the constraint is enforced.
+                Mode::Constant => {
+                    let result =
+                        std::panic::catch_unwind(|| Literal::<Circuit>::new(mode, expected.clone()).cast(to_type));
+                    assert!(result.is_err());
+                }
+                _ => {
+                    Circuit::scope(format!("{mode} cast"), || {
+                        let _candidate = literal.cast(to_type).unwrap();
+                        assert!(!Circuit::is_satisfied_in_scope());
+                    });
+                }
+            },
+        }
+        Circuit::reset();
+
+        // Check the lossy cast.
+        let literal = Literal::<Circuit>::new(mode, expected.clone());
+        let expected = expected.cast_lossy(to_type).unwrap();
+        Circuit::scope(format!("{mode} cast lossy"), || {
+            let candidate = literal.cast_lossy(to_type).unwrap();
+            assert_eq!(expected, candidate.eject_value());
+            assert!(Circuit::is_satisfied_in_scope());
+        });
+        Circuit::reset();
+    }
+
+    fn run_cast_test(mode: Mode) {
+        let rng = &mut TestRng::default();
+
+        let integer_types = [
+            LiteralType::I8,
+            LiteralType::I16,
+            LiteralType::I32,
+            LiteralType::I64,
+            LiteralType::I128,
+            LiteralType::U8,
+            LiteralType::U16,
+            LiteralType::U32,
+            LiteralType::U64,
+            LiteralType::U128,
+        ];
+
+        for _ in 0..ITERATIONS {
+            // Sample the integers, using small values to cover checked casts that succeed.
+            let integers = [
+                console::Literal::I8(Uniform::rand(rng)),
+                console::Literal::I16(Uniform::rand(rng)),
+                console::Literal::I64(Uniform::rand(rng)),
+                console::Literal::U8(Uniform::rand(rng)),
+                console::Literal::U32(Uniform::rand(rng)),
+                console::Literal::U128(Uniform::rand(rng)),
+                console::Literal::I32(console::I32::new(rng.gen_range(-200..200))),
+                console::Literal::U64(console::U64::new(rng.gen_range(0..300))),
+            ];
+            for integer in integers {
+                // Integers into integers.
+                for to_type in integer_types {
+                    check_cast(mode, integer.clone(), to_type);
+                }
+                // Integers into fields.
+                check_cast(mode, integer.clone(), LiteralType::Field);
+            }
+
+            // Fields into integers.
+            for field in [console::Field::rand(rng), console::Field::from_u64(rng.gen_range(0..300))] {
+                for to_type in integer_types {
+                    check_cast(mode, console::Literal::Field(field), to_type);
+                }
+            }
+
+            // Booleans into integers and fields.
+            let boolean = console::Literal::Boolean(Uniform::rand(rng));
+            check_cast(mode, boolean.clone(), LiteralType::U8);
+            check_cast(mode, boolean.clone(), LiteralType::I128);
+            check_cast(mode, boolean, LiteralType::Field);
+
+            // Group elements into fields, and vice versa.
+            let group: console::Group<_> = Uniform::rand(rng);
+            check_cast(mode, console::Literal::Group(group), LiteralType::Field);
+            check_cast(mode, console::Literal::Field(group.to_x_coordinate()), LiteralType::Group);
+
+            // Addresses into fields.
+            check_cast(mode, console::Literal::Address(console::Address::new(group)), LiteralType::Field);
+        }
+    }
+
+    #[test]
+    fn test_cast_constant() {
+        run_cast_test(Mode::Constant);
+    }
+
+    #[test]
+    fn test_cast_public() {
+        run_cast_test(Mode::Public);
+    }
+
+    #[test]
+    fn test_cast_private() {
+        run_cast_test(Mode::Private);
+    }
+}
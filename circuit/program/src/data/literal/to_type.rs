@@ -37,6 +37,7 @@ impl<A: Aleo> Literal<A> {
             Self::U128(..) => console::LiteralType::U128,
             Self::Scalar(..) => console::LiteralType::Scalar,
             Self::String(..) => console::LiteralType::String,
+            Self::Signature(..) => console::LiteralType::Signature,
         }
     }
 }
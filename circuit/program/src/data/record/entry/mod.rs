@@ -19,9 +19,9 @@ mod find;
 mod num_randomizers;
 mod to_bits;
 
-use crate::{Ciphertext, Identifier, Plaintext, Visibility};
+use crate::{Access, Ciphertext, Plaintext, Visibility};
 use snarkvm_circuit_network::Aleo;
-use snarkvm_circuit_types::{environment::prelude::*, Boolean};
+use snarkvm_circuit_types::{Boolean, environment::prelude::*};
 
 /// An entry stored in program data.
 #[derive(Clone)]
@@ -34,10 +34,10 @@ mod to_bits;
 mod to_commitment;
 mod to_fields;
 
-use crate::{Ciphertext, Identifier, Plaintext, ProgramID, Visibility};
+use crate::{Access, Ciphertext, Identifier, Plaintext, ProgramID, Visibility};
 use snarkvm_circuit_account::{PrivateKey, ViewKey};
 use snarkvm_circuit_network::Aleo;
-use snarkvm_circuit_types::{environment::prelude::*, Boolean, Field, Group, Scalar, U32};
+use snarkvm_circuit_types::{Boolean, Field, Group, Scalar, U32, environment::prelude::*};
 
 #[derive(Clone)]
 pub struct Record<A: Aleo, Private: Visibility<A>> {
@@ -19,9 +19,9 @@ mod find;
 mod to_bits;
 mod to_fields;
 
-use crate::{Entry, Identifier, Plaintext, Record};
+use crate::{Access, Entry, Plaintext, Record};
 use snarkvm_circuit_network::Aleo;
-use snarkvm_circuit_types::{environment::prelude::*, Boolean, Field};
+use snarkvm_circuit_types::{Boolean, Field, environment::prelude::*};
 
 #[derive(Clone)]
 pub enum Value<A: Aleo> {
@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+use super::*;
+
+impl<N: Network> FromBits for Signature<N> {
+    /// Initializes a new signature from a list of little-endian bits.
+    fn from_bits_le(bits_le: &[bool]) -> Result<Self> {
+        let scalar_size_in_bits = Scalar::<N>::size_in_bits();
+        let field_size_in_bits = Field::<N>::size_in_bits();
+
+        // Ensure the number of bits matches the signature size.
+        ensure!(bits_le.len() == Self::size_in_bits(), "Invalid signature size: found {} bits", bits_le.len());
+
+        // Split the bits into the challenge, response, `pk_sig`, and `pr_sig`.
+        let (challenge, bits_le) = bits_le.split_at(scalar_size_in_bits);
+        let (response, bits_le) = bits_le.split_at(scalar_size_in_bits);
+        let (pk_sig, pr_sig) = bits_le.split_at(field_size_in_bits);
+
+        let challenge = Scalar::from_bits_le(challenge)?;
+        let response = Scalar::from_bits_le(response)?;
+        let pk_sig = Group::from_x_coordinate(Field::from_bits_le(pk_sig)?)?;
+        let pr_sig = Group::from_x_coordinate(Field::from_bits_le(pr_sig)?)?;
+        let compute_key = ComputeKey::try_from((pk_sig, pr_sig))?;
+        Ok(Self { challenge, response, compute_key })
+    }
+
+    /// Initializes a new signature from a list of big-endian bits.
+    fn from_bits_be(bits_be: &[bool]) -> Result<Self> {
+        let scalar_size_in_bits = Scalar::<N>::size_in_bits();
+        let field_size_in_bits = Field::<N>::size_in_bits();
+
+        // Ensure the number of bits matches the signature size.
+        ensure!(bits_be.len() == Self::size_in_bits(), "Invalid signature size: found {} bits", bits_be.len());
+
+        // Split the bits into the challenge, response, `pk_sig`, and `pr_sig`.
+        let (challenge, bits_be) = bits_be.split_at(scalar_size_in_bits);
+        let (response, bits_be) = bits_be.split_at(scalar_size_in_bits);
+        let (pk_sig, pr_sig) = bits_be.split_at(field_size_in_bits);
+
+        let challenge = Scalar::from_bits_be(challenge)?;
+        let response = Scalar::from_bits_be(response)?;
+        let pk_sig = Group::from_x_coordinate(Field::from_bits_be(pk_sig)?)?;
+        let pr_sig = Group::from_x_coordinate(Field::from_bits_be(pr_sig)?)?;
+        let compute_key = ComputeKey::try_from((pk_sig, pr_sig))?;
+        Ok(Self { challenge, response, compute_key })
+    }
+}
+
+impl<N: Network> SizeInBits for Signature<N> {
+    /// Returns the signature size in bits.
+    #[inline]
+    fn size_in_bits() -> usize {
+        // The challenge and response are scalars, and the compute key is serialized as two x-coordinates.
+        2 * Scalar::<N>::size_in_bits() + 2 * Field::<N>::size_in_bits()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_from_bits_invalid_size() {
+        let mut rng = TestRng::default();
+
+        // Sample a new signature.
+        let signature = test_helpers::sample_signature(1, &mut rng);
+
+        // Ensure a truncated or extended list of bits is rejected.
+        let bits_le = signature.to_bits_le();
+        assert!(Signature::<CurrentNetwork>::from_bits_le(&bits_le[1..]).is_err());
+        assert!(Signature::<CurrentNetwork>::from_bits_le(&[bits_le, vec![false]].concat()).is_err());
+    }
+}
@@ -0,0 +1,552 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_console_algorithms::{
+    Blake2Xs,
+    Pedersen128,
+    Pedersen64,
+    Poseidon2,
+    Poseidon4,
+    Poseidon8,
+    BHP1024,
+    BHP256,
+    BHP512,
+    BHP768,
+};
+
+use rand_chacha::{rand_core::SeedableRng, ChaChaRng};
+
+/// The maximum degree of the universal SRS for the development network.
+const DEVNET_UNIVERSAL_SRS_MAX_DEGREE: usize = tunable_usize(option_env!("DEVNET_UNIVERSAL_SRS_MAX_DEGREE"), 1 << 18);
+/// The seed used to sample the universal SRS for the development network.
+/// Note: The SRS is derived from a public seed, so the development network must *never* be used in production.
+const DEVNET_UNIVERSAL_SRS_SEED: u64 = 0xDE7E7;
+
+/// Returns the given build-time environment variable as an integer, or the default if it is not set.
+/// This is used to tune the development network constants, i.e. `DEVNET_NUM_BLOCKS_PER_EPOCH=32 cargo build`.
+/// Note: The value is parsed at compile time, so an invalid value fails the build.
+const fn tunable(value: Option<&str>, default: u64) -> u64 {
+    let bytes = match value {
+        Some(value) => value.as_bytes(),
+        None => return default,
+    };
+    assert!(!bytes.is_empty(), "A devnet constant must be a decimal integer");
+
+    let mut result = 0u64;
+    let mut index = 0;
+    while index < bytes.len() {
+        assert!(bytes[index].is_ascii_digit(), "A devnet constant must be a decimal integer");
+        result = match result.checked_mul(10) {
+            Some(result) => result,
+            None => panic!("A devnet constant must fit in 64 bits"),
+        };
+        result = match result.checked_add((bytes[index] - b'0') as u64) {
+            Some(result) => result,
+            None => panic!("A devnet constant must fit in 64 bits"),
+        };
+        index += 1;
+    }
+    result
+}
+
+/// Returns the given build-time environment variable as a `u16`, or the default if it is not set.
+#[allow(clippy::cast_possible_truncation)]
+const fn tunable_u16(value: Option<&str>, default: u16) -> u16 {
+    let value = tunable(value, default as u64);
+    assert!(value <= u16::MAX as u64, "A devnet constant must fit in 16 bits");
+    value as u16
+}
+
+/// Returns the given build-time environment variable as a `u32`, or the default if it is not set.
+#[allow(clippy::cast_possible_truncation)]
+const fn tunable_u32(value: Option<&str>, default: u32) -> u32 {
+    let value = tunable(value, default as u64);
+    assert!(value <= u32::MAX as u64, "A devnet constant must fit in 32 bits");
+    value as u32
+}
+
+/// Returns the given build-time environment variable as a `usize`, or the default if it is not set.
+#[allow(clippy::cast_possible_truncation)]
+const fn tunable_usize(value: Option<&str>, default: usize) -> usize {
+    let value = tunable(value, default as u64);
+    assert!(value <= usize::MAX as u64, "A devnet constant must fit in a usize");
+    value as usize
+}
+
+/// A pair of proving and verifying keys for a circuit on the development network.
+pub type DevnetCircuitKeys = (Arc<MarlinProvingKey<Devnet>>, Arc<MarlinVerifyingKey<Devnet>>);
+
+/// The genesis block bytes for the development network.
+static DEVNET_GENESIS_BYTES: OnceCell<Vec<u8>> = OnceCell::new();
+
+/// The proving keys for `credits.aleo` on the development network.
+static DEVNET_CREDITS_PROVING_KEYS: OnceCell<IndexMap<String, Arc<MarlinProvingKey<Console>>>> = OnceCell::new();
+/// The verifying keys for `credits.aleo` on the development network.
+static DEVNET_CREDITS_VERIFYING_KEYS: OnceCell<IndexMap<String, Arc<MarlinVerifyingKey<Console>>>> = OnceCell::new();
+/// The proving key for the inclusion circuit on the development network.
+static DEVNET_INCLUSION_PROVING_KEY: OnceCell<Arc<MarlinProvingKey<Console>>> = OnceCell::new();
+/// The verifying key for the inclusion circuit on the development network.
+static DEVNET_INCLUSION_VERIFYING_KEY: OnceCell<Arc<MarlinVerifyingKey<Console>>> = OnceCell::new();
+
+lazy_static! {
+    /// The group bases for the Aleo signature and encryption schemes.
+    pub static ref DEVNET_GENERATOR_G: Vec<Group<Devnet>> = Devnet::new_bases("AleoAccountEncryptionAndSignatureScheme0");
+
+    /// The Marlin sponge parameters.
+    pub static ref DEVNET_MARLIN_FS_PARAMETERS: FiatShamirParameters<Devnet> = FiatShamir::<Devnet>::sample_parameters();
+
+    /// The balance commitment domain as a constant field element.
+    pub static ref DEVNET_BCM_DOMAIN: Field<Devnet> = Field::<Devnet>::new_domain_separator("AleoBalanceCommitment0");
+    /// The encryption domain as a constant field element.
+    pub static ref DEVNET_ENCRYPTION_DOMAIN: Field<Devnet> = Field::<Devnet>::new_domain_separator("AleoSymmetricEncryption0");
+    /// The graph key domain as a constant field element.
+    pub static ref DEVNET_GRAPH_KEY_DOMAIN: Field<Devnet> = Field::<Devnet>::new_domain_separator("AleoGraphKey0");
+    /// The randomizer domain as a constant field element.
+    pub static ref DEVNET_RANDOMIZER_DOMAIN: Field<Devnet> = Field::<Devnet>::new_domain_separator("AleoRandomizer0");
+    /// The balance commitment randomizer domain as a constant field element.
+    pub static ref DEVNET_R_BCM_DOMAIN: Field<Devnet> = Field::<Devnet>::new_domain_separator("AleoBalanceRandomizer0");
+    /// The serial number domain as a constant field element.
+    pub static ref DEVNET_SERIAL_NUMBER_DOMAIN: Field<Devnet> = Field::<Devnet>::new_domain_separator("AleoSerialNumber0");
+
+    /// The BHP hash function, which can take an input of up to 256 bits.
+    pub static ref DEVNET_BHP_256: BHP256<Devnet> = BHP256::<Devnet>::setup("AleoBHP256").expect("Failed to setup BHP256");
+    /// The BHP hash function, which can take an input of up to 512 bits.
+    pub static ref DEVNET_BHP_512: BHP512<Devnet> = BHP512::<Devnet>::setup("AleoBHP512").expect("Failed to setup BHP512");
+    /// The BHP hash function, which can take an input of up to 768 bits.
+    pub static ref DEVNET_BHP_768: BHP768<Devnet> = BHP768::<Devnet>::setup("AleoBHP768").expect("Failed to setup BHP768");
+    /// The BHP hash function, which can take an input of up to 1024 bits.
+    pub static ref DEVNET_BHP_1024: BHP1024<Devnet> = BHP1024::<Devnet>::setup("AleoBHP1024").expect("Failed to setup BHP1024");
+
+    /// The Pedersen hash function, which can take an input of up to 64 bits.
+    pub static ref DEVNET_PEDERSEN_64: Pedersen64<Devnet> = Pedersen64::<Devnet>::setup("AleoPedersen64");
+    /// The Pedersen hash function, which can take an input of up to 128 bits.
+    pub static ref DEVNET_PEDERSEN_128: Pedersen128<Devnet> = Pedersen128::<Devnet>::setup("AleoPedersen128");
+
+    /// The Poseidon hash function, using a rate of 2.
+    pub static ref DEVNET_POSEIDON_2: Poseidon2<Devnet> = Poseidon2::<Devnet>::setup("AleoPoseidon2").expect("Failed to setup Poseidon2");
+    /// The Poseidon hash function, using a rate of 4.
+    pub static ref DEVNET_POSEIDON_4: Poseidon4<Devnet> = Poseidon4::<Devnet>::setup("AleoPoseidon4").expect("Failed to setup Poseidon4");
+    /// The Poseidon hash function, using a rate of 8.
+    pub static ref DEVNET_POSEIDON_8: Poseidon8<Devnet> = Poseidon8::<Devnet>::setup("AleoPoseidon8").expect("Failed to setup Poseidon8");
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Devnet;
+
+impl Devnet {
+    /// Initializes a new instance of group bases from a given input domain message.
+    fn new_bases(message: &str) -> Vec<Group<Self>> {
+        // Hash the given message to a point on the curve, to initialize the starting base.
+        let (base, _, _) = Blake2Xs::hash_to_curve::<<Self as Environment>::Affine>(message);
+
+        // Compute the bases up to the size of the scalar field (in bits).
+        let mut g = Group::<Self>::new(base);
+        let mut g_bases = Vec::with_capacity(Scalar::<Self>::size_in_bits());
+        for _ in 0..Scalar::<Self>::size_in_bits() {
+            g_bases.push(g);
+            g = g.double();
+        }
+        g_bases
+    }
+
+    /// Sets the genesis block bytes for the development network.
+    /// The genesis block may only be set once, and must be set before `Devnet::genesis_bytes` is called.
+    pub fn set_genesis_bytes(genesis_bytes: Vec<u8>) -> Result<()> {
+        DEVNET_GENESIS_BYTES.set(genesis_bytes).map_err(|_| anyhow!("The devnet genesis block was already set"))
+    }
+
+    /// Returns `true` if the circuit keys for `credits.aleo` and the inclusion circuit are set.
+    pub fn has_circuit_keys() -> bool {
+        DEVNET_CREDITS_PROVING_KEYS.get().is_some()
+            && DEVNET_CREDITS_VERIFYING_KEYS.get().is_some()
+            && DEVNET_INCLUSION_PROVING_KEY.get().is_some()
+            && DEVNET_INCLUSION_VERIFYING_KEY.get().is_some()
+    }
+
+    /// Sets the circuit keys for `credits.aleo` and the inclusion circuit, which are synthesized at startup.
+    /// The circuit keys may only be set once.
+    pub fn set_circuit_keys(
+        credits_keys: IndexMap<String, DevnetCircuitKeys>,
+        inclusion_keys: DevnetCircuitKeys,
+    ) -> Result<()> {
+        // Ensure the circuit keys have not been set.
+        ensure!(!Self::has_circuit_keys(), "The devnet circuit keys were already set");
+
+        // Split the credits keys into the proving and verifying keys.
+        let (credits_proving_keys, credits_verifying_keys) = credits_keys
+            .into_iter()
+            .map(|(function_name, (proving_key, verifying_key))| {
+                ((function_name.clone(), proving_key), (function_name, verifying_key))
+            })
+            .unzip();
+        let (inclusion_proving_key, inclusion_verifying_key) = inclusion_keys;
+
+        // Set the circuit keys.
+        let error = || anyhow!("The devnet circuit keys were already set");
+        DEVNET_CREDITS_PROVING_KEYS.set(credits_proving_keys).map_err(|_| error())?;
+        DEVNET_CREDITS_VERIFYING_KEYS.set(credits_verifying_keys).map_err(|_| error())?;
+        DEVNET_INCLUSION_PROVING_KEY.set(inclusion_proving_key).map_err(|_| error())?;
+        DEVNET_INCLUSION_VERIFYING_KEY.set(inclusion_verifying_key).map_err(|_| error())
+    }
+}
+
+impl Environment for Devnet {
+    type Affine = <Console as Environment>::Affine;
+    type BigInteger = <Console as Environment>::BigInteger;
+    type Field = <Console as Environment>::Field;
+    type PairingCurve = <Console as Environment>::PairingCurve;
+    type Projective = <Console as Environment>::Projective;
+    type Scalar = <Console as Environment>::Scalar;
+
+    /// The coefficient `A` of the twisted Edwards curve.
+    const EDWARDS_A: Self::Field = Console::EDWARDS_A;
+    /// The coefficient `D` of the twisted Edwards curve.
+    const EDWARDS_D: Self::Field = Console::EDWARDS_D;
+    /// The coefficient `A` of the Montgomery curve.
+    const MONTGOMERY_A: Self::Field = Console::MONTGOMERY_A;
+    /// The coefficient `B` of the Montgomery curve.
+    const MONTGOMERY_B: Self::Field = Console::MONTGOMERY_B;
+}
+
+impl Network for Devnet {
+    /// The block hash type.
+    type BlockHash = AleoID<Field<Self>, { hrp2!("ab") }>;
+    /// The state root type.
+    type StateRoot = AleoID<Field<Self>, { hrp2!("ar") }>;
+    /// The transaction ID type.
+    type TransactionID = AleoID<Field<Self>, { hrp2!("at") }>;
+    /// The transition ID type.
+    type TransitionID = AleoID<Field<Self>, { hrp2!("as") }>;
+
+    /// The anchor time per block in seconds, which must be greater than the round time per block.
+    const ANCHOR_TIME: u16 = tunable_u16(option_env!("DEVNET_ANCHOR_TIME"), 5);
+    /// The coinbase puzzle degree.
+    const COINBASE_PUZZLE_DEGREE: u32 = tunable_u32(option_env!("DEVNET_COINBASE_PUZZLE_DEGREE"), (1 << 8) - 1);
+    /// The network edition.
+    const EDITION: u16 = 0;
+    /// The genesis block coinbase target.
+    const GENESIS_COINBASE_TARGET: u64 =
+        tunable(option_env!("DEVNET_GENESIS_COINBASE_TARGET"), (1u64 << 5).saturating_sub(1));
+    /// The genesis block proof target.
+    const GENESIS_PROOF_TARGET: u64 = tunable(option_env!("DEVNET_GENESIS_PROOF_TARGET"), 1);
+    /// The network ID.
+    const ID: u16 = 4;
+    /// The function name for the inclusion circuit.
+    const INCLUSION_FUNCTION_NAME: &'static str = "inclusion";
+    /// The maximum number of prover solutions that can be included per block.
+    const MAX_PROVER_SOLUTIONS: usize = tunable_usize(option_env!("DEVNET_MAX_PROVER_SOLUTIONS"), 1 << 20);
+    /// The network name.
+    const NAME: &'static str = "Aleo Devnet";
+    /// The number of blocks per epoch.
+    const NUM_BLOCKS_PER_EPOCH: u32 = tunable_u32(option_env!("DEVNET_NUM_BLOCKS_PER_EPOCH"), 1 << 4);
+
+    /// Returns the genesis block bytes.
+    fn genesis_bytes() -> Result<&'static [u8]> {
+        DEVNET_GENESIS_BYTES
+            .get()
+            .map(|genesis_bytes| genesis_bytes.as_slice())
+            .ok_or_else(|| anyhow!("The devnet genesis block must be set with 'Devnet::set_genesis_bytes'"))
+    }
+
+    /// Returns the proving key for the given function name in `credits.aleo`.
+    fn get_credits_proving_key(function_name: String) -> Result<&'static Arc<MarlinProvingKey<Self>>> {
+        DEVNET_CREDITS_PROVING_KEYS
+            .get()
+            .ok_or_else(|| anyhow!("The devnet circuit keys have not been synthesized"))?
+            .get(&function_name)
+            .ok_or_else(|| anyhow!("Proving key for credits.aleo/{function_name}' not found"))
+    }
+
+    /// Loads the proving key for the given function name in `credits.aleo`.
+    /// Note: The devnet keys are synthesized in memory, so this returns the cached key.
+    fn load_credits_proving_key(function_name: String) -> Result<Arc<MarlinProvingKey<Self>>> {
+        Self::get_credits_proving_key(function_name).cloned()
+    }
+
+    /// Returns the verifying key for the given function name in `credits.aleo`.
+    fn get_credits_verifying_key(function_name: String) -> Result<&'static Arc<MarlinVerifyingKey<Self>>> {
+        DEVNET_CREDITS_VERIFYING_KEYS
+            .get()
+            .ok_or_else(|| anyhow!("The devnet circuit keys have not been synthesized"))?
+            .get(&function_name)
+            .ok_or_else(|| anyhow!("Verifying key for credits.aleo/{function_name}' not found"))
+    }
+
+    /// Returns the `proving key` for the inclusion circuit.
+    fn inclusion_proving_key() -> Result<&'static Arc<MarlinProvingKey<Self>>> {
+        DEVNET_INCLUSION_PROVING_KEY
+            .get()
+            .ok_or_else(|| anyhow!("The devnet inclusion proving key has not been synthesized"))
+    }
+
+    /// Returns the `verifying key` for the inclusion circuit.
+    fn inclusion_verifying_key() -> Result<&'static Arc<MarlinVerifyingKey<Self>>> {
+        DEVNET_INCLUSION_VERIFYING_KEY
+            .get()
+            .ok_or_else(|| anyhow!("The devnet inclusion verifying key has not been synthesized"))
+    }
+
+    /// Returns the universal SRS, which is sampled from a fixed seed on first use.
+    fn universal_srs() -> Result<UniversalSRS<Self::PairingCurve>> {
+        static INSTANCE: OnceCell<UniversalSRS<<Console as Environment>::PairingCurve>> = OnceCell::new();
+        INSTANCE
+            .get_or_try_init(|| {
+                let rng = &mut ChaChaRng::seed_from_u64(DEVNET_UNIVERSAL_SRS_SEED);
+                UniversalSRS::setup(DEVNET_UNIVERSAL_SRS_MAX_DEGREE, rng)
+            })
+            .cloned()
+    }
+
+    /// Returns the powers of `G`.
+    fn g_powers() -> &'static Vec<Group<Self>> {
+        &DEVNET_GENERATOR_G
+    }
+
+    /// Returns the scalar multiplication on the generator `G`.
+    fn g_scalar_multiply(scalar: &Scalar<Self>) -> Group<Self> {
+        DEVNET_GENERATOR_G
+            .iter()
+            .zip_eq(&scalar.to_bits_le())
+            .filter_map(|(base, bit)| match bit {
+                true => Some(base),
+                false => None,
+            })
+            .sum()
+    }
+
+    /// Returns the sponge parameters used for the sponge in the Marlin SNARK.
+    fn marlin_fs_parameters() -> &'static FiatShamirParameters<Self> {
+        &DEVNET_MARLIN_FS_PARAMETERS
+    }
+
+    /// Returns the balance commitment domain as a constant field element.
+    fn bcm_domain() -> Field<Self> {
+        *DEVNET_BCM_DOMAIN
+    }
+
+    /// Returns the encryption domain as a constant field element.
+    fn encryption_domain() -> Field<Self> {
+        *DEVNET_ENCRYPTION_DOMAIN
+    }
+
+    /// Returns the graph key domain as a constant field element.
+    fn graph_key_domain() -> Field<Self> {
+        *DEVNET_GRAPH_KEY_DOMAIN
+    }
+
+    /// Returns the randomizer domain as a constant field element.
+    fn randomizer_domain() -> Field<Self> {
+        *DEVNET_RANDOMIZER_DOMAIN
+    }
+
+    /// Returns the balance commitment randomizer domain as a constant field element.
+    fn r_bcm_domain() -> Field<Self> {
+        *DEVNET_R_BCM_DOMAIN
+    }
+
+    /// Returns the serial number domain as a constant field element.
+    fn serial_number_domain() -> Field<Self> {
+        *DEVNET_SERIAL_NUMBER_DOMAIN
+    }
+
+    /// Returns a BHP commitment with an input hasher of 256-bits.
+    fn commit_bhp256(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+        DEVNET_BHP_256.commit(input, randomizer)
+    }
+
+    /// Returns a BHP commitment with an input hasher of 512-bits.
+    fn commit_bhp512(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+        DEVNET_BHP_512.commit(input, randomizer)
+    }
+
+    /// Returns a BHP commitment with an input hasher of 768-bits.
+    fn commit_bhp768(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+        DEVNET_BHP_768.commit(input, randomizer)
+    }
+
+    /// Returns a BHP commitment with an input hasher of 1024-bits.
+    fn commit_bhp1024(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+        DEVNET_BHP_1024.commit(input, randomizer)
+    }
+
+    /// Returns a Pedersen commitment for the given (up to) 64-bit input and randomizer.
+    fn commit_ped64(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+        DEVNET_PEDERSEN_64.commit_uncompressed(input, randomizer)
+    }
+
+    /// Returns a Pedersen commitment for the given (up to) 128-bit input and randomizer.
+    fn commit_ped128(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+        DEVNET_PEDERSEN_128.commit_uncompressed(input, randomizer)
+    }
+
+    /// Returns the BHP hash with an input hasher of 256-bits.
+    fn hash_bhp256(input: &[bool]) -> Result<Field<Self>> {
+        DEVNET_BHP_256.hash(input)
+    }
+
+    /// Returns the BHP hash with an input hasher of 512-bits.
+    fn hash_bhp512(input: &[bool]) -> Result<Field<Self>> {
+        DEVNET_BHP_512.hash(input)
+    }
+
+    /// Returns the BHP hash with an input hasher of 768-bits.
+    fn hash_bhp768(input: &[bool]) -> Result<Field<Self>> {
+        DEVNET_BHP_768.hash(input)
+    }
+
+    /// Returns the BHP hash with an input hasher of 1024-bits.
+    fn hash_bhp1024(input: &[bool]) -> Result<Field<Self>> {
+        DEVNET_BHP_1024.hash(input)
+    }
+
+    /// Returns the Pedersen hash for a given (up to) 64-bit input.
+    fn hash_ped64(input: &[bool]) -> Result<Field<Self>> {
+        DEVNET_PEDERSEN_64.hash(input)
+    }
+
+    /// Returns the Pedersen hash for a given (up to) 128-bit input.
+    fn hash_ped128(input: &[bool]) -> Result<Field<Self>> {
+        DEVNET_PEDERSEN_128.hash(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 2.
+    fn hash_psd2(input: &[Field<Self>]) -> Result<Field<Self>> {
+        DEVNET_POSEIDON_2.hash(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 4.
+    fn hash_psd4(input: &[Field<Self>]) -> Result<Field<Self>> {
+        DEVNET_POSEIDON_4.hash(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 8.
+    fn hash_psd8(input: &[Field<Self>]) -> Result<Field<Self>> {
+        DEVNET_POSEIDON_8.hash(input)
+    }
+
+    /// Returns the extended Poseidon hash with an input rate of 2.
+    fn hash_many_psd2(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
+        DEVNET_POSEIDON_2.hash_many(input, num_outputs)
+    }
+
+    /// Returns the extended Poseidon hash with an input rate of 4.
+    fn hash_many_psd4(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
+        DEVNET_POSEIDON_4.hash_many(input, num_outputs)
+    }
+
+    /// Returns the extended Poseidon hash with an input rate of 8.
+    fn hash_many_psd8(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
+        DEVNET_POSEIDON_8.hash_many(input, num_outputs)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 2 on the affine curve.
+    fn hash_to_group_psd2(input: &[Field<Self>]) -> Result<Group<Self>> {
+        DEVNET_POSEIDON_2.hash_to_group(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 4 on the affine curve.
+    fn hash_to_group_psd4(input: &[Field<Self>]) -> Result<Group<Self>> {
+        DEVNET_POSEIDON_4.hash_to_group(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 8 on the affine curve.
+    fn hash_to_group_psd8(input: &[Field<Self>]) -> Result<Group<Self>> {
+        DEVNET_POSEIDON_8.hash_to_group(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 2 on the scalar field.
+    fn hash_to_scalar_psd2(input: &[Field<Self>]) -> Result<Scalar<Self>> {
+        DEVNET_POSEIDON_2.hash_to_scalar(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 4 on the scalar field.
+    fn hash_to_scalar_psd4(input: &[Field<Self>]) -> Result<Scalar<Self>> {
+        DEVNET_POSEIDON_4.hash_to_scalar(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 8 on the scalar field.
+    fn hash_to_scalar_psd8(input: &[Field<Self>]) -> Result<Scalar<Self>> {
+        DEVNET_POSEIDON_8.hash_to_scalar(input)
+    }
+
+    /// Returns a Merkle tree with a BHP leaf hasher of 1024-bits and a BHP path hasher of 512-bits.
+    fn merkle_tree_bhp<const DEPTH: u8>(leaves: &[Vec<bool>]) -> Result<BHPMerkleTree<Self, DEPTH>> {
+        MerkleTree::new(&*DEVNET_BHP_1024, &*DEVNET_BHP_512, leaves)
+    }
+
+    /// Returns a Merkle tree with a Poseidon leaf hasher with input rate of 4 and a Poseidon path hasher with input rate of 2.
+    fn merkle_tree_psd<const DEPTH: u8>(leaves: &[Vec<Field<Self>>]) -> Result<PoseidonMerkleTree<Self, DEPTH>> {
+        MerkleTree::new(&*DEVNET_POSEIDON_4, &*DEVNET_POSEIDON_2, leaves)
+    }
+
+    /// Returns `true` if the given Merkle path is valid for the given root and leaf.
+    fn verify_merkle_path_bhp<const DEPTH: u8>(
+        path: &MerklePath<Self, DEPTH>,
+        root: &Field<Self>,
+        leaf: &Vec<bool>,
+    ) -> bool {
+        path.verify(&*DEVNET_BHP_1024, &*DEVNET_BHP_512, root, leaf)
+    }
+
+    /// Returns `true` if the given Merkle path is valid for the given root and leaf.
+    fn verify_merkle_path_psd<const DEPTH: u8>(
+        path: &MerklePath<Self, DEPTH>,
+        root: &Field<Self>,
+        leaf: &Vec<Field<Self>>,
+    ) -> bool {
+        path.verify(&*DEVNET_POSEIDON_4, &*DEVNET_POSEIDON_2, root, leaf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = Devnet;
+
+    #[test]
+    fn test_g_scalar_multiply() {
+        // Compute G^r.
+        let scalar = Scalar::rand(&mut TestRng::default());
+        let group = CurrentNetwork::g_scalar_multiply(&scalar);
+        assert_eq!(group, CurrentNetwork::g_powers()[0] * scalar);
+    }
+
+    #[test]
+    fn test_set_genesis_bytes() {
+        // Set the genesis block bytes.
+        let genesis_bytes = vec![1u8, 2, 3, 4];
+        CurrentNetwork::set_genesis_bytes(genesis_bytes.clone()).unwrap();
+        assert_eq!(CurrentNetwork::genesis_bytes().unwrap(), &genesis_bytes[..]);
+
+        // Ensure the genesis block bytes cannot be set again.
+        assert!(CurrentNetwork::set_genesis_bytes(vec![5u8, 6, 7, 8]).is_err());
+        assert_eq!(CurrentNetwork::genesis_bytes().unwrap(), &genesis_bytes[..]);
+    }
+
+    #[test]
+    fn test_universal_srs_is_deterministic() {
+        let srs = CurrentNetwork::universal_srs().unwrap();
+        assert_eq!(srs.max_degree(), DEVNET_UNIVERSAL_SRS_MAX_DEGREE);
+
+        // Ensure a freshly sampled SRS matches the cached SRS.
+        let rng = &mut ChaChaRng::seed_from_u64(DEVNET_UNIVERSAL_SRS_SEED);
+        let expected = UniversalSRS::<<Console as Environment>::PairingCurve>::setup(16, rng).unwrap();
+        assert_eq!(srs.power_of_beta_g(1).unwrap(), expected.power_of_beta_g(1).unwrap());
+        assert_eq!(srs.beta_h(), expected.beta_h());
+    }
+}
@@ -27,6 +27,9 @@ pub use snarkvm_console_network_environment::*;
 mod helpers;
 pub use helpers::*;
 
+mod devnet;
+pub use devnet::*;
+
 mod testnet3;
 pub use testnet3::*;
 
@@ -37,7 +40,7 @@ pub mod prelude {
 use crate::environment::prelude::*;
 use snarkvm_algorithms::{
     crypto_hash::PoseidonSponge,
-    snark::marlin::{CircuitProvingKey, CircuitVerifyingKey, MarlinHidingMode},
+    snark::marlin::{CircuitProvingKey, CircuitVerifyingKey, MarlinHidingMode, UniversalSRS},
     AlgebraicSponge,
 };
 use snarkvm_console_algorithms::{Poseidon2, Poseidon4, BHP1024, BHP512};
@@ -112,6 +115,10 @@ pub trait Network:
     const MAX_DATA_DEPTH: usize = 32;
     /// The maximum number of values and/or entries in data.
     const MAX_DATA_ENTRIES: usize = 32;
+    /// The minimum number of elements in an array.
+    const MIN_ARRAY_ELEMENTS: usize = 1;
+    /// The maximum number of elements in an array.
+    const MAX_ARRAY_ELEMENTS: usize = 32;
     /// The maximum number of fields in data (must not exceed u16::MAX).
     #[allow(clippy::cast_possible_truncation)]
     const MAX_DATA_SIZE_IN_FIELDS: u32 = ((128 * 1024 * 8) / Field::<Self>::SIZE_IN_DATA_BITS) as u32;
@@ -140,19 +147,25 @@ pub trait Network:
     type TransitionID: Bech32ID<Field<Self>>;
 
     /// Returns the genesis block bytes.
-    fn genesis_bytes() -> &'static [u8];
+    fn genesis_bytes() -> Result<&'static [u8]>;
 
     /// Returns the proving key for the given function name in `credits.aleo`.
     fn get_credits_proving_key(function_name: String) -> Result<&'static Arc<MarlinProvingKey<Self>>>;
 
+    /// Loads the proving key for the given function name in `credits.aleo`, without caching it.
+    fn load_credits_proving_key(function_name: String) -> Result<Arc<MarlinProvingKey<Self>>>;
+
     /// Returns the verifying key for the given function name in `credits.aleo`.
     fn get_credits_verifying_key(function_name: String) -> Result<&'static Arc<MarlinVerifyingKey<Self>>>;
 
     /// Returns the `proving key` for the inclusion circuit.
-    fn inclusion_proving_key() -> &'static Arc<MarlinProvingKey<Self>>;
+    fn inclusion_proving_key() -> Result<&'static Arc<MarlinProvingKey<Self>>>;
 
     /// Returns the `verifying key` for the inclusion circuit.
-    fn inclusion_verifying_key() -> &'static Arc<MarlinVerifyingKey<Self>>;
+    fn inclusion_verifying_key() -> Result<&'static Arc<MarlinVerifyingKey<Self>>>;
+
+    /// Returns the universal SRS.
+    fn universal_srs() -> Result<UniversalSRS<Self::PairingCurve>>;
 
     /// Returns the powers of `G`.
     fn g_powers() -> &'static Vec<Group<Self>>;
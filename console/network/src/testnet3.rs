@@ -139,8 +139,8 @@ impl Network for Testnet3 {
     const NAME: &'static str = "Aleo Testnet 3";
 
     /// Returns the genesis block bytes.
-    fn genesis_bytes() -> &'static [u8] {
-        snarkvm_parameters::testnet3::GenesisBytes::load_bytes()
+    fn genesis_bytes() -> Result<&'static [u8]> {
+        Ok(snarkvm_parameters::testnet3::GenesisBytes::load_bytes())
     }
 
     /// Returns the proving key for the given function name in `credits.aleo`.
@@ -150,6 +150,20 @@ impl Network for Testnet3 {
             .ok_or_else(|| anyhow!("Proving key for credits.aleo/{function_name}' not found"))
     }
 
+    /// Loads the proving key for the given function name in `credits.aleo`, without caching it.
+    fn load_credits_proving_key(function_name: String) -> Result<Arc<MarlinProvingKey<Self>>> {
+        let key_bytes = match function_name.as_str() {
+            "mint" => snarkvm_parameters::testnet3::MintProver::load_bytes()?,
+            "transfer" => snarkvm_parameters::testnet3::TransferProver::load_bytes()?,
+            "join" => snarkvm_parameters::testnet3::JoinProver::load_bytes()?,
+            "split" => snarkvm_parameters::testnet3::SplitProver::load_bytes()?,
+            "fee" => snarkvm_parameters::testnet3::FeeProver::load_bytes()?,
+            _ => bail!("Proving key for credits.aleo/{function_name}' not found"),
+        };
+        // Skipping the first 2 bytes, which is the encoded version.
+        Ok(Arc::new(CircuitProvingKey::from_bytes_le(&key_bytes[2..])?))
+    }
+
     /// Returns the verifying key for the given function name in `credits.aleo`.
     fn get_credits_verifying_key(function_name: String) -> Result<&'static Arc<MarlinVerifyingKey<Self>>> {
         CREDITS_VERIFYING_KEYS
@@ -158,29 +172,32 @@ impl Network for Testnet3 {
     }
 
     /// Returns the `proving key` for the inclusion circuit.
-    fn inclusion_proving_key() -> &'static Arc<MarlinProvingKey<Self>> {
+    fn inclusion_proving_key() -> Result<&'static Arc<MarlinProvingKey<Self>>> {
         static INSTANCE: OnceCell<Arc<MarlinProvingKey<Console>>> = OnceCell::new();
-        INSTANCE.get_or_init(|| {
+        INSTANCE.get_or_try_init(|| {
             // Skipping the first 2 bytes, which is the encoded version.
-            Arc::new(
-                CircuitProvingKey::from_bytes_le(&snarkvm_parameters::testnet3::INCLUSION_PROVING_KEY[2..])
-                    .expect("Failed to load inclusion proving key."),
-            )
+            CircuitProvingKey::from_bytes_le(&snarkvm_parameters::testnet3::INCLUSION_PROVING_KEY[2..])
+                .map(Arc::new)
+                .map_err(|_| anyhow!("Failed to load inclusion proving key."))
         })
     }
 
     /// Returns the `verifying key` for the inclusion circuit.
-    fn inclusion_verifying_key() -> &'static Arc<MarlinVerifyingKey<Self>> {
+    fn inclusion_verifying_key() -> Result<&'static Arc<MarlinVerifyingKey<Self>>> {
         static INSTANCE: OnceCell<Arc<MarlinVerifyingKey<Console>>> = OnceCell::new();
-        INSTANCE.get_or_init(|| {
+        INSTANCE.get_or_try_init(|| {
             // Skipping the first 2 bytes, which is the encoded version.
-            Arc::new(
-                CircuitVerifyingKey::from_bytes_le(&snarkvm_parameters::testnet3::INCLUSION_VERIFYING_KEY[2..])
-                    .expect("Failed to load inclusion verifying key."),
-            )
+            CircuitVerifyingKey::from_bytes_le(&snarkvm_parameters::testnet3::INCLUSION_VERIFYING_KEY[2..])
+                .map(Arc::new)
+                .map_err(|_| anyhow!("Failed to load inclusion verifying key."))
         })
     }
 
+    /// Returns the universal SRS, which is loaded from `snarkvm-parameters`.
+    fn universal_srs() -> Result<UniversalSRS<Self::PairingCurve>> {
+        UniversalSRS::load()
+    }
+
     /// Returns the powers of `G`.
     fn g_powers() -> &'static Vec<Group<Self>> {
         &GENERATOR_G
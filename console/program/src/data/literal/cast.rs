@@ -0,0 +1,204 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_console_network::environment::traits::integers::IntegerType;
+use snarkvm_console_types::integers::Integer;
+
+impl<N: Network> Literal<N> {
+    /// Casts the literal into the given literal type.
+    /// This method fails if the value of the literal does not fit in the given literal type.
+    pub fn cast(&self, to_type: LiteralType) -> Result<Self> {
+        self.cast_internal(to_type, false)
+    }
+
+    /// Casts the literal into the given literal type, truncating the value if it does not fit.
+    /// Integers are truncated to their lower bits, and negative integers are cast as their two's complement.
+    pub fn cast_lossy(&self, to_type: LiteralType) -> Result<Self> {
+        self.cast_internal(to_type, true)
+    }
+
+    /// Casts the literal into the given literal type, truncating the value iff `is_lossy` is `true`.
+    fn cast_internal(&self, to_type: LiteralType, is_lossy: bool) -> Result<Self> {
+        // Ensure the cast is supported.
+        let from_type = self.to_type();
+        ensure!(from_type.is_castable_to(&to_type), "Cannot cast a '{from_type}' literal into a '{to_type}'");
+
+        // Casting a literal into its own type is a no-op.
+        if from_type == to_type {
+            return Ok(self.clone());
+        }
+
+        match (self, to_type) {
+            // Cast the address into its field representation (i.e. the x-coordinate).
+            (Self::Address(address), LiteralType::Field) => Ok(Self::Field(address.to_field()?)),
+            // Cast the field element into the group element with the given x-coordinate.
+            (Self::Field(field), LiteralType::Group) => Ok(Self::Group(Group::from_x_coordinate(*field)?)),
+            // Cast the group element into its x-coordinate.
+            (Self::Group(group), LiteralType::Field) => Ok(Self::Field(group.to_x_coordinate())),
+            // Otherwise, cast the bits of the boolean, field element, or integer.
+            _ => {
+                // Retrieve the little-endian bits, and whether they are a two's complement representation.
+                let (bits_le, is_signed) = match self {
+                    Self::Boolean(boolean) => (vec![**boolean], false),
+                    Self::Field(field) => (field.to_bits_le(), false),
+                    Self::I8(integer) => (integer.to_bits_le(), true),
+                    Self::I16(integer) => (integer.to_bits_le(), true),
+                    Self::I32(integer) => (integer.to_bits_le(), true),
+                    Self::I64(integer) => (integer.to_bits_le(), true),
+                    Self::I128(integer) => (integer.to_bits_le(), true),
+                    Self::U8(integer) => (integer.to_bits_le(), false),
+                    Self::U16(integer) => (integer.to_bits_le(), false),
+                    Self::U32(integer) => (integer.to_bits_le(), false),
+                    Self::U64(integer) => (integer.to_bits_le(), false),
+                    Self::U128(integer) => (integer.to_bits_le(), false),
+                    _ => bail!("Cannot cast a '{from_type}' literal into a '{to_type}'"),
+                };
+
+                match to_type {
+                    LiteralType::Field => {
+                        // If the cast is checked, ensure the value is not negative.
+                        if !is_lossy && is_signed {
+                            ensure!(!bits_le.last().unwrap_or(&false), "Cannot cast a negative integer into a field");
+                        }
+                        Ok(Self::Field(Field::from_bits_le(&bits_le)?))
+                    }
+                    LiteralType::I8 => Ok(Self::I8(cast_integer(&bits_le, is_signed, is_lossy)?)),
+                    LiteralType::I16 => Ok(Self::I16(cast_integer(&bits_le, is_signed, is_lossy)?)),
+                    LiteralType::I32 => Ok(Self::I32(cast_integer(&bits_le, is_signed, is_lossy)?)),
+                    LiteralType::I64 => Ok(Self::I64(cast_integer(&bits_le, is_signed, is_lossy)?)),
+                    LiteralType::I128 => Ok(Self::I128(cast_integer(&bits_le, is_signed, is_lossy)?)),
+                    LiteralType::U8 => Ok(Self::U8(cast_integer(&bits_le, is_signed, is_lossy)?)),
+                    LiteralType::U16 => Ok(Self::U16(cast_integer(&bits_le, is_signed, is_lossy)?)),
+                    LiteralType::U32 => Ok(Self::U32(cast_integer(&bits_le, is_signed, is_lossy)?)),
+                    LiteralType::U64 => Ok(Self::U64(cast_integer(&bits_le, is_signed, is_lossy)?)),
+                    LiteralType::U128 => Ok(Self::U128(cast_integer(&bits_le, is_signed, is_lossy)?)),
+                    _ => bail!("Cannot cast a '{from_type}' literal into a '{to_type}'"),
+                }
+            }
+        }
+    }
+}
+
+/// Casts the given little-endian bits into an integer of type `I`.
+/// The bits are sign-extended if `is_signed` is `true`, and zero-extended otherwise.
+/// If `is_lossy` is `false`, this method fails if the value does not fit in the integer type.
+fn cast_integer<N: Network, I: IntegerType>(
+    bits_le: &[bool],
+    is_signed: bool,
+    is_lossy: bool,
+) -> Result<Integer<N, I>> {
+    // Retrieve the bit that extends the value beyond its given bits.
+    let extension = is_signed && *bits_le.last().unwrap_or(&false);
+
+    // Resize the bits to the size of the integer type.
+    let num_bits = usize::try_from(I::BITS)?;
+    let mut integer_bits_le = bits_le.iter().take(num_bits).copied().collect::<Vec<_>>();
+    integer_bits_le.resize(num_bits, extension);
+
+    // If the cast is checked, ensure the value fits in the integer type.
+    if !is_lossy {
+        // Retrieve the sign of the integer.
+        let sign = I::is_signed() && integer_bits_le[num_bits - 1];
+        // Ensure the truncated bits and the extension bit all match the sign of the integer.
+        ensure!(
+            bits_le.iter().skip(num_bits).all(|bit| *bit == sign) && extension == sign,
+            "The value does not fit in a '{}' integer",
+            Integer::<N, I>::type_name()
+        );
+    }
+
+    Integer::from_bits_le(&integer_bits_le)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    fn check_cast(input: &str, to_type: LiteralType, expected: Option<&str>, expected_lossy: &str) -> Result<()> {
+        let input = Literal::<CurrentNetwork>::from_str(input)?;
+        // Check the checked cast.
+        match expected {
+            Some(expected) => assert_eq!(input.cast(to_type)?, Literal::from_str(expected)?),
+            None => assert!(input.cast(to_type).is_err()),
+        }
+        // Check the lossy cast.
+        assert_eq!(input.cast_lossy(to_type)?, Literal::from_str(expected_lossy)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast_integers() -> Result<()> {
+        // Widening.
+        check_cast("5u8", LiteralType::U64, Some("5u64"), "5u64")?;
+        check_cast("-5i8", LiteralType::I64, Some("-5i64"), "-5i64")?;
+        check_cast("200u8", LiteralType::I16, Some("200i16"), "200i16")?;
+        // Narrowing.
+        check_cast("255u16", LiteralType::U8, Some("255u8"), "255u8")?;
+        check_cast("256u16", LiteralType::U8, None, "0u8")?;
+        check_cast("-128i16", LiteralType::I8, Some("-128i8"), "-128i8")?;
+        check_cast("-129i16", LiteralType::I8, None, "127i8")?;
+        // Sign changes.
+        check_cast("-1i8", LiteralType::U8, None, "255u8")?;
+        check_cast("-1i8", LiteralType::U16, None, "65535u16")?;
+        check_cast("128u8", LiteralType::I8, None, "-128i8")?;
+        check_cast("127u8", LiteralType::I8, Some("127i8"), "127i8")?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast_fields() -> Result<()> {
+        // Integers into fields.
+        check_cast("7u32", LiteralType::Field, Some("7field"), "7field")?;
+        check_cast("-1i8", LiteralType::Field, None, "255field")?;
+        // Fields into integers.
+        check_cast("7field", LiteralType::U8, Some("7u8"), "7u8")?;
+        check_cast("300field", LiteralType::U8, None, "44u8")?;
+        check_cast("200field", LiteralType::I8, None, "-56i8")?;
+        // Booleans into integers and fields.
+        check_cast("true", LiteralType::I8, Some("1i8"), "1i8")?;
+        check_cast("false", LiteralType::U128, Some("0u128"), "0u128")?;
+        check_cast("true", LiteralType::Field, Some("1field"), "1field")?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast_groups_and_addresses() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a group element.
+        let group = Group::<CurrentNetwork>::rand(rng);
+        let x_coordinate = Literal::Field(group.to_x_coordinate());
+        // Ensure the group element and its x-coordinate cast into each other.
+        assert_eq!(Literal::Group(group).cast(LiteralType::Field)?, x_coordinate);
+        assert_eq!(x_coordinate.cast(LiteralType::Group)?, Literal::Group(group));
+
+        // Ensure the address casts into its x-coordinate.
+        let address = Literal::Address(Address::new(group));
+        assert_eq!(address.cast(LiteralType::Field)?, x_coordinate);
+        assert_eq!(address.cast_lossy(LiteralType::Field)?, x_coordinate);
+
+        // Ensure unsupported casts fail.
+        assert!(address.cast(LiteralType::Group).is_err());
+        assert!(Literal::Group(group).cast(LiteralType::U8).is_err());
+        assert!(Literal::<CurrentNetwork>::from_str("1u8")?.cast(LiteralType::Boolean).is_err());
+        assert!(Literal::<CurrentNetwork>::from_str("1u8")?.cast(LiteralType::Scalar).is_err());
+        Ok(())
+    }
+}
@@ -18,51 +18,84 @@ use super::*;
 
 impl<N: Network> Plaintext<N> {
     /// Returns the plaintext member from the given path.
-    pub fn find(&self, path: &[Identifier<N>]) -> Result<Plaintext<N>> {
+    pub fn find(&self, path: &[Access<N>]) -> Result<Plaintext<N>> {
         // Ensure the path is not empty.
         ensure!(!path.is_empty(), "Attempted to find member with an empty path.");
 
-        match self {
-            // Halts if the value is not a struct.
-            Self::Literal(..) => bail!("'{self}' is not a struct"),
-            // Retrieve the value of the member (from the value).
-            Self::Struct(members, ..) => {
-                // Initialize the members starting from the top-level.
-                let mut submembers = members;
+        // Initialize the plaintext starting from the top-level.
+        let mut plaintext = self;
 
-                // Initialize the output.
-                let mut output = None;
-
-                // Iterate through the path to retrieve the value.
-                for (i, identifier) in path.iter().enumerate() {
-                    // If this is not the last item in the path, ensure the value is a struct.
-                    if i != path.len() - 1 {
-                        match submembers.get(identifier) {
-                            // Halts if the member is not a struct.
-                            Some(Self::Literal(..)) => bail!("'{identifier}' must be a struct"),
-                            // Retrieve the member and update `submembers` for the next iteration.
-                            Some(Self::Struct(members, ..)) => submembers = members,
-                            // Halts if the member does not exist.
-                            None => bail!("Failed to locate member '{identifier}' in '{self}'"),
-                        }
-                    }
-                    // Otherwise, return the final member.
-                    else {
-                        match submembers.get(identifier) {
-                            // Return the plaintext member.
-                            Some(plaintext) => output = Some(plaintext.clone()),
-                            // Halts if the member does not exist.
-                            None => bail!("Failed to locate member '{identifier}' in '{self}'"),
-                        }
-                    }
+        // Iterate through the path to retrieve the value.
+        for access in path.iter() {
+            plaintext = match (plaintext, access) {
+                // Retrieve the member of the struct.
+                (Self::Struct(members, ..), Access::Member(identifier)) => match members.get(identifier) {
+                    // Retrieve the member and update `plaintext` for the next iteration.
+                    Some(member) => member,
+                    // Halts if the member does not exist.
+                    None => bail!("Failed to locate member '{identifier}' in '{self}'"),
+                },
+                // Retrieve the element of the array.
+                (Self::Array(elements, ..), Access::Index(index)) => match elements.get(**index as usize) {
+                    // Retrieve the element and update `plaintext` for the next iteration.
+                    Some(element) => element,
+                    // Halts if the index is out of bounds.
+                    None => bail!("Index '{index}' is out of bounds in '{self}'"),
+                },
+                // Halts if the value is a literal.
+                (Self::Literal(..), _) => bail!("'{plaintext}' is not a struct or an array"),
+                // Halts if the access does not match the value.
+                (Self::Struct(..), Access::Index(index)) => bail!("Cannot access index '{index}' of a struct"),
+                (Self::Array(..), Access::Member(identifier)) => {
+                    bail!("Cannot access member '{identifier}' of an array")
                 }
+            };
+        }
 
-                // Return the output.
-                match output {
-                    Some(output) => Ok(output),
-                    None => bail!("Failed to locate member from path in '{self}': {:?}", path),
-                }
+        // Return the output.
+        Ok(plaintext.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Register;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_find() -> Result<()> {
+        let plaintext = Plaintext::<CurrentNetwork>::from_str(
+            "{ a: [1u8, 2u8, 3u8], b: [{ c: true }, { c: false }], d: { e: [[0field, 1field], [2field, 3field]] } }",
+        )?;
+
+        let find = |path: &str| {
+            let path = Register::<CurrentNetwork>::from_str(&format!("r0{path}"))?;
+            match path {
+                Register::Access(_, path) => plaintext.find(&path),
+                Register::Locator(..) => bail!("Expected a register access"),
             }
-        }
+        };
+
+        assert_eq!(find(".a")?, Plaintext::from_str("[1u8, 2u8, 3u8]")?);
+        assert_eq!(find(".a[0u32]")?, Plaintext::from_str("1u8")?);
+        assert_eq!(find(".a[2u32]")?, Plaintext::from_str("3u8")?);
+        assert_eq!(find(".b[1u32].c")?, Plaintext::from_str("false")?);
+        assert_eq!(find(".d.e[1u32]")?, Plaintext::from_str("[2field, 3field]")?);
+        assert_eq!(find(".d.e[1u32][0u32]")?, Plaintext::from_str("2field")?);
+
+        // Ensure out of bounds indices fail.
+        assert!(find(".a[3u32]").is_err());
+        assert!(find(".d.e[2u32][0u32]").is_err());
+        // Ensure mismatched accesses fail.
+        assert!(find("[0u32]").is_err());
+        assert!(find(".a.b").is_err());
+        assert!(find(".a[0u32][0u32]").is_err());
+        assert!(find(".f").is_err());
+        // Ensure an empty path fails.
+        assert!(plaintext.find(&[]).is_err());
+        Ok(())
     }
 }
@@ -62,6 +62,28 @@ impl<N: Network> Parser for Plaintext<N> {
             Ok((string, Plaintext::Struct(IndexMap::from_iter(members.into_iter()), Default::default())))
         }
 
+        /// Parses a plaintext as an array: `[plaintext_0, ..., plaintext_n]`.
+        fn parse_array<N: Network>(string: &str) -> ParserResult<Plaintext<N>> {
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the "[" from the string.
+            let (string, _) = tag("[")(string)?;
+            // Parse the elements.
+            let (string, elements) = map_res(separated_list1(tag(","), Plaintext::parse), |elements: Vec<_>| {
+                // Ensure the number of elements is within the allowed bounds.
+                match (N::MIN_ARRAY_ELEMENTS..=N::MAX_ARRAY_ELEMENTS).contains(&elements.len()) {
+                    true => Ok(elements),
+                    false => Err(error(format!("Found an array of invalid size ({})", elements.len()))),
+                }
+            })(string)?;
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the ']' from the string.
+            let (string, _) = tag("]")(string)?;
+            // Output the plaintext.
+            Ok((string, Plaintext::Array(elements, Default::default())))
+        }
+
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse to determine the plaintext (order matters).
@@ -70,6 +92,8 @@ impl<N: Network> Parser for Plaintext<N> {
             map(Literal::parse, |literal| Self::Literal(literal, Default::default())),
             // Parse a plaintext struct.
             parse_struct,
+            // Parse a plaintext array.
+            parse_array,
         ))(string)
     }
 }
@@ -113,39 +137,44 @@ impl<N: Network> Plaintext<N> {
 
         match self {
             // Prints the literal, i.e. 10field
-            Self::Literal(literal, ..) => write!(f, "{:indent$}{literal}", "", indent = depth * INDENT),
+            Self::Literal(literal, ..) => write!(f, "{literal}"),
             // Prints the struct, i.e. { first: 10i64, second: 198u64 }
             Self::Struct(struct_, ..) => {
                 // Print the opening brace.
                 write!(f, "{{")?;
                 // Print the members.
                 struct_.iter().enumerate().try_for_each(|(i, (name, plaintext))| {
-                    match plaintext {
-                        Self::Literal(literal, ..) => match i == struct_.len() - 1 {
-                            true => {
-                                // Print the last member without a comma.
-                                write!(f, "\n{:indent$}{name}: {literal}", "", indent = (depth + 1) * INDENT)?;
-                                // Print the closing brace.
-                                write!(f, "\n{:indent$}}}", "", indent = depth * INDENT)
-                            }
-                            // Print the member with a comma.
-                            false => write!(f, "\n{:indent$}{name}: {literal},", "", indent = (depth + 1) * INDENT),
-                        },
-                        Self::Struct(..) => {
-                            // Print the member name.
-                            write!(f, "\n{:indent$}{name}: ", "", indent = (depth + 1) * INDENT)?;
-                            // Print the member.
-                            plaintext.fmt_internal(f, depth + 1)?;
-                            // Print the closing brace.
-                            match i == struct_.len() - 1 {
-                                // Print the last member without a comma.
-                                true => write!(f, "\n{:indent$}}}", "", indent = depth * INDENT),
-                                // Print the member with a comma.
-                                false => write!(f, "\n{:indent$}}},", "", indent = depth * INDENT),
-                            }
-                        }
+                    // Print the member name.
+                    write!(f, "\n{:indent$}{name}: ", "", indent = (depth + 1) * INDENT)?;
+                    // Print the member.
+                    plaintext.fmt_internal(f, depth + 1)?;
+                    // Print the comma, if this is not the last member.
+                    match i == struct_.len() - 1 {
+                        true => Ok(()),
+                        false => write!(f, ","),
                     }
-                })
+                })?;
+                // Print the closing brace.
+                write!(f, "\n{:indent$}}}", "", indent = depth * INDENT)
+            }
+            // Prints the array, i.e. [10i64, 198i64]
+            Self::Array(array, ..) => {
+                // Print the opening bracket.
+                write!(f, "[")?;
+                // Print the elements.
+                array.iter().enumerate().try_for_each(|(i, element)| {
+                    // Print the indentation.
+                    write!(f, "\n{:indent$}", "", indent = (depth + 1) * INDENT)?;
+                    // Print the element.
+                    element.fmt_internal(f, depth + 1)?;
+                    // Print the comma, if this is not the last element.
+                    match i == array.len() - 1 {
+                        true => Ok(()),
+                        false => write!(f, ","),
+                    }
+                })?;
+                // Print the closing bracket.
+                write!(f, "\n{:indent$}]", "", indent = depth * INDENT)
             }
         }
     }
@@ -213,11 +242,80 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_struct_with_nested_members() -> Result<()> {
+        let expected = r"{
+  foo: {
+    bar: 5u8
+  },
+  baz: {
+    qux: 10field
+  }
+}";
+        let (remainder, candidate) = Plaintext::<CurrentNetwork>::parse("{ foo: { bar: 5u8 }, baz: { qux: 10field } }")?;
+        assert_eq!(expected, candidate.to_string());
+        assert_eq!("", remainder);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_array() -> Result<()> {
+        // Sanity check.
+        let expected = r"[
+  1u8,
+  2u8,
+  3u8
+]";
+        let (remainder, candidate) = Plaintext::<CurrentNetwork>::parse("[1u8, 2u8, 3u8]")?;
+        assert_eq!(expected, candidate.to_string());
+        assert_eq!("", remainder);
+
+        let expected = r"{
+  foo: [
+    {
+      bar: [
+        true,
+        false
+      ]
+    },
+    {
+      bar: [
+        false,
+        true
+      ]
+    }
+  ],
+  baz: [
+    [
+      1field
+    ],
+    [
+      2field
+    ]
+  ]
+}";
+        let (remainder, candidate) = Plaintext::<CurrentNetwork>::parse(
+            "{ foo: [{ bar: [true, false] }, { bar: [false,true] }], baz: [ [1field], [2field] ] }",
+        )?;
+        assert_eq!(expected, candidate.to_string());
+        assert_eq!("", remainder);
+        // Ensure the display output parses back into the same plaintext.
+        assert_eq!(candidate, Plaintext::from_str(expected)?);
+
+        // Ensure the array size is within bounds.
+        let elements = vec!["1u8"; CurrentNetwork::MAX_ARRAY_ELEMENTS].join(", ");
+        assert!(Plaintext::<CurrentNetwork>::from_str(&format!("[{elements}]")).is_ok());
+        let elements = vec!["1u8"; CurrentNetwork::MAX_ARRAY_ELEMENTS + 1].join(", ");
+        assert!(Plaintext::<CurrentNetwork>::from_str(&format!("[{elements}]")).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_parse_fails() {
         // Must be non-empty.
         assert!(Plaintext::<CurrentNetwork>::parse("").is_err());
         assert!(Plaintext::<CurrentNetwork>::parse("{}").is_err());
+        assert!(Plaintext::<CurrentNetwork>::parse("[]").is_err());
 
         // Invalid characters.
         assert!(Plaintext::<CurrentNetwork>::parse("_").is_err());
@@ -55,6 +55,8 @@ impl<N: Network> Parser for Entry<N, Plaintext<N>> {
                 parse_literal,
                 // Parse a struct.
                 parse_struct,
+                // Parse an array.
+                parse_array,
             ))(string)?;
             // Return the identifier, plaintext, and visibility.
             Ok((string, (identifier, plaintext, mode)))
@@ -96,6 +98,44 @@ impl<N: Network> Parser for Entry<N, Plaintext<N>> {
             Ok((string, (Plaintext::Struct(IndexMap::from_iter(members.into_iter()), Default::default()), mode)))
         }
 
+        /// Parses an entry as an array: `[plaintext_0.visibility, ..., plaintext_n.visibility]`.
+        /// Observe the `visibility` is the same for all elements of the plaintext value.
+        fn parse_array<N: Network>(string: &str) -> ParserResult<(Plaintext<N>, Mode)> {
+            /// Parses a sanitized element: `entry`.
+            fn parse_element<N: Network>(string: &str) -> ParserResult<(Plaintext<N>, Mode)> {
+                // Parse the whitespace and comments from the string.
+                let (string, _) = Sanitizer::parse(string)?;
+                // Parse the plaintext and visibility from the string.
+                alt((parse_literal, parse_struct, parse_array))(string)
+            }
+
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the "[" from the string.
+            let (string, _) = tag("[")(string)?;
+            // Parse the elements.
+            let (string, (elements, mode)) = map_res(separated_list1(tag(","), parse_element), |elements: Vec<_>| {
+                // Ensure the elements all have the same visibility.
+                let mode = elements.iter().map(|(_, mode)| mode).dedup().collect::<Vec<_>>();
+                let mode = match mode.len() == 1 {
+                    true => *mode[0],
+                    false => return Err(error("Elements of array in entry have different visibilities")),
+                };
+                // Ensure the number of elements is within the allowed bounds.
+                match (N::MIN_ARRAY_ELEMENTS..=N::MAX_ARRAY_ELEMENTS).contains(&elements.len()) {
+                    // Return the elements and the visibility.
+                    true => Ok((elements.into_iter().map(|(p, _)| p).collect::<Vec<_>>(), mode)),
+                    false => Err(error(format!("Found an array of invalid size ({})", elements.len()))),
+                }
+            })(string)?;
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the ']' from the string.
+            let (string, _) = tag("]")(string)?;
+            // Output the plaintext and visibility.
+            Ok((string, (Plaintext::Array(elements, Default::default()), mode)))
+        }
+
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse to determine the entry (order matters).
@@ -104,6 +144,8 @@ impl<N: Network> Parser for Entry<N, Plaintext<N>> {
             parse_literal,
             // Parse a struct.
             parse_struct,
+            // Parse an array.
+            parse_array,
         ))(string)?;
 
         // Return the entry.
@@ -158,48 +200,53 @@ impl<N: Network> Entry<N, Plaintext<N>> {
             Self::Private(private) => (private, "private"),
         };
 
+        // Prints the given plaintext with the same visibility as this entry.
+        let fmt_plaintext = |f: &mut Formatter, plaintext: &Plaintext<N>, depth: usize| match self {
+            Self::Constant(..) => Self::Constant(plaintext.clone()).fmt_internal(f, depth),
+            Self::Public(..) => Self::Public(plaintext.clone()).fmt_internal(f, depth),
+            Self::Private(..) => Self::Private(plaintext.clone()).fmt_internal(f, depth),
+        };
+
         match plaintext {
             // Prints the literal, i.e. 10field.public
-            Plaintext::Literal(literal, ..) => {
-                write!(f, "{:indent$}{literal}.{visibility}", "", indent = depth * INDENT)
-            }
+            Plaintext::Literal(literal, ..) => write!(f, "{literal}.{visibility}"),
             // Prints the struct, i.e. { first: 10i64.private, second: 198u64.private }
             Plaintext::Struct(struct_, ..) => {
                 // Print the opening brace.
                 write!(f, "{{")?;
                 // Print the members.
                 struct_.iter().enumerate().try_for_each(|(i, (name, plaintext))| {
-                    match plaintext {
-                        #[rustfmt::skip]
-                        Plaintext::Literal(literal, ..) => match i == struct_.len() - 1 {
-                            true => {
-                                // Print the last member without a comma.
-                                write!(f, "\n{:indent$}{name}: {literal}.{visibility}", "", indent = (depth + 1) * INDENT)?;
-                                // Print the closing brace.
-                                write!(f, "\n{:indent$}}}", "", indent = depth * INDENT)
-                            }
-                            // Print the member with a comma.
-                            false => write!(f, "\n{:indent$}{name}: {literal}.{visibility},", "", indent = (depth + 1) * INDENT),
-                        },
-                        Plaintext::Struct(..) => {
-                            // Print the member name.
-                            write!(f, "\n{:indent$}{name}: ", "", indent = (depth + 1) * INDENT)?;
-                            // Print the member.
-                            match self {
-                                Self::Constant(..) => Self::Constant(plaintext.clone()).fmt_internal(f, depth + 1)?,
-                                Self::Public(..) => Self::Public(plaintext.clone()).fmt_internal(f, depth + 1)?,
-                                Self::Private(..) => Self::Private(plaintext.clone()).fmt_internal(f, depth + 1)?,
-                            }
-                            // Print the closing brace.
-                            match i == struct_.len() - 1 {
-                                // Print the last member without a comma.
-                                true => write!(f, "\n{:indent$}}}", "", indent = depth * INDENT),
-                                // Print the member with a comma.
-                                false => write!(f, "\n{:indent$}}},", "", indent = depth * INDENT),
-                            }
-                        }
+                    // Print the member name.
+                    write!(f, "\n{:indent$}{name}: ", "", indent = (depth + 1) * INDENT)?;
+                    // Print the member.
+                    fmt_plaintext(f, plaintext, depth + 1)?;
+                    // Print the comma, if this is not the last member.
+                    match i == struct_.len() - 1 {
+                        true => Ok(()),
+                        false => write!(f, ","),
                     }
-                })
+                })?;
+                // Print the closing brace.
+                write!(f, "\n{:indent$}}}", "", indent = depth * INDENT)
+            }
+            // Prints the array, i.e. [10i64.private, 198i64.private]
+            Plaintext::Array(array, ..) => {
+                // Print the opening bracket.
+                write!(f, "[")?;
+                // Print the elements.
+                array.iter().enumerate().try_for_each(|(i, element)| {
+                    // Print the indentation.
+                    write!(f, "\n{:indent$}", "", indent = (depth + 1) * INDENT)?;
+                    // Print the element.
+                    fmt_plaintext(f, element, depth + 1)?;
+                    // Print the comma, if this is not the last element.
+                    match i == array.len() - 1 {
+                        true => Ok(()),
+                        false => write!(f, ","),
+                    }
+                })?;
+                // Print the closing bracket.
+                write!(f, "\n{:indent$}]", "", indent = depth * INDENT)
             }
         }
     }
@@ -256,4 +303,40 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_array() -> Result<()> {
+        let expected = r"{
+  foo: [
+    5u8.private,
+    6u8.private
+  ],
+  bar: [
+    {
+      baz: true.private
+    },
+    {
+      baz: false.private
+    }
+  ]
+}";
+        let (remainder, candidate) = Entry::<CurrentNetwork, Plaintext<CurrentNetwork>>::parse(
+            "{ foo: [5u8.private, 6u8.private], bar: [{ baz: true.private }, { baz: false.private }] }",
+        )?;
+        assert_eq!(expected, candidate.to_string());
+        assert_eq!("", remainder);
+        // Ensure the display output parses back into the same entry.
+        assert_eq!(candidate, Entry::from_str(expected)?);
+
+        let (remainder, candidate) =
+            Entry::<CurrentNetwork, Plaintext<CurrentNetwork>>::parse("[1field.public, 2field.public]")?;
+        assert_eq!(candidate, Entry::Public(Plaintext::from_str("[1field, 2field]")?));
+        assert_eq!("", remainder);
+
+        // Ensure the elements must have the same visibility.
+        assert!(Entry::<CurrentNetwork, Plaintext<CurrentNetwork>>::parse("[1field.public, 2field.private]").is_err());
+        // Ensure the array must be non-empty.
+        assert!(Entry::<CurrentNetwork, Plaintext<CurrentNetwork>>::parse("[]").is_err());
+        Ok(())
+    }
 }
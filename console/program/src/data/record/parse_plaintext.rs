@@ -175,16 +175,7 @@ impl<N: Network> Record<N, Plaintext<N>> {
             // Print the identifier.
             write!(f, "\n{:indent$}{identifier}: ", "", indent = (depth + 1) * INDENT)?;
             // Print the entry.
-            match entry {
-                // If the entry is a literal, print the entry without indentation.
-                Entry::Constant(Plaintext::Literal(..))
-                | Entry::Public(Plaintext::Literal(..))
-                | Entry::Private(Plaintext::Literal(..)) => write!(f, "{entry}")?,
-                // If the entry is a struct, print the entry with indentation.
-                Entry::Constant(Plaintext::Struct(..))
-                | Entry::Public(Plaintext::Struct(..))
-                | Entry::Private(Plaintext::Struct(..)) => entry.fmt_internal(f, depth + 1)?,
-            }
+            entry.fmt_internal(f, depth + 1)?;
             // Print the comma.
             write!(f, ",")?;
         }
@@ -26,14 +26,32 @@ impl<N: Network> FromBytes for Register<N> {
             1 => {
                 // Read the number of identifiers.
                 let num_identifiers = u16::read_le(&mut reader)?;
+                // Ensure the number of identifiers is within `N::MAX_DATA_DEPTH`.
+                if num_identifiers as usize > N::MAX_DATA_DEPTH {
+                    return Err(error(format!("Register 'r{locator}' has too many accesses ({num_identifiers})")));
+                }
                 // Read the identifiers.
-                let mut identifiers = Vec::with_capacity(num_identifiers as usize);
+                let mut accesses = Vec::with_capacity(num_identifiers as usize);
                 for _ in 0..num_identifiers {
-                    identifiers.push(Identifier::read_le(&mut reader)?);
+                    accesses.push(Access::Member(Identifier::read_le(&mut reader)?));
+                }
+                Ok(Self::Access(locator, accesses))
+            }
+            2 => {
+                // Read the number of accesses.
+                let num_accesses = u16::read_le(&mut reader)?;
+                // Ensure the number of accesses is within `N::MAX_DATA_DEPTH`.
+                if num_accesses as usize > N::MAX_DATA_DEPTH {
+                    return Err(error(format!("Register 'r{locator}' has too many accesses ({num_accesses})")));
+                }
+                // Read the accesses.
+                let mut accesses = Vec::with_capacity(num_accesses as usize);
+                for _ in 0..num_accesses {
+                    accesses.push(Access::read_le(&mut reader)?);
                 }
-                Ok(Self::Member(locator, identifiers))
+                Ok(Self::Access(locator, accesses))
             }
-            2.. => Err(error(format!("Failed to deserialize register variant {variant}"))),
+            3.. => Err(error(format!("Failed to deserialize register variant {variant}"))),
         }
     }
 }
@@ -46,19 +64,78 @@ impl<N: Network> ToBytes for Register<N> {
                 u8::write_le(&0u8, &mut writer)?;
                 variable_length_integer(locator).write_le(&mut writer)
             }
-            Self::Member(locator, identifiers) => {
-                // Ensure the number of identifiers is within `N::MAX_DATA_DEPTH`.
-                if identifiers.len() > N::MAX_DATA_DEPTH {
-                    return Err(error("Failed to serialize register: too many identifiers"));
+            Self::Access(locator, accesses) => {
+                // Ensure the number of accesses is within `N::MAX_DATA_DEPTH`.
+                if accesses.len() > N::MAX_DATA_DEPTH {
+                    return Err(error("Failed to serialize register: too many accesses"));
                 }
 
-                u8::write_le(&1u8, &mut writer)?;
-                variable_length_integer(locator).write_le(&mut writer)?;
-                u16::try_from(identifiers.len())
-                    .or_halt_with::<N>("Register path length exceeds u16::MAX")
-                    .write_le(&mut writer)?;
-                identifiers.write_le(&mut writer)
+                // Retrieve the identifiers, if the accesses are all member accesses.
+                let identifiers = accesses
+                    .iter()
+                    .map(|access| match access {
+                        Access::Member(identifier) => Some(identifier),
+                        Access::Index(..) => None,
+                    })
+                    .collect::<Option<Vec<_>>>();
+
+                match identifiers {
+                    // Write the member accesses in the original encoding, which only contains identifiers.
+                    Some(identifiers) => {
+                        u8::write_le(&1u8, &mut writer)?;
+                        variable_length_integer(locator).write_le(&mut writer)?;
+                        u16::try_from(identifiers.len())
+                            .or_halt_with::<N>("Register path length exceeds u16::MAX")
+                            .write_le(&mut writer)?;
+                        identifiers.into_iter().try_for_each(|identifier| identifier.write_le(&mut writer))
+                    }
+                    // Otherwise, write the accesses with their variants.
+                    None => {
+                        u8::write_le(&2u8, &mut writer)?;
+                        variable_length_integer(locator).write_le(&mut writer)?;
+                        u16::try_from(accesses.len())
+                            .or_halt_with::<N>("Register path length exceeds u16::MAX")
+                            .write_le(&mut writer)?;
+                        accesses.write_le(&mut writer)
+                    }
+                }
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        for case in ["r0", "r1.owner", "r2.foo.bar", "r3[0u32]", "r4.foo[1u32].bar"] {
+            let expected = Register::<CurrentNetwork>::from_str(case)?;
+            let expected_bytes = expected.to_bytes_le()?;
+            assert_eq!(expected, Register::read_le(&expected_bytes[..])?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_member_bytes_are_unchanged() -> Result<()> {
+        // Construct the original encoding of a register with member accesses.
+        let identifiers = [Identifier::<CurrentNetwork>::from_str("foo")?, Identifier::from_str("bar")?];
+        let mut expected_bytes = vec![1u8];
+        expected_bytes.extend(variable_length_integer(&5).to_bytes_le()?);
+        expected_bytes.extend(2u16.to_bytes_le()?);
+        for identifier in &identifiers {
+            expected_bytes.extend(identifier.to_bytes_le()?);
+        }
+
+        // Ensure the register is encoded and decoded as before.
+        let register = Register::<CurrentNetwork>::from_str("r5.foo.bar")?;
+        assert_eq!(expected_bytes, register.to_bytes_le()?);
+        assert_eq!(register, Register::read_le(&expected_bytes[..])?);
+        Ok(())
+    }
+}
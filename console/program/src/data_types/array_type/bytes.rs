@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> FromBytes for ArrayType<N> {
+    /// Reads an array type from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the element type.
+        // Note: The reader is passed as a trait object to bound the recursion in monomorphization.
+        let element_type = PlaintextType::read_le(&mut reader as &mut dyn Read)?;
+        // Read the length of the array.
+        let length = U32::read_le(&mut reader)?;
+        // Return the array type.
+        Self::new(element_type, length).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl<N: Network> ToBytes for ArrayType<N> {
+    /// Writes the array type to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the element type.
+        // Note: The writer is passed as a trait object to bound the recursion in monomorphization.
+        self.element_type.write_le(&mut writer as &mut dyn Write)?;
+        // Write the length of the array.
+        self.length.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        for case in ["[field; 1u32]", "[u8; 32u32]", "[foo; 4u32]", "[[boolean; 2u32]; 3u32]"] {
+            let expected = ArrayType::<CurrentNetwork>::from_str(case)?;
+            let expected_bytes = expected.to_bytes_le()?;
+            assert_eq!(expected, ArrayType::read_le(&expected_bytes[..])?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_fails() -> Result<()> {
+        // The length must be within the allowed bounds.
+        let mut bytes = PlaintextType::<CurrentNetwork>::from_str("field")?.to_bytes_le()?;
+        bytes.extend(0u32.to_bytes_le()?);
+        assert!(ArrayType::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+
+        let mut bytes = PlaintextType::<CurrentNetwork>::from_str("field")?.to_bytes_le()?;
+        bytes.extend((u32::try_from(CurrentNetwork::MAX_ARRAY_ELEMENTS)? + 1).to_bytes_le()?);
+        assert!(ArrayType::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod bytes;
+mod parse;
+mod serialize;
+
+use crate::{LiteralType, PlaintextType, U32};
+use snarkvm_console_network::prelude::*;
+
+/// An `ArrayType` defines the type of a fixed-length array of plaintext elements.
+/// The format of the type is `[<element_type>; <length>]`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ArrayType<N: Network> {
+    /// The type of the elements in the array.
+    element_type: Box<PlaintextType<N>>,
+    /// The number of elements in the array.
+    length: U32<N>,
+}
+
+impl<N: Network> ArrayType<N> {
+    /// Initializes a new array type with the given element type and length.
+    pub fn new(element_type: PlaintextType<N>, length: U32<N>) -> Result<Self> {
+        // Ensure the length is within the allowed bounds.
+        ensure!(
+            (N::MIN_ARRAY_ELEMENTS..=N::MAX_ARRAY_ELEMENTS).contains(&(*length as usize)),
+            "An array must have between {} and {} elements, found {length}",
+            N::MIN_ARRAY_ELEMENTS,
+            N::MAX_ARRAY_ELEMENTS
+        );
+        // Ensure the nesting depth of the array is within `N::MAX_DATA_DEPTH`.
+        let mut depth = 1;
+        let mut current = &element_type;
+        while let PlaintextType::Array(array_type) = current {
+            depth += 1;
+            current = array_type.element_type();
+        }
+        ensure!(depth <= N::MAX_DATA_DEPTH, "An array cannot be nested more than {} times", N::MAX_DATA_DEPTH);
+        // Initialize the array type.
+        let array_type = Self { element_type: Box::new(element_type), length };
+        // Ensure an array of literals fits within `u16::MAX` bits, as it may be an element or member of another value.
+        // Note: The size of an array of structs is checked by the program, where the structs are defined.
+        if let PlaintextType::Literal(literal_type) = array_type.base_element_type() {
+            array_type.check_size_in_bits(Self::literal_size_in_bits(*literal_type))?;
+        }
+        // Return the array type.
+        Ok(array_type)
+    }
+
+    /// Returns the maximum number of bits of a plaintext literal of the given type.
+    pub fn literal_size_in_bits(literal_type: LiteralType) -> usize {
+        // The variant bits, the literal type (u8), the literal size (u16), and the literal.
+        2 + 8 + 16 + literal_type.size_in_bits::<N>()
+    }
+
+    /// Returns the maximum number of bits of a plaintext array of this type,
+    /// given the maximum number of bits of a plaintext of the base element type.
+    pub fn size_in_bits(&self, base_element_size_in_bits: usize) -> usize {
+        let element_size_in_bits = match self.element_type() {
+            PlaintextType::Array(array_type) => array_type.size_in_bits(base_element_size_in_bits),
+            _ => base_element_size_in_bits,
+        };
+        // The variant bits, the array length (u32), and each element with its size (u16).
+        (*self.length as usize).saturating_mul(16usize.saturating_add(element_size_in_bits)).saturating_add(2 + 32)
+    }
+
+    /// Ensures a plaintext array of this type fits within `u16::MAX` bits,
+    /// given the maximum number of bits of a plaintext of the base element type.
+    pub fn check_size_in_bits(&self, base_element_size_in_bits: usize) -> Result<()> {
+        let size_in_bits = self.size_in_bits(base_element_size_in_bits);
+        ensure!(
+            size_in_bits <= u16::MAX as usize,
+            "Array type '{self}' exceeds the maximum size of {} bits, found {size_in_bits} bits",
+            u16::MAX
+        );
+        Ok(())
+    }
+
+    /// Returns the type of the elements in the array.
+    pub fn element_type(&self) -> &PlaintextType<N> {
+        &self.element_type
+    }
+
+    /// Returns the innermost (non-array) type of the elements in the array.
+    pub fn base_element_type(&self) -> &PlaintextType<N> {
+        let mut element_type = self.element_type();
+        while let PlaintextType::Array(array_type) = element_type {
+            element_type = array_type.element_type();
+        }
+        element_type
+    }
+
+    /// Returns the number of elements in the array.
+    pub const fn length(&self) -> &U32<N> {
+        &self.length
+    }
+
+    /// Returns `true` if the elements of the array are of the given literal type.
+    pub fn is_array_of(&self, literal_type: LiteralType) -> bool {
+        *self.element_type == PlaintextType::Literal(literal_type)
+    }
+}
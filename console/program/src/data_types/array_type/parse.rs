@@ -0,0 +1,133 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Parser for ArrayType<N> {
+    /// Parses a string into an array type.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opening bracket from the string.
+        let (string, _) = tag("[")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the element type from the string.
+        let (string, element_type) = PlaintextType::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the semicolon from the string.
+        let (string, _) = tag(";")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the length from the string, and initialize the array type.
+        let (string, array_type) =
+            map_res(U32::parse, |length| ArrayType::new(element_type.clone(), length))(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the closing bracket from the string.
+        let (string, _) = tag("]")(string)?;
+        // Return the array type.
+        Ok((string, array_type))
+    }
+}
+
+impl<N: Network> FromStr for ArrayType<N> {
+    type Err = Error;
+
+    /// Returns an array type from a string literal.
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for ArrayType<N> {
+    /// Prints the array type as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for ArrayType<N> {
+    /// Prints the array type as a string, i.e. `[u8; 4u32]`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "[{}; {}]", self.element_type, self.length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Identifier;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let expected = ArrayType::<CurrentNetwork>::new(PlaintextType::Literal(LiteralType::U8), U32::new(4))?;
+        assert_eq!(ArrayType::parse("[u8; 4u32]"), Ok(("", expected.clone())));
+        assert_eq!(ArrayType::parse("[ u8 ;4u32 ]"), Ok(("", expected)));
+
+        let expected =
+            ArrayType::<CurrentNetwork>::new(PlaintextType::Struct(Identifier::from_str("foo")?), U32::new(2))?;
+        assert_eq!(ArrayType::parse("[foo; 2u32]"), Ok(("", expected)));
+
+        let inner = ArrayType::<CurrentNetwork>::new(PlaintextType::Literal(LiteralType::Field), U32::new(2))?;
+        let expected = ArrayType::<CurrentNetwork>::new(PlaintextType::Array(inner), U32::new(3))?;
+        assert_eq!(ArrayType::parse("[[field; 2u32]; 3u32]"), Ok(("", expected)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_fails() {
+        // Must be non-empty.
+        assert!(ArrayType::<CurrentNetwork>::parse("[u8; 0u32]").is_err());
+        // Must not exceed the maximum number of elements.
+        assert!(ArrayType::<CurrentNetwork>::parse("[u8; 33u32]").is_err());
+        // Must have a `u32` length.
+        assert!(ArrayType::<CurrentNetwork>::parse("[u8; 4]").is_err());
+        assert!(ArrayType::<CurrentNetwork>::parse("[u8; 4u8]").is_err());
+        // Must be well-formed.
+        assert!(ArrayType::<CurrentNetwork>::parse("[u8]").is_err());
+        assert!(ArrayType::<CurrentNetwork>::parse("[u8; 4u32").is_err());
+        assert!(ArrayType::<CurrentNetwork>::parse("u8; 4u32]").is_err());
+        // Must not contain a visibility.
+        assert!(ArrayType::<CurrentNetwork>::parse("[u8.public; 4u32]").is_err());
+        // Must fit within `u16::MAX` bits.
+        assert!(ArrayType::<CurrentNetwork>::parse("[[u8; 32u32]; 32u32]").is_ok());
+        assert!(ArrayType::<CurrentNetwork>::parse("[[field; 32u32]; 32u32]").is_err());
+        assert!(ArrayType::<CurrentNetwork>::parse("[[[boolean; 32u32]; 32u32]; 32u32]").is_err());
+        // Must not exceed the maximum depth.
+        let nested = (0..CurrentNetwork::MAX_DATA_DEPTH).fold("boolean".to_string(), |acc, _| format!("[{acc}; 1u32]"));
+        assert!(ArrayType::<CurrentNetwork>::parse(&nested).is_ok());
+        assert!(ArrayType::<CurrentNetwork>::parse(&format!("[{nested}; 1u32]")).is_err());
+    }
+
+    #[test]
+    fn test_display() -> Result<()> {
+        assert_eq!(ArrayType::<CurrentNetwork>::from_str("[u8; 4u32]")?.to_string(), "[u8; 4u32]");
+        assert_eq!(ArrayType::<CurrentNetwork>::from_str("[ [foo;2u32] ; 3u32]")?.to_string(), "[[foo; 2u32]; 3u32]");
+        Ok(())
+    }
+}
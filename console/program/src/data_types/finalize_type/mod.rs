@@ -23,7 +23,7 @@ use snarkvm_console_network::prelude::*;
 
 use enum_index::EnumIndex;
 
-#[derive(Copy, Clone, PartialEq, Eq, Hash, EnumIndex)]
+#[derive(Clone, PartialEq, Eq, Hash, EnumIndex)]
 pub enum FinalizeType<N: Network> {
     /// A publicly-visible type.
     Public(PlaintextType<N>),
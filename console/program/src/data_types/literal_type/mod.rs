@@ -18,7 +18,9 @@ mod bytes;
 mod parse;
 mod serialize;
 
+use snarkvm_console_account::Signature;
 use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::{prelude::*, Boolean};
 
 use core::fmt::{self, Debug, Display};
 use num_derive::FromPrimitive;
@@ -58,6 +60,8 @@ pub enum LiteralType {
     Scalar,
     /// The string type.
     String,
+    /// The signature type.
+    Signature,
 }
 
 impl LiteralType {
@@ -80,6 +84,62 @@ impl LiteralType {
             Self::U128 => "u128",
             Self::Scalar => "scalar",
             Self::String => "string",
+            Self::Signature => "signature",
+        }
+    }
+
+    /// Returns the maximum number of bits of a literal of this type.
+    pub fn size_in_bits<N: Network>(&self) -> usize {
+        match self {
+            Self::Address => Address::<N>::size_in_bits(),
+            Self::Boolean => Boolean::<N>::size_in_bits(),
+            Self::Field => Field::<N>::size_in_bits(),
+            Self::Group => Group::<N>::size_in_bits(),
+            Self::I8 => I8::<N>::size_in_bits(),
+            Self::I16 => I16::<N>::size_in_bits(),
+            Self::I32 => I32::<N>::size_in_bits(),
+            Self::I64 => I64::<N>::size_in_bits(),
+            Self::I128 => I128::<N>::size_in_bits(),
+            Self::U8 => U8::<N>::size_in_bits(),
+            Self::U16 => U16::<N>::size_in_bits(),
+            Self::U32 => U32::<N>::size_in_bits(),
+            Self::U64 => U64::<N>::size_in_bits(),
+            Self::U128 => U128::<N>::size_in_bits(),
+            Self::Scalar => Scalar::<N>::size_in_bits(),
+            Self::String => N::MAX_STRING_BYTES as usize * 8,
+            Self::Signature => Signature::<N>::size_in_bits(),
+        }
+    }
+
+    /// Returns `true` if the literal type is a signed or unsigned integer type.
+    pub const fn is_integer(&self) -> bool {
+        matches!(
+            self,
+            Self::I8
+                | Self::I16
+                | Self::I32
+                | Self::I64
+                | Self::I128
+                | Self::U8
+                | Self::U16
+                | Self::U32
+                | Self::U64
+                | Self::U128
+        )
+    }
+
+    /// Returns `true` if a literal of this type can be cast into a literal of the given type.
+    pub fn is_castable_to(&self, to_type: &Self) -> bool {
+        match (self, to_type) {
+            // A literal can always be cast into its own type.
+            (from_type, to_type) if from_type == to_type => true,
+            // An address can be cast into its field representation.
+            (Self::Address, Self::Field) => true,
+            // A field element can be cast into the group element with the same x-coordinate, and vice versa.
+            (Self::Field, Self::Group) | (Self::Group, Self::Field) => true,
+            // A boolean, field element, or integer can be cast into a field element or an integer.
+            (Self::Boolean | Self::Field, to_type) => *to_type == Self::Field || to_type.is_integer(),
+            (from_type, to_type) => from_type.is_integer() && (*to_type == Self::Field || to_type.is_integer()),
         }
     }
 }
@@ -69,6 +69,11 @@ mod tests {
         "passport",
         "object",
         "array",
+        // Array
+        "[field; 1u32]",
+        "[u8; 32u32]",
+        "[signature; 4u32]",
+        "[[boolean; 2u32]; 3u32]",
     ];
 
     fn check_serde_json<
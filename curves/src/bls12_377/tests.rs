@@ -69,7 +69,9 @@ use snarkvm_fields::{
 use snarkvm_utilities::{
     biginteger::{BigInteger, BigInteger256, BigInteger384},
     rand::{TestRng, Uniform},
+    read_raw,
     BitIteratorBE,
+    ZeroCopy,
 };
 
 use rand::Rng;
@@ -654,6 +656,53 @@ fn test_g1_generator() {
     assert!(generator.is_in_correct_subgroup_assuming_on_curve());
 }
 
+#[test]
+fn test_g1_affine_zero_copy() {
+    let mut rng = TestRng::default();
+
+    for point in [G1Affine::zero(), G1Affine::prime_subgroup_generator(), G1Projective::rand(&mut rng).to_affine()] {
+        let mut bytes = Vec::new();
+        point.write_raw(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), std::mem::size_of::<G1Affine>());
+        assert_eq!(read_raw::<G1Affine>(&bytes), Some(point));
+
+        // Ensure an invalid infinity flag is rejected.
+        let mut invalid = bytes.clone();
+        invalid[2 * std::mem::size_of::<Fq>()] = 2;
+        assert_eq!(read_raw::<G1Affine>(&invalid), None);
+        assert!(!G1Affine::is_valid_raw_layout(&invalid));
+
+        // Ensure a non-canonical coordinate is rejected.
+        let mut invalid = bytes.clone();
+        invalid[..std::mem::size_of::<Fq>()].fill(0xff);
+        assert_eq!(read_raw::<G1Affine>(&invalid), None);
+    }
+
+    // Ensure a point that is not on the curve is rejected.
+    let mut point = G1Projective::rand(&mut rng).to_affine();
+    point.y.double_in_place();
+    let mut bytes = Vec::new();
+    point.write_raw(&mut bytes).unwrap();
+    assert_eq!(read_raw::<G1Affine>(&bytes), None);
+
+    // Ensure a point that is on the curve, but not in the prime-order subgroup, is rejected.
+    let point = loop {
+        let x = Fq::rand(&mut rng);
+        if let Some(y) = (x.square() * x + Bls12_377G1Parameters::WEIERSTRASS_B).sqrt() {
+            let point = G1Affine::from_coordinates_unchecked((x, y, false));
+            if !point.is_in_correct_subgroup_assuming_on_curve() {
+                break point;
+            }
+        }
+    };
+    assert!(point.is_on_curve());
+    let mut bytes = Vec::new();
+    point.write_raw(&mut bytes).unwrap();
+    assert_eq!(read_raw::<G1Affine>(&bytes), None);
+    // Ensure the point only passes the layout check, which skips the subgroup check.
+    assert!(G1Affine::is_valid_raw_layout(&bytes));
+}
+
 #[test]
 fn test_g2_projective_curve() {
     let mut rng = TestRng::default();
@@ -0,0 +1,190 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::{
+    FftParameters,
+    FieldParameters,
+    Fp256,
+    Fp256Parameters,
+    PoseidonDefaultParameters,
+    PoseidonDefaultParametersEntry,
+};
+use snarkvm_utilities::biginteger::BigInteger256 as BigInteger;
+
+/// BN254 base field.
+///
+/// Roots of unity computed from modulus and R using this sage code:
+///
+/// ```ignore
+/// q = 21888242871839275222246405745257275088696311157297823662689037894645226208583
+/// R = 6350874878119819312338956282401532409788428879151445726012394534686998597021 # Montgomery R
+/// s = 1
+/// o = q - 1
+/// F = GF(q)
+/// g = F.multiplicative_generator()
+/// assert g.multiplicative_order() == o
+/// g2 = g ** (o/2**s)
+/// assert g2.multiplicative_order() == 2**s
+/// def into_chunks(val, width, n):
+///     return [int(int(val) // (2 ** (width * i)) % 2 ** width) for i in range(n)]
+/// print("Gen (g % q): ", g % q)
+/// print("Gen (g * R % q): ", g * R % q)
+/// print("Gen into_chunks(g * R % q): ", into_chunks(g * R % q, 64, 4))
+/// print("2-adic gen (g2 % q): ", g2 % q)
+/// print("2-adic gen (g2 * R % q): ", g2 * R % q)
+/// print("2-adic gen into_chunks(g2 * R % q): ", into_chunks(g2 * R % q, 64, 4))
+/// ```
+pub type Fq = Fp256<FqParameters>;
+
+pub struct FqParameters;
+
+impl Fp256Parameters for FqParameters {}
+
+impl FftParameters for FqParameters {
+    type BigInteger = BigInteger;
+
+    #[rustfmt::skip]
+    const POWERS_OF_ROOTS_OF_UNITY: &'static [BigInteger] = &[];
+    #[rustfmt::skip]
+    const TWO_ADICITY: u32 = 1;
+    /// TWO_ADIC_ROOT_OF_UNITY = 21888242871839275222246405745257275088696311157297823662689037894645226208582
+    /// Encoded in Montgomery form, the value is
+    /// (21888242871839275222246405745257275088696311157297823662689037894645226208582 * R % q) =
+    /// 15537367993719455909907449462855742678907882278146377936676643359958227611562
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([
+        7548957153968385962u64,
+        10162512645738643279u64,
+        5900175412809962033u64,
+        2475245527108272378u64,
+    ]);
+}
+
+impl FieldParameters for FqParameters {
+    #[rustfmt::skip]
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+    /// GENERATOR = 3
+    /// Encoded in Montgomery form, so the value is
+    /// (3 * R) % q = 19052624634359457937016868847204597229365286637454337178037183604060995791063
+    #[rustfmt::skip]
+    const GENERATOR: BigInteger = BigInteger([
+        8797723225643362519u64,
+        2263834496217719225u64,
+        3696305541684646532u64,
+        3035258219084094862u64,
+    ]);
+    #[rustfmt::skip]
+    const INV: u64 = 9786893198990664585u64;
+    /// MODULUS = 21888242871839275222246405745257275088696311157297823662689037894645226208583
+    #[rustfmt::skip]
+    const MODULUS: BigInteger = BigInteger([
+        4332616871279656263u64,
+        10917124144477883021u64,
+        13281191951274694749u64,
+        3486998266802970665u64,
+    ]);
+    #[rustfmt::skip]
+    const MODULUS_BITS: u32 = 254;
+    /// (q - 1)/2 =
+    /// 10944121435919637611123202872628637544348155578648911831344518947322613104291
+    #[rustfmt::skip]
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0x9e10460b6c3e7ea3,
+        0xcbc0b548b438e546,
+        0xdc2822db40c0ac2e,
+        0x183227397098d014,
+    ]);
+    #[rustfmt::skip]
+    const R: BigInteger = BigInteger([
+        15230403791020821917u64,
+        754611498739239741u64,
+        7381016538464732716u64,
+        1011752739694698287u64,
+    ]);
+    #[rustfmt::skip]
+    const R2: BigInteger = BigInteger([
+        17522657719365597833u64,
+        13107472804851548667u64,
+        5164255478447964150u64,
+        493319470278259999u64,
+    ]);
+    #[rustfmt::skip]
+    const REPR_SHAVE_BITS: u32 = 2;
+    // T and T_MINUS_ONE_DIV_TWO, where q - 1 = 2^s * t
+
+    /// t = (q - 1) / 2^s =
+    /// 10944121435919637611123202872628637544348155578648911831344518947322613104291
+    #[rustfmt::skip]
+    const T: BigInteger = BigInteger([
+        0x9e10460b6c3e7ea3,
+        0xcbc0b548b438e546,
+        0xdc2822db40c0ac2e,
+        0x183227397098d014,
+    ]);
+    /// (t - 1) / 2 =
+    /// 5472060717959818805561601436314318772174077789324455915672259473661306552145
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0x4f082305b61f3f51,
+        0x65e05aa45a1c72a3,
+        0x6e14116da0605617,
+        0xc19139cb84c680a,
+    ]);
+}
+
+impl PoseidonDefaultParameters for FqParameters {
+    const PARAMS_OPT_FOR_CONSTRAINTS: [PoseidonDefaultParametersEntry; 7] = [
+        PoseidonDefaultParametersEntry::new(2, 5, 8, 57, 0),
+        PoseidonDefaultParametersEntry::new(3, 5, 8, 56, 0),
+        PoseidonDefaultParametersEntry::new(4, 5, 8, 60, 0),
+        PoseidonDefaultParametersEntry::new(5, 5, 8, 60, 0),
+        PoseidonDefaultParametersEntry::new(6, 5, 8, 63, 0),
+        PoseidonDefaultParametersEntry::new(7, 5, 8, 64, 0),
+        PoseidonDefaultParametersEntry::new(8, 5, 8, 63, 0),
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_fields::{FftField, Field, PrimeField};
+
+    #[test]
+    fn test_powers_of_root_of_unity() {
+        let two = Fq::from(2u8);
+
+        // Compute the expected powers of root of unity.
+        let root_of_unity = Fq::two_adic_root_of_unity();
+        let powers = (0..FqParameters::TWO_ADICITY - 1)
+            .map(|i| root_of_unity.pow(two.pow(Fq::from(i as u64).to_bigint()).to_bigint()))
+            .collect::<Vec<_>>();
+
+        // Ensure the correct number of powers of root of unity are present.
+        assert_eq!(FqParameters::POWERS_OF_ROOTS_OF_UNITY.len() as u64, (FqParameters::TWO_ADICITY - 1) as u64);
+        assert_eq!(FqParameters::POWERS_OF_ROOTS_OF_UNITY.len(), powers.len());
+
+        // Ensure the expected and candidate powers match.
+        for (expected, candidate) in powers.iter().zip(FqParameters::POWERS_OF_ROOTS_OF_UNITY) {
+            assert_eq!(&expected.0, candidate);
+        }
+    }
+
+    #[test]
+    fn test_two_adic_root_of_unity() {
+        let expected = Fq::multiplicative_generator().pow(FqParameters::T);
+        assert_eq!(expected, Fq::two_adic_root_of_unity());
+    }
+}
@@ -0,0 +1,218 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::{
+    FftParameters,
+    FieldParameters,
+    Fp256,
+    Fp256Parameters,
+    PoseidonDefaultParameters,
+    PoseidonDefaultParametersEntry,
+};
+use snarkvm_utilities::biginteger::BigInteger256 as BigInteger;
+
+/// BN254 scalar field.
+///
+/// Roots of unity computed from modulus and R using this sage code:
+///
+/// ```ignore
+/// r = 21888242871839275222246405745257275088548364400416034343698204186575808495617
+/// R = 6350874878119819312338956282401532410528162663560392320966563075034087161851 # Montgomery R
+/// s = 28
+/// o = r - 1
+/// F = GF(r)
+/// g = F.multiplicative_generator()
+/// assert g.multiplicative_order() == o
+/// g2 = g ** (o/2**s)
+/// assert g2.multiplicative_order() == 2**s
+/// def into_chunks(val, width, n):
+///     return [int(int(val) // (2 ** (width * i)) % 2 ** width) for i in range(n)]
+/// print("Gen (g % r): ", g % r)
+/// print("Gen (g * R % r): ", g * R % r)
+/// print("Gen into_chunks(g * R % r): ", into_chunks(g * R % r, 64, 4))
+/// print("2-adic gen (g2 % r): ", g2 % r)
+/// print("2-adic gen (g2 * R % r): ", g2 * R % r)
+/// print("2-adic gen into_chunks(g2 * R % r): ", into_chunks(g2 * R % r, 64, 4))
+/// ```
+pub type Fr = Fp256<FrParameters>;
+
+pub struct FrParameters;
+
+impl Fp256Parameters for FrParameters {}
+
+impl FftParameters for FrParameters {
+    type BigInteger = BigInteger;
+
+    #[rustfmt::skip]
+    const POWERS_OF_ROOTS_OF_UNITY: &'static [BigInteger] = &[
+        BigInteger([7164790868263648668, 11685701338293206998, 6216421865291908056, 1756667274303109607]),
+        BigInteger([9752936507266105959, 5348720230078088009, 5260292176761762174, 2901751774574995986]),
+        BigInteger([11574976869426667774, 8346404630851443869, 16265394844070150903, 2953258270719640246]),
+        BigInteger([4615902642690590690, 6134873876046256837, 12284038495204781367, 1929482068950855225]),
+        BigInteger([14349239625054146608, 6921551207862507052, 18118683713921591273, 1608532401648772956]),
+        BigInteger([7946641145325326592, 969841580145911218, 11818051773797416465, 100388451406924968]),
+        BigInteger([11208934381583276786, 11404787351872891744, 6020699551552748349, 736500182191088572]),
+        BigInteger([2876148227092131644, 12761095682120477095, 4217097394129715305, 1337555827105544614]),
+        BigInteger([15722862782222013210, 2768014498722779036, 2628883128475234437, 953468478303896090]),
+        BigInteger([332883432715745846, 17288164495239076796, 9521933811794747092, 2917351315921729886]),
+        BigInteger([2695059332351921028, 3681756976552838752, 15652699593782614257, 391020205919292724]),
+        BigInteger([15710166113306598524, 5202267687001034049, 3834126958155696428, 292675683781301650]),
+        BigInteger([5166727567922263091, 7870667697742947537, 11615929479959371587, 1982180879151846742]),
+        BigInteger([9245598687428458580, 8838150927900666610, 18215189573602806219, 2109404493484656327]),
+        BigInteger([13408237293463268024, 3315033480800973078, 13043429665830969434, 485849827241071834]),
+        BigInteger([9893525192068583126, 10767225357138593671, 6458643640047354207, 1363434072172425038]),
+        BigInteger([2149433286998154195, 7503606743496520621, 13734990843630409192, 1986921657395378246]),
+        BigInteger([5253204365569675186, 721696602807884295, 18396512017052282378, 2930027165227162999]),
+        BigInteger([2230605301802602884, 14950495845623933484, 13260351491197330712, 2542498290207801484]),
+        BigInteger([11264412956888409054, 7217754055544630797, 989794962706710533, 67638165875019906]),
+        BigInteger([11722790937999592140, 10588620065779569518, 6806458596428537113, 1764199008876438857]),
+        BigInteger([754850123644763057, 8913411448040029680, 13171383856017827550, 34537031232319564]),
+        BigInteger([10272715034288153023, 10131902019376053954, 6042967365317120846, 2778886360237976124]),
+        BigInteger([2455860039615204452, 2952429719635721714, 16534267467014174157, 3135063178989759747]),
+        BigInteger([17633419364494446339, 7501640028906350796, 10152728670129313642, 1620215397350784254]),
+        BigInteger([17919060829948181567, 3269832130201093566, 15669990316177631735, 2612225116521290153]),
+        BigInteger([9184314736506630027, 6863229837856182560, 10210050104002964590, 3114093135882422479]),
+    ];
+    #[rustfmt::skip]
+    const TWO_ADICITY: u32 = 28;
+    /// TWO_ADIC_ROOT_OF_UNITY = 19103219067921713944291392827692070036145651957329286315305642004821462161904
+    /// Encoded in Montgomery form, the value is
+    /// (19103219067921713944291392827692070036145651957329286315305642004821462161904 * R % r) =
+    /// 11026779196025039675543067535165575398706865421176733435921293210460577938844
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([
+        7164790868263648668u64,
+        11685701338293206998u64,
+        6216421865291908056u64,
+        1756667274303109607u64,
+    ]);
+}
+
+impl FieldParameters for FrParameters {
+    #[rustfmt::skip]
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+    /// GENERATOR = 5
+    /// Encoded in Montgomery form, so the value is
+    /// (5 * R) % r = 9866131518759821339448375666750386964092448917385927261134611188594627313638
+    #[rustfmt::skip]
+    const GENERATOR: BigInteger = BigInteger([
+        1949230679015292902u64,
+        16913946402569752895u64,
+        5177146667339417225u64,
+        1571765431670520771u64,
+    ]);
+    #[rustfmt::skip]
+    const INV: u64 = 14042775128853446655u64;
+    /// MODULUS = 21888242871839275222246405745257275088548364400416034343698204186575808495617
+    #[rustfmt::skip]
+    const MODULUS: BigInteger = BigInteger([
+        4891460686036598785u64,
+        2896914383306846353u64,
+        13281191951274694749u64,
+        3486998266802970665u64,
+    ]);
+    #[rustfmt::skip]
+    const MODULUS_BITS: u32 = 254;
+    /// (r - 1)/2 =
+    /// 10944121435919637611123202872628637544274182200208017171849102093287904247808
+    #[rustfmt::skip]
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0xa1f0fac9f8000000,
+        0x9419f4243cdcb848,
+        0xdc2822db40c0ac2e,
+        0x183227397098d014,
+    ]);
+    #[rustfmt::skip]
+    const R: BigInteger = BigInteger([
+        12436184717236109307u64,
+        3962172157175319849u64,
+        7381016538464732718u64,
+        1011752739694698287u64,
+    ]);
+    #[rustfmt::skip]
+    const R2: BigInteger = BigInteger([
+        1997599621687373223u64,
+        6052339484930628067u64,
+        10108755138030829701u64,
+        150537098327114917u64,
+    ]);
+    #[rustfmt::skip]
+    const REPR_SHAVE_BITS: u32 = 2;
+    // T and T_MINUS_ONE_DIV_TWO, where r - 1 = 2^s * t
+
+    /// t = (r - 1) / 2^s =
+    /// 81540058820840996586704275553141814055101440848469862132140264610111
+    #[rustfmt::skip]
+    const T: BigInteger = BigInteger([
+        0x9b9709143e1f593f,
+        0x181585d2833e8487,
+        0x131a029b85045b68,
+        0x30644e72e,
+    ]);
+    /// (t - 1) / 2 =
+    /// 40770029410420498293352137776570907027550720424234931066070132305055
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0xcdcb848a1f0fac9f,
+        0xc0ac2e9419f4243,
+        0x98d014dc2822db4,
+        0x183227397,
+    ]);
+}
+
+impl PoseidonDefaultParameters for FrParameters {
+    const PARAMS_OPT_FOR_CONSTRAINTS: [PoseidonDefaultParametersEntry; 7] = [
+        PoseidonDefaultParametersEntry::new(2, 5, 8, 57, 0),
+        PoseidonDefaultParametersEntry::new(3, 5, 8, 56, 0),
+        PoseidonDefaultParametersEntry::new(4, 5, 8, 60, 0),
+        PoseidonDefaultParametersEntry::new(5, 5, 8, 60, 0),
+        PoseidonDefaultParametersEntry::new(6, 5, 8, 63, 0),
+        PoseidonDefaultParametersEntry::new(7, 5, 8, 64, 0),
+        PoseidonDefaultParametersEntry::new(8, 5, 8, 63, 0),
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_fields::{FftField, Field, PrimeField};
+
+    #[test]
+    fn test_powers_of_root_of_unity() {
+        let two = Fr::from(2u8);
+
+        // Compute the expected powers of root of unity.
+        let root_of_unity = Fr::two_adic_root_of_unity();
+        let powers = (0..FrParameters::TWO_ADICITY - 1)
+            .map(|i| root_of_unity.pow(two.pow(Fr::from(i as u64).to_bigint()).to_bigint()))
+            .collect::<Vec<_>>();
+
+        // Ensure the correct number of powers of root of unity are present.
+        assert_eq!(FrParameters::POWERS_OF_ROOTS_OF_UNITY.len() as u64, (FrParameters::TWO_ADICITY - 1) as u64);
+        assert_eq!(FrParameters::POWERS_OF_ROOTS_OF_UNITY.len(), powers.len());
+
+        // Ensure the expected and candidate powers match.
+        for (expected, candidate) in powers.iter().zip(FrParameters::POWERS_OF_ROOTS_OF_UNITY) {
+            assert_eq!(&expected.0, candidate);
+        }
+    }
+
+    #[test]
+    fn test_two_adic_root_of_unity() {
+        let expected = Fr::multiplicative_generator().pow(FrParameters::T);
+        assert_eq!(expected, Fr::two_adic_root_of_unity());
+    }
+}
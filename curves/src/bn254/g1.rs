@@ -0,0 +1,104 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::{field, PrimeField, Zero};
+use snarkvm_utilities::{biginteger::BigInteger256, BitIteratorBE};
+
+use crate::{
+    bn254::{Fq, Fr},
+    traits::{ModelParameters, ShortWeierstrassParameters},
+    ProjectiveCurve,
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Bn254G1Parameters;
+
+impl ModelParameters for Bn254G1Parameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl ShortWeierstrassParameters for Bn254G1Parameters {
+    /// AFFINE_GENERATOR_COEFFS = (G1_GENERATOR_X, G1_GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) = (G1_GENERATOR_X, G1_GENERATOR_Y);
+    /// B1 is unused, as the scalar multiplication does not use the GLV decomposition.
+    const B1: Fr = field!(Fr, BigInteger256([0, 0, 0, 0]));
+    /// B2 is unused, as the scalar multiplication does not use the GLV decomposition.
+    const B2: Fr = field!(Fr, BigInteger256([0, 0, 0, 0]));
+    /// COFACTOR = 1
+    const COFACTOR: &'static [u64] = &[0x1];
+    /// COFACTOR_INV = COFACTOR^{-1} mod r = 1
+    const COFACTOR_INV: Fr = field!(
+        Fr,
+        BigInteger256([12436184717236109307, 3962172157175319849, 7381016538464732718, 1011752739694698287])
+    );
+    /// PHI = 2203960485148121921418603742825762020974279258880205651966, a primitive cube root of unity,
+    /// such that (PHI * x, y) = LAMBDA * (x, y) for
+    /// LAMBDA = 4407920970296243842393367215006156084916469457145843978461
+    const PHI: Fq =
+        field!(Fq, BigInteger256([0x71930c11d782e155, 0xa6bb947cffbe3323, 0xaa303344d4741444, 0x2c3b3f0d26594943]));
+    /// R128 = 2^128 - 1
+    const R128: Fr = field!(
+        Fr,
+        BigInteger256([5170585343355584191, 11036242958294675700, 9558352872217760170, 2751515240904090456])
+    );
+    /// WEIERSTRASS_A = 0
+    const WEIERSTRASS_A: Fq = field!(Fq, BigInteger256([0x0, 0x0, 0x0, 0x0]));
+    /// WEIERSTRASS_B = 3
+    const WEIERSTRASS_B: Fq =
+        field!(Fq, BigInteger256([0x7a17caa950ad28d7, 0x1f6ac17ae15521b9, 0x334bea4e696bd284, 0x2a1f6744ce179d8e]));
+
+    #[inline(always)]
+    fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
+        Self::BaseField::zero()
+    }
+
+    /// The cofactor of G1 is 1, so every point on the curve is in the correct subgroup.
+    fn is_in_correct_subgroup_assuming_on_curve(_: &super::G1Affine) -> bool {
+        true
+    }
+
+    fn glv_endomorphism(
+        mut p: crate::templates::short_weierstrass_jacobian::Affine<Self>,
+    ) -> crate::templates::short_weierstrass_jacobian::Affine<Self> {
+        p.x *= &Self::PHI;
+        p
+    }
+
+    fn mul_projective(
+        p: crate::templates::short_weierstrass_jacobian::Projective<Self>,
+        by: Self::ScalarField,
+    ) -> crate::templates::short_weierstrass_jacobian::Projective<Self> {
+        // The GLV decomposition of `PrimeField::decompose` is specific to BLS12 curves,
+        // so the scalar multiplication uses double-and-add.
+        let mut acc = crate::templates::short_weierstrass_jacobian::Projective::<Self>::zero();
+        for bit in BitIteratorBE::new_without_leading_zeros(by.to_bigint()) {
+            acc.double_in_place();
+            if bit {
+                acc += &p;
+            }
+        }
+        acc
+    }
+}
+
+/// G1_GENERATOR_X = 1
+pub const G1_GENERATOR_X: Fq =
+    field!(Fq, BigInteger256([0xd35d438dc58f0d9d, 0xa78eb28f5c70b3d, 0x666ea36f7879462c, 0xe0a77c19a07df2f]));
+
+/// G1_GENERATOR_Y = 2
+pub const G1_GENERATOR_Y: Fq =
+    field!(Fq, BigInteger256([0xa6ba871b8b1e1b3a, 0x14f1d651eb8e167b, 0xccdd46def0f28c58, 0x1c14ef83340fbe5e]));
@@ -0,0 +1,357 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    bn254::{
+        g1::Bn254G1Parameters,
+        g2::Bn254G2Parameters,
+        Bn254,
+        Fq,
+        Fq12,
+        Fq2,
+        Fq2Parameters,
+        Fq6,
+        Fq6Parameters,
+        FqParameters,
+        Fr,
+        FrParameters,
+        G1Affine,
+        G1Projective,
+        G2Affine,
+        G2Projective,
+    },
+    templates::short_weierstrass_jacobian::tests::sw_tests,
+    traits::{
+        tests_field::{
+            fft_field_test,
+            field_serialization_test,
+            field_test,
+            frobenius_test,
+            primefield_test,
+            random_sqrt_tonelli_tests,
+            sqrt_field_test,
+        },
+        tests_group::*,
+        tests_projective::curve_tests,
+        AffineCurve,
+        PairingCurve,
+        PairingEngine,
+        ProjectiveCurve,
+        ShortWeierstrassParameters,
+    },
+};
+use snarkvm_fields::{
+    fp6_3over2::Fp6Parameters,
+    FftField,
+    FftParameters,
+    Field,
+    FieldParameters,
+    Fp2Parameters,
+    LegendreSymbol::*,
+    One,
+    PrimeField,
+    SquareRootField,
+    Zero,
+};
+use snarkvm_utilities::{
+    rand::{TestRng, Uniform},
+    read_raw,
+    BitIteratorBE,
+    ZeroCopy,
+};
+
+use rand::Rng;
+use std::{
+    ops::{Mul, MulAssign},
+    str::FromStr,
+};
+
+pub(crate) const ITERATIONS: usize = 10;
+
+#[test]
+fn test_bn254_fr() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..ITERATIONS {
+        let a: Fr = rng.gen();
+        let b: Fr = rng.gen();
+        field_test(a, b, &mut rng);
+        primefield_test::<Fr>(&mut rng);
+        sqrt_field_test(b, &mut rng);
+        field_serialization_test::<Fr>(&mut rng);
+    }
+    fft_field_test::<Fr>();
+}
+
+#[test]
+fn test_bn254_fq() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..ITERATIONS {
+        let a: Fq = rng.gen();
+        let b: Fq = rng.gen();
+        field_test(a, b, &mut rng);
+        primefield_test::<Fq>(&mut rng);
+        sqrt_field_test(a, &mut rng);
+        field_serialization_test::<Fq>(&mut rng);
+    }
+}
+
+#[test]
+fn test_bn254_fq2() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..ITERATIONS {
+        let a: Fq2 = rng.gen();
+        let b: Fq2 = rng.gen();
+        field_test(a, b, &mut rng);
+        sqrt_field_test(a, &mut rng);
+    }
+    frobenius_test::<Fq2, _>(Fq::characteristic(), 13, &mut rng);
+    field_serialization_test::<Fq2>(&mut rng);
+}
+
+#[test]
+fn test_bn254_fq6() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..ITERATIONS {
+        let g: Fq6 = rng.gen();
+        let h: Fq6 = rng.gen();
+        field_test(g, h, &mut rng);
+    }
+    frobenius_test::<Fq6, _>(Fq::characteristic(), 13, &mut rng);
+    field_serialization_test::<Fq6>(&mut rng);
+}
+
+#[test]
+fn test_bn254_fq12() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..ITERATIONS {
+        let g: Fq12 = rng.gen();
+        let h: Fq12 = rng.gen();
+        field_test(g, h, &mut rng);
+    }
+    frobenius_test::<Fq12, _>(Fq::characteristic(), 13, &mut rng);
+    field_serialization_test::<Fq12>(&mut rng);
+}
+
+#[test]
+fn test_fq_sqrt_tonelli() {
+    let mut rng = TestRng::default();
+
+    random_sqrt_tonelli_tests::<Fq>(&mut rng);
+}
+
+#[test]
+fn test_fr_sqrt_tonelli() {
+    let mut rng = TestRng::default();
+
+    random_sqrt_tonelli_tests::<Fr>(&mut rng);
+}
+
+#[test]
+fn test_num_bits() {
+    assert_eq!(FqParameters::MODULUS_BITS, 254);
+    assert_eq!(FqParameters::CAPACITY, 253);
+    assert_eq!(FrParameters::MODULUS_BITS, 254);
+    assert_eq!(FrParameters::CAPACITY, 253);
+}
+
+#[test]
+fn test_root_of_unity() {
+    assert_eq!(FqParameters::TWO_ADICITY, 1);
+    assert_eq!(Fq::two_adic_root_of_unity(), -Fq::one());
+    assert!(Fq::multiplicative_generator().sqrt().is_none());
+
+    assert_eq!(FrParameters::TWO_ADICITY, 28);
+    assert_eq!(Fr::two_adic_root_of_unity().pow([1 << FrParameters::TWO_ADICITY]), Fr::one());
+    assert_ne!(Fr::two_adic_root_of_unity().pow([1 << (FrParameters::TWO_ADICITY - 1)]), Fr::one());
+    assert!(Fr::multiplicative_generator().sqrt().is_none());
+}
+
+#[test]
+fn test_fq2_legendre() {
+    assert_eq!(Zero, Fq2::zero().legendre());
+    // -1 = u^2
+    assert_eq!(QuadraticResidue, (-Fq2::one()).legendre());
+    let quadratic_non_residue = Fq2::new(Fq2Parameters::QUADRATIC_NONRESIDUE.0, Fq2Parameters::QUADRATIC_NONRESIDUE.1);
+    assert_eq!(QuadraticNonResidue, quadratic_non_residue.legendre());
+}
+
+#[test]
+fn test_fq2_mul_nonresidue() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..1000 {
+        let a = Fq::rand(&mut rng);
+        assert_eq!(Fq2Parameters::mul_fp_by_nonresidue(&a), Fq2Parameters::NONRESIDUE * a);
+
+        let a = Fq2::rand(&mut rng);
+        assert_eq!(Fq6Parameters::mul_fp2_by_nonresidue(&a), Fq6Parameters::NONRESIDUE * a);
+    }
+}
+
+#[test]
+fn test_fq12_mul_by_034() {
+    let mut rng = TestRng::default();
+
+    for _ in 0..1000 {
+        let c0 = Fq2::rand(&mut rng);
+        let c3 = Fq2::rand(&mut rng);
+        let c4 = Fq2::rand(&mut rng);
+        let mut a = Fq12::rand(&mut rng);
+        let mut b = a;
+
+        a.mul_by_034(&c0, &c3, &c4);
+        b.mul_assign(&Fq12::new(Fq6::new(c0, Fq2::zero(), Fq2::zero()), Fq6::new(c3, c4, Fq2::zero())));
+
+        assert_eq!(a, b);
+    }
+}
+
+#[test]
+fn test_g1_projective_mul() {
+    let mut rng = TestRng::default();
+
+    let point = G1Projective::rand(&mut rng);
+    let scalar = Fr::rand(&mut rng);
+    let affine = point.to_affine();
+    assert_eq!(point.mul(scalar), affine.mul(scalar));
+    assert_eq!(affine.mul(scalar), affine.mul_bits(BitIteratorBE::new_without_leading_zeros(scalar.to_bigint())));
+}
+
+#[test]
+fn test_g1_endomorphism() {
+    let mut rng = TestRng::default();
+
+    // LAMBDA, the eigenvalue of the endomorphism (x, y) -> (PHI * x, y).
+    let lambda = Fr::from_str("4407920970296243842393367215006156084916469457145843978461").unwrap();
+    let point = G1Projective::rand(&mut rng).to_affine();
+    assert_eq!(Bn254G1Parameters::glv_endomorphism(point).to_projective(), point.mul(lambda));
+}
+
+#[test]
+fn test_g1_projective_curve() {
+    let mut rng = TestRng::default();
+
+    curve_tests::<G1Projective>(&mut rng);
+    sw_tests::<Bn254G1Parameters>(&mut rng);
+}
+
+#[test]
+fn test_g1_projective_group() {
+    let mut rng = TestRng::default();
+
+    let a: G1Projective = rng.gen();
+    let b: G1Projective = rng.gen();
+    projective_test(a, b, &mut rng);
+}
+
+#[test]
+fn test_g1_generator() {
+    let generator = G1Affine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_g1_affine_zero_copy() {
+    let mut rng = TestRng::default();
+
+    for point in [G1Affine::zero(), G1Affine::prime_subgroup_generator(), G1Projective::rand(&mut rng).to_affine()] {
+        let mut bytes = Vec::new();
+        point.write_raw(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), std::mem::size_of::<G1Affine>());
+        assert_eq!(read_raw::<G1Affine>(&bytes), Some(point));
+    }
+
+    // Ensure a point that is not on the curve is rejected.
+    let mut point = G1Projective::rand(&mut rng).to_affine();
+    point.y.double_in_place();
+    let mut bytes = Vec::new();
+    point.write_raw(&mut bytes).unwrap();
+    assert_eq!(read_raw::<G1Affine>(&bytes), None);
+}
+
+#[test]
+fn test_g2_projective_curve() {
+    let mut rng = TestRng::default();
+
+    curve_tests::<G2Projective>(&mut rng);
+    sw_tests::<Bn254G2Parameters>(&mut rng);
+}
+
+#[test]
+fn test_g2_projective_group() {
+    let mut rng = TestRng::default();
+
+    let a: G2Projective = rng.gen();
+    let b: G2Projective = rng.gen();
+    projective_test(a, b, &mut rng);
+}
+
+#[test]
+fn test_g2_generator() {
+    let generator = G2Affine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_bilinearity() {
+    let mut rng = TestRng::default();
+
+    let a: G1Projective = rng.gen();
+    let b: G2Projective = rng.gen();
+    let s: Fr = rng.gen();
+
+    let sa = a * s;
+    let sb = b * s;
+
+    let ans1 = Bn254::pairing(sa, b);
+    let ans2 = Bn254::pairing(a, sb);
+    let ans3 = Bn254::pairing(a, b).pow(s.to_bigint());
+
+    assert_eq!(ans1, ans2);
+    assert_eq!(ans2, ans3);
+
+    assert_ne!(ans1, Fq12::one());
+    assert_ne!(ans2, Fq12::one());
+    assert_ne!(ans3, Fq12::one());
+
+    assert_eq!(ans1.pow(Fr::characteristic()), Fq12::one());
+    assert_eq!(ans2.pow(Fr::characteristic()), Fq12::one());
+    assert_eq!(ans3.pow(Fr::characteristic()), Fq12::one());
+}
+
+#[test]
+fn test_product_of_pairings() {
+    let mut rng = TestRng::default();
+
+    let a: G1Projective = rng.gen();
+    let b: G2Projective = rng.gen();
+    let s: Fr = rng.gen();
+
+    // Ensure e(s * a, b) * e(-a, s * b) = 1.
+    let p1 = (a * s).to_affine().prepare();
+    let q1 = b.to_affine().prepare();
+    let p2 = (-a).to_affine().prepare();
+    let q2 = (b * s).to_affine().prepare();
+    assert_eq!(Bn254::product_of_pairings([(&p1, &q1), (&p2, &q2)].into_iter()), Fq12::one());
+    assert_ne!(Bn254::product_of_pairings([(&p1, &q1)].into_iter()), Fq12::one());
+}
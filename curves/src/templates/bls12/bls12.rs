@@ -22,7 +22,7 @@ use crate::{
         },
         short_weierstrass_jacobian,
     },
-    traits::{ModelParameters, PairingCurve, PairingEngine, ShortWeierstrassParameters},
+    traits::{ModelParameters, PairingCurve, PairingEngine, ShortWeierstrassParameters, ZeroCopyField},
     AffineCurve,
 };
 use snarkvm_fields::{
@@ -51,7 +51,7 @@ pub trait Bls12Parameters: 'static + Copy + Clone + Debug + PartialEq + Eq + Has
     const X: &'static [u64];
     const X_IS_NEGATIVE: bool;
     const TWIST_TYPE: TwistType;
-    type Fp: PrimeField + SquareRootField + Into<<Self::Fp as PrimeField>::BigInteger>;
+    type Fp: PrimeField + SquareRootField + Into<<Self::Fp as PrimeField>::BigInteger> + ZeroCopyField;
     type Fp2Params: Fp2Parameters<Fp = Self::Fp>;
     type Fp6Params: Fp6Parameters<Fp2Params = Self::Fp2Params>;
     type Fp12Params: Fp12Parameters<Fp6Params = Self::Fp6Params>;
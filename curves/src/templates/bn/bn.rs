@@ -0,0 +1,228 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    templates::{
+        bls12::TwistType,
+        bn::{
+            g1::{G1Affine, G1Prepared, G1Projective},
+            g2::{G2Affine, G2Prepared, G2Projective},
+        },
+    },
+    traits::{ModelParameters, PairingCurve, PairingEngine, ShortWeierstrassParameters, ZeroCopyField},
+};
+use snarkvm_fields::{
+    fp6_3over2::Fp6Parameters,
+    Field,
+    Fp12,
+    Fp12Parameters,
+    Fp2,
+    Fp2Parameters,
+    One,
+    PrimeField,
+    SquareRootField,
+};
+use core::{fmt::Debug, hash::Hash, marker::PhantomData};
+use serde::{Deserialize, Serialize};
+
+pub trait BnParameters: 'static + Copy + Clone + Debug + PartialEq + Eq + Hash + Send + Sync + Sized {
+    /// The parameter `x` of the BN curve.
+    const X: &'static [u64];
+    const X_IS_NEGATIVE: bool;
+    /// The NAF of `6x + 2`, with the least significant digit first.
+    const ATE_LOOP_COUNT: &'static [i8];
+    const TWIST_TYPE: TwistType;
+    /// The coefficients of the Frobenius endomorphism on the twist.
+    const TWIST_MUL_BY_Q_X: Fp2<Self::Fp2Params>;
+    const TWIST_MUL_BY_Q_Y: Fp2<Self::Fp2Params>;
+    type Fp: PrimeField + SquareRootField + Into<<Self::Fp as PrimeField>::BigInteger> + ZeroCopyField;
+    type Fp2Params: Fp2Parameters<Fp = Self::Fp>;
+    type Fp6Params: Fp6Parameters<Fp2Params = Self::Fp2Params>;
+    type Fp12Params: Fp12Parameters<Fp6Params = Self::Fp6Params>;
+    type G1Parameters: ShortWeierstrassParameters<BaseField = Self::Fp>;
+    type G2Parameters: ShortWeierstrassParameters<
+        BaseField = Fp2<Self::Fp2Params>,
+        ScalarField = <Self::G1Parameters as ModelParameters>::ScalarField,
+    >;
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Bn<P: BnParameters>(PhantomData<P>);
+
+type CoeffTriplet<T> = (Fp2<T>, Fp2<T>, Fp2<T>);
+
+impl<P: BnParameters> Bn<P> {
+    /// Evaluate the line function at point p.
+    fn ell(f: &mut Fp12<P::Fp12Params>, coeffs: &CoeffTriplet<P::Fp2Params>, p: &G1Affine<P>) {
+        let mut c0 = coeffs.0;
+        let mut c1 = coeffs.1;
+        let mut c2 = coeffs.2;
+
+        match P::TWIST_TYPE {
+            TwistType::M => {
+                c2.mul_by_fp(&p.y);
+                c1.mul_by_fp(&p.x);
+                f.mul_by_014(&c0, &c1, &c2);
+            }
+            TwistType::D => {
+                c0.mul_by_fp(&p.y);
+                c1.mul_by_fp(&p.x);
+                f.mul_by_034(&c0, &c1, &c2);
+            }
+        }
+    }
+
+    fn exp_by_neg_x(mut f: Fp12<P::Fp12Params>) -> Fp12<P::Fp12Params> {
+        f = f.cyclotomic_exp(P::X);
+        if !P::X_IS_NEGATIVE {
+            f.conjugate();
+        }
+        f
+    }
+}
+
+impl<P: BnParameters> PairingEngine for Bn<P>
+where
+    G1Affine<P>: PairingCurve<
+        BaseField = <P::G1Parameters as ModelParameters>::BaseField,
+        ScalarField = <P::G1Parameters as ModelParameters>::ScalarField,
+        Projective = G1Projective<P>,
+        PairWith = G2Affine<P>,
+        Prepared = G1Prepared<P>,
+        PairingResult = Fp12<P::Fp12Params>,
+    >,
+    G2Affine<P>: PairingCurve<
+        BaseField = <P::G2Parameters as ModelParameters>::BaseField,
+        ScalarField = <P::G1Parameters as ModelParameters>::ScalarField,
+        Projective = G2Projective<P>,
+        PairWith = G1Affine<P>,
+        Prepared = G2Prepared<P>,
+        PairingResult = Fp12<P::Fp12Params>,
+    >,
+{
+    type Fq = P::Fp;
+    type Fqe = Fp2<P::Fp2Params>;
+    type Fqk = Fp12<P::Fp12Params>;
+    type Fr = <P::G1Parameters as ModelParameters>::ScalarField;
+    type G1Affine = G1Affine<P>;
+    type G1Projective = G1Projective<P>;
+    type G2Affine = G2Affine<P>;
+    type G2Projective = G2Projective<P>;
+
+    fn miller_loop<'a, I>(i: I) -> Self::Fqk
+    where
+        I: Iterator<
+            Item = (&'a <Self::G1Affine as PairingCurve>::Prepared, &'a <Self::G2Affine as PairingCurve>::Prepared),
+        >,
+    {
+        let mut pairs = vec![];
+        for (p, q) in i {
+            if !p.is_zero() && !q.is_zero() {
+                pairs.push((p, q.ell_coeffs.iter()));
+            }
+        }
+
+        let mut f = Self::Fqk::one();
+
+        // The loop count is in NAF form, with the least significant digit first,
+        // and the most significant digit is skipped, as in `G2Prepared::from_affine`.
+        for (i, digit) in P::ATE_LOOP_COUNT.iter().rev().skip(1).enumerate() {
+            if i != 0 {
+                f.square_in_place();
+            }
+
+            for &mut (p, ref mut coeffs) in &mut pairs {
+                Self::ell(&mut f, coeffs.next().unwrap(), &p.0);
+            }
+
+            if *digit != 0 {
+                for &mut (p, ref mut coeffs) in &mut pairs {
+                    Self::ell(&mut f, coeffs.next().unwrap(), &p.0);
+                }
+            }
+        }
+
+        if P::X_IS_NEGATIVE {
+            f.conjugate();
+        }
+
+        // Evaluate the lines through the Frobenius images of each point in G2.
+        for &mut (p, ref mut coeffs) in &mut pairs {
+            Self::ell(&mut f, coeffs.next().unwrap(), &p.0);
+        }
+        for &mut (p, ref mut coeffs) in &mut pairs {
+            Self::ell(&mut f, coeffs.next().unwrap(), &p.0);
+        }
+
+        f
+    }
+
+    fn final_exponentiation(f: &Self::Fqk) -> Option<Self::Fqk> {
+        // Computing the final exponentiation following
+        // Fuentes-Castaneda, Knapp and Rodriguez-Henriquez, "Faster hashing to G2",
+        // which computes the hard part as a power of f^((p^4 - p^2 + 1) / r),
+        // using an addition chain in `x`.
+
+        // f1 = r.conjugate() = f^(p^6)
+        let mut f1 = *f;
+        f1.conjugate();
+
+        match f.inverse() {
+            Some(mut f2) => {
+                // f2 = f^(-1);
+                // r = f^(p^6 - 1)
+                let mut r = f1 * f2;
+
+                // f2 = f^(p^6 - 1)
+                f2 = r;
+                // r = f^((p^6 - 1)(p^2))
+                r.frobenius_map(2);
+
+                // r = f^((p^6 - 1)(p^2) + (p^6 - 1))
+                // r = f^((p^6 - 1)(p^2 + 1))
+                r *= &f2;
+
+                // Hard part of the final exponentation is below,
+                // computed with an addition chain in `-x`.
+                let y0 = Self::exp_by_neg_x(r);
+                let y1 = y0.cyclotomic_square();
+                let y2 = y1.cyclotomic_square();
+                let mut y3 = y2 * y1;
+                let y4 = Self::exp_by_neg_x(y3);
+                let y5 = y4.cyclotomic_square();
+                let mut y6 = Self::exp_by_neg_x(y5);
+                y3.conjugate();
+                y6.conjugate();
+                let y7 = y6 * y4;
+                let mut y8 = y7 * y3;
+                let y9 = y8 * y1;
+                let y10 = y8 * y4;
+                let y11 = y10 * r;
+                let mut y12 = y9;
+                y12.frobenius_map(1);
+                let y13 = y12 * y11;
+                y8.frobenius_map(2);
+                let y14 = y8 * y13;
+                r.conjugate();
+                let mut y15 = r * y9;
+                y15.frobenius_map(3);
+                let y16 = y15 * y14;
+                Some(y16)
+            }
+            None => None,
+        }
+    }
+}
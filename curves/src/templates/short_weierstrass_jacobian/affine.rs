@@ -17,7 +17,7 @@
 use crate::{
     impl_sw_curve_serializer,
     templates::short_weierstrass_jacobian::Projective,
-    traits::{AffineCurve, ProjectiveCurve, ShortWeierstrassParameters as Parameters},
+    traits::{AffineCurve, ProjectiveCurve, ShortWeierstrassParameters as Parameters, ZeroCopyField},
 };
 use snarkvm_fields::{Field, One, SquareRootField, Zero};
 use snarkvm_utilities::{
@@ -25,14 +25,17 @@ use snarkvm_utilities::{
     io::{Error, ErrorKind, Read, Result as IoResult, Write},
     rand::Uniform,
     serialize::*,
+    write_padding,
     FromBytes,
     ToBits,
     ToBytes,
     ToMinimalBits,
+    ZeroCopy,
 };
 
 use core::{
     fmt::{Display, Formatter, Result as FmtResult},
+    mem::size_of,
     ops::{Mul, Neg},
 };
 use rand::{
@@ -42,6 +45,7 @@ use rand::{
 use serde::{Deserialize, Serialize};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[repr(C)]
 pub struct Affine<P: Parameters> {
     pub x: P::BaseField,
     pub y: P::BaseField,
@@ -313,6 +317,64 @@ impl<P: Parameters> FromBytes for Affine<P> {
     }
 }
 
+impl<P: Parameters> Affine<P>
+where
+    P::BaseField: ZeroCopyField,
+{
+    /// Returns the point represented by `bytes`, if its coordinates and infinity flag are valid.
+    /// Note: This does not check that the point is on the curve, or in the prime-order subgroup.
+    #[inline]
+    fn from_raw_unchecked(bytes: &[u8]) -> Option<Self> {
+        // Under `#[repr(C)]`, the coordinates are followed immediately by the infinity flag.
+        let size = size_of::<P::BaseField>();
+        if bytes.len() != size_of::<Self>() {
+            return None;
+        }
+        // Ensure the coordinates are valid field elements.
+        let x = P::BaseField::read_raw(&bytes[..size])?;
+        let y = P::BaseField::read_raw(&bytes[size..2 * size])?;
+        // Ensure the infinity flag is a valid `bool`.
+        let infinity = match bytes[2 * size] {
+            0 => false,
+            1 => true,
+            _ => return None,
+        };
+        Some(Self::new(x, y, infinity))
+    }
+}
+
+/// The zero-copy layout of an affine point is its in-memory representation: the `x` and `y`
+/// coordinates, followed by the infinity flag as a single byte, and zero padding.
+// SAFETY: `Affine` is `#[repr(C)]`, and both `is_valid_raw` and `is_valid_raw_layout` check each of its fields.
+unsafe impl<P: Parameters> ZeroCopy for Affine<P>
+where
+    P::BaseField: ZeroCopyField,
+{
+    #[inline]
+    fn write_raw<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.x.write_raw(&mut writer)?;
+        self.y.write_raw(&mut writer)?;
+        (self.infinity as u8).write_raw(&mut writer)?;
+        write_padding(&mut writer, size_of::<Self>() - 2 * size_of::<P::BaseField>() - 1)
+    }
+
+    /// Returns `true` if `bytes` represents a point on the curve and in the prime-order subgroup.
+    #[inline]
+    fn is_valid_raw(bytes: &[u8]) -> bool {
+        // Ensure the point is on the curve, and in the correct subgroup.
+        match Self::from_raw_unchecked(bytes) {
+            Some(point) => point.is_on_curve() && point.is_in_correct_subgroup_assuming_on_curve(),
+            None => false,
+        }
+    }
+
+    /// Returns `true` if `bytes` represents a point with valid coordinates and infinity flag.
+    #[inline]
+    fn is_valid_raw_layout(bytes: &[u8]) -> bool {
+        Self::from_raw_unchecked(bytes).is_some()
+    }
+}
+
 impl<P: Parameters> Distribution<Affine<P>> for Standard {
     #[inline]
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Affine<P> {
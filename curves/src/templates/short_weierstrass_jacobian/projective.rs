@@ -126,7 +126,17 @@ impl<P: Parameters> Distribution<Projective<P>> for Standard {
             let greatest = rng.gen();
 
             if let Some(p) = Affine::from_x_coordinate(x, greatest) {
-                return p.mul_by_cofactor_to_projective();
+                let p = p.mul_by_cofactor_to_projective();
+                // Clearing a cofactor of 1 leaves the point normalized, so rescale it by a random `z`.
+                if P::COFACTOR == [1] {
+                    let z = P::BaseField::rand(rng);
+                    if z.is_zero() {
+                        continue;
+                    }
+                    let z2 = z.square();
+                    return Projective::new(p.x * z2, p.y * z2 * z, z);
+                }
+                return p;
             }
         }
     }
@@ -20,6 +20,9 @@ pub use group::*;
 pub mod pairing_engine;
 pub use pairing_engine::*;
 
+pub mod zero_copy;
+pub use zero_copy::*;
+
 #[cfg(test)]
 pub mod tests_field;
 
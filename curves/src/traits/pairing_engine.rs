@@ -16,6 +16,7 @@
 
 use crate::traits::{AffineCurve, PairingCurve, ProjectiveCurve};
 use snarkvm_fields::{Field, PrimeField, SquareRootField, ToConstraintField};
+use snarkvm_utilities::ZeroCopy;
 
 use core::{fmt::Debug, hash::Hash, iter};
 
@@ -31,7 +32,8 @@ pub trait PairingEngine: Sized + 'static + Copy + Debug + PartialEq + Eq + Hash
     type G1Affine: AffineCurve<BaseField = Self::Fq, ScalarField = Self::Fr, Projective = Self::G1Projective>
         + PairingCurve<PairWith = Self::G2Affine, PairingResult = Self::Fqk>
         + From<Self::G1Projective>
-        + ToConstraintField<Self::Fq>;
+        + ToConstraintField<Self::Fq>
+        + ZeroCopy;
 
     /// The projective representation of an element in G2.
     type G2Projective: ProjectiveCurve<BaseField = Self::Fqe, ScalarField = Self::Fr, Affine = Self::G2Affine>
@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::{Fp256, Fp256Parameters, Fp384, Fp384Parameters, PrimeField};
+use snarkvm_utilities::{
+    io::{Result as IoResult, Write},
+    read_raw,
+    BigInteger256,
+    BigInteger384,
+    ZeroCopy,
+};
+
+use core::marker::PhantomData;
+
+/// A prime field whose in-memory representation is the (Montgomery) representation of its `BigInteger`,
+/// so that the coordinates of a curve point can be read in place from a memory-mapped file.
+///
+/// # Safety
+///
+/// Implementors must be `#[repr(transparent)]` wrappers around a `BigInteger` that is `ZeroCopy`,
+/// and `read_raw` must only return a value for bytes that are the in-memory representation of that value.
+pub unsafe trait ZeroCopyField: PrimeField {
+    /// Writes the in-memory representation of `self`.
+    fn write_raw<W: Write>(&self, writer: W) -> IoResult<()>;
+
+    /// Returns the field element represented by `bytes`, if it is a (Montgomery) representation
+    /// that is less than the modulus.
+    fn read_raw(bytes: &[u8]) -> Option<Self>;
+}
+
+// SAFETY: `Fp256` is a transparent wrapper around its `BigInteger`.
+unsafe impl<P: Fp256Parameters> ZeroCopyField for Fp256<P> {
+    #[inline]
+    fn write_raw<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.0.write_raw(writer)
+    }
+
+    #[inline]
+    fn read_raw(bytes: &[u8]) -> Option<Self> {
+        read_raw::<BigInteger256>(bytes).filter(|bigint| *bigint < P::MODULUS).map(|bigint| Fp256(bigint, PhantomData))
+    }
+}
+
+// SAFETY: `Fp384` is a transparent wrapper around its `BigInteger`.
+unsafe impl<P: Fp384Parameters> ZeroCopyField for Fp384<P> {
+    #[inline]
+    fn write_raw<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.0.write_raw(writer)
+    }
+
+    #[inline]
+    fn read_raw(bytes: &[u8]) -> Option<Self> {
+        read_raw::<BigInteger384>(bytes).filter(|bigint| *bigint < P::MODULUS).map(|bigint| Fp384(bigint, PhantomData))
+    }
+}
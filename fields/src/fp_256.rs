@@ -57,6 +57,8 @@ pub trait Fp256Parameters: FieldParameters<BigInteger = BigInteger> {}
     PartialEq(bound = ""),
     Eq(bound = "")
 )]
+// Note: The zero-copy layout of field elements in `snarkvm-curves` relies on this representation.
+#[repr(transparent)]
 pub struct Fp256<P>(
     pub BigInteger,
     #[derivative(Debug = "ignore")]
@@ -195,43 +197,58 @@ impl<P: Fp256Parameters> Field for Fp256<P> {
         //   single bitshift by the limb size after each iteration. This means we only
         //   need to store a single extra limb overall, instead of keeping around all the
         //   intermediate results and eventually having twice as many limbs.
-
-        // Algorithm 2, line 2
-        let (u0, u1, u2, u3) = (0..4).fold((0, 0, 0, 0), |(u0, u1, u2, u3), j| {
-            // Algorithm 2, line 3
-            // For each pair in the overall sum of products:
-            let (t0, t1, t2, t3, mut t4) =
-                a.clone().zip(b.clone()).fold((u0, u1, u2, u3, 0), |(t0, t1, t2, t3, mut t4), (a, b)| {
-                    // Compute digit_j x row and accumulate into `u`.
-                    let mut carry = 0;
-                    let t0 = fa::mac_with_carry(t0, a.0.0[j], b.0.0[0], &mut carry);
-                    let t1 = fa::mac_with_carry(t1, a.0.0[j], b.0.0[1], &mut carry);
-                    let t2 = fa::mac_with_carry(t2, a.0.0[j], b.0.0[2], &mut carry);
-                    let t3 = fa::mac_with_carry(t3, a.0.0[j], b.0.0[3], &mut carry);
-                    let _ = fa::adc(&mut t4, 0, carry);
-
-                    (t0, t1, t2, t3, t4)
-                });
-
-            // Algorithm 2, lines 4-5
-            // This is a single step of the usual Montgomery reduction process.
-            let k = t0.wrapping_mul(P::INV);
-            let mut carry = 0;
-            let _ = fa::mac_with_carry(t0, k, P::MODULUS.0[0], &mut carry);
-            let r1 = fa::mac_with_carry(t1, k, P::MODULUS.0[1], &mut carry);
-            let r2 = fa::mac_with_carry(t2, k, P::MODULUS.0[2], &mut carry);
-            let r3 = fa::mac_with_carry(t3, k, P::MODULUS.0[3], &mut carry);
-            let _ = fa::adc(&mut t4, 0, carry);
-            let r4 = t4;
-
-            (r1, r2, r3, r4)
-        });
-
-        // Because we represent F_p elements in non-redundant form, we need a final
-        // conditional subtraction to ensure the output is in range.
-        let mut result = Self(BigInteger([u0, u1, u2, u3]), PhantomData);
-        result.reduce();
-        result
+        //
+        // For `n` pairs, the result is bounded by `n * MODULUS^2 / R + MODULUS`, so a single conditional
+        // subtraction only reduces it for up to `2 * (256 - MODULUS_BITS) - 1` pairs, given the spare bits
+        // of the modulus. Longer sums are split into chunks of that many pairs, and the chunks are summed.
+        let chunk_size = (2 * (256 - P::MODULUS_BITS as usize)).saturating_sub(1).max(1);
+        let mut pairs = a.zip(b);
+        let mut sum = Self::zero();
+        loop {
+            let chunk = pairs.clone().take(chunk_size);
+
+            // Algorithm 2, line 2
+            let (u0, u1, u2, u3) = (0..4).fold((0, 0, 0, 0), |(u0, u1, u2, u3), j| {
+                // Algorithm 2, line 3
+                // For each pair in the overall sum of products:
+                let (t0, t1, t2, t3, mut t4) =
+                    chunk.clone().fold((u0, u1, u2, u3, 0), |(t0, t1, t2, t3, mut t4), (a, b)| {
+                        // Compute digit_j x row and accumulate into `u`.
+                        let mut carry = 0;
+                        let t0 = fa::mac_with_carry(t0, a.0.0[j], b.0.0[0], &mut carry);
+                        let t1 = fa::mac_with_carry(t1, a.0.0[j], b.0.0[1], &mut carry);
+                        let t2 = fa::mac_with_carry(t2, a.0.0[j], b.0.0[2], &mut carry);
+                        let t3 = fa::mac_with_carry(t3, a.0.0[j], b.0.0[3], &mut carry);
+                        let _ = fa::adc(&mut t4, 0, carry);
+
+                        (t0, t1, t2, t3, t4)
+                    });
+
+                // Algorithm 2, lines 4-5
+                // This is a single step of the usual Montgomery reduction process.
+                let k = t0.wrapping_mul(P::INV);
+                let mut carry = 0;
+                let _ = fa::mac_with_carry(t0, k, P::MODULUS.0[0], &mut carry);
+                let r1 = fa::mac_with_carry(t1, k, P::MODULUS.0[1], &mut carry);
+                let r2 = fa::mac_with_carry(t2, k, P::MODULUS.0[2], &mut carry);
+                let r3 = fa::mac_with_carry(t3, k, P::MODULUS.0[3], &mut carry);
+                let _ = fa::adc(&mut t4, 0, carry);
+                let r4 = t4;
+
+                (r1, r2, r3, r4)
+            });
+
+            // Because we represent F_p elements in non-redundant form, we need a final
+            // conditional subtraction to ensure the output is in range.
+            let mut result = Self(BigInteger([u0, u1, u2, u3]), PhantomData);
+            result.reduce();
+            sum += result;
+
+            // Advance past the chunk, and stop once all of the pairs are summed.
+            if pairs.nth(chunk_size - 1).is_none() || pairs.clone().next().is_none() {
+                return sum;
+            }
+        }
     }
 
     #[inline]
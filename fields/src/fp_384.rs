@@ -57,6 +57,8 @@ pub trait Fp384Parameters: FieldParameters<BigInteger = BigInteger> {}
     PartialEq(bound = "P: Fp384Parameters"),
     Eq(bound = "P: Fp384Parameters")
 )]
+// Note: The zero-copy layout of field elements in `snarkvm-curves` relies on this representation.
+#[repr(transparent)]
 pub struct Fp384<P: Fp384Parameters>(
     pub BigInteger,
     #[derivative(Debug = "ignore")]
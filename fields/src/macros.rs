@@ -96,6 +96,12 @@ macro_rules! sqrt_impl {
                 let v = $self.pow($P::T_MINUS_ONE_DIV_TWO);
                 let x = *$self * v.square();
 
+                // If the two-adicity is 1, i.e. MODULUS = 3 (mod 4), then `x` is 1,
+                // and the square root is `self^((t + 1) / 2)`.
+                if n == 1 {
+                    return Some(*$self * v);
+                }
+
                 let k = ((n - 1) as f64).sqrt().floor() as u64;
                 // It's important that k_2 results in a number which makes `l_minus_one_times_k`
                 // divisible by `k`, because the native arithmetic will not match the field
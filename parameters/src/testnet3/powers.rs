@@ -21,6 +21,8 @@ use snarkvm_utilities::{
     CanonicalSerialize,
     Compress,
     FromBytes,
+    MappedSlice,
+    MappedVec,
     Read,
     SerializationError,
     ToBytes,
@@ -28,6 +30,8 @@ use snarkvm_utilities::{
     Validate,
     Write,
 };
+#[cfg(not(target_family = "wasm"))]
+use snarkvm_utilities::{MappedReader, ZeroCopyWriter};
 
 use anyhow::{anyhow, bail, ensure, Result};
 use std::{collections::BTreeMap, ops::Range, sync::Arc};
@@ -94,6 +98,31 @@ impl<E: PairingEngine> PowersOfG<E> {
         Ok(powers)
     }
 
+    /// Initializes a complete instance of the powers, from the given group elements.
+    /// Unlike the hard-coded instance, this instance cannot be extended by downloading more powers,
+    /// and its maximum degree is given by the number of powers of beta G.
+    pub fn new(
+        powers_of_beta_g: Vec<E::G1Affine>,
+        powers_of_beta_times_gamma_g: BTreeMap<usize, E::G1Affine>,
+        negative_powers_of_beta_h: BTreeMap<usize, E::G2Affine>,
+        beta_h: E::G2Affine,
+    ) -> Result<Self> {
+        // Ensure the number of powers is valid.
+        ensure!(!powers_of_beta_g.is_empty(), "The SRS must contain at least one power of beta G");
+        ensure!(powers_of_beta_g.len() <= MAX_NUM_POWERS, "The SRS contains too many powers of beta G");
+
+        // Initialize the powers of beta G, without any shifted powers.
+        let powers_of_beta_g =
+            PowersOfBetaG { powers_of_beta_g: powers_of_beta_g.into(), shifted_powers_of_beta_g: MappedVec::default() };
+        // Initialize the powers.
+        Ok(Self {
+            powers_of_beta_g,
+            powers_of_beta_times_gamma_g: Arc::new(powers_of_beta_times_gamma_g),
+            negative_powers_of_beta_h: Arc::new(negative_powers_of_beta_h),
+            beta_h,
+        })
+    }
+
     /// Download the powers of beta G specified by `range`.
     pub fn download_powers_for(&mut self, range: Range<usize>) -> Result<()> {
         self.powers_of_beta_g.download_powers_for(&range)
@@ -106,7 +135,7 @@ impl<E: PairingEngine> PowersOfG<E> {
 
     /// Returns the maximum possible number of contiguous powers of beta G starting from the 0-th power.
     pub fn max_num_powers(&self) -> usize {
-        MAX_NUM_POWERS
+        self.powers_of_beta_g.max_num_powers()
     }
 
     /// Returns the powers of beta * gamma G.
@@ -120,7 +149,8 @@ impl<E: PairingEngine> PowersOfG<E> {
     }
 
     /// Returns the powers of `beta * G` that lie within `range`.
-    pub fn powers_of_beta_g(&mut self, range: Range<usize>) -> Result<&[E::G1Affine]> {
+    /// If the powers were memory-mapped with `read_mapped`, they are not copied out of the file.
+    pub fn powers_of_beta_g(&mut self, range: Range<usize>) -> Result<MappedSlice<E::G1Affine>> {
         self.powers_of_beta_g.powers(range)
     }
 
@@ -131,6 +161,28 @@ impl<E: PairingEngine> PowersOfG<E> {
     pub fn beta_h(&self) -> E::G2Affine {
         self.beta_h
     }
+
+    /// Writes the powers in the zero-copy layout, so that they can be memory-mapped with `read_mapped`.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn write_mapped<W: Write>(&mut self, writer: &mut ZeroCopyWriter<W>) -> Result<()> {
+        self.powers_of_beta_g.write_mapped(writer)?;
+        self.powers_of_beta_times_gamma_g.serialize_uncompressed(&mut *writer)?;
+        self.negative_powers_of_beta_h.serialize_uncompressed(&mut *writer)?;
+        self.beta_h.serialize_uncompressed(&mut *writer)?;
+        Ok(())
+    }
+
+    /// Reads the powers from a memory-mapped file in the zero-copy layout.
+    /// The powers of beta G are not copied out of the file, and each degree of them
+    /// is only validated when it is first requested.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn read_mapped(reader: &mut MappedReader) -> Result<Self> {
+        let powers_of_beta_g = PowersOfBetaG::read_mapped(reader)?;
+        let powers_of_beta_times_gamma_g = Arc::new(BTreeMap::deserialize_uncompressed(&mut *reader)?);
+        let negative_powers_of_beta_h = Arc::new(BTreeMap::deserialize_uncompressed(&mut *reader)?);
+        let beta_h = E::G2Affine::deserialize_uncompressed(&mut *reader)?;
+        Ok(Self { powers_of_beta_g, powers_of_beta_times_gamma_g, negative_powers_of_beta_h, beta_h })
+    }
 }
 
 impl<E: PairingEngine> CanonicalSerialize for PowersOfG<E> {
@@ -195,10 +247,10 @@ impl<E: PairingEngine> ToBytes for PowersOfG<E> {
 #[derive(Debug, Clone, CanonicalSerialize, CanonicalDeserialize)]
 pub struct PowersOfBetaG<E: PairingEngine> {
     /// Group elements of form `[G, \beta * G, \beta^2 * G, ..., \beta^d G]`.
-    powers_of_beta_g: Vec<E::G1Affine>,
+    powers_of_beta_g: MappedVec<E::G1Affine>,
     /// Group elements of form `[\beta^i * G, \beta^2 * G, ..., \beta^D G]`.
     /// where D is the maximum degree supported by the SRS.
-    shifted_powers_of_beta_g: Vec<E::G1Affine>,
+    shifted_powers_of_beta_g: MappedVec<E::G1Affine>,
 }
 
 impl<E: PairingEngine> PowersOfBetaG<E> {
@@ -207,19 +259,49 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
         self.powers_of_beta_g.len()
     }
 
+    /// Returns the maximum possible number of contiguous powers of beta G starting from the 0-th power.
+    /// If there are no shifted powers, then all of the powers are contiguous, and there are no more to download.
+    pub fn max_num_powers(&self) -> usize {
+        match self.shifted_powers_of_beta_g.is_empty() {
+            true => self.powers_of_beta_g.len(),
+            false => MAX_NUM_POWERS,
+        }
+    }
+
     /// Initializes the hard-coded instance of the powers.
     fn load() -> Result<Self> {
         // Deserialize the group elements.
-        let powers_of_beta_g = Vec::deserialize_uncompressed_unchecked(&**POWERS_OF_BETA_G_15)?;
+        let powers_of_beta_g = MappedVec::deserialize_uncompressed_unchecked(&**POWERS_OF_BETA_G_15)?;
 
         // Ensure the number of elements is correct.
         ensure!(powers_of_beta_g.len() == NUM_POWERS_15, "Incorrect number of powers in the recovered SRS");
 
-        let shifted_powers_of_beta_g = Vec::deserialize_uncompressed_unchecked(&**SHIFTED_POWERS_OF_BETA_G_15)?;
+        let shifted_powers_of_beta_g = MappedVec::deserialize_uncompressed_unchecked(&**SHIFTED_POWERS_OF_BETA_G_15)?;
         ensure!(shifted_powers_of_beta_g.len() == NUM_POWERS_15, "Incorrect number of powers in the recovered SRS");
         Ok(PowersOfBetaG { powers_of_beta_g, shifted_powers_of_beta_g })
     }
 
+    /// Writes the powers in the zero-copy layout.
+    #[cfg(not(target_family = "wasm"))]
+    fn write_mapped<W: Write>(&mut self, writer: &mut ZeroCopyWriter<W>) -> Result<()> {
+        writer.write_slice(self.powers_of_beta_g.as_slice()?)?;
+        writer.write_slice(self.shifted_powers_of_beta_g.as_slice()?)?;
+        Ok(())
+    }
+
+    /// Reads the powers from a memory-mapped file in the zero-copy layout.
+    /// The powers are validated one degree at a time, i.e. the first `2^15` powers,
+    /// and then each subsequent power of two, as they are requested.
+    #[cfg(not(target_family = "wasm"))]
+    fn read_mapped(reader: &mut MappedReader) -> Result<Self> {
+        let powers_of_beta_g = reader.read_vec(NUM_POWERS_15)?;
+        let shifted_powers_of_beta_g = reader.read_vec(NUM_POWERS_15)?;
+        ensure!(!powers_of_beta_g.is_empty(), "The SRS must contain at least one power of beta G");
+        ensure!(powers_of_beta_g.len() <= MAX_NUM_POWERS, "The SRS contains too many powers of beta G");
+        ensure!(shifted_powers_of_beta_g.len() <= MAX_NUM_POWERS, "The SRS contains too many shifted powers");
+        Ok(PowersOfBetaG { powers_of_beta_g, shifted_powers_of_beta_g })
+    }
+
     /// Returns the range of powers of beta G.
     /// In detail, it returns the range of the available "normal" powers of beta G, i.e. the
     /// contiguous range of powers of beta G starting from G, and, the range of shifted_powers.
@@ -233,9 +315,9 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
             let lower_shifted_bound = MAX_NUM_POWERS - self.shifted_powers_of_beta_g.len();
             ((0..self.powers_of_beta_g.len()), (lower_shifted_bound..MAX_NUM_POWERS))
         } else {
-            // We can only be in this case if have downloaded all possible powers.
-            assert_eq!(self.powers_of_beta_g.len(), MAX_NUM_POWERS, "Incorrect number of powers in the recovered SRS");
-            ((0..MAX_NUM_POWERS), (0..MAX_NUM_POWERS))
+            // We can only be in this case if we have all possible powers.
+            let num_powers = self.powers_of_beta_g.len();
+            ((0..num_powers), (0..num_powers))
         }
     }
 
@@ -262,29 +344,28 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
     }
 
     /// Assumes that we have the requisite powers.
-    fn shifted_powers(&self, range: Range<usize>) -> Result<&[E::G1Affine]> {
+    fn shifted_powers(&mut self, range: Range<usize>) -> Result<MappedSlice<E::G1Affine>> {
         ensure!(
             self.contains_in_shifted_powers(&range),
             "Requested range is not contained in the available shifted powers"
         );
 
-        if range.start < MAX_NUM_POWERS / 2 {
-            ensure!(self.shifted_powers_of_beta_g.is_empty());
-            // In this case, we have downloaded all the powers, and so
+        if self.shifted_powers_of_beta_g.is_empty() {
+            // In this case, we have all the powers, and so
             // all the powers reside in self.powers_of_beta_g.
-            Ok(&self.powers_of_beta_g[range])
+            Ok(self.powers_of_beta_g.get(range)?)
         } else {
             // In this case, the shifted powers still reside in self.shifted_powers_of_beta_g.
             let lower = self.shifted_powers_of_beta_g.len() - (MAX_NUM_POWERS - range.start);
             let upper = self.shifted_powers_of_beta_g.len() - (MAX_NUM_POWERS - range.end);
-            Ok(&self.shifted_powers_of_beta_g[lower..upper])
+            Ok(self.shifted_powers_of_beta_g.get(lower..upper)?)
         }
     }
 
     /// Assumes that we have the requisite powers.
-    fn normal_powers(&self, range: Range<usize>) -> Result<&[E::G1Affine]> {
+    fn normal_powers(&mut self, range: Range<usize>) -> Result<MappedSlice<E::G1Affine>> {
         ensure!(self.contains_in_normal_powers(&range), "Requested range is not contained in the available powers");
-        Ok(&self.powers_of_beta_g[range])
+        Ok(self.powers_of_beta_g.get(range)?)
     }
 
     /// Returns the power of beta times G specified by `target`.
@@ -293,12 +374,12 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
     }
 
     /// Slices the underlying file to return a vector of affine elements between `lower` and `upper`.
-    fn powers(&mut self, range: Range<usize>) -> Result<&[E::G1Affine]> {
+    fn powers(&mut self, range: Range<usize>) -> Result<MappedSlice<E::G1Affine>> {
         if range.is_empty() {
-            return Ok(&self.powers_of_beta_g[0..0]);
+            return Ok(MappedSlice::default());
         }
         ensure!(range.start < range.end, "Lower power must be less than upper power");
-        ensure!(range.end <= MAX_NUM_POWERS, "Upper bound must be less than the maximum number of powers");
+        ensure!(range.end <= self.max_num_powers(), "Upper bound must be less than the maximum number of powers");
         if !self.contains_powers(&range) {
             // We must download the powers.
             self.download_powers_for(&range)?;
@@ -313,12 +394,14 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
         if self.contains_in_normal_powers(range) || self.contains_in_shifted_powers(range) {
             return Ok(());
         }
+        // If there are no shifted powers, then all possible powers are already present.
+        ensure!(!self.shifted_powers_of_beta_g.is_empty(), "Requesting more powers than exist in the SRS");
         let half_max = MAX_NUM_POWERS / 2;
         if (range.start <= half_max) && (range.end > half_max) {
             // If the range contains the midpoint, then we must download all the powers.
             // (because we round up to the next power of two).
             self.download_powers_up_to(range.end)?;
-            self.shifted_powers_of_beta_g = Vec::new();
+            self.shifted_powers_of_beta_g = MappedVec::default();
         } else if self.distance_from_shifted_of(range) < self.distance_from_normal_of(range) {
             // If the range is closer to the shifted powers, then we download the shifted powers.
             self.download_shifted_powers_from(range.start)?;
@@ -359,10 +442,12 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
         ensure!(final_power_of_two * 2 == accumulator, "Ensure the loop terminates at the right power of two");
 
         // Reserve capacity for the new powers of two.
+        // Note: If the powers are memory-mapped, they are copied out of the file here.
         let additional_size = final_power_of_two
             .checked_sub(self.powers_of_beta_g.len())
             .ok_or_else(|| anyhow!("final_power_of_two is smaller than existing powers"))?;
-        self.powers_of_beta_g.reserve(additional_size);
+        let powers_of_beta_g = self.powers_of_beta_g.to_mut()?;
+        powers_of_beta_g.reserve(additional_size);
 
         // Download the powers of two.
         for num_powers in &download_queue {
@@ -390,7 +475,7 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
             // Deserialize the group elements.
             let additional_powers = Vec::deserialize_uncompressed_unchecked(&*additional_bytes)?;
             // Extend the powers.
-            self.powers_of_beta_g.extend(&additional_powers);
+            powers_of_beta_g.extend(&additional_powers);
         }
         ensure!(self.powers_of_beta_g.len() == final_power_of_two, "Loaded an incorrect number of powers");
         Ok(())
@@ -473,8 +558,8 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
                 final_powers.extend(additional_powers);
             }
         }
-        final_powers.extend(self.shifted_powers_of_beta_g.iter());
-        self.shifted_powers_of_beta_g = final_powers;
+        final_powers.extend(self.shifted_powers_of_beta_g.as_slice()?);
+        self.shifted_powers_of_beta_g = final_powers.into();
 
         ensure!(
             self.shifted_powers_of_beta_g.len() == final_num_powers,
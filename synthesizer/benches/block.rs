@@ -27,7 +27,7 @@ type CurrentNetwork = Testnet3;
 
 /// Loads the genesis block.
 fn load_genesis_block() -> Block<CurrentNetwork> {
-    Block::<CurrentNetwork>::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap()
+    Block::<CurrentNetwork>::from_bytes_le(CurrentNetwork::genesis_bytes().unwrap()).unwrap()
 }
 
 /// Helper method to benchmark serialization.
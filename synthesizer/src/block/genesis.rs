@@ -77,7 +77,7 @@ mod tests {
         let mut rng = TestRng::default();
 
         // Load the genesis block.
-        let genesis_block = Block::<CurrentNetwork>::read_le(CurrentNetwork::genesis_bytes()).unwrap();
+        let genesis_block = Block::<CurrentNetwork>::read_le(CurrentNetwork::genesis_bytes().unwrap()).unwrap();
         assert!(genesis_block.is_genesis());
 
         // Sample a new genesis block.
@@ -23,7 +23,7 @@ mod merkle;
 mod serialize;
 mod string;
 
-use crate::block::Transactions;
+use crate::block::{coinbase_target, proof_target, Transactions};
 use console::{
     network::prelude::*,
     program::{HeaderLeaf, HeaderPath, HeaderTree, HEADER_DEPTH},
@@ -135,4 +135,136 @@ impl<N: Network> Header<N> {
     pub const fn timestamp(&self) -> i64 {
         self.metadata.timestamp()
     }
+
+    /// Returns the coinbase target and proof target for the next block, given the timestamp of the next block.
+    pub fn next_targets(&self, next_timestamp: i64) -> Result<(u64, u64)> {
+        // Compute the next coinbase target, from the last coinbase target and timestamp.
+        let next_coinbase_target = coinbase_target(
+            self.last_coinbase_target(),
+            self.last_coinbase_timestamp(),
+            next_timestamp,
+            N::ANCHOR_TIME,
+            N::NUM_BLOCKS_PER_EPOCH,
+        )?;
+        // Ensure the next coinbase target is at or above the minimum.
+        let next_coinbase_target = core::cmp::max(next_coinbase_target, N::GENESIS_COINBASE_TARGET);
+        // Compute the next proof target.
+        let next_proof_target = proof_target(next_coinbase_target);
+        Ok((next_coinbase_target, next_proof_target))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u32 = 1_000;
+
+    /// Returns the header of the next block, using the targets computed from the given header.
+    fn sample_next_header(
+        header: &Header<CurrentNetwork>,
+        timestamp: i64,
+        has_coinbase: bool,
+    ) -> Header<CurrentNetwork> {
+        // Compute the next targets.
+        let (coinbase_target, proof_target) = header.next_targets(timestamp).unwrap();
+        // Update the last coinbase target and timestamp, if the block contains a coinbase solution.
+        let (last_coinbase_target, last_coinbase_timestamp) = match has_coinbase {
+            true => (coinbase_target, timestamp),
+            false => (header.last_coinbase_target(), header.last_coinbase_timestamp()),
+        };
+        // Construct the metadata.
+        let metadata = Metadata::new(
+            CurrentNetwork::ID,
+            header.round() + 1,
+            header.height() + 1,
+            coinbase_target,
+            proof_target,
+            last_coinbase_target,
+            last_coinbase_timestamp,
+            timestamp,
+        )
+        .unwrap();
+        // Construct the header.
+        Header::from(Field::one(), Field::one(), Field::zero(), metadata).unwrap()
+    }
+
+    #[test]
+    fn test_next_targets_from_genesis() {
+        let genesis =
+            Header::<CurrentNetwork>::from(Field::zero(), Field::one(), Field::zero(), Metadata::genesis().unwrap())
+                .unwrap();
+
+        // Ensure a block at the anchor time carries over the genesis targets.
+        let timestamp = genesis.timestamp() + CurrentNetwork::ANCHOR_TIME as i64;
+        let (coinbase_target, proof_target) = genesis.next_targets(timestamp).unwrap();
+        assert_eq!(coinbase_target, CurrentNetwork::GENESIS_COINBASE_TARGET);
+        assert_eq!(proof_target, CurrentNetwork::GENESIS_PROOF_TARGET);
+
+        // Ensure a slow block does not decrease the targets below the genesis targets.
+        let timestamp = genesis.timestamp() + 100 * CurrentNetwork::ANCHOR_TIME as i64;
+        let (coinbase_target, proof_target) = genesis.next_targets(timestamp).unwrap();
+        assert_eq!(coinbase_target, CurrentNetwork::GENESIS_COINBASE_TARGET);
+        assert_eq!(proof_target, CurrentNetwork::GENESIS_PROOF_TARGET);
+
+        // Ensure a fast block increases the targets.
+        let timestamp = genesis.timestamp() + 1;
+        let (coinbase_target, proof_target) = genesis.next_targets(timestamp).unwrap();
+        assert!(coinbase_target > CurrentNetwork::GENESIS_COINBASE_TARGET);
+        assert!(proof_target >= CurrentNetwork::GENESIS_PROOF_TARGET);
+    }
+
+    #[test]
+    fn test_next_targets_sequence() {
+        let mut rng = TestRng::default();
+
+        let mut header =
+            Header::<CurrentNetwork>::from(Field::zero(), Field::one(), Field::zero(), Metadata::genesis().unwrap())
+                .unwrap();
+
+        for _ in 0..ITERATIONS {
+            // Sample a block time, and whether the block contains a coinbase solution.
+            let block_time = rng.gen_range(1..4 * CurrentNetwork::ANCHOR_TIME as i64);
+            let has_coinbase = rng.gen_bool(0.8);
+
+            let next_header = sample_next_header(&header, header.timestamp() + block_time, has_coinbase);
+            // Ensure the next header is valid.
+            assert!(next_header.is_valid());
+            // Ensure the targets are deterministic.
+            assert_eq!(
+                header.next_targets(next_header.timestamp()).unwrap(),
+                (next_header.coinbase_target(), next_header.proof_target())
+            );
+
+            header = next_header;
+        }
+    }
+
+    #[test]
+    fn test_next_targets_without_coinbase() {
+        let mut header =
+            Header::<CurrentNetwork>::from(Field::zero(), Field::one(), Field::zero(), Metadata::genesis().unwrap())
+                .unwrap();
+
+        // Produce fast blocks with coinbase solutions, to raise the coinbase target.
+        for _ in 0..ITERATIONS {
+            header = sample_next_header(&header, header.timestamp() + 1, true);
+        }
+        let raised_target = header.coinbase_target();
+        assert!(raised_target > CurrentNetwork::GENESIS_COINBASE_TARGET);
+
+        // Produce blocks without coinbase solutions, and ensure the coinbase target decreases at every block.
+        let mut previous_target = raised_target;
+        for _ in 0..2 * ITERATIONS {
+            header = sample_next_header(&header, header.timestamp() + CurrentNetwork::ANCHOR_TIME as i64, false);
+            assert!(header.coinbase_target() <= previous_target);
+            assert_eq!(header.last_coinbase_target(), raised_target);
+            previous_target = header.coinbase_target();
+        }
+        // Ensure the coinbase target eventually returns to the minimum.
+        assert_eq!(header.coinbase_target(), CurrentNetwork::GENESIS_COINBASE_TARGET);
+    }
 }
@@ -0,0 +1,168 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+/// The number of seconds in a (non-leap) year.
+const SECONDS_IN_A_YEAR: u32 = 60 * 60 * 24 * 365;
+
+/// Returns the anchor block height after the given number of years, given the anchor time.
+///     H_Y = floor(Y * S_Y / T_A)
+///     S_Y = Seconds in a year.
+///     T_A = Anchor time per block.
+pub const fn anchor_block_height(anchor_time: u16, num_years: u32) -> u32 {
+    // Compute the anchor block height at year 1.
+    let anchor_block_height_at_year_1 = SECONDS_IN_A_YEAR / anchor_time as u32;
+    // Compute the anchor block height at the given year.
+    anchor_block_height_at_year_1.saturating_mul(num_years)
+}
+
+/// Returns the staking reward per block, given the starting supply and anchor time.
+///     R_staking = floor((0.025 * S) / H_Y1)
+///     S = Starting supply.
+///     H_Y1 = Anchor block height at year 1.
+pub const fn staking_reward(starting_supply: u64, anchor_time: u16) -> u64 {
+    // Compute the anchor block height at year 1.
+    let anchor_block_height_at_year_1 = anchor_block_height(anchor_time, 1) as u64;
+    // Compute the annual staking reward, which is 2.5% of the starting supply.
+    let annual_staking_reward = (starting_supply / 1000).saturating_mul(25);
+    // Compute the staking reward per block.
+    annual_staking_reward / anchor_block_height_at_year_1
+}
+
+/// Returns the coinbase reward for the given block height.
+/// The coinbase reward decreases linearly to zero by the anchor block height at year 10.
+///     R_coinbase = max(0, H_Y10 - H) * R_anchor
+///     R_anchor = floor((2 * S) / (H_Y10 * (H_Y10 + 1)))
+///     S = Starting supply.
+///     H = Block height.
+///     H_Y10 = Anchor block height at year 10.
+pub const fn coinbase_reward(block_height: u32, starting_supply: u64, anchor_time: u16) -> u64 {
+    // Compute the anchor block height at year 10.
+    let anchor_block_height_at_year_10 = anchor_block_height(anchor_time, 10) as u64;
+    // Compute the anchor reward.
+    let anchor_reward = (2 * starting_supply as u128)
+        / (anchor_block_height_at_year_10 as u128 * (anchor_block_height_at_year_10 as u128 + 1));
+    // Compute the remaining number of blocks until the anchor block height at year 10.
+    let num_remaining_blocks = anchor_block_height_at_year_10.saturating_sub(block_height as u64);
+    // Compute the coinbase reward.
+    num_remaining_blocks.saturating_mul(anchor_reward as u64)
+}
+
+/// Returns the block reward, given the starting supply, anchor time, and coinbase reward.
+/// The block reward is the staking reward plus one third of the coinbase reward.
+///     R_block = R_staking + floor(R_coinbase / 3)
+pub const fn block_reward(starting_supply: u64, anchor_time: u16, coinbase_reward: u64) -> u64 {
+    staking_reward(starting_supply, anchor_time).saturating_add(coinbase_reward / 3)
+}
+
+/// Returns the puzzle reward, given the coinbase reward.
+/// The puzzle reward is two thirds of the coinbase reward.
+///     R_puzzle = floor(2 * R_coinbase / 3)
+pub const fn puzzle_reward(coinbase_reward: u64) -> u64 {
+    coinbase_reward.saturating_mul(2) / 3
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::{Network, Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    const EXPECTED_ANCHOR_BLOCK_HEIGHT_AT_YEAR_1: u32 = 1_261_440;
+    const EXPECTED_STAKING_REWARD: u64 = 21_800_481;
+    const EXPECTED_COINBASE_REWARD_AT_BLOCK_1: u64 = 163_987_187;
+
+    #[test]
+    fn test_anchor_block_height() {
+        let anchor_time = CurrentNetwork::ANCHOR_TIME;
+
+        assert_eq!(anchor_block_height(anchor_time, 0), 0);
+        assert_eq!(anchor_block_height(anchor_time, 1), EXPECTED_ANCHOR_BLOCK_HEIGHT_AT_YEAR_1);
+        assert_eq!(anchor_block_height(anchor_time, 10), 10 * EXPECTED_ANCHOR_BLOCK_HEIGHT_AT_YEAR_1);
+        assert_eq!(anchor_block_height(anchor_time, u32::MAX), u32::MAX);
+    }
+
+    #[test]
+    fn test_staking_reward() {
+        let starting_supply = CurrentNetwork::STARTING_SUPPLY;
+        let anchor_time = CurrentNetwork::ANCHOR_TIME;
+
+        let reward = staking_reward(starting_supply, anchor_time);
+        assert_eq!(reward, EXPECTED_STAKING_REWARD);
+
+        // Ensure the annual staking reward does not exceed 2.5% of the starting supply.
+        let annual_reward = reward * anchor_block_height(anchor_time, 1) as u64;
+        assert!(annual_reward <= starting_supply / 40);
+        // Ensure the rounding error is less than one block of rewards.
+        assert!(starting_supply / 40 - annual_reward < reward);
+    }
+
+    #[test]
+    fn test_coinbase_reward() {
+        let starting_supply = CurrentNetwork::STARTING_SUPPLY;
+        let anchor_time = CurrentNetwork::ANCHOR_TIME;
+        let anchor_block_height_at_year_10 = anchor_block_height(anchor_time, 10);
+
+        assert_eq!(coinbase_reward(1, starting_supply, anchor_time), EXPECTED_COINBASE_REWARD_AT_BLOCK_1);
+
+        // Ensure the coinbase reward decreases strictly until year 10, and is zero thereafter.
+        let mut previous_reward = coinbase_reward(0, starting_supply, anchor_time);
+        for height in (1..anchor_block_height_at_year_10).step_by(10_007) {
+            let reward = coinbase_reward(height, starting_supply, anchor_time);
+            assert!(reward < previous_reward);
+            previous_reward = reward;
+        }
+        assert_eq!(coinbase_reward(anchor_block_height_at_year_10, starting_supply, anchor_time), 0);
+        assert_eq!(coinbase_reward(anchor_block_height_at_year_10 + 1, starting_supply, anchor_time), 0);
+        assert_eq!(coinbase_reward(u32::MAX, starting_supply, anchor_time), 0);
+    }
+
+    #[test]
+    fn test_coinbase_reward_total_issuance() {
+        let starting_supply = CurrentNetwork::STARTING_SUPPLY;
+        let anchor_time = CurrentNetwork::ANCHOR_TIME;
+        let anchor_block_height_at_year_10 = anchor_block_height(anchor_time, 10);
+
+        // Sum the coinbase rewards over every block height.
+        let total_issuance = (0..=anchor_block_height_at_year_10)
+            .map(|height| coinbase_reward(height, starting_supply, anchor_time) as u128)
+            .sum::<u128>();
+
+        // Ensure the total coinbase issuance does not exceed the starting supply.
+        assert!(total_issuance <= starting_supply as u128);
+        // Ensure the total coinbase issuance is at least 90% of the starting supply.
+        assert!(total_issuance >= (starting_supply as u128 * 9) / 10);
+    }
+
+    #[test]
+    fn test_block_and_puzzle_reward() {
+        let starting_supply = CurrentNetwork::STARTING_SUPPLY;
+        let anchor_time = CurrentNetwork::ANCHOR_TIME;
+
+        for height in [0, 1, 1_000, 1_000_000, anchor_block_height(anchor_time, 10)] {
+            let coinbase_reward = coinbase_reward(height, starting_supply, anchor_time);
+            let block_reward = block_reward(starting_supply, anchor_time, coinbase_reward);
+            let puzzle_reward = puzzle_reward(coinbase_reward);
+
+            // Ensure the block reward and puzzle reward do not exceed the coinbase reward (plus staking reward).
+            let staking_reward = staking_reward(starting_supply, anchor_time);
+            assert!(block_reward - staking_reward + puzzle_reward <= coinbase_reward);
+            // Ensure at most one unit is lost to rounding.
+            assert!(coinbase_reward - (block_reward - staking_reward + puzzle_reward) <= 1);
+        }
+        assert_eq!(puzzle_reward(u64::MAX), u64::MAX / 3);
+    }
+}
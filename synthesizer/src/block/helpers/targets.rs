@@ -0,0 +1,281 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use console::network::prelude::*;
+
+/// The number of fractional bits used in the fixed-point exponent of the retargeting algorithm.
+const RBITS: u32 = 16;
+/// The fixed-point radix of the retargeting algorithm.
+const RADIX: u128 = 1 << RBITS;
+
+/// Returns the coinbase target for the next block, given the last coinbase target and timestamp,
+/// the timestamp of the next block, the anchor time, and the number of blocks per epoch.
+///
+/// The coinbase target increases when coinbase solutions arrive faster than the anchor time,
+/// and decreases when they arrive slower, with a half-life of half an epoch.
+pub fn coinbase_target(
+    last_coinbase_target: u64,
+    last_coinbase_timestamp: i64,
+    next_timestamp: i64,
+    anchor_time: u16,
+    num_blocks_per_epoch: u32,
+) -> Result<u64> {
+    // Compute the half-life, in seconds.
+    let half_life = num_blocks_per_epoch.saturating_div(2).saturating_mul(anchor_time as u32);
+    // Retarget the last coinbase target, such that a longer block time decreases the target.
+    retarget(last_coinbase_target, last_coinbase_timestamp, next_timestamp, anchor_time, half_life, true)
+}
+
+/// Returns the proof target for the given coinbase target.
+///     proof_target = floor(coinbase_target / 2^7) + 1
+pub const fn proof_target(coinbase_target: u64) -> u64 {
+    (coinbase_target >> 7).saturating_add(1)
+}
+
+/// Retargets the given target using the ASERT algorithm (absolutely scheduled exponentially rising targets).
+///     T_next = T_prev * 2^(drift / half_life)
+///     drift = (timestamp_next - timestamp_prev) - anchor_time
+///
+/// If `is_inverse` is `true`, the drift is negated, so that a longer block time decreases the target.
+fn retarget(
+    previous_target: u64,
+    previous_timestamp: i64,
+    next_timestamp: i64,
+    anchor_time: u16,
+    half_life: u32,
+    is_inverse: bool,
+) -> Result<u64> {
+    // Ensure the half-life is nonzero.
+    ensure!(half_life > 0, "The half-life for retargeting must be nonzero");
+
+    // Compute the drift from the anchor time, in seconds.
+    let drift = {
+        let time_elapsed = (next_timestamp as i128).saturating_sub(previous_timestamp as i128);
+        let drift = time_elapsed.saturating_sub(anchor_time as i128);
+        // If there is no drift, return the previous target.
+        if drift == 0 {
+            return Ok(previous_target);
+        }
+        match is_inverse {
+            true => -drift,
+            false => drift,
+        }
+    };
+
+    // Compute the exponent as a fixed-point number, and split it into its integral and fractional parts.
+    let exponent = (drift * RADIX as i128).div_euclid(half_life as i128);
+    let integral = exponent >> RBITS;
+    let fractional = (exponent - (integral << RBITS)) as u128;
+    ensure!(fractional < RADIX, "The fractional part of the retarget exponent is out of range");
+
+    // Approximate 2^fractional with a cubic polynomial, in fixed-point with `RBITS` fractional bits.
+    //     2^x ~= 1 + 0.695502049712533x + 0.2262697964x^2 + 0.0782318x^3
+    let fractional_multiplier = RADIX
+        + ((195_766_423_245_049_u128 * fractional
+            + 971_821_376_u128 * fractional.pow(2)
+            + 5_127_u128 * fractional.pow(3)
+            + 2_u128.pow(RBITS * 3 - 1))
+            >> (RBITS * 3));
+
+    // Scale the previous target by the fractional multiplier.
+    // Note: This is at most 81 bits, as the previous target is at most 64 bits and the multiplier is at most 17 bits.
+    let candidate_target = (previous_target as u128) * fractional_multiplier;
+
+    // Shift the candidate target by the integral part of the exponent, removing the fixed-point scaling.
+    let shifts = integral - RBITS as i128;
+    let candidate_target = match shifts.is_negative() {
+        true => match u32::try_from(-shifts) {
+            Ok(shifts) => candidate_target.checked_shr(shifts).unwrap_or(0),
+            Err(_) => 0,
+        },
+        false => match u32::try_from(shifts) {
+            Ok(shifts) if shifts < candidate_target.leading_zeros() => candidate_target << shifts,
+            _ => u128::MAX,
+        },
+    };
+
+    // Clamp the candidate target to the range [1, u64::MAX].
+    Ok(candidate_target.clamp(1, u64::MAX as u128) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 10_000;
+
+    /// Returns the next coinbase target, using the parameters of the current network.
+    fn next_coinbase_target(last_coinbase_target: u64, last_coinbase_timestamp: i64, next_timestamp: i64) -> u64 {
+        coinbase_target(
+            last_coinbase_target,
+            last_coinbase_timestamp,
+            next_timestamp,
+            CurrentNetwork::ANCHOR_TIME,
+            CurrentNetwork::NUM_BLOCKS_PER_EPOCH,
+        )
+        .unwrap()
+    }
+
+    /// Returns the half-life of the current network, in seconds.
+    fn half_life() -> i64 {
+        (CurrentNetwork::NUM_BLOCKS_PER_EPOCH / 2) as i64 * CurrentNetwork::ANCHOR_TIME as i64
+    }
+
+    #[test]
+    fn test_proof_target() {
+        assert_eq!(proof_target(0), 1);
+        assert_eq!(proof_target(CurrentNetwork::GENESIS_COINBASE_TARGET), CurrentNetwork::GENESIS_PROOF_TARGET);
+        assert_eq!(proof_target(1 << 20), (1 << 13) + 1);
+        assert_eq!(proof_target(u64::MAX), (u64::MAX >> 7) + 1);
+    }
+
+    #[test]
+    fn test_coinbase_target_at_anchor_time() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let target = rng.gen_range(1..u64::MAX);
+            let timestamp = rng.gen_range(0..i64::MAX / 2);
+
+            // Ensure the target is unchanged when the block time is the anchor time.
+            let next_timestamp = timestamp + CurrentNetwork::ANCHOR_TIME as i64;
+            assert_eq!(next_coinbase_target(target, timestamp, next_timestamp), target);
+        }
+    }
+
+    #[test]
+    fn test_coinbase_target_half_life() {
+        let target = 1u64 << 40;
+        let timestamp = CurrentNetwork::GENESIS_TIMESTAMP;
+        let anchor_time = CurrentNetwork::ANCHOR_TIME as i64;
+
+        // Ensure the target halves when the block time exceeds the anchor time by one half-life.
+        let next_timestamp = timestamp + anchor_time + half_life();
+        assert_eq!(next_coinbase_target(target, timestamp, next_timestamp), target / 2);
+        // Ensure the target quarters when the block time exceeds the anchor time by two half-lives.
+        let next_timestamp = timestamp + anchor_time + 2 * half_life();
+        assert_eq!(next_coinbase_target(target, timestamp, next_timestamp), target / 4);
+        // Ensure the target doubles when the block time is one half-life below the anchor time.
+        let next_timestamp = timestamp + anchor_time - half_life();
+        assert_eq!(next_coinbase_target(target, timestamp, next_timestamp), target * 2);
+    }
+
+    #[test]
+    fn test_coinbase_target_bounds() {
+        let timestamp = CurrentNetwork::GENESIS_TIMESTAMP;
+
+        // Ensure the target saturates at `u64::MAX`.
+        assert_eq!(next_coinbase_target(u64::MAX, timestamp, timestamp), u64::MAX);
+        assert_eq!(next_coinbase_target(u64::MAX / 2, timestamp, i64::MIN), u64::MAX);
+        // Ensure the target does not fall below 1.
+        assert_eq!(next_coinbase_target(1, timestamp, i64::MAX), 1);
+        assert_eq!(next_coinbase_target(u64::MAX, i64::MIN, i64::MAX), 1);
+        // Ensure a zero half-life is rejected.
+        assert!(coinbase_target(1, timestamp, timestamp + 1, CurrentNetwork::ANCHOR_TIME, 1).is_err());
+    }
+
+    #[test]
+    fn test_coinbase_target_is_monotonic() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let target = rng.gen_range(1u64 << 10..1u64 << 50);
+            let timestamp = CurrentNetwork::GENESIS_TIMESTAMP;
+            let block_time = rng.gen_range(1..10 * half_life());
+
+            // Ensure a longer block time never increases the target.
+            let faster = next_coinbase_target(target, timestamp, timestamp + block_time);
+            let slower = next_coinbase_target(target, timestamp, timestamp + block_time + 1);
+            assert!(slower <= faster, "Expected {slower} <= {faster} for a block time of {block_time}");
+        }
+    }
+
+    #[test]
+    fn test_coinbase_target_sequence_of_slow_and_fast_blocks() {
+        const NUM_BLOCKS: i64 = 1_000;
+
+        let anchor_time = CurrentNetwork::ANCHOR_TIME as i64;
+        let starting_target = CurrentNetwork::GENESIS_COINBASE_TARGET << 20;
+
+        // Produce blocks at twice the anchor time, and ensure the target decreases at every block.
+        let mut target = starting_target;
+        let mut timestamp = CurrentNetwork::GENESIS_TIMESTAMP;
+        for _ in 0..NUM_BLOCKS {
+            let next_timestamp = timestamp + 2 * anchor_time;
+            let next_target = next_coinbase_target(target, timestamp, next_timestamp);
+            assert!(next_target < target);
+            target = next_target;
+            timestamp = next_timestamp;
+        }
+        // Ensure the target halved once for every half-life of accumulated drift.
+        let num_halvings = NUM_BLOCKS * anchor_time / half_life();
+        assert!(target <= starting_target >> num_halvings);
+        assert!(target > starting_target >> (num_halvings + 1));
+
+        // Produce blocks at half the anchor time, and ensure the target increases at every block.
+        for _ in 0..NUM_BLOCKS {
+            let next_timestamp = timestamp + anchor_time / 2;
+            let next_target = next_coinbase_target(target, timestamp, next_timestamp);
+            assert!(next_target > target);
+            target = next_target;
+            timestamp = next_timestamp;
+        }
+    }
+
+    #[test]
+    fn test_coinbase_target_converges() {
+        let mut rng = TestRng::default();
+        let anchor_time = CurrentNetwork::ANCHOR_TIME as i64;
+
+        // Simulate a network whose expected block time is proportional to the coinbase target,
+        // such that the block time equals the anchor time at the equilibrium target.
+        let equilibrium_target = CurrentNetwork::GENESIS_COINBASE_TARGET << 16;
+
+        let mut target = CurrentNetwork::GENESIS_COINBASE_TARGET;
+        let mut timestamp = CurrentNetwork::GENESIS_TIMESTAMP;
+        let mut total_block_time = 0;
+
+        for i in 0..ITERATIONS {
+            // Compute the block time, with up to 20% of random noise.
+            let expected_block_time = (target as u128 * anchor_time as u128 / equilibrium_target as u128) as i64;
+            let noise = rng.gen_range(-expected_block_time / 5..=expected_block_time / 5);
+            let block_time = (expected_block_time + noise).max(1);
+
+            let next_timestamp = timestamp + block_time;
+            target = next_coinbase_target(target, timestamp, next_timestamp);
+            timestamp = next_timestamp;
+
+            // Track the total block time over the second half of the simulation.
+            if i >= ITERATIONS / 2 {
+                total_block_time += block_time;
+            }
+        }
+
+        // Ensure the average block time converged to the anchor time, within 10%.
+        let average_block_time = total_block_time / (ITERATIONS / 2) as i64;
+        assert!(
+            (average_block_time - anchor_time).abs() <= anchor_time / 10,
+            "Average block time {average_block_time}"
+        );
+        // Ensure the target converged to the equilibrium target, within 25%.
+        let lower_bound = equilibrium_target - equilibrium_target / 4;
+        let upper_bound = equilibrium_target + equilibrium_target / 4;
+        assert!((lower_bound..=upper_bound).contains(&target), "Target {target} did not converge");
+    }
+}
@@ -17,6 +17,9 @@
 mod header;
 pub use header::*;
 
+mod helpers;
+pub use helpers::*;
+
 mod transaction;
 pub use transaction::*;
 
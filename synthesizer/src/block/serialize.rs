@@ -112,7 +112,7 @@ mod tests {
     #[test]
     fn test_genesis_serde_json() -> Result<()> {
         // Load the genesis block.
-        let genesis_block = Block::<CurrentNetwork>::read_le(CurrentNetwork::genesis_bytes()).unwrap();
+        let genesis_block = Block::<CurrentNetwork>::read_le(CurrentNetwork::genesis_bytes()?).unwrap();
 
         // Serialize
         let expected_string = &genesis_block.to_string();
@@ -128,7 +128,7 @@ mod tests {
     #[test]
     fn test_genesis_bincode() -> Result<()> {
         // Load the genesis block.
-        let genesis_block = Block::<CurrentNetwork>::read_le(CurrentNetwork::genesis_bytes()).unwrap();
+        let genesis_block = Block::<CurrentNetwork>::read_le(CurrentNetwork::genesis_bytes()?).unwrap();
 
         // Serialize
         let expected_bytes = genesis_block.to_bytes_le()?;
@@ -20,6 +20,9 @@ pub use helpers::*;
 mod hash;
 use hash::*;
 
+mod solver;
+pub use solver::*;
+
 #[cfg(test)]
 mod tests;
 
@@ -117,18 +120,9 @@ impl<N: Network> CoinbasePuzzle<N> {
             Self::Verifier(_) => bail!("Cannot prove the coinbase puzzle with a verifier"),
         };
 
-        let polynomial = Self::prover_polynomial(epoch_challenge, address, nonce)?;
-
-        let product_evaluations = {
-            let polynomial_evaluations = pk.product_domain.in_order_fft_with_pc(&polynomial, &pk.fft_precomputation);
-            let product_evaluations = pk.product_domain.mul_polynomials_in_evaluation_domain(
-                &polynomial_evaluations,
-                &epoch_challenge.epoch_polynomial_evaluations().evaluations,
-            );
-            product_evaluations
-        };
-        let (commitment, _rand) =
-            KZG10::commit_lagrange(&pk.lagrange_basis(), &product_evaluations, None, &Default::default(), None)?;
+        // Compute the commitment to the product polynomial.
+        let (polynomial, product_evaluations, commitment) =
+            Self::commit_product_polynomial(pk, epoch_challenge, address, nonce)?;
 
         let partial_solution = PartialSolution::new(address, nonce, commitment);
 
@@ -141,21 +135,8 @@ impl<N: Network> CoinbasePuzzle<N> {
             );
         }
 
-        let point = hash_commitment(&commitment)?;
-        let product_eval_at_point = polynomial.evaluate(point) * epoch_challenge.epoch_polynomial().evaluate(point);
-
-        let proof = KZG10::open_lagrange(
-            &pk.lagrange_basis(),
-            pk.product_domain_elements(),
-            &product_evaluations,
-            point,
-            product_eval_at_point,
-        )?;
-        ensure!(!proof.is_hiding(), "The prover solution must contain a non-hiding proof");
-
-        debug_assert!(KZG10::check(&pk.verifying_key, &commitment, point, product_eval_at_point, &proof)?);
-
-        Ok(ProverSolution::new(partial_solution, proof))
+        // Open the product polynomial, to produce the prover solution.
+        Self::open_product_polynomial(pk, epoch_challenge, &polynomial, &product_evaluations, partial_solution)
     }
 
     /// Returns a coinbase solution for the given epoch challenge and prover solutions.
@@ -375,6 +356,63 @@ impl<N: Network> CoinbasePuzzle<N> {
         Ok(product_domain)
     }
 
+    /// Returns the prover polynomial, the evaluations of the product polynomial, and the commitment
+    /// to the product polynomial, for the given epoch challenge, address, and nonce.
+    #[allow(clippy::type_complexity)]
+    fn commit_product_polynomial(
+        pk: &CoinbaseProvingKey<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        address: Address<N>,
+        nonce: u64,
+    ) -> Result<(
+        DensePolynomial<<N::PairingCurve as PairingEngine>::Fr>,
+        Vec<<N::PairingCurve as PairingEngine>::Fr>,
+        KZGCommitment<N::PairingCurve>,
+    )> {
+        let polynomial = Self::prover_polynomial(epoch_challenge, address, nonce)?;
+
+        let product_evaluations = {
+            let polynomial_evaluations = pk.product_domain.in_order_fft_with_pc(&polynomial, &pk.fft_precomputation);
+            let product_evaluations = pk.product_domain.mul_polynomials_in_evaluation_domain(
+                &polynomial_evaluations,
+                &epoch_challenge.epoch_polynomial_evaluations().evaluations,
+            );
+            product_evaluations
+        };
+        let (commitment, _rand) =
+            KZG10::commit_lagrange(&pk.lagrange_basis(), &product_evaluations, None, &Default::default(), None)?;
+
+        Ok((polynomial, product_evaluations, commitment))
+    }
+
+    /// Returns the prover solution for the given partial solution, by opening the product polynomial
+    /// at the point derived from the commitment.
+    fn open_product_polynomial(
+        pk: &CoinbaseProvingKey<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        polynomial: &DensePolynomial<<N::PairingCurve as PairingEngine>::Fr>,
+        product_evaluations: &[<N::PairingCurve as PairingEngine>::Fr],
+        partial_solution: PartialSolution<N>,
+    ) -> Result<ProverSolution<N>> {
+        let commitment = *partial_solution.commitment();
+
+        let point = hash_commitment(&commitment)?;
+        let product_eval_at_point = polynomial.evaluate(point) * epoch_challenge.epoch_polynomial().evaluate(point);
+
+        let proof = KZG10::open_lagrange(
+            &pk.lagrange_basis(),
+            pk.product_domain_elements(),
+            product_evaluations,
+            point,
+            product_eval_at_point,
+        )?;
+        ensure!(!proof.is_hiding(), "The prover solution must contain a non-hiding proof");
+
+        debug_assert!(KZG10::check(&pk.verifying_key, &commitment, point, product_eval_at_point, &proof)?);
+
+        Ok(ProverSolution::new(partial_solution, proof))
+    }
+
     /// Returns the prover polynomial for the coinbase puzzle.
     fn prover_polynomial(
         epoch_challenge: &EpochChallenge<N>,
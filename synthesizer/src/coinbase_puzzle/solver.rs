@@ -0,0 +1,161 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use core::ops::Range;
+use std::{
+    sync::atomic::{AtomicBool, AtomicU64, Ordering},
+    time::{Duration, Instant},
+};
+
+/// The statistics from a search for a prover solution.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SolverStatistics {
+    /// The number of nonces attempted.
+    num_attempts: u64,
+    /// The highest proof target found among the attempted nonces.
+    best_proof_target: u64,
+    /// The time elapsed during the search.
+    elapsed: Duration,
+}
+
+impl SolverStatistics {
+    /// Returns the number of nonces attempted.
+    pub const fn num_attempts(&self) -> u64 {
+        self.num_attempts
+    }
+
+    /// Returns the highest proof target found among the attempted nonces.
+    pub const fn best_proof_target(&self) -> u64 {
+        self.best_proof_target
+    }
+
+    /// Returns the time elapsed during the search.
+    pub const fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Returns the number of nonces attempted per second.
+    pub fn attempts_per_second(&self) -> f64 {
+        match self.elapsed.as_secs_f64() {
+            elapsed if elapsed > 0.0 => self.num_attempts as f64 / elapsed,
+            _ => 0.0,
+        }
+    }
+}
+
+impl<N: Network> CoinbasePuzzle<N> {
+    /// Searches the given range of nonces in parallel for a prover solution that meets the minimum proof target.
+    /// Returns the prover solution, if one is found, along with the statistics of the search.
+    ///
+    /// # Note
+    /// If multiple nonces meet the minimum proof target, any one of them may be returned.
+    pub fn solve(
+        &self,
+        epoch_challenge: &EpochChallenge<N>,
+        address: Address<N>,
+        nonces: Range<u64>,
+        minimum_proof_target: u64,
+    ) -> Result<(Option<ProverSolution<N>>, SolverStatistics)> {
+        self.solve_with_terminator(epoch_challenge, address, nonces, minimum_proof_target, &AtomicBool::new(false))
+    }
+
+    /// Searches the given range of nonces in parallel for a prover solution that meets the minimum proof target,
+    /// stopping early if the terminator is set. Returns the prover solution, if one is found before the range
+    /// is exhausted or the search is terminated, along with the statistics of the search.
+    ///
+    /// # Note
+    /// If multiple nonces meet the minimum proof target, any one of them may be returned.
+    pub fn solve_with_terminator(
+        &self,
+        epoch_challenge: &EpochChallenge<N>,
+        address: Address<N>,
+        nonces: Range<u64>,
+        minimum_proof_target: u64,
+        terminator: &AtomicBool,
+    ) -> Result<(Option<ProverSolution<N>>, SolverStatistics)> {
+        // Retrieve the coinbase proving key.
+        let pk = match self {
+            Self::Prover(coinbase_proving_key) => coinbase_proving_key,
+            Self::Verifier(_) => bail!("Cannot solve the coinbase puzzle with a verifier"),
+        };
+        // Ensure the epoch challenge matches the degree of the coinbase proving key.
+        ensure!(
+            Self::product_domain(epoch_challenge.degree())?.size() == pk.product_domain.size(),
+            "The epoch challenge degree ({}) does not match the coinbase puzzle",
+            epoch_challenge.degree()
+        );
+
+        let timer = Instant::now();
+        let num_attempts = AtomicU64::new(0);
+        let best_proof_target = AtomicU64::new(0);
+
+        // Attempts the given nonce, returning the commitment if it meets the minimum proof target.
+        let attempt = |nonce: u64| {
+            // Stop the search if the terminator is set.
+            if terminator.load(Ordering::Relaxed) {
+                return Some(Err(anyhow!("The coinbase puzzle solver was terminated")));
+            }
+            // Compute the commitment to the product polynomial, and its proof target.
+            let result = Self::commit_product_polynomial(pk, epoch_challenge, address, nonce).and_then(
+                |(polynomial, product_evaluations, commitment)| {
+                    let partial_solution = PartialSolution::new(address, nonce, commitment);
+                    Ok((polynomial, product_evaluations, partial_solution, partial_solution.to_target()?))
+                },
+            );
+            num_attempts.fetch_add(1, Ordering::Relaxed);
+            match result {
+                Ok((polynomial, product_evaluations, partial_solution, proof_target)) => {
+                    best_proof_target.fetch_max(proof_target, Ordering::Relaxed);
+                    match proof_target >= minimum_proof_target {
+                        true => Some(Ok((polynomial, product_evaluations, partial_solution))),
+                        false => None,
+                    }
+                }
+                Err(error) => Some(Err(error)),
+            }
+        };
+
+        // Search the nonces for a commitment that meets the minimum proof target.
+        #[cfg(feature = "parallel")]
+        let result = nonces.into_par_iter().find_map_any(attempt);
+        #[cfg(not(feature = "parallel"))]
+        let result = nonces.into_iter().find_map(attempt);
+
+        // Open the product polynomial for the solution, if one was found.
+        let solution = match result {
+            Some(Ok((polynomial, product_evaluations, partial_solution))) => Some(Self::open_product_polynomial(
+                pk,
+                epoch_challenge,
+                &polynomial,
+                &product_evaluations,
+                partial_solution,
+            )?),
+            // If the search was terminated, return without a solution.
+            Some(Err(_)) if terminator.load(Ordering::Relaxed) => None,
+            Some(Err(error)) => return Err(error),
+            None => None,
+        };
+
+        let statistics = SolverStatistics {
+            num_attempts: num_attempts.into_inner(),
+            best_proof_target: best_proof_target.into_inner(),
+            elapsed: timer.elapsed(),
+        };
+        Ok((solution, statistics))
+    }
+}
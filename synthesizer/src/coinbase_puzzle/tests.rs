@@ -19,6 +19,7 @@ use console::{account::*, network::Testnet3};
 use snarkvm_utilities::Uniform;
 
 use rand::RngCore;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 const ITERATIONS: u64 = 100;
 
@@ -108,3 +109,113 @@ fn test_edge_case_for_degree() {
     let coinbase_solution = puzzle.accumulate_unchecked(&epoch_challenge, &[prover_solution]).unwrap();
     assert!(puzzle.verify(&coinbase_solution, &epoch_challenge, 0u64, 0u64).unwrap());
 }
+
+#[test]
+fn test_solve() {
+    let mut rng = TestRng::default();
+
+    let max_degree = 1 << 15;
+    let max_config = PuzzleConfig { degree: max_degree };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+
+    let degree = (1 << 8) - 1;
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+
+    let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+    let address = Address::try_from(private_key).unwrap();
+
+    // Compute the proof targets for a range of nonces.
+    let nonces = 0..ITERATIONS;
+    let proof_targets = nonces
+        .clone()
+        .map(|nonce| puzzle.prove(&epoch_challenge, address, nonce, None).unwrap().to_target().unwrap())
+        .collect::<Vec<_>>();
+    let best_proof_target = *proof_targets.iter().max().unwrap();
+
+    // Ensure the solver finds a solution that meets the best proof target.
+    let (solution, statistics) = puzzle.solve(&epoch_challenge, address, nonces.clone(), best_proof_target).unwrap();
+    let solution = solution.unwrap();
+    assert!(nonces.contains(&solution.nonce()));
+    assert_eq!(solution.to_target().unwrap(), best_proof_target);
+    assert!(statistics.num_attempts() >= 1 && statistics.num_attempts() <= ITERATIONS);
+    assert_eq!(statistics.best_proof_target(), best_proof_target);
+
+    // Ensure the solution is identical to the one from `prove`, and verifies.
+    assert_eq!(solution, puzzle.prove(&epoch_challenge, address, solution.nonce(), None).unwrap());
+    let coinbase_solution = puzzle.accumulate_unchecked(&epoch_challenge, &[solution]).unwrap();
+    assert!(puzzle.verify(&coinbase_solution, &epoch_challenge, 0u64, best_proof_target).unwrap());
+
+    // Ensure the solver exhausts the range when no nonce meets the minimum proof target.
+    let (solution, statistics) =
+        puzzle.solve(&epoch_challenge, address, nonces.clone(), best_proof_target.saturating_add(1)).unwrap();
+    assert!(solution.is_none());
+    assert_eq!(statistics.num_attempts(), ITERATIONS);
+    assert_eq!(statistics.best_proof_target(), best_proof_target);
+    assert!(statistics.attempts_per_second() > 0.0);
+
+    // Ensure the solver returns no solution for an empty range.
+    let (solution, statistics) = puzzle.solve(&epoch_challenge, address, 0..0, 0).unwrap();
+    assert!(solution.is_none());
+    assert_eq!(statistics.num_attempts(), 0);
+}
+
+#[test]
+fn test_solve_with_terminator() {
+    let mut rng = TestRng::default();
+
+    let max_degree = 1 << 15;
+    let max_config = PuzzleConfig { degree: max_degree };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+
+    let degree = (1 << 8) - 1;
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+
+    let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+    let address = Address::try_from(private_key).unwrap();
+
+    // Ensure the solver stops immediately if the terminator is set.
+    let terminator = AtomicBool::new(true);
+    let (solution, statistics) =
+        puzzle.solve_with_terminator(&epoch_challenge, address, 0..u64::MAX, u64::MAX, &terminator).unwrap();
+    assert!(solution.is_none());
+    assert_eq!(statistics.num_attempts(), 0);
+
+    // Ensure the solver stops when the terminator is set during the search.
+    let terminator = AtomicBool::new(false);
+    let (solution, statistics) = std::thread::scope(|scope| {
+        let handle = scope.spawn(|| {
+            puzzle.solve_with_terminator(&epoch_challenge, address, 0..u64::MAX, u64::MAX, &terminator).unwrap()
+        });
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        terminator.store(true, Ordering::Relaxed);
+        handle.join().unwrap()
+    });
+    assert!(solution.is_none());
+    assert!(statistics.num_attempts() < u64::MAX);
+}
+
+#[test]
+fn test_solve_with_verifier() {
+    let mut rng = TestRng::default();
+
+    let max_degree = 1 << 15;
+    let max_config = PuzzleConfig { degree: max_degree };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+
+    let degree = (1 << 8) - 1;
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+    let verifier = CoinbasePuzzle::<Testnet3>::Verifier(Arc::new(puzzle.coinbase_verifying_key().clone()));
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+
+    let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+    let address = Address::try_from(private_key).unwrap();
+
+    // Ensure the verifier cannot solve the coinbase puzzle.
+    assert!(verifier.solve(&epoch_challenge, address, 0..ITERATIONS, 0).is_err());
+
+    // Ensure the solver rejects an epoch challenge of a mismatched degree.
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), (1 << 10) - 1).unwrap();
+    assert!(puzzle.solve(&epoch_challenge, address, 0..ITERATIONS, 0).is_err());
+}
@@ -45,6 +45,28 @@ impl<N: Network> Process<N> {
         &self,
         deployment: &Deployment<N>,
         rng: &mut R,
+    ) -> Result<()> {
+        self.verify_deployment_internal::<A, R>(deployment, None, rng)
+    }
+
+    /// Verifies the given deployment is well-formed, except for its certificates,
+    /// which are appended to `deferred_certificates` to be verified in batch.
+    #[inline]
+    pub fn verify_deployment_deferred<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        deployment: &Deployment<N>,
+        deferred_certificates: &mut Vec<DeferredCertificate<N>>,
+        rng: &mut R,
+    ) -> Result<()> {
+        self.verify_deployment_internal::<A, R>(deployment, Some(deferred_certificates), rng)
+    }
+
+    /// Verifies the given deployment is well-formed, deferring its certificates if `deferred_certificates` is given.
+    fn verify_deployment_internal<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        deployment: &Deployment<N>,
+        deferred_certificates: Option<&mut Vec<DeferredCertificate<N>>>,
+        rng: &mut R,
     ) -> Result<()> {
         let timer = timer!("Process::verify_deployment");
         // Retrieve the program ID.
@@ -56,7 +78,12 @@ impl<N: Network> Process<N> {
         lap!(timer, "Compute the stack");
 
         // Ensure the verifying keys are well-formed and the certificates are valid.
-        let verification = stack.verify_deployment::<A, R>(deployment, rng);
+        let verification = match deferred_certificates {
+            Some(deferred_certificates) => {
+                stack.verify_deployment_deferred::<A, R>(deployment, deferred_certificates, rng)
+            }
+            None => stack.verify_deployment::<A, R>(deployment, rng),
+        };
         lap!(timer, "Verify the deployment");
 
         finish!(timer);
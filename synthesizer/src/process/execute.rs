@@ -57,6 +57,27 @@ impl<N: Network> Process<N> {
     /// Note: This does *not* check that the global state root exists in the ledger.
     #[inline]
     pub fn verify_execution<const VERIFY_INCLUSION: bool>(&self, execution: &Execution<N>) -> Result<()> {
+        self.verify_execution_internal::<VERIFY_INCLUSION>(execution, None)
+    }
+
+    /// Verifies the given execution is valid, except for its transition proofs,
+    /// which are appended to `deferred_proofs` to be verified in batch.
+    /// Note: This does *not* check that the global state root exists in the ledger.
+    #[inline]
+    pub fn verify_execution_deferred<const VERIFY_INCLUSION: bool>(
+        &self,
+        execution: &Execution<N>,
+        deferred_proofs: &mut Vec<DeferredProof<N>>,
+    ) -> Result<()> {
+        self.verify_execution_internal::<VERIFY_INCLUSION>(execution, Some(deferred_proofs))
+    }
+
+    /// Verifies the given execution is valid, deferring its transition proofs if `deferred_proofs` is given.
+    fn verify_execution_internal<const VERIFY_INCLUSION: bool>(
+        &self,
+        execution: &Execution<N>,
+        mut deferred_proofs: Option<&mut Vec<DeferredProof<N>>>,
+    ) -> Result<()> {
         let timer = timer!("Process::verify_execution");
 
         // Ensure the execution contains transitions.
@@ -214,11 +235,17 @@ impl<N: Network> Process<N> {
 
             // Retrieve the verifying key.
             let verifying_key = self.get_verifying_key(stack.program_id(), function.name())?;
-            // Ensure the transition proof is valid.
-            ensure!(
-                verifying_key.verify(function.name(), &inputs, transition.proof()),
-                "Transition is invalid - failed to verify transition proof"
-            );
+            match deferred_proofs.as_deref_mut() {
+                // Defer the transition proof, to be verified in batch.
+                Some(deferred_proofs) => {
+                    deferred_proofs.push((*transition.id(), verifying_key, inputs, transition.proof().clone()))
+                }
+                // Ensure the transition proof is valid.
+                None => ensure!(
+                    verifying_key.verify(function.name(), &inputs, transition.proof()),
+                    "Transition is invalid - failed to verify transition proof"
+                ),
+            }
 
             lap!(timer, "Verify transition proof for {}", function.name());
         }
@@ -227,14 +254,15 @@ impl<N: Network> Process<N> {
         Ok(())
     }
 
-    /// Finalizes the execution.
+    /// Finalizes the execution, and returns the finalize outputs for each transition with a finalize scope.
     /// This method assumes the given execution **is valid**.
     #[inline]
+    #[allow(clippy::type_complexity)]
     pub fn finalize_execution<P: ProgramStorage<N>>(
         &self,
         store: &ProgramStore<N, P>,
         execution: &Execution<N>,
-    ) -> Result<()> {
+    ) -> Result<Vec<(N::TransitionID, Vec<Value<N>>)>> {
         let timer = timer!("Program::finalize_execution");
 
         // Ensure the execution contains transitions.
@@ -256,6 +284,9 @@ impl<N: Network> Process<N> {
         }
         lap!(timer, "Verify the number of transitions");
 
+        // Initialize a list for the finalize outputs of each transition.
+        let mut finalize_outputs = Vec::new();
+
         // TODO (howardwu): This is a temporary approach. We should create a "CallStack" and recurse through the stack.
         //  Currently this loop assumes a linearly execution stack.
         // Finalize each transition, starting from the last one.
@@ -298,21 +329,22 @@ impl<N: Network> Process<N> {
                 let output_registers =
                     &finalize.outputs().iter().map(|output| output.register().clone()).collect::<Vec<_>>();
 
-                // TODO (howardwu): Save the outputs in ProgramStore.
                 // Load the outputs.
-                let _outputs = output_registers
+                let outputs = output_registers
                     .iter()
                     .map(|register| {
                         // Retrieve the stack value from the register.
                         registers.load(stack, &Operand::Register(register.clone()))
                     })
                     .collect::<Result<Vec<_>>>()?;
+                // Save the outputs for the transition.
+                finalize_outputs.push((*transition.id(), outputs));
 
                 lap!(timer, "Finalize transition for {function_name}");
             }
         }
         finish!(timer);
 
-        Ok(())
+        Ok(finalize_outputs)
     }
 }
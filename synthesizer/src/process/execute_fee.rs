@@ -84,6 +84,19 @@ impl<N: Network> Process<N> {
     /// Note: This does *not* check that the global state root exists in the ledger.
     #[inline]
     pub fn verify_fee(&self, fee: &Fee<N>) -> Result<()> {
+        self.verify_fee_internal(fee, None)
+    }
+
+    /// Verifies the given fee is valid, except for its transition proof,
+    /// which is appended to `deferred_proofs` to be verified in batch.
+    /// Note: This does *not* check that the global state root exists in the ledger.
+    #[inline]
+    pub fn verify_fee_deferred(&self, fee: &Fee<N>, deferred_proofs: &mut Vec<DeferredProof<N>>) -> Result<()> {
+        self.verify_fee_internal(fee, Some(deferred_proofs))
+    }
+
+    /// Verifies the given fee is valid, deferring its transition proof if `deferred_proofs` is given.
+    fn verify_fee_internal(&self, fee: &Fee<N>, deferred_proofs: Option<&mut Vec<DeferredProof<N>>>) -> Result<()> {
         let timer = timer!("Process::verify_fee");
 
         #[cfg(debug_assertions)]
@@ -169,11 +182,17 @@ impl<N: Network> Process<N> {
 
         // Retrieve the verifying key.
         let verifying_key = self.get_verifying_key(stack.program_id(), function.name())?;
-        // Ensure the transition proof is valid.
-        ensure!(
-            verifying_key.verify(function.name(), &inputs, fee.proof()),
-            "Fee is invalid - failed to verify transition proof"
-        );
+        match deferred_proofs {
+            // Defer the transition proof, to be verified in batch.
+            Some(deferred_proofs) => {
+                deferred_proofs.push((*fee.transition_id(), verifying_key, inputs, fee.proof().clone()))
+            }
+            // Ensure the transition proof is valid.
+            None => ensure!(
+                verifying_key.verify(function.name(), &inputs, fee.proof()),
+                "Fee is invalid - failed to verify transition proof"
+            ),
+        }
         lap!(timer, "Verify the transition proof");
 
         finish!(timer);
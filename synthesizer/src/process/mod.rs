@@ -26,12 +26,12 @@ mod execute_fee;
 use crate::{
     block::{Input, Transition},
     program::{Instruction, Operand, Program},
-    snark::{ProvingKey, UniversalSRS, VerifyingKey},
+    snark::{Certificate, Proof, ProvingKey, UniversalSRS, VerifyingKey},
     store::{ProgramStorage, ProgramStore},
 };
 use console::{
     account::PrivateKey,
-    network::prelude::*,
+    network::{prelude::*, Devnet},
     program::{Identifier, Plaintext, ProgramID, Record, Request, Response, Value},
     types::{I64, U16, U64},
 };
@@ -39,6 +39,8 @@ use console::{
 use aleo_std::prelude::{finish, lap, timer};
 use indexmap::IndexMap;
 use parking_lot::RwLock;
+#[cfg(not(target_family = "wasm"))]
+use std::path::Path;
 use std::sync::Arc;
 
 #[cfg(test)]
@@ -47,6 +49,15 @@ use std::collections::HashMap;
 #[cfg(feature = "aleo-cli")]
 use colored::Colorize;
 
+/// A transition proof whose verification was deferred, so that it can be verified in batch.
+/// It consists of the transition ID, the verifying key, the verifier inputs, and the proof.
+pub type DeferredProof<N> = (<N as Network>::TransitionID, VerifyingKey<N>, Vec<<N as Environment>::Field>, Proof<N>);
+
+/// A deployment certificate whose verification was deferred, so that it can be verified in batch.
+/// It consists of the program ID, the function name, the verifying key, the circuit assignment, and the certificate.
+pub type DeferredCertificate<N> =
+    (ProgramID<N>, Identifier<N>, VerifyingKey<N>, circuit::Assignment<<N as Environment>::Field>, Certificate<N>);
+
 #[derive(Clone)]
 pub struct Process<N: Network> {
     /// The universal SRS.
@@ -134,11 +145,11 @@ impl<N: Network> Process<N> {
         lap!(timer, "Load circuit keys");
 
         // Initialize the inclusion proving key.
-        let _ = N::inclusion_proving_key();
+        N::inclusion_proving_key()?;
         lap!(timer, "Load inclusion proving key");
 
         // Initialize the inclusion verifying key.
-        let _ = N::inclusion_verifying_key();
+        N::inclusion_verifying_key()?;
         lap!(timer, "Load inclusion verifying key");
 
         // Add the stack to the process.
@@ -149,6 +160,106 @@ impl<N: Network> Process<N> {
         Ok(process)
     }
 
+    /// Initializes a new process, memory-mapping the proving keys for 'credits.aleo' from the given directory.
+    /// Any proving key that is missing from the directory is loaded as usual, and written to the directory
+    /// in the zero-copy layout, so that subsequent loads map it from disk instead.
+    /// Once a mapped proving key is validated, its digest is recorded alongside it (`{function}.prover.digest`),
+    /// so that subsequent loads of the unchanged file skip its validation.
+    /// If the directory contains a universal SRS (`universal.srs.mapped`), it is mapped as well.
+    #[cfg(not(target_family = "wasm"))]
+    #[inline]
+    pub fn load_mapped<P: AsRef<Path>>(directory: P) -> Result<Self> {
+        let timer = timer!("Process::load_mapped");
+        let directory = directory.as_ref();
+
+        // Initialize the universal SRS.
+        let srs_path = directory.join("universal.srs.mapped");
+        let universal_srs = match srs_path.exists() {
+            true => UniversalSRS::load_mapped(&srs_path)?,
+            false => UniversalSRS::load()?,
+        };
+
+        // Initialize the process.
+        let mut process = Self { universal_srs: Arc::new(universal_srs), stacks: IndexMap::new() };
+        lap!(timer, "Initialize process");
+
+        // Initialize the 'credits.aleo' program.
+        let program = Program::credits()?;
+        lap!(timer, "Load credits program");
+
+        // Compute the 'credits.aleo' program stack.
+        let stack = Stack::new(&process, &program)?;
+        lap!(timer, "Initialize stack");
+
+        // Synthesize the 'credits.aleo' circuit keys.
+        for function_name in program.functions().keys() {
+            let path = directory.join(format!("{function_name}.prover.mapped"));
+            let digest_path = directory.join(format!("{function_name}.prover.digest"));
+
+            // Write the proving key to the directory, if it is missing.
+            if !path.exists() {
+                // Load the proving key without caching it, as it is only needed to write the mapped file.
+                let proving_key = ProvingKey::<N>::new(N::load_credits_proving_key(function_name.to_string())?);
+                write_atomically(&path, |temporary_path| proving_key.write_mapped(temporary_path))?;
+            }
+
+            // Map the proving key, skipping its validation if the file is unchanged since it was last validated.
+            let digest = file_digest(&path)?;
+            let proving_key = match std::fs::read(&digest_path) {
+                Ok(recorded_digest) if recorded_digest == digest => ProvingKey::load_mapped_prevalidated(&path)?,
+                _ => {
+                    let proving_key = ProvingKey::load_mapped(&path)?;
+                    // Record the digest of the validated file.
+                    write_atomically(&digest_path, |temporary_path| Ok(std::fs::write(temporary_path, &digest)?))?;
+                    proving_key
+                }
+            };
+            stack.insert_proving_key(function_name, proving_key)?;
+            lap!(timer, "Map proving key for {function_name}");
+
+            // Load the verifying key.
+            let verifying_key = N::get_credits_verifying_key(function_name.to_string())?;
+            stack.insert_verifying_key(function_name, VerifyingKey::new(verifying_key.clone()))?;
+            lap!(timer, "Load verifying key for {function_name}");
+        }
+        lap!(timer, "Load circuit keys");
+
+        // Initialize the inclusion proving key.
+        N::inclusion_proving_key()?;
+        lap!(timer, "Load inclusion proving key");
+
+        // Initialize the inclusion verifying key.
+        N::inclusion_verifying_key()?;
+        lap!(timer, "Load inclusion verifying key");
+
+        // Add the stack to the process.
+        process.stacks.insert(*program.id(), stack);
+
+        finish!(timer, "Process::load_mapped");
+        // Return the process.
+        Ok(process)
+    }
+
+    /// Initializes a new process without loading the circuit keys for 'credits.aleo'.
+    /// This version is suitable for WebAssembly, where programs are evaluated without proving.
+    #[cfg(feature = "wasm")]
+    #[inline]
+    pub fn load_web() -> Result<Self> {
+        // Initialize the process.
+        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+
+        // Initialize the 'credits.aleo' program.
+        let program = Program::credits()?;
+
+        // Compute the 'credits.aleo' program stack.
+        let stack = Stack::new(&process, &program)?;
+
+        // Add the stack to the process.
+        process.stacks.insert(*program.id(), stack);
+        // Return the process.
+        Ok(process)
+    }
+
     /// Initializes a new process with a cache of previously used keys. This version is suitable for tests
     /// (which often use nested loops that keep reusing those), as their deserialization is slow.
     #[cfg(test)]
@@ -276,6 +387,42 @@ impl<N: Network> Process<N> {
     }
 }
 
+/// Writes the file at the given path, by writing to a uniquely-named temporary file and renaming it,
+/// so that a partially-written file is never read, even if several processes write the file at once.
+#[cfg(not(target_family = "wasm"))]
+fn write_atomically(path: &Path, write: impl FnOnce(&Path) -> Result<()>) -> Result<()> {
+    let file_name = path.file_name().ok_or_else(|| anyhow!("Invalid file path '{}'", path.display()))?;
+    let temporary_path = path.with_file_name(format!(
+        "{}.{}.{:016x}.tmp",
+        file_name.to_string_lossy(),
+        std::process::id(),
+        rand::random::<u64>()
+    ));
+    // Remove the temporary file, if it could not be written or renamed.
+    if let Err(error) = write(&temporary_path).and_then(|_| Ok(std::fs::rename(&temporary_path, path)?)) {
+        let _ = std::fs::remove_file(&temporary_path);
+        return Err(error);
+    }
+    Ok(())
+}
+
+/// Returns the BLAKE2b digest of the file at the given path.
+#[cfg(not(target_family = "wasm"))]
+fn file_digest(path: &Path) -> Result<Vec<u8>> {
+    use blake2::Digest;
+    use std::io::Read;
+
+    let mut file = std::fs::File::open(path)?;
+    let mut hasher = blake2::Blake2b512::new();
+    let mut buffer = vec![0u8; 1 << 20];
+    loop {
+        match file.read(&mut buffer)? {
+            0 => return Ok(hasher.finalize().to_vec()),
+            num_bytes => hasher.update(&buffer[..num_bytes]),
+        }
+    }
+}
+
 #[cfg(test)]
 pub(crate) mod test_helpers {
     use super::*;
@@ -399,6 +546,53 @@ function compute:
     }
 }
 
+impl Process<Devnet> {
+    /// Initializes a new process for the development network.
+    /// On first use, this synthesizes the circuit keys for 'credits.aleo' (with `Process::setup`)
+    /// and the inclusion circuit, from the universal SRS of the development network.
+    #[inline]
+    pub fn load_devnet<R: Rng + CryptoRng>(rng: &mut R) -> Result<Self> {
+        static SETUP: parking_lot::Mutex<()> = parking_lot::const_mutex(());
+
+        // Synthesize the circuit keys, if they have not been set.
+        let _guard = SETUP.lock();
+        if !Devnet::has_circuit_keys() {
+            let timer = timer!("Process::load_devnet");
+
+            // Synthesize the 'credits.aleo' circuit keys.
+            let process = Self::setup::<circuit::AleoDevnet, _>(rng)?;
+            let stack = process.get_stack("credits.aleo")?;
+            let credits_keys = stack
+                .program()
+                .functions()
+                .keys()
+                .map(|function_name| {
+                    let proving_key = stack.get_proving_key(function_name)?;
+                    let verifying_key = stack.get_verifying_key(function_name)?;
+                    Ok((
+                        function_name.to_string(),
+                        (Arc::new((*proving_key).clone()), Arc::new((*verifying_key).clone())),
+                    ))
+                })
+                .collect::<Result<IndexMap<_, _>>>()?;
+            lap!(timer, "Synthesize credits program keys");
+
+            // Synthesize the inclusion circuit keys.
+            let (proving_key, verifying_key) =
+                Inclusion::synthesize_keys::<circuit::AleoDevnet, _>(process.universal_srs(), rng)?;
+            let inclusion_keys = (Arc::new((*proving_key).clone()), Arc::new((*verifying_key).clone()));
+            lap!(timer, "Synthesize inclusion circuit keys");
+
+            // Set the circuit keys for the development network.
+            Devnet::set_circuit_keys(credits_keys, inclusion_keys)?;
+            finish!(timer);
+        }
+
+        // Load the process.
+        Self::load()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -520,6 +714,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_process_load_devnet() {
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<Devnet>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+
+        // Construct the process, synthesizing the circuit keys for the development network.
+        let process = Process::<Devnet>::load_devnet(rng).unwrap();
+        assert!(Devnet::has_circuit_keys());
+        assert!(Devnet::get_credits_proving_key("mint".to_string()).is_ok());
+        assert!(Devnet::get_credits_verifying_key("mint".to_string()).is_ok());
+
+        // Ensure a second process reuses the circuit keys.
+        let process_2 = Process::<Devnet>::load_devnet(rng).unwrap();
+        let function_name = Identifier::from_str("mint").unwrap();
+        assert_eq!(
+            process.get_verifying_key("credits.aleo", function_name).unwrap().to_bytes_le().unwrap(),
+            process_2.get_verifying_key("credits.aleo", function_name).unwrap().to_bytes_le().unwrap()
+        );
+
+        // Authorize the function call.
+        let inputs = [Value::from_str(&caller.to_string()).unwrap(), Value::from_str("100_u64").unwrap()];
+        let authorization = process
+            .authorize::<circuit::network::AleoDevnet, _>(
+                &caller_private_key,
+                "credits.aleo",
+                "mint",
+                inputs.iter(),
+                rng,
+            )
+            .unwrap();
+
+        // Execute and verify the request.
+        let (response, execution, _inclusion) =
+            process.execute::<circuit::network::AleoDevnet, _>(authorization, rng).unwrap();
+        assert_eq!(1, response.outputs().len());
+        process.verify_execution::<true>(&execution).unwrap();
+    }
+
     #[test]
     fn test_process_circuit_key() {
         // Initialize a new program.
@@ -643,6 +878,17 @@ function hello_world:
 
         process.verify_execution::<false>(&execution).unwrap();
 
+        // Verify the execution again, deferring the transition proof.
+        let mut deferred_proofs = Vec::new();
+        process.verify_execution_deferred::<false>(&execution, &mut deferred_proofs).unwrap();
+        assert_eq!(execution.len(), deferred_proofs.len());
+        // Ensure the deferred proof verifies in batch.
+        let instances = deferred_proofs
+            .iter()
+            .map(|(_, verifying_key, inputs, proof)| (verifying_key, &inputs[..], proof))
+            .collect::<Vec<_>>();
+        assert_eq!(VerifyingKey::find_invalid_proof(&instances, &[], rng), None);
+
         // use circuit::Environment;
         //
         // assert_eq!(20060, CurrentAleo::num_constants());
@@ -1265,6 +1511,379 @@ finalize compute:
         assert_eq!(candidate, Value::from_str("0u64").unwrap());
     }
 
+    #[test]
+    fn test_process_execute_and_finalize_instructions() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program testing.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    input r2 as u64.public;
+    add r1 r2 into r3;
+    finalize r0 r3;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    mul r1 2u64 into r2;
+    is.eq r2 16u64 into r3;
+    assert.eq r3 true;
+    ternary r3 r2 r1 into r4;
+    hash.psd2 r4 into r5;
+    increment account[r0] by r4;
+    output r4 as u64.public;
+",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the program ID.
+        let program_id = program.id();
+        // Declare the mapping.
+        let mapping_name = Identifier::from_str("account").unwrap();
+        // Declare the function name.
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let mut process = Process::load().unwrap();
+
+        // Initialize a new program store.
+        let store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+
+        // Add the program to the process.
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+        // Check that the deployment verifies.
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+        // Finalize the deployment.
+        process.finalize_deployment(&store, &deployment).unwrap();
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+
+        // Declare the input value.
+        let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+        let r1 = Value::<CurrentNetwork>::from_str("3u64").unwrap();
+        let r2 = Value::<CurrentNetwork>::from_str("5u64").unwrap();
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1, r2].iter(), rng)
+            .unwrap();
+        assert_eq!(authorization.len(), 1);
+
+        // Execute the request.
+        let (response, execution, _inclusion) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        let candidate = response.outputs();
+        assert_eq!(0, candidate.len());
+
+        // Verify the execution.
+        process.verify_execution::<true>(&execution).unwrap();
+
+        // Now, finalize the execution.
+        let finalize_outputs = process.finalize_execution(&store, &execution).unwrap();
+
+        // Check that the finalize outputs were returned for the transition.
+        let transition_id = *execution.peek().unwrap().id();
+        assert_eq!(finalize_outputs, vec![(transition_id, vec![Value::from_str("16u64").unwrap()])]);
+
+        // Check that the account balance is now 16.
+        let candidate =
+            store.get_value(program_id, &mapping_name, &Plaintext::from(Literal::Address(caller))).unwrap().unwrap();
+        assert_eq!(candidate, Value::from_str("16u64").unwrap());
+    }
+
+    #[test]
+    fn test_process_execute_and_finalize_get_set_remove() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program testing.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function deposit:
+    input r0 as address.public;
+    input r1 as u64.public;
+    finalize r0 r1;
+
+finalize deposit:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_init account[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into account[r0];
+    get account[r0] into r4;
+    assert.eq r3 r4;
+
+function clear:
+    input r0 as address.public;
+    finalize r0;
+
+finalize clear:
+    input r0 as address.public;
+    remove account[r0];
+",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the program ID.
+        let program_id = program.id();
+        // Declare the mapping.
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let mut process = Process::load().unwrap();
+
+        // Initialize a new program store.
+        let store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+
+        // Add the program to the process.
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+        // Check that the deployment verifies.
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+        // Finalize the deployment.
+        process.finalize_deployment(&store, &deployment).unwrap();
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+        // Declare the mapping key.
+        let key = Plaintext::from(Literal::Address(caller));
+
+        // Declare the input value.
+        let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+        let r1 = Value::<CurrentNetwork>::from_str("5u64").unwrap();
+
+        // Deposit twice, to initialize the key, and then to update the value.
+        for expected in ["5u64", "10u64"] {
+            // Authorize the function call.
+            let authorization = process
+                .authorize::<CurrentAleo, _>(
+                    &caller_private_key,
+                    program.id(),
+                    Identifier::from_str("deposit").unwrap(),
+                    [r0.clone(), r1.clone()].iter(),
+                    rng,
+                )
+                .unwrap();
+            // Execute the request.
+            let (_response, execution, _inclusion) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+            // Verify the execution.
+            process.verify_execution::<true>(&execution).unwrap();
+            // Now, finalize the execution.
+            process.finalize_execution(&store, &execution).unwrap();
+
+            // Check that the account balance is updated.
+            let candidate = store.get_value(program_id, &mapping_name, &key).unwrap().unwrap();
+            assert_eq!(candidate, Value::from_str(expected).unwrap());
+        }
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(
+                &caller_private_key,
+                program.id(),
+                Identifier::from_str("clear").unwrap(),
+                [r0].iter(),
+                rng,
+            )
+            .unwrap();
+        // Execute the request.
+        let (_response, execution, _inclusion) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        // Verify the execution.
+        process.verify_execution::<true>(&execution).unwrap();
+        // Now, finalize the execution.
+        process.finalize_execution(&store, &execution).unwrap();
+
+        // Check that the account is removed.
+        assert!(store.get_value(program_id, &mapping_name, &key).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_process_execute_and_finalize_struct() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program testing.aleo;
+
+struct message:
+    first as field;
+    second as field;
+
+mapping messages:
+    key owner as address.public;
+    value message as message.public;
+
+function store:
+    input r0 as address.public;
+    input r1 as message.public;
+    finalize r0 r1;
+
+finalize store:
+    input r0 as address.public;
+    input r1 as message.public;
+    set r1 into messages[r0];
+    get messages[r0] into r2;
+    add r2.first r2.second into r3;
+    output r3 as field.public;
+",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the program ID.
+        let program_id = program.id();
+        // Declare the mapping.
+        let mapping_name = Identifier::from_str("messages").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let mut process = Process::load().unwrap();
+
+        // Initialize a new program store.
+        let store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+
+        // Add the program to the process.
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+        // Check that the deployment verifies.
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+        // Finalize the deployment.
+        process.finalize_deployment(&store, &deployment).unwrap();
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+
+        // Declare the input value.
+        let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+        let r1 = Value::<CurrentNetwork>::from_str("{ first: 2field, second: 3field }").unwrap();
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(
+                &caller_private_key,
+                program.id(),
+                Identifier::from_str("store").unwrap(),
+                [r0, r1.clone()].iter(),
+                rng,
+            )
+            .unwrap();
+        // Execute the request.
+        let (_response, execution, _inclusion) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        // Verify the execution.
+        process.verify_execution::<true>(&execution).unwrap();
+
+        // Check that the struct is passed into 'finalize'.
+        let transition = execution.peek().unwrap();
+        assert_eq!(transition.finalize().unwrap()[1], r1);
+        // Check that the transition round-trips with the struct in 'finalize'.
+        assert_eq!(transition, &Transition::from_str(&transition.to_string()).unwrap());
+        assert_eq!(transition, &Transition::read_le(&transition.to_bytes_le().unwrap()[..]).unwrap());
+
+        // Now, finalize the execution.
+        let finalize_outputs = process.finalize_execution(&store, &execution).unwrap();
+        // Check that the finalize outputs were returned for the transition.
+        assert_eq!(finalize_outputs, vec![(*transition.id(), vec![Value::from_str("5field").unwrap()])]);
+
+        // Check that the struct is stored in the mapping.
+        let key = Plaintext::from(Literal::Address(caller));
+        let candidate = store.get_value(program_id, &mapping_name, &key).unwrap().unwrap();
+        assert_eq!(candidate, r1);
+    }
+
+    #[test]
+    fn test_process_finalize_input_type_checks() {
+        // Construct the process.
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+
+        // Ensure a record cannot be passed into 'finalize'.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program records.aleo;
+
+record token:
+    owner as address.private;
+    gates as u64.private;
+
+function burn:
+    input r0 as token.record;
+    finalize r0;
+
+finalize burn:
+    input r0 as token.record;
+    assert.eq r0.gates r0.gates;
+",
+        )
+        .unwrap();
+        assert!(process.add_program(&program).is_err());
+
+        // Ensure the finalize operands must match the finalize input types.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program mismatch.aleo;
+
+struct message:
+    first as field;
+    second as field;
+
+function store:
+    input r0 as message.public;
+    finalize r0.first;
+
+finalize store:
+    input r0 as message.public;
+    add r0.first r0.second into r1;
+",
+        )
+        .unwrap();
+        assert!(process.add_program(&program).is_err());
+
+        // Ensure a record-derived value can be passed into 'finalize'.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program derived.aleo;
+
+struct message:
+    first as field;
+    second as field;
+
+record letter:
+    owner as address.private;
+    gates as u64.private;
+    message as message.private;
+
+function open:
+    input r0 as letter.record;
+    finalize r0.message;
+
+finalize open:
+    input r0 as message.public;
+    add r0.first r0.second into r1;
+",
+        )
+        .unwrap();
+        assert!(process.add_program(&program).is_ok());
+    }
+
     #[test]
     fn test_process_execute_mint_public() {
         // Initialize a new program.
@@ -59,6 +59,29 @@ impl<N: Network> Stack<N> {
         &self,
         deployment: &Deployment<N>,
         rng: &mut R,
+    ) -> Result<()> {
+        self.verify_deployment_internal::<A, R>(deployment, None, rng)
+    }
+
+    /// Checks each function in the program on the given verifying key, except for the certificates,
+    /// which are appended to `deferred_certificates` to be verified in batch.
+    #[inline]
+    pub fn verify_deployment_deferred<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        deployment: &Deployment<N>,
+        deferred_certificates: &mut Vec<DeferredCertificate<N>>,
+        rng: &mut R,
+    ) -> Result<()> {
+        self.verify_deployment_internal::<A, R>(deployment, Some(deferred_certificates), rng)
+    }
+
+    /// Checks each function in the program on the given verifying key and certificate,
+    /// deferring the certificates if `deferred_certificates` is given.
+    fn verify_deployment_internal<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        deployment: &Deployment<N>,
+        mut deferred_certificates: Option<&mut Vec<DeferredCertificate<N>>>,
+        rng: &mut R,
     ) -> Result<()> {
         let timer = timer!("Stack::verify_deployment");
 
@@ -148,13 +171,23 @@ impl<N: Network> Stack<N> {
             // Check the certificate.
             match assignments.read().last() {
                 None => bail!("The assignment for function '{}' is missing in '{program_id}'", function.name()),
-                Some(assignment) => {
-                    // Ensure the certificate is valid.
-                    if !certificate.verify(function.name(), assignment, verifying_key) {
-                        bail!("The certificate for function '{}' is invalid in '{program_id}'", function.name())
+                Some(assignment) => match deferred_certificates.as_deref_mut() {
+                    // Defer the certificate, to be verified in batch.
+                    Some(deferred_certificates) => deferred_certificates.push((
+                        *program_id,
+                        *function.name(),
+                        verifying_key.clone(),
+                        assignment.clone(),
+                        certificate.clone(),
+                    )),
+                    None => {
+                        // Ensure the certificate is valid.
+                        if !certificate.verify(function.name(), assignment, verifying_key) {
+                            bail!("The certificate for function '{}' is invalid in '{program_id}'", function.name())
+                        }
+                        lap!(timer, "Ensure the certificate is valid");
                     }
-                    lap!(timer, "Ensure the certificate is valid");
-                }
+                },
             };
         }
 
@@ -111,8 +111,8 @@ impl<N: Network> Stack<N> {
         // Ensure the call stack is not `Evaluate`.
         ensure!(!matches!(call_stack, CallStack::Evaluate(..)), "Illegal operation: cannot evaluate in execute mode");
 
-        // Ensure the circuit environment is clean.
-        A::reset();
+        // Enter a new circuit environment, which is exited when this function returns.
+        let environment = A::enter();
 
         // Retrieve the next request.
         let console_request = call_stack.pop()?;
@@ -277,18 +277,10 @@ impl<N: Network> Stack<N> {
                 for operand in command.operands() {
                     // Retrieve the finalize input.
                     let value = registers.load_circuit(self, operand)?;
-                    // TODO (howardwu): Expand the scope of 'finalize' to support other register types.
+                    // Ensure the value is a plaintext value.
                     //  See `RegisterTypes::initialize_function_types()` for the same set of checks.
-                    // Ensure the value is a literal (for now).
                     match value {
-                        circuit::Value::Plaintext(circuit::Plaintext::Literal(..)) => (),
-                        circuit::Value::Plaintext(circuit::Plaintext::Struct(..)) => {
-                            bail!(
-                                "'{}/{}' attempts to pass an 'struct' into 'finalize'",
-                                self.program_id(),
-                                function.name()
-                            );
-                        }
+                        circuit::Value::Plaintext(..) => (),
                         circuit::Value::Record(..) => {
                             bail!(
                                 "'{}/{}' attempts to pass a 'record' into 'finalize'",
@@ -418,8 +410,8 @@ impl<N: Network> Stack<N> {
             );
         }
 
-        // Eject the circuit assignment and reset the circuit.
-        let assignment = A::eject_assignment_and_reset();
+        // Eject the circuit assignment, and exit the circuit environment.
+        let assignment = environment.eject_assignment();
 
         // If the circuit is in `Synthesize` or `Execute` mode, synthesize the circuit key, if it does not exist.
         if matches!(registers.call_stack(), CallStack::Synthesize(..))
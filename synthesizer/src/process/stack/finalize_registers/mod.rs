@@ -17,10 +17,11 @@
 mod load;
 mod store;
 
-use crate::{FinalizeTypes, Operand, Stack};
+use crate::{FinalizeTypes, Operand, RegistersCaller, RegistersLoad, RegistersStore, Stack};
 use console::{
     network::prelude::*,
     program::{Entry, Literal, Plaintext, Register, Value},
+    types::{Address, Field},
 };
 
 use indexmap::IndexMap;
@@ -40,3 +41,17 @@ impl<N: Network> FinalizeRegisters<N> {
         Self { finalize_types, registers: IndexMap::new() }
     }
 }
+
+impl<N: Network> RegistersCaller<N> for FinalizeRegisters<N> {
+    /// Returns the transition caller.
+    #[inline]
+    fn caller(&self) -> Result<Address<N>> {
+        bail!("Forbidden operation: Cannot use the transition caller in 'finalize'")
+    }
+
+    /// Returns the transition view key.
+    #[inline]
+    fn tvk(&self) -> Result<Field<N>> {
+        bail!("Forbidden operation: Cannot use the transition view key in 'finalize'")
+    }
+}
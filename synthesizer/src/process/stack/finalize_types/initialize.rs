@@ -27,7 +27,7 @@ impl<N: Network> FinalizeTypes<N> {
         // Step 1. Check the inputs are well-formed.
         for input in finalize.inputs() {
             // Check the input register type.
-            finalize_types.check_input(stack, input.register(), &RegisterType::from(*input.finalize_type()))?;
+            finalize_types.check_input(stack, input.register(), &RegisterType::from(input.finalize_type().clone()))?;
         }
 
         // Step 2. Check the commands are well-formed.
@@ -39,7 +39,7 @@ impl<N: Network> FinalizeTypes<N> {
         // Step 3. Check the outputs are well-formed.
         for output in finalize.outputs() {
             // Retrieve the register type and check the output register type.
-            finalize_types.check_output(stack, output.register(), &RegisterType::from(*output.finalize_type()))?;
+            finalize_types.check_output(stack, output.register(), &RegisterType::from(output.finalize_type().clone()))?;
         }
 
         Ok(finalize_types)
@@ -68,7 +68,7 @@ impl<N: Network> FinalizeTypes<N> {
                 }
             }
             // Ensure the register is a locator, and not a member.
-            Register::Member(..) => bail!("Register '{register}' must be a locator."),
+            Register::Access(..) => bail!("Register '{register}' must be a locator."),
         }
     }
 
@@ -91,7 +91,7 @@ impl<N: Network> FinalizeTypes<N> {
                 }
             }
             // Ensure the register is a locator, and not a member.
-            Register::Member(..) => bail!("Register '{register}' must be a locator."),
+            Register::Access(..) => bail!("Register '{register}' must be a locator."),
         }
     }
 }
@@ -109,6 +109,16 @@ impl<N: Network> FinalizeTypes<N> {
                     bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
                 }
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Ensure the base element type of the array is defined in the program.
+                if let PlaintextType::Struct(struct_name) = array_type.base_element_type() {
+                    if !stack.program().contains_struct(struct_name) {
+                        bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
+                    }
+                    // Ensure the array fits within `u16::MAX` bits.
+                    stack.program().check_array_size(array_type)?;
+                }
+            }
             RegisterType::Record(identifier) => {
                 // Ensure the record type is defined in the program.
                 if !stack.program().contains_record(identifier) {
@@ -124,7 +134,7 @@ impl<N: Network> FinalizeTypes<N> {
         };
 
         // Insert the input register.
-        self.add_input(register.clone(), *register_type)?;
+        self.add_input(register.clone(), register_type.clone())?;
 
         // Ensure the register type and the input type match.
         if *register_type != self.get_type(stack, register)? {
@@ -155,6 +165,16 @@ impl<N: Network> FinalizeTypes<N> {
                     bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
                 }
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Ensure the base element type of the array is defined in the program.
+                if let PlaintextType::Struct(struct_name) = array_type.base_element_type() {
+                    if !stack.program().contains_struct(struct_name) {
+                        bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
+                    }
+                    // Ensure the array fits within `u16::MAX` bits.
+                    stack.program().check_array_size(array_type)?;
+                }
+            }
             RegisterType::Record(identifier) => {
                 // Ensure the record type is defined in the program.
                 if !stack.program().contains_record(identifier) {
@@ -187,6 +207,10 @@ impl<N: Network> FinalizeTypes<N> {
             Command::Decrement(decrement) => self.check_decrement(stack, finalize_name, decrement)?,
             Command::Instruction(instruction) => self.check_instruction(stack, finalize_name, instruction)?,
             Command::Increment(increment) => self.check_increment(stack, finalize_name, increment)?,
+            Command::Get(get) => self.check_get(stack, finalize_name, get)?,
+            Command::GetOrInit(get_or_init) => self.check_get_or_init(stack, finalize_name, get_or_init)?,
+            Command::Set(set) => self.check_set(stack, finalize_name, set)?,
+            Command::Remove(remove) => self.check_remove(stack, finalize_name, remove)?,
         }
         Ok(())
     }
@@ -216,7 +240,7 @@ impl<N: Network> FinalizeTypes<N> {
         match value_type {
             RegisterType::Plaintext(PlaintextType::Literal(literal_type)) => {
                 match literal_type {
-                    LiteralType::Address | LiteralType::Boolean | LiteralType::String => {
+                    LiteralType::Address | LiteralType::Boolean | LiteralType::String | LiteralType::Signature => {
                         bail!("Decrement cannot decrement by a(n) '{literal_type}' (found at '{decrement}')")
                     }
                     // These literal types are valid for the 'decrement' command.
@@ -238,6 +262,9 @@ impl<N: Network> FinalizeTypes<N> {
             RegisterType::Plaintext(PlaintextType::Struct(..)) => {
                 bail!("Decrement cannot decrement by an 'struct' (found at '{decrement}')")
             }
+            RegisterType::Plaintext(PlaintextType::Array(..)) => {
+                bail!("Decrement cannot decrement by an 'array' (found at '{decrement}')")
+            }
             RegisterType::Record(..) => bail!("Decrement cannot decrement by a 'record' (found at '{decrement}')"),
             RegisterType::ExternalRecord(..) => {
                 bail!("Decrement cannot decrement by an 'external record' (found at '{decrement}')")
@@ -272,7 +299,7 @@ impl<N: Network> FinalizeTypes<N> {
         match value_type {
             RegisterType::Plaintext(PlaintextType::Literal(literal_type)) => {
                 match literal_type {
-                    LiteralType::Address | LiteralType::Boolean | LiteralType::String => {
+                    LiteralType::Address | LiteralType::Boolean | LiteralType::String | LiteralType::Signature => {
                         bail!("Increment cannot increment by a(n) '{literal_type}' (found at '{increment}')")
                     }
                     // These literal types are valid for the 'increment' command.
@@ -294,6 +321,9 @@ impl<N: Network> FinalizeTypes<N> {
             RegisterType::Plaintext(PlaintextType::Struct(..)) => {
                 bail!("Increment cannot increment by an 'struct' (found at '{increment}')")
             }
+            RegisterType::Plaintext(PlaintextType::Array(..)) => {
+                bail!("Increment cannot increment by an 'array' (found at '{increment}')")
+            }
             RegisterType::Record(..) => bail!("Increment cannot increment by a 'record' (found at '{increment}')"),
             RegisterType::ExternalRecord(..) => {
                 bail!("Increment cannot increment by an 'external record' (found at '{increment}')")
@@ -303,6 +333,141 @@ impl<N: Network> FinalizeTypes<N> {
         Ok(())
     }
 
+    /// Ensures the given get command is well-formed.
+    #[inline]
+    fn check_get(&mut self, stack: &Stack<N>, finalize_name: &Identifier<N>, get: &Get<N>) -> Result<()> {
+        // Ensure the declared mapping in get is defined in the program.
+        if !stack.program().contains_mapping(get.mapping_name()) {
+            bail!("Mapping '{}' in '{}/{finalize_name}' is not defined.", get.mapping_name(), stack.program_id())
+        }
+        // Retrieve the mapping from the program.
+        let mapping = stack.program().get_mapping(get.mapping_name())?;
+
+        // Retrieve the register type of the key.
+        let key_type = self.get_type_from_operand(stack, get.key())?;
+        // Ensure the key type matches the mapping key type.
+        let mapping_key_type = RegisterType::from(mapping.key().finalize_type().clone());
+        if key_type != mapping_key_type {
+            bail!("Key type in 'get' is incorrect. Expected '{mapping_key_type}', found '{key_type}' (at '{get}')")
+        }
+
+        // Ensure the destination register is a locator (and does not reference a member).
+        ensure!(
+            matches!(get.destination(), Register::Locator(..)),
+            "Destination '{}' must be a locator.",
+            get.destination()
+        );
+        // Insert the destination register, with the mapping value type.
+        self.add_destination(get.destination().clone(), RegisterType::from(mapping.value().finalize_type().clone()))?;
+
+        Ok(())
+    }
+
+    /// Ensures the given get.or_init command is well-formed.
+    #[inline]
+    fn check_get_or_init(
+        &mut self,
+        stack: &Stack<N>,
+        finalize_name: &Identifier<N>,
+        get_or_init: &GetOrInit<N>,
+    ) -> Result<()> {
+        // Ensure the declared mapping in get.or_init is defined in the program.
+        if !stack.program().contains_mapping(get_or_init.mapping_name()) {
+            bail!(
+                "Mapping '{}' in '{}/{finalize_name}' is not defined.",
+                get_or_init.mapping_name(),
+                stack.program_id()
+            )
+        }
+        // Retrieve the mapping from the program.
+        let mapping = stack.program().get_mapping(get_or_init.mapping_name())?;
+
+        // Retrieve the register type of the key.
+        let key_type = self.get_type_from_operand(stack, get_or_init.key())?;
+        // Ensure the key type matches the mapping key type.
+        let mapping_key_type = RegisterType::from(mapping.key().finalize_type().clone());
+        if key_type != mapping_key_type {
+            bail!(
+                "Key type in 'get.or_init' is incorrect. Expected '{mapping_key_type}', found '{key_type}' (at '{get_or_init}')"
+            )
+        }
+
+        // Retrieve the register type of the default value.
+        let default_type = self.get_type_from_operand(stack, get_or_init.default())?;
+        // Ensure the default value type matches the mapping value type.
+        let mapping_value_type = RegisterType::from(mapping.value().finalize_type().clone());
+        if default_type != mapping_value_type {
+            bail!(
+                "Default value type in 'get.or_init' is incorrect. Expected '{mapping_value_type}', found '{default_type}' (at '{get_or_init}')"
+            )
+        }
+
+        // Ensure the destination register is a locator (and does not reference a member).
+        ensure!(
+            matches!(get_or_init.destination(), Register::Locator(..)),
+            "Destination '{}' must be a locator.",
+            get_or_init.destination()
+        );
+        // Insert the destination register, with the mapping value type.
+        self.add_destination(get_or_init.destination().clone(), mapping_value_type)?;
+
+        Ok(())
+    }
+
+    /// Ensures the given set command is well-formed.
+    #[inline]
+    fn check_set(&self, stack: &Stack<N>, finalize_name: &Identifier<N>, set: &Set<N>) -> Result<()> {
+        // Ensure the declared mapping in set is defined in the program.
+        if !stack.program().contains_mapping(set.mapping_name()) {
+            bail!("Mapping '{}' in '{}/{finalize_name}' is not defined.", set.mapping_name(), stack.program_id())
+        }
+        // Retrieve the mapping from the program.
+        let mapping = stack.program().get_mapping(set.mapping_name())?;
+
+        // Retrieve the register type of the key.
+        let key_type = self.get_type_from_operand(stack, set.key())?;
+        // Ensure the key type matches the mapping key type.
+        let mapping_key_type = RegisterType::from(mapping.key().finalize_type().clone());
+        if key_type != mapping_key_type {
+            bail!("Key type in 'set' is incorrect. Expected '{mapping_key_type}', found '{key_type}' (at '{set}')")
+        }
+
+        // Retrieve the register type of the value.
+        let value_type = self.get_type_from_operand(stack, set.value())?;
+        // Ensure the value type matches the mapping value type.
+        let mapping_value_type = RegisterType::from(mapping.value().finalize_type().clone());
+        if value_type != mapping_value_type {
+            bail!(
+                "Value type in 'set' is incorrect. Expected '{mapping_value_type}', found '{value_type}' (at '{set}')"
+            )
+        }
+
+        Ok(())
+    }
+
+    /// Ensures the given remove command is well-formed.
+    #[inline]
+    fn check_remove(&self, stack: &Stack<N>, finalize_name: &Identifier<N>, remove: &Remove<N>) -> Result<()> {
+        // Ensure the declared mapping in remove is defined in the program.
+        if !stack.program().contains_mapping(remove.mapping_name()) {
+            bail!("Mapping '{}' in '{}/{finalize_name}' is not defined.", remove.mapping_name(), stack.program_id())
+        }
+        // Retrieve the mapping from the program.
+        let mapping = stack.program().get_mapping(remove.mapping_name())?;
+
+        // Retrieve the register type of the key.
+        let key_type = self.get_type_from_operand(stack, remove.key())?;
+        // Ensure the key type matches the mapping key type.
+        let mapping_key_type = RegisterType::from(mapping.key().finalize_type().clone());
+        if key_type != mapping_key_type {
+            bail!(
+                "Key type in 'remove' is incorrect. Expected '{mapping_key_type}', found '{key_type}' (at '{remove}')"
+            )
+        }
+
+        Ok(())
+    }
+
     /// Ensures the given instruction is well-formed.
     #[inline]
     fn check_instruction(
@@ -351,7 +516,10 @@ impl<N: Network> FinalizeTypes<N> {
                 // Ensure the opcode **is** a reserved opcode.
                 ensure!(Program::<N>::is_reserved_opcode(opcode), "'{opcode}' is not an opcode.");
                 // Ensure the instruction is not the cast operation.
-                ensure!(!matches!(instruction, Instruction::Cast(..)), "Instruction '{instruction}' is a 'cast'.");
+                ensure!(
+                    !matches!(instruction, Instruction::Cast(..) | Instruction::CastLossy(..)),
+                    "Instruction '{instruction}' is a 'cast'."
+                );
                 // Ensure the instruction has one destination register.
                 ensure!(
                     instruction.destinations().len() == 1,
@@ -379,11 +547,12 @@ impl<N: Network> FinalizeTypes<N> {
             Opcode::Call => {
                 bail!("Instruction 'call' is not allowed in 'finalize'");
             }
-            Opcode::Cast => {
-                // Retrieve the cast operation.
-                let operation = match instruction {
-                    Instruction::Cast(operation) => operation,
-                    _ => bail!("Instruction '{instruction}' is not a cast operation."),
+            Opcode::Cast(opcode) => {
+                // Retrieve the casted register type.
+                let register_type = match (opcode, instruction) {
+                    ("cast", Instruction::Cast(operation)) => operation.register_type(),
+                    ("cast.lossy", Instruction::CastLossy(operation)) => operation.register_type(),
+                    _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
                 };
 
                 // Ensure the instruction has one destination register.
@@ -393,9 +562,9 @@ impl<N: Network> FinalizeTypes<N> {
                 );
 
                 // Ensure the casted register type is defined.
-                match operation.register_type() {
+                match register_type {
                     RegisterType::Plaintext(PlaintextType::Literal(..)) => {
-                        bail!("Casting to literal is currently unsupported")
+                        // Note: The operand type is checked against the literal type in `output_types`.
                     }
                     RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
                         // Ensure the struct name exists in the program.
@@ -407,6 +576,18 @@ impl<N: Network> FinalizeTypes<N> {
                         // Ensure the operand types match the struct.
                         self.matches_struct(stack, instruction.operands(), &struct_)?;
                     }
+                    RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                        // Ensure the base element type of the array is defined in the program.
+                        if let PlaintextType::Struct(struct_name) = array_type.base_element_type() {
+                            if !stack.program().contains_struct(struct_name) {
+                                bail!("Struct '{struct_name}' is not defined.")
+                            }
+                            // Ensure the array fits within `u16::MAX` bits.
+                            stack.program().check_array_size(array_type)?;
+                        }
+                        // Ensure the operand types match the array.
+                        self.matches_array(stack, instruction.operands(), array_type)?;
+                    }
                     RegisterType::Record(..) => {
                         bail!("Unsupported operation: Cannot cast to a record (yet).")
                         // // Ensure the record type is defined in the program.
@@ -548,6 +729,16 @@ impl<N: Network> FinalizeTypes<N> {
                     _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
                 }
             }
+            Opcode::Sign(opcode) => {
+                // Ensure the instruction is the correct one.
+                match opcode {
+                    "sign.verify" => ensure!(
+                        matches!(instruction, Instruction::SignVerify(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+                }
+            }
         }
         Ok(())
     }
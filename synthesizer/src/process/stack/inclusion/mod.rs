@@ -15,6 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
+    snark::UniversalSRS,
     BlockStorage,
     BlockStore,
     Execution,
@@ -30,8 +31,18 @@ use crate::{
     VerifyingKey,
 };
 use console::{
+    account::PrivateKey,
     network::prelude::*,
-    program::{InputID, StatePath, TransactionLeaf, TransitionLeaf, TRANSACTION_DEPTH},
+    program::{
+        InputID,
+        Plaintext,
+        Record,
+        StatePath,
+        TransactionLeaf,
+        TransitionLeaf,
+        TRANSACTION_DEPTH,
+        TRANSITION_DEPTH,
+    },
     types::{Field, Group},
 };
 
@@ -95,10 +106,13 @@ impl<N: Network, B: BlockStorage<N>> Query<N, B> {
             Self::VM(block_store) => {
                 block_store.get_program(program_id)?.ok_or_else(|| anyhow!("Program {program_id} not found in storage"))
             }
+            #[cfg(not(target_family = "wasm"))]
             Self::REST(url) => match N::ID {
                 3 => Ok(Self::get_request(&format!("{url}/testnet3/program/{program_id}"))?.json()?),
                 _ => bail!("Unsupported network ID in inclusion query"),
             },
+            #[cfg(target_family = "wasm")]
+            Self::REST(url) => bail!("Blocking requests to {url} are not supported in WebAssembly"),
         }
     }
 
@@ -106,10 +120,13 @@ impl<N: Network, B: BlockStorage<N>> Query<N, B> {
     pub fn current_state_root(&self) -> Result<N::StateRoot> {
         match self {
             Self::VM(block_store) => Ok(block_store.current_state_root()),
+            #[cfg(not(target_family = "wasm"))]
             Self::REST(url) => match N::ID {
                 3 => Ok(Self::get_request(&format!("{url}/testnet3/latest/stateRoot"))?.json()?),
                 _ => bail!("Unsupported network ID in inclusion query"),
             },
+            #[cfg(target_family = "wasm")]
+            Self::REST(url) => bail!("Blocking requests to {url} are not supported in WebAssembly"),
         }
     }
 
@@ -117,14 +134,18 @@ impl<N: Network, B: BlockStorage<N>> Query<N, B> {
     pub fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
         match self {
             Self::VM(block_store) => block_store.get_state_path_for_commitment(commitment),
+            #[cfg(not(target_family = "wasm"))]
             Self::REST(url) => match N::ID {
                 3 => Ok(Self::get_request(&format!("{url}/testnet3/statePath/{commitment}"))?.json()?),
                 _ => bail!("Unsupported network ID in inclusion query"),
             },
+            #[cfg(target_family = "wasm")]
+            Self::REST(url) => bail!("Blocking requests to {url} are not supported in WebAssembly"),
         }
     }
 
     /// Performs a GET request to the given URL.
+    #[cfg(not(target_family = "wasm"))]
     fn get_request(url: &str) -> Result<reqwest::blocking::Response> {
         let response = reqwest::blocking::get(url)?;
         if response.status().is_success() { Ok(response) } else { bail!("Failed to fetch from {}", url) }
@@ -159,6 +180,46 @@ impl<N: Network> Inclusion<N> {
         Self { input_tasks: HashMap::new(), output_commitments: HashMap::new() }
     }
 
+    /// Returns the proving and verifying key for the inclusion circuit, synthesized from the given universal SRS.
+    /// This is used by networks that do not load the inclusion circuit keys from `snarkvm-parameters`.
+    pub fn synthesize_keys<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        universal_srs: &UniversalSRS<N>,
+        rng: &mut R,
+    ) -> Result<(ProvingKey<N>, VerifyingKey<N>)> {
+        // Sample a commitment, and compute its serial number for a random private key.
+        let private_key = PrivateKey::<N>::new(rng)?;
+        let commitment = Field::rand(rng);
+        let h = N::hash_to_group_psd2(&[N::serial_number_domain(), commitment])?;
+        let gamma = h * private_key.sk_sig();
+        let serial_number = Record::<N, Plaintext<N>>::serial_number_from_gamma(&gamma, commitment)?;
+
+        // Compute the transition path for the commitment.
+        let transition_leaf = TransitionLeaf::new_with_version(0, 3, commitment);
+        let transition_tree = N::merkle_tree_bhp::<TRANSITION_DEPTH>(&[transition_leaf.to_bits_le()])?;
+        let transition_path = transition_tree.prove(0, &transition_leaf.to_bits_le())?;
+        // Compute the transaction path for the transition.
+        let transaction_leaf = TransactionLeaf::new_execution(0, *transition_tree.root());
+        let transaction_tree = N::merkle_tree_bhp::<TRANSACTION_DEPTH>(&[transaction_leaf.to_bits_le()])?;
+        let transaction_path = transaction_tree.prove(0, &transaction_leaf.to_bits_le())?;
+        // Construct the local state path.
+        let local_state_root = (*transaction_tree.root()).into();
+        let state_path = StatePath::new_local(
+            N::StateRoot::default(),
+            local_state_root,
+            transaction_path,
+            transaction_leaf,
+            transition_path,
+            transition_leaf,
+        )?;
+
+        // Construct the assignment for the inclusion circuit.
+        let assignment =
+            InclusionAssignment::new(state_path, commitment, gamma, serial_number, local_state_root, false)
+                .to_circuit_assignment::<A>()?;
+        // Synthesize the proving and verifying key.
+        universal_srs.to_circuit_key(&Identifier::from_str(N::INCLUSION_FUNCTION_NAME)?, &assignment)
+    }
+
     /// Inserts the transition to build state for the inclusion proof.
     pub fn insert_transition(&mut self, input_ids: &[InputID<N>], transition: &Transition<N>) -> Result<()> {
         // Ensure the transition inputs and input IDs are the same length.
@@ -327,7 +388,7 @@ impl<N: Network> Inclusion<N> {
             }
             false => {
                 // Fetch the inclusion proving key.
-                let proving_key = ProvingKey::<N>::new(N::inclusion_proving_key().clone());
+                let proving_key = ProvingKey::<N>::new(N::inclusion_proving_key()?.clone());
 
                 // Compute the inclusion batch proof.
                 let (global_state_root, inclusion_proof) = Self::prove_batch::<A, R>(&proving_key, assignments, rng)?;
@@ -427,7 +488,7 @@ impl<N: Network> Inclusion<N> {
         }
 
         // Fetch the inclusion proving key.
-        let proving_key = ProvingKey::<N>::new(N::inclusion_proving_key().clone());
+        let proving_key = ProvingKey::<N>::new(N::inclusion_proving_key()?.clone());
 
         // Compute the inclusion batch proof.
         let (global_state_root, inclusion_proof) = Self::prove_batch::<A, R>(&proving_key, assignments, rng)?;
@@ -491,7 +552,7 @@ impl<N: Network> Inclusion<N> {
                 }
 
                 // Fetch the inclusion verifying key.
-                let verifying_key = VerifyingKey::<N>::new(N::inclusion_verifying_key().clone());
+                let verifying_key = VerifyingKey::<N>::new(N::inclusion_verifying_key()?.clone());
                 // Verify the inclusion proof.
                 ensure!(
                     verifying_key.verify_batch(N::INCLUSION_FUNCTION_NAME, &batch_verifier_inputs, inclusion_proof),
@@ -552,7 +613,7 @@ impl<N: Network> Inclusion<N> {
         }
 
         // Fetch the inclusion verifying key.
-        let verifying_key = VerifyingKey::<N>::new(N::inclusion_verifying_key().clone());
+        let verifying_key = VerifyingKey::<N>::new(N::inclusion_verifying_key()?.clone());
         // Verify the inclusion proof.
         ensure!(
             verifying_key.verify_batch(N::INCLUSION_FUNCTION_NAME, &batch_verifier_inputs, inclusion_proof),
@@ -635,9 +696,8 @@ impl<N: Network> InclusionAssignment<N> {
     pub fn to_circuit_assignment<A: circuit::Aleo<Network = N>>(&self) -> Result<circuit::Assignment<N::Field>> {
         use circuit::Inject;
 
-        // Ensure the circuit environment is clean.
-        assert_eq!(A::count(), (0, 1, 0, 0, 0));
-        A::reset();
+        // Enter a new circuit environment, which is exited when this function returns.
+        let environment = A::enter();
 
         // Inject the state path as `Mode::Private` (with a global state root as `Mode::Public`).
         let state_path = circuit::StatePath::<A>::new(circuit::Mode::Private, self.state_path.clone());
@@ -667,8 +727,8 @@ impl<N: Network> InclusionAssignment<N> {
         #[cfg(debug_assertions)]
         Stack::log_circuit::<A, _>(&format!("State Path for {}", self.serial_number));
 
-        // Eject the assignment and reset the circuit environment.
-        Ok(A::eject_assignment_and_reset())
+        // Eject the assignment, and exit the circuit environment.
+        Ok(environment.eject_assignment())
     }
 }
 
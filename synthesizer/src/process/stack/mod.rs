@@ -41,6 +41,9 @@ pub use register_types::*;
 mod registers;
 pub use registers::*;
 
+mod traits;
+pub use traits::*;
+
 mod authorize;
 mod deploy;
 mod evaluate;
@@ -51,6 +54,7 @@ use crate::{
     CallOperator,
     Certificate,
     Closure,
+    DeferredCertificate,
     Function,
     Instruction,
     Operand,
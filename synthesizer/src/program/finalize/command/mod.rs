@@ -20,9 +20,21 @@ pub use decrement::*;
 mod finalize;
 pub use finalize::*;
 
+mod get;
+pub use get::*;
+
+mod get_or_init;
+pub use get_or_init::*;
+
 mod increment;
 pub use increment::*;
 
+mod remove;
+pub use remove::*;
+
+mod set;
+pub use set::*;
+
 use crate::{program::Instruction, FinalizeRegisters, ProgramStorage, ProgramStore, Stack};
 use console::network::prelude::*;
 
@@ -34,6 +46,15 @@ pub enum Command<N: Network> {
     Instruction(Instruction<N>),
     /// Increments the value stored at the `first` operand in `mapping` by the amount in the `second` operand.
     Increment(Increment<N>),
+    /// Loads the value stored at the `key` operand in `mapping` into `destination`.
+    Get(Get<N>),
+    /// Loads the value stored at the `key` operand in `mapping` into `destination`,
+    /// initializing the key with the `default` operand if it does not exist.
+    GetOrInit(GetOrInit<N>),
+    /// Sets the value stored at the `key` operand in `mapping` to the `value` operand.
+    Set(Set<N>),
+    /// Removes the key-value pair stored at the `key` operand in `mapping`.
+    Remove(Remove<N>),
 }
 
 impl<N: Network> Command<N> {
@@ -47,10 +68,12 @@ impl<N: Network> Command<N> {
     ) -> Result<()> {
         match self {
             Command::Decrement(decrement) => decrement.evaluate_finalize(stack, store, registers),
-            // TODO (howardwu): Implement support for instructions (consider using a trait for `Registers::load/store`).
-            // Command::Instruction(instruction) => instruction.evaluate_finalize(stack, registers),
-            Command::Instruction(_) => bail!("Instructions in 'finalize' are not supported (yet)."),
+            Command::Instruction(instruction) => instruction.evaluate_finalize(stack, registers),
             Command::Increment(increment) => increment.evaluate_finalize(stack, store, registers),
+            Command::Get(get) => get.evaluate_finalize(stack, store, registers),
+            Command::GetOrInit(get_or_init) => get_or_init.evaluate_finalize(stack, store, registers),
+            Command::Set(set) => set.evaluate_finalize(stack, store, registers),
+            Command::Remove(remove) => remove.evaluate_finalize(stack, store, registers),
         }
     }
 }
@@ -67,8 +90,16 @@ impl<N: Network> FromBytes for Command<N> {
             1 => Ok(Self::Instruction(Instruction::read_le(&mut reader)?)),
             // Read the increment.
             2 => Ok(Self::Increment(Increment::read_le(&mut reader)?)),
+            // Read the get.
+            3 => Ok(Self::Get(Get::read_le(&mut reader)?)),
+            // Read the get.or_init.
+            4 => Ok(Self::GetOrInit(GetOrInit::read_le(&mut reader)?)),
+            // Read the set.
+            5 => Ok(Self::Set(Set::read_le(&mut reader)?)),
+            // Read the remove.
+            6 => Ok(Self::Remove(Remove::read_le(&mut reader)?)),
             // Invalid variant.
-            3.. => Err(error(format!("Invalid command variant: {}", variant))),
+            7.. => Err(error(format!("Invalid command variant: {}", variant))),
         }
     }
 }
@@ -95,6 +126,30 @@ impl<N: Network> ToBytes for Command<N> {
                 // Write the increment.
                 increment.write_le(&mut writer)
             }
+            Self::Get(get) => {
+                // Write the variant.
+                3u8.write_le(&mut writer)?;
+                // Write the get.
+                get.write_le(&mut writer)
+            }
+            Self::GetOrInit(get_or_init) => {
+                // Write the variant.
+                4u8.write_le(&mut writer)?;
+                // Write the get.or_init.
+                get_or_init.write_le(&mut writer)
+            }
+            Self::Set(set) => {
+                // Write the variant.
+                5u8.write_le(&mut writer)?;
+                // Write the set.
+                set.write_le(&mut writer)
+            }
+            Self::Remove(remove) => {
+                // Write the variant.
+                6u8.write_le(&mut writer)?;
+                // Write the remove.
+                remove.write_le(&mut writer)
+            }
         }
     }
 }
@@ -103,8 +158,14 @@ impl<N: Network> Parser for Command<N> {
     /// Parses the string into the command.
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
+        // Note that the order of the parsers is important, as `get.or_init` must be parsed before `get`,
+        // and `remove` must be parsed before the `rem` instruction.
         alt((
             map(Decrement::parse, |decrement| Self::Decrement(decrement)),
+            map(GetOrInit::parse, |get_or_init| Self::GetOrInit(get_or_init)),
+            map(Get::parse, |get| Self::Get(get)),
+            map(Remove::parse, |remove| Self::Remove(remove)),
+            map(Set::parse, |set| Self::Set(set)),
             map(Instruction::parse, |instruction| Self::Instruction(instruction)),
             map(Increment::parse, |increment| Self::Increment(increment)),
         ))(string)
@@ -143,6 +204,10 @@ impl<N: Network> Display for Command<N> {
             Self::Decrement(decrement) => Display::fmt(decrement, f),
             Self::Instruction(instruction) => Display::fmt(instruction, f),
             Self::Increment(increment) => Display::fmt(increment, f),
+            Self::Get(get) => Display::fmt(get, f),
+            Self::GetOrInit(get_or_init) => Display::fmt(get_or_init, f),
+            Self::Set(set) => Display::fmt(set, f),
+            Self::Remove(remove) => Display::fmt(remove, f),
         }
     }
 }
@@ -173,6 +238,30 @@ mod tests {
         let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
         let bytes = command.to_bytes_le().unwrap();
         assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // Get
+        let expected = "get object[r0] into r1;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // GetOrInit
+        let expected = "get.or_init object[r0] r1 into r2;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // Set
+        let expected = "set r0 into object[r1];";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // Remove
+        let expected = "remove object[r0];";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
     }
 
     #[test]
@@ -194,5 +283,29 @@ mod tests {
         let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
         assert_eq!(Command::Increment(Increment::from_str(expected).unwrap()), command);
         assert_eq!(expected, command.to_string());
+
+        // Get
+        let expected = "get object[r0] into r1;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Get(Get::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // GetOrInit
+        let expected = "get.or_init object[r0] r1 into r2;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::GetOrInit(GetOrInit::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // Set
+        let expected = "set r0 into object[r1];";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Set(Set::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // Remove
+        let expected = "remove object[r0];";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Remove(Remove::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
     }
 }
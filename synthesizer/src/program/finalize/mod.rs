@@ -65,7 +65,7 @@ impl<N: Network> Finalize<N> {
 
     /// Returns the finalize input types.
     pub fn input_types(&self) -> Vec<FinalizeType<N>> {
-        self.inputs.iter().map(|input| *input.finalize_type()).collect()
+        self.inputs.iter().map(|input| input.finalize_type().clone()).collect()
     }
 
     /// Returns the finalize commands.
@@ -80,7 +80,7 @@ impl<N: Network> Finalize<N> {
 
     /// Returns the finalize output types.
     pub fn output_types(&self) -> Vec<FinalizeType<N>> {
-        self.outputs.iter().map(|output| *output.finalize_type()).collect()
+        self.outputs.iter().map(|output| output.finalize_type().clone()).collect()
     }
 }
 
@@ -133,6 +133,18 @@ impl<N: Network> Finalize<N> {
             }
         }
 
+        // If the command loads a value from a mapping, ensure the destination register is a locator.
+        match &command {
+            Command::Get(get) => {
+                ensure!(matches!(get.destination(), Register::Locator(..)), "Destination register must be a locator")
+            }
+            Command::GetOrInit(get_or_init) => ensure!(
+                matches!(get_or_init.destination(), Register::Locator(..)),
+                "Destination register must be a locator"
+            ),
+            _ => (),
+        }
+
         // Insert the command.
         self.commands.push(command);
         Ok(())
@@ -26,7 +26,7 @@ pub use operation::*;
 mod bytes;
 mod parse;
 
-use crate::{Registers, Stack};
+use crate::{FinalizeRegisters, Registers, Stack};
 use console::{
     network::{
         prelude::{
@@ -171,6 +171,10 @@ pub enum Instruction<N: Network> {
     Ternary(Ternary<N>),
     /// Performs a bitwise `xor` on `first` and `second`, storing the outcome in `destination`.
     Xor(Xor<N>),
+    /// Casts the operands into the declared type, truncating a literal if it does not fit in the declared type.
+    CastLossy(CastLossy<N>),
+    /// Computes whether `signature` is valid for the given `address` and `message`, storing the outcome in `destination`.
+    SignVerify(SignVerify<N>),
 }
 
 /// Creates a match statement that applies the given operation for each instruction.
@@ -258,6 +262,8 @@ macro_rules! instruction {
             SubWrapped,
             Ternary,
             Xor,
+            CastLossy,
+            SignVerify,
         }}
     };
     // A variant **without** curly braces:
@@ -359,7 +365,13 @@ impl<N: Network> Instruction<N> {
         stack: &Stack<N>,
         registers: &mut Registers<N, A>,
     ) -> Result<()> {
-        instruction!(self, |instruction| instruction.evaluate::<A>(stack, registers))
+        instruction!(self, |instruction| instruction.evaluate(stack, registers))
+    }
+
+    /// Evaluates the instruction in a `finalize` scope.
+    #[inline]
+    pub fn evaluate_finalize(&self, stack: &Stack<N>, registers: &mut FinalizeRegisters<N>) -> Result<()> {
+        instruction!(self, |instruction| instruction.evaluate_finalize(stack, registers))
     }
 
     /// Executes the instruction.
@@ -404,9 +416,17 @@ mod tests {
     fn test_opcodes() {
         // Sanity check the number of instructions is unchanged.
         assert_eq!(
-            56,
+            58,
             Instruction::<CurrentNetwork>::OPCODES.len(),
             "Update me if the number of instructions changes."
         );
+        // Ensure the existing opcode indices are unchanged.
+        assert_eq!(Opcode::Literal("abs"), Instruction::<CurrentNetwork>::OPCODES[0]);
+        assert_eq!(Opcode::Cast("cast"), Instruction::<CurrentNetwork>::OPCODES[8]);
+        assert_eq!(Opcode::Commit("commit.bhp256"), Instruction::<CurrentNetwork>::OPCODES[9]);
+        assert_eq!(Opcode::Literal("xor"), Instruction::<CurrentNetwork>::OPCODES[55]);
+        // Ensure the new instructions are appended after the existing ones.
+        assert_eq!(Opcode::Cast("cast.lossy"), Instruction::<CurrentNetwork>::OPCODES[56]);
+        assert_eq!(Opcode::Sign("sign.verify"), Instruction::<CurrentNetwork>::OPCODES[57]);
     }
 }
@@ -14,7 +14,17 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{CallStack, Opcode, Operand, Registers, Stack};
+use crate::{
+    CallStack,
+    FinalizeRegisters,
+    Opcode,
+    Operand,
+    Registers,
+    RegistersCaller,
+    RegistersLoad,
+    RegistersStore,
+    Stack,
+};
 use console::{
     network::prelude::*,
     program::{Identifier, Locator, Register, RegisterType, Request, ValueType},
@@ -229,6 +239,12 @@ impl<N: Network> Call<N> {
         Ok(())
     }
 
+    /// Evaluates the instruction in a `finalize` scope.
+    #[inline]
+    pub fn evaluate_finalize(&self, _stack: &Stack<N>, _registers: &mut FinalizeRegisters<N>) -> Result<()> {
+        bail!("Forbidden operation: Cannot invoke a 'call' in 'finalize'")
+    }
+
     /// Executes the instruction.
     #[inline]
     pub fn execute<A: circuit::Aleo<Network = N>>(
@@ -282,8 +298,7 @@ impl<N: Network> Call<N> {
             let num_public = A::num_public();
 
             use circuit::Eject;
-            // Eject the existing circuit.
-            let r1cs = A::eject_r1cs_and_reset();
+            // Note: The external function is synthesized in its own circuit environment.
             let (request, response) = {
                 // Eject the circuit inputs.
                 let inputs = inputs.eject_value();
@@ -369,8 +384,6 @@ impl<N: Network> Call<N> {
                     }
                 }
             };
-            // Inject the existing circuit.
-            A::inject_r1cs(r1cs);
 
             use circuit::Inject;
 
@@ -478,7 +491,7 @@ impl<N: Network> Call<N> {
                 bail!("Expected {} outputs, found {}", closure.outputs().len(), self.destinations.len())
             }
             // Return the output register types.
-            Ok(closure.outputs().iter().map(|output| *output.register_type()).collect())
+            Ok(closure.outputs().iter().map(|output| output.register_type().clone()).collect())
         }
         // If the operator is a function, retrieve the function and compute the output types.
         else if let Ok(function) = program.get_function(resource) {
@@ -498,7 +511,7 @@ impl<N: Network> Call<N> {
             function
                 .output_types()
                 .into_iter()
-                .map(|output_type| match (is_external, output_type) {
+                .map(|output_type| match (is_external, &output_type) {
                     // If the output is a record and the function is external, return the external record type.
                     (true, ValueType::Record(record_name)) => Ok(RegisterType::ExternalRecord(Locator::from_str(
                         &format!("{}/{}", program.id(), record_name),
@@ -698,7 +711,7 @@ mod tests {
     use super::*;
     use console::{
         network::Testnet3,
-        program::{Address, Identifier, Literal, U64},
+        program::{Access, Address, Identifier, Literal, U64},
     };
 
     type CurrentNetwork = Testnet3;
@@ -753,9 +766,9 @@ mod tests {
             "call transfer r0.owner r0.gates r0.token_amount into r1 r2 r3",
             CallOperator::from_str("transfer").unwrap(),
             vec![
-                Operand::Register(Register::Member(0, vec![Identifier::from_str("owner").unwrap()])),
-                Operand::Register(Register::Member(0, vec![Identifier::from_str("gates").unwrap()])),
-                Operand::Register(Register::Member(0, vec![Identifier::from_str("token_amount").unwrap()])),
+                Operand::Register(Register::Access(0, vec![Access::Member(Identifier::from_str("owner").unwrap())])),
+                Operand::Register(Register::Access(0, vec![Access::Member(Identifier::from_str("gates").unwrap())])),
+                Operand::Register(Register::Access(0, vec![Access::Member(Identifier::from_str("token_amount").unwrap())])),
             ],
             vec![Register::Locator(1), Register::Locator(2), Register::Locator(3)],
         );
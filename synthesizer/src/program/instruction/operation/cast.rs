@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Opcode, Operand, Registers, Stack};
+use crate::{FinalizeRegisters, Opcode, Operand, Registers, RegistersCaller, RegistersLoad, RegistersStore, Stack};
 use console::{
     network::prelude::*,
     program::{
@@ -37,9 +37,19 @@ use console::{
 
 use indexmap::IndexMap;
 
+/// Casts the operands into the declared type, halting if a literal does not fit in the declared type.
+pub type Cast<N> = CastOperation<N, { CastVariant::Cast as u8 }>;
+/// Casts the operands into the declared type, truncating a literal if it does not fit in the declared type.
+pub type CastLossy<N> = CastOperation<N, { CastVariant::CastLossy as u8 }>;
+
+enum CastVariant {
+    Cast,
+    CastLossy,
+}
+
 /// Casts the operands into the declared type.
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct Cast<N: Network> {
+pub struct CastOperation<N: Network, const VARIANT: u8> {
     /// The operands.
     operands: Vec<Operand<N>>,
     /// The destination register.
@@ -48,11 +58,15 @@ pub struct Cast<N: Network> {
     register_type: RegisterType<N>,
 }
 
-impl<N: Network> Cast<N> {
+impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
     /// Returns the opcode.
     #[inline]
     pub const fn opcode() -> Opcode {
-        Opcode::Cast
+        match VARIANT {
+            0 => Opcode::Cast("cast"),
+            1 => Opcode::Cast("cast.lossy"),
+            _ => panic!("Invalid 'cast' instruction opcode"),
+        }
     }
 
     /// Returns the operands in the operation.
@@ -74,31 +88,49 @@ impl<N: Network> Cast<N> {
     }
 }
 
-impl<N: Network> Cast<N> {
+impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
     /// Evaluates the instruction.
     #[inline]
-    pub fn evaluate<A: circuit::Aleo<Network = N>>(
+    pub fn evaluate(
         &self,
         stack: &Stack<N>,
-        registers: &mut Registers<N, A>,
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N> + RegistersCaller<N>),
     ) -> Result<()> {
         // Load the operands values.
         let inputs: Vec<_> = self.operands.iter().map(|operand| registers.load(stack, operand)).try_collect()?;
 
-        match self.register_type {
-            RegisterType::Plaintext(PlaintextType::Literal(..)) => bail!("Casting to literal is currently unsupported"),
+        match &self.register_type {
+            RegisterType::Plaintext(PlaintextType::Literal(literal_type)) => {
+                // Ensure there is exactly one operand.
+                ensure!(inputs.len() == 1, "Casting to a literal requires exactly one operand");
+
+                // Retrieve the literal from the operand.
+                let literal = match &inputs[0] {
+                    Value::Plaintext(Plaintext::Literal(literal, ..)) => literal,
+                    _ => bail!("Casting to a literal requires a literal operand"),
+                };
+
+                // Cast the literal.
+                let output = match VARIANT {
+                    0 => literal.cast(*literal_type)?,
+                    1 => literal.cast_lossy(*literal_type)?,
+                    _ => bail!("Invalid 'cast' variant: {VARIANT}"),
+                };
+                // Store the literal.
+                registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(output)))
+            }
             RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
                 // Ensure the operands is not empty.
                 ensure!(!inputs.is_empty(), "Casting to a struct requires at least one operand");
 
                 // Retrieve the struct and ensure it is defined in the program.
-                let struct_ = stack.program().get_struct(&struct_name)?;
+                let struct_ = stack.program().get_struct(struct_name)?;
 
                 // Initialize the struct members.
                 let mut members = IndexMap::new();
                 for (member, (member_name, member_type)) in inputs.iter().zip_eq(struct_.members()) {
                     // Compute the register type.
-                    let register_type = RegisterType::Plaintext(*member_type);
+                    let register_type = RegisterType::Plaintext(member_type.clone());
                     // Retrieve the plaintext value from the entry.
                     let plaintext = match member {
                         Value::Plaintext(plaintext) => {
@@ -119,12 +151,47 @@ impl<N: Network> Cast<N> {
                 // Store the struct.
                 registers.store(stack, &self.destination, Value::Plaintext(struct_))
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Ensure the number of operands matches the length of the array.
+                ensure!(
+                    inputs.len() == **array_type.length() as usize,
+                    "Casting to an array requires {} operands, found {}",
+                    array_type.length(),
+                    inputs.len()
+                );
+
+                // Compute the register type of the elements.
+                let register_type = RegisterType::Plaintext(array_type.element_type().clone());
+
+                // Initialize the array elements.
+                let mut elements = Vec::with_capacity(inputs.len());
+                for element in inputs.iter() {
+                    // Retrieve the plaintext value from the element.
+                    let plaintext = match element {
+                        Value::Plaintext(plaintext) => {
+                            // Ensure the element matches the register type.
+                            stack.matches_register_type(&Value::Plaintext(plaintext.clone()), &register_type)?;
+                            // Output the plaintext.
+                            plaintext.clone()
+                        }
+                        // Ensure the array element is not a record.
+                        Value::Record(..) => bail!("Casting a record into an array element is illegal"),
+                    };
+                    // Append the element to the array elements.
+                    elements.push(plaintext);
+                }
+
+                // Construct the array.
+                let array = Plaintext::Array(elements, Default::default());
+                // Store the array.
+                registers.store(stack, &self.destination, Value::Plaintext(array))
+            }
             RegisterType::Record(record_name) => {
                 // Ensure the operands length is at least 2.
                 ensure!(inputs.len() >= 2, "Casting to a record requires at least two operands");
 
                 // Retrieve the struct and ensure it is defined in the program.
-                let record_type = stack.program().get_record(&record_name)?;
+                let record_type = stack.program().get_record(record_name)?;
 
                 // Initialize the record owner.
                 let owner: Owner<N, Plaintext<N>> = match &inputs[0] {
@@ -160,7 +227,7 @@ impl<N: Network> Cast<N> {
                 let mut entries = IndexMap::new();
                 for (entry, (entry_name, entry_type)) in inputs.iter().skip(2).zip_eq(record_type.entries()) {
                     // Compute the register type.
-                    let register_type = RegisterType::from(ValueType::from(*entry_type));
+                    let register_type = RegisterType::from(ValueType::from(entry_type.clone()));
                     // Retrieve the plaintext value from the entry.
                     let plaintext = match entry {
                         Value::Plaintext(plaintext) => {
@@ -198,6 +265,12 @@ impl<N: Network> Cast<N> {
         }
     }
 
+    /// Evaluates the instruction in a `finalize` scope.
+    #[inline]
+    pub fn evaluate_finalize(&self, stack: &Stack<N>, registers: &mut FinalizeRegisters<N>) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
     /// Executes the instruction.
     #[inline]
     pub fn execute<A: circuit::Aleo<Network = N>>(
@@ -211,20 +284,42 @@ impl<N: Network> Cast<N> {
         let inputs: Vec<_> =
             self.operands.iter().map(|operand| registers.load_circuit(stack, operand)).try_collect()?;
 
-        match self.register_type {
-            RegisterType::Plaintext(PlaintextType::Literal(..)) => bail!("Casting to literal is currently unsupported"),
+        match &self.register_type {
+            RegisterType::Plaintext(PlaintextType::Literal(literal_type)) => {
+                // Ensure there is exactly one operand.
+                ensure!(inputs.len() == 1, "Casting to a literal requires exactly one operand");
+
+                // Retrieve the literal from the operand.
+                let literal = match &inputs[0] {
+                    circuit::Value::Plaintext(circuit::Plaintext::Literal(literal, ..)) => literal,
+                    _ => bail!("Casting to a literal requires a literal operand"),
+                };
+
+                // Cast the literal.
+                let output = match VARIANT {
+                    0 => literal.cast(*literal_type)?,
+                    1 => literal.cast_lossy(*literal_type)?,
+                    _ => bail!("Invalid 'cast' variant: {VARIANT}"),
+                };
+                // Store the literal.
+                registers.store_circuit(
+                    stack,
+                    &self.destination,
+                    circuit::Value::Plaintext(circuit::Plaintext::from(output)),
+                )
+            }
             RegisterType::Plaintext(PlaintextType::Struct(struct_)) => {
                 // Ensure the operands is not empty.
                 ensure!(!inputs.is_empty(), "Casting to a struct requires at least one operand");
 
                 // Retrieve the struct and ensure it is defined in the program.
-                let struct_ = stack.program().get_struct(&struct_)?;
+                let struct_ = stack.program().get_struct(struct_)?;
 
                 // Initialize the struct members.
                 let mut members = IndexMap::new();
                 for (member, (member_name, member_type)) in inputs.iter().zip_eq(struct_.members()) {
                     // Compute the register type.
-                    let register_type = RegisterType::Plaintext(*member_type);
+                    let register_type = RegisterType::Plaintext(member_type.clone());
                     // Retrieve the plaintext value from the entry.
                     let plaintext = match member {
                         circuit::Value::Plaintext(plaintext) => {
@@ -250,12 +345,50 @@ impl<N: Network> Cast<N> {
                 // Store the struct.
                 registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(struct_))
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Ensure the number of operands matches the length of the array.
+                ensure!(
+                    inputs.len() == **array_type.length() as usize,
+                    "Casting to an array requires {} operands, found {}",
+                    array_type.length(),
+                    inputs.len()
+                );
+
+                // Compute the register type of the elements.
+                let register_type = RegisterType::Plaintext(array_type.element_type().clone());
+
+                // Initialize the array elements.
+                let mut elements = Vec::with_capacity(inputs.len());
+                for element in inputs.iter() {
+                    // Retrieve the plaintext value from the element.
+                    let plaintext = match element {
+                        circuit::Value::Plaintext(plaintext) => {
+                            // Ensure the element matches the register type.
+                            stack.matches_register_type(
+                                &circuit::Value::Plaintext(plaintext.clone()).eject_value(),
+                                &register_type,
+                            )?;
+                            // Output the plaintext.
+                            plaintext.clone()
+                        }
+                        // Ensure the array element is not a record.
+                        circuit::Value::Record(..) => bail!("Casting a record into an array element is illegal"),
+                    };
+                    // Append the element to the array elements.
+                    elements.push(plaintext);
+                }
+
+                // Construct the array.
+                let array = circuit::Plaintext::Array(elements, Default::default());
+                // Store the array.
+                registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(array))
+            }
             RegisterType::Record(record_name) => {
                 // Ensure the operands length is at least 2.
                 ensure!(inputs.len() >= 2, "Casting to a record requires at least two operands");
 
                 // Retrieve the struct and ensure it is defined in the program.
-                let record_type = stack.program().get_record(&record_name)?;
+                let record_type = stack.program().get_record(record_name)?;
 
                 // Initialize the record owner.
                 let owner: circuit::Owner<A, circuit::Plaintext<A>> = match &inputs[0] {
@@ -298,7 +431,7 @@ impl<N: Network> Cast<N> {
                 let mut entries = IndexMap::new();
                 for (entry, (entry_name, entry_type)) in inputs.iter().skip(2).zip_eq(record_type.entries()) {
                     // Compute the register type.
-                    let register_type = RegisterType::from(ValueType::from(*entry_type));
+                    let register_type = RegisterType::from(ValueType::from(entry_type.clone()));
                     // Retrieve the plaintext value from the entry.
                     let plaintext = match entry {
                         circuit::Value::Plaintext(plaintext) => {
@@ -354,11 +487,22 @@ impl<N: Network> Cast<N> {
         );
 
         // Ensure the output type is defined in the program.
-        match self.register_type {
-            RegisterType::Plaintext(PlaintextType::Literal(..)) => bail!("Casting to literal is currently unsupported"),
+        match &self.register_type {
+            RegisterType::Plaintext(PlaintextType::Literal(literal_type)) => {
+                // Ensure there is exactly one input type.
+                ensure!(input_types.len() == 1, "Casting to a literal requires exactly one operand");
+                // Ensure the input type is a literal that can be cast into the literal type.
+                match &input_types[0] {
+                    RegisterType::Plaintext(PlaintextType::Literal(input_type)) => ensure!(
+                        input_type.is_castable_to(literal_type),
+                        "Cannot cast a '{input_type}' literal into a '{literal_type}'"
+                    ),
+                    input_type => bail!("Casting to a literal requires a literal operand, found '{input_type}'"),
+                }
+            }
             RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
                 // Retrieve the struct and ensure it is defined in the program.
-                let struct_ = stack.program().get_struct(&struct_name)?;
+                let struct_ = stack.program().get_struct(struct_name)?;
                 // Ensure the input types match the struct.
                 for ((_, member_type), input_type) in struct_.members().iter().zip_eq(input_types) {
                     match input_type {
@@ -380,9 +524,26 @@ impl<N: Network> Cast<N> {
                     }
                 }
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Ensure the number of input types matches the length of the array.
+                ensure!(
+                    input_types.len() == **array_type.length() as usize,
+                    "Casting to an array requires {} operands, found {}",
+                    array_type.length(),
+                    input_types.len()
+                );
+                // Ensure the input types match the element type.
+                for input_type in input_types {
+                    ensure!(
+                        *input_type == RegisterType::Plaintext(array_type.element_type().clone()),
+                        "Array '{array_type}' element type mismatch: expected '{}', found '{input_type}'",
+                        array_type.element_type()
+                    )
+                }
+            }
             RegisterType::Record(record_name) => {
                 // Retrieve the record type and ensure is defined in the program.
-                let record = stack.program().get_record(&record_name)?;
+                let record = stack.program().get_record(record_name)?;
 
                 // Ensure the input types length is at least 2.
                 ensure!(input_types.len() >= 2, "Casting to a record requires at least two operands");
@@ -427,11 +588,11 @@ impl<N: Network> Cast<N> {
             }
         }
 
-        Ok(vec![self.register_type])
+        Ok(vec![self.register_type.clone()])
     }
 }
 
-impl<N: Network> Parser for Cast<N> {
+impl<N: Network, const VARIANT: u8> Parser for CastOperation<N, VARIANT> {
     /// Parses a string into an operation.
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
@@ -471,16 +632,14 @@ impl<N: Network> Parser for Cast<N> {
         };
         match operands.len() <= max_operands {
             true => Ok((string, Self { operands, destination, register_type })),
-            false => {
-                map_res(fail, |_: ParserResult<Self>| Err(error("Failed to parse 'cast' opcode: too many operands")))(
-                    string,
-                )
-            }
+            false => map_res(fail, |_: ParserResult<Self>| {
+                Err(error(format!("Failed to parse '{}' opcode: too many operands", Self::opcode())))
+            })(string),
         }
     }
 }
 
-impl<N: Network> FromStr for Cast<N> {
+impl<N: Network, const VARIANT: u8> FromStr for CastOperation<N, VARIANT> {
     type Err = Error;
 
     /// Parses a string into an operation.
@@ -498,14 +657,14 @@ impl<N: Network> FromStr for Cast<N> {
     }
 }
 
-impl<N: Network> Debug for Cast<N> {
+impl<N: Network, const VARIANT: u8> Debug for CastOperation<N, VARIANT> {
     /// Prints the operation as a string.
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         Display::fmt(self, f)
     }
 }
 
-impl<N: Network> Display for Cast<N> {
+impl<N: Network, const VARIANT: u8> Display for CastOperation<N, VARIANT> {
     /// Prints the operation to a string.
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         // Ensure the number of operands is within the bounds.
@@ -525,7 +684,7 @@ impl<N: Network> Display for Cast<N> {
     }
 }
 
-impl<N: Network> FromBytes for Cast<N> {
+impl<N: Network, const VARIANT: u8> FromBytes for CastOperation<N, VARIANT> {
     /// Reads the operation from a buffer.
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         // Read the number of operands.
@@ -565,7 +724,7 @@ impl<N: Network> FromBytes for Cast<N> {
     }
 }
 
-impl<N: Network> ToBytes for Cast<N> {
+impl<N: Network, const VARIANT: u8> ToBytes for CastOperation<N, VARIANT> {
     /// Writes the operation to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Ensure the number of operands is within the bounds.
@@ -592,10 +751,125 @@ impl<N: Network> ToBytes for Cast<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use console::{network::Testnet3, program::Identifier};
+    use crate::{
+        program::instruction::operation::test_helpers::{sample_registers, CurrentAleo},
+        ProvingKey,
+        VerifyingKey,
+    };
+    use console::{
+        network::Testnet3,
+        program::{Access, Identifier},
+    };
+
+    use std::collections::HashMap;
 
     type CurrentNetwork = Testnet3;
 
+    /// Samples the stack for a program that casts `r0` into `r1`.
+    fn sample_stack(
+        opcode: Opcode,
+        from_type: LiteralType,
+        to_type: LiteralType,
+        cache: &mut HashMap<String, (ProvingKey<CurrentNetwork>, VerifyingKey<CurrentNetwork>)>,
+    ) -> Result<Stack<CurrentNetwork>> {
+        crate::program::instruction::operation::test_helpers::sample_stack(
+            &format!(
+                "input r0 as {from_type}.private;
+                {opcode} r0 into r1 as {to_type};
+                output r1 as {to_type}.private;"
+            ),
+            cache,
+        )
+    }
+
+    /// Checks that casting `input` into `to_type` with the given operation returns `expected` in console and circuit.
+    fn check_cast_literal<const VARIANT: u8>(
+        input: &str,
+        to_type: LiteralType,
+        expected: Option<&str>,
+        cache: &mut HashMap<String, (ProvingKey<CurrentNetwork>, VerifyingKey<CurrentNetwork>)>,
+    ) {
+        use circuit::Eject;
+
+        let input = Literal::<CurrentNetwork>::from_str(input).unwrap();
+        let opcode = CastOperation::<CurrentNetwork, VARIANT>::opcode();
+
+        // Initialize the stack and the operation.
+        let stack = sample_stack(opcode, input.to_type(), to_type, cache).unwrap();
+        let operation =
+            CastOperation::<CurrentNetwork, VARIANT>::from_str(&format!("{opcode} r0 into r1 as {to_type}")).unwrap();
+        let destination = Operand::Register(Register::Locator(1));
+
+        // Initialize the registers.
+        let mut registers = sample_registers(&stack, &[Value::Plaintext(Plaintext::from(input))]).unwrap();
+
+        match expected {
+            Some(expected) => {
+                let expected = Literal::from_str(expected).unwrap();
+                // Ensure the console output is correct.
+                operation.evaluate(&stack, &mut registers).unwrap();
+                assert_eq!(registers.load_literal(&stack, &destination).unwrap(), expected);
+                // Ensure the circuit output is correct.
+                operation.execute::<CurrentAleo>(&stack, &mut registers).unwrap();
+                assert_eq!(registers.load_literal_circuit(&stack, &destination).unwrap().eject_value(), expected);
+                assert!(<CurrentAleo as circuit::Environment>::is_satisfied());
+            }
+            None => {
+                // Ensure the console cast fails.
+                assert!(operation.evaluate(&stack, &mut registers).is_err());
+                // Ensure the circuit is not satisfied.
+                operation.execute::<CurrentAleo>(&stack, &mut registers).unwrap();
+                assert!(!<CurrentAleo as circuit::Environment>::is_satisfied());
+            }
+        }
+        <CurrentAleo as circuit::Environment>::reset();
+    }
+
+    #[test]
+    fn test_cast_literals() {
+        // Prepare the key cache.
+        let cache = &mut Default::default();
+
+        check_cast_literal::<0>("200u8", LiteralType::I16, Some("200i16"), cache);
+        check_cast_literal::<0>("300u16", LiteralType::U8, None, cache);
+        check_cast_literal::<0>("-1i8", LiteralType::Field, None, cache);
+        check_cast_literal::<0>("true", LiteralType::U32, Some("1u32"), cache);
+        check_cast_literal::<1>("300u16", LiteralType::U8, Some("44u8"), cache);
+        check_cast_literal::<1>("-1i8", LiteralType::U16, Some("65535u16"), cache);
+        check_cast_literal::<1>("-1i8", LiteralType::Field, Some("255field"), cache);
+    }
+
+    #[test]
+    fn test_cast_literals_type_check() {
+        // Prepare the key cache.
+        let cache = &mut Default::default();
+
+        // Ensure supported casts type check.
+        assert!(sample_stack(Opcode::Cast("cast"), LiteralType::U8, LiteralType::I128, cache).is_ok());
+        assert!(sample_stack(Opcode::Cast("cast.lossy"), LiteralType::Field, LiteralType::U64, cache).is_ok());
+        assert!(sample_stack(Opcode::Cast("cast"), LiteralType::Address, LiteralType::Field, cache).is_ok());
+        // Ensure unsupported casts are rejected.
+        assert!(sample_stack(Opcode::Cast("cast"), LiteralType::Address, LiteralType::U8, cache).is_err());
+        assert!(sample_stack(Opcode::Cast("cast.lossy"), LiteralType::Scalar, LiteralType::Field, cache).is_err());
+    }
+
+    #[test]
+    fn test_parse_cast_into_literal() {
+        let (string, cast) = Cast::<CurrentNetwork>::parse("cast r0 into r1 as u8").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(cast.operands, vec![Operand::Register(Register::Locator(0))], "The operands are incorrect");
+        assert_eq!(cast.destination, Register::Locator(1), "The destination register is incorrect");
+        assert_eq!(cast.register_type, RegisterType::Plaintext(PlaintextType::Literal(LiteralType::U8)));
+
+        let (string, cast) = CastLossy::<CurrentNetwork>::parse("cast.lossy r0 into r1 as u8").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(cast.to_string(), "cast.lossy r0 into r1 as u8");
+
+        // Ensure the opcodes are not interchangeable.
+        assert!(Cast::<CurrentNetwork>::parse("cast.lossy r0 into r1 as u8").is_err());
+        assert!(CastLossy::<CurrentNetwork>::parse("cast r0 into r1 as u8").is_err());
+    }
+
     #[test]
     fn test_parse() {
         let (string, cast) =
@@ -604,17 +878,17 @@ mod tests {
         assert_eq!(cast.operands.len(), 3, "The number of operands is incorrect");
         assert_eq!(
             cast.operands[0],
-            Operand::Register(Register::Member(0, vec![Identifier::from_str("owner").unwrap()])),
+            Operand::Register(Register::Access(0, vec![Access::Member(Identifier::from_str("owner").unwrap())])),
             "The first operand is incorrect"
         );
         assert_eq!(
             cast.operands[1],
-            Operand::Register(Register::Member(0, vec![Identifier::from_str("gates").unwrap()])),
+            Operand::Register(Register::Access(0, vec![Access::Member(Identifier::from_str("gates").unwrap())])),
             "The second operand is incorrect"
         );
         assert_eq!(
             cast.operands[2],
-            Operand::Register(Register::Member(0, vec![Identifier::from_str("token_amount").unwrap()])),
+            Operand::Register(Register::Access(0, vec![Access::Member(Identifier::from_str("token_amount").unwrap())])),
             "The third operand is incorrect"
         );
         assert_eq!(cast.destination, Register::Locator(1), "The destination register is incorrect");
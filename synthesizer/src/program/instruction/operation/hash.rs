@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Opcode, Operand, Registers, Stack};
+use crate::{FinalizeRegisters, Opcode, Operand, Registers, RegistersLoad, RegistersStore, Stack};
 use console::{
     network::prelude::*,
     program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
@@ -53,13 +53,21 @@ enum Hasher {
     PSD8,
 }
 
+/// The byte that precedes a declared destination type in the encoding of a hash instruction.
+/// Note: This byte is not a valid operand variant, so instructions without a declared type
+/// keep their original encoding.
+const DESTINATION_TYPE_MARKER: u8 = u8::MAX;
+
 /// Hashes the operand into the declared type.
+/// If no type is declared, the operand is hashed into a field element.
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct HashInstruction<N: Network, const VARIANT: u8> {
     /// The operand as `input`.
     operands: Vec<Operand<N>>,
     /// The destination register.
     destination: Register<N>,
+    /// The destination register type, if it is declared.
+    destination_type: Option<LiteralType>,
 }
 
 impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
@@ -94,37 +102,79 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
     pub fn destinations(&self) -> Vec<Register<N>> {
         vec![self.destination.clone()]
     }
+
+    /// Returns the destination register type.
+    /// If no type is declared, the operand is hashed into a field element.
+    #[inline]
+    pub fn destination_type(&self) -> LiteralType {
+        self.destination_type.unwrap_or(LiteralType::Field)
+    }
+
+    /// Returns `true` if the hash can be output as the given literal type.
+    /// Poseidon hashes may be output as a field element, group element, scalar, or integer.
+    /// The remaining hashes may be output as a field element or integer.
+    /// Integers are the lower bits of the field element output.
+    #[inline]
+    fn is_valid_destination_type(destination_type: LiteralType) -> bool {
+        match destination_type {
+            LiteralType::Field => true,
+            LiteralType::Group | LiteralType::Scalar => matches!(VARIANT, 6..=8),
+            _ => destination_type.is_integer(),
+        }
+    }
 }
 
 impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
     /// Evaluates the instruction.
     #[inline]
-    pub fn evaluate<A: circuit::Aleo<Network = N>>(
+    pub fn evaluate(
         &self,
         stack: &Stack<N>,
-        registers: &mut Registers<N, A>,
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
     ) -> Result<()> {
         // Ensure the number of operands is correct.
         if self.operands.len() != 1 {
             bail!("Instruction '{}' expects 1 operands, found {} operands", Self::opcode(), self.operands.len())
         }
+        // Ensure the destination type is valid.
+        if !Self::is_valid_destination_type(self.destination_type()) {
+            bail!("Instruction '{}' cannot output a '{}'", Self::opcode(), self.destination_type())
+        }
         // Load the operand.
         let input = registers.load(stack, &self.operands[0])?;
         // Hash the input.
-        let output = match VARIANT {
-            0 => N::hash_bhp256(&input.to_bits_le())?,
-            1 => N::hash_bhp512(&input.to_bits_le())?,
-            2 => N::hash_bhp768(&input.to_bits_le())?,
-            3 => N::hash_bhp1024(&input.to_bits_le())?,
-            4 => N::hash_ped64(&input.to_bits_le())?,
-            5 => N::hash_ped128(&input.to_bits_le())?,
-            6 => N::hash_psd2(&input.to_fields()?)?,
-            7 => N::hash_psd4(&input.to_fields()?)?,
-            8 => N::hash_psd8(&input.to_fields()?)?,
-            _ => bail!("Invalid 'hash' variant: {VARIANT}"),
+        let output = match (VARIANT, self.destination_type()) {
+            (6, LiteralType::Group) => Literal::Group(N::hash_to_group_psd2(&input.to_fields()?)?),
+            (7, LiteralType::Group) => Literal::Group(N::hash_to_group_psd4(&input.to_fields()?)?),
+            (8, LiteralType::Group) => Literal::Group(N::hash_to_group_psd8(&input.to_fields()?)?),
+            (6, LiteralType::Scalar) => Literal::Scalar(N::hash_to_scalar_psd2(&input.to_fields()?)?),
+            (7, LiteralType::Scalar) => Literal::Scalar(N::hash_to_scalar_psd4(&input.to_fields()?)?),
+            (8, LiteralType::Scalar) => Literal::Scalar(N::hash_to_scalar_psd8(&input.to_fields()?)?),
+            (_, destination_type) => {
+                let output = match VARIANT {
+                    0 => N::hash_bhp256(&input.to_bits_le())?,
+                    1 => N::hash_bhp512(&input.to_bits_le())?,
+                    2 => N::hash_bhp768(&input.to_bits_le())?,
+                    3 => N::hash_bhp1024(&input.to_bits_le())?,
+                    4 => N::hash_ped64(&input.to_bits_le())?,
+                    5 => N::hash_ped128(&input.to_bits_le())?,
+                    6 => N::hash_psd2(&input.to_fields()?)?,
+                    7 => N::hash_psd4(&input.to_fields()?)?,
+                    8 => N::hash_psd8(&input.to_fields()?)?,
+                    _ => bail!("Invalid 'hash' variant: {VARIANT}"),
+                };
+                // Truncate the field element into the destination type.
+                Literal::Field(output).cast_lossy(destination_type)?
+            }
         };
         // Store the output.
-        registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(Literal::Field(output))))
+        registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(output)))
+    }
+
+    /// Evaluates the instruction in a `finalize` scope.
+    #[inline]
+    pub fn evaluate_finalize(&self, stack: &Stack<N>, registers: &mut FinalizeRegisters<N>) -> Result<()> {
+        self.evaluate(stack, registers)
     }
 
     /// Executes the instruction.
@@ -140,24 +190,39 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         if self.operands.len() != 1 {
             bail!("Instruction '{}' expects 1 operands, found {} operands", Self::opcode(), self.operands.len())
         }
+        // Ensure the destination type is valid.
+        if !Self::is_valid_destination_type(self.destination_type()) {
+            bail!("Instruction '{}' cannot output a '{}'", Self::opcode(), self.destination_type())
+        }
         // Load the operand.
         let input = registers.load_circuit(stack, &self.operands[0])?;
         // Hash the input.
-        let output = match VARIANT {
-            0 => A::hash_bhp256(&input.to_bits_le()),
-            1 => A::hash_bhp512(&input.to_bits_le()),
-            2 => A::hash_bhp768(&input.to_bits_le()),
-            3 => A::hash_bhp1024(&input.to_bits_le()),
-            4 => A::hash_ped64(&input.to_bits_le()),
-            5 => A::hash_ped128(&input.to_bits_le()),
-            6 => A::hash_psd2(&input.to_fields()),
-            7 => A::hash_psd4(&input.to_fields()),
-            8 => A::hash_psd8(&input.to_fields()),
-            _ => bail!("Invalid 'hash' variant: {VARIANT}"),
+        let output = match (VARIANT, self.destination_type()) {
+            (6, LiteralType::Group) => circuit::Literal::Group(A::hash_to_group_psd2(&input.to_fields())),
+            (7, LiteralType::Group) => circuit::Literal::Group(A::hash_to_group_psd4(&input.to_fields())),
+            (8, LiteralType::Group) => circuit::Literal::Group(A::hash_to_group_psd8(&input.to_fields())),
+            (6, LiteralType::Scalar) => circuit::Literal::Scalar(A::hash_to_scalar_psd2(&input.to_fields())),
+            (7, LiteralType::Scalar) => circuit::Literal::Scalar(A::hash_to_scalar_psd4(&input.to_fields())),
+            (8, LiteralType::Scalar) => circuit::Literal::Scalar(A::hash_to_scalar_psd8(&input.to_fields())),
+            (_, destination_type) => {
+                let output = match VARIANT {
+                    0 => A::hash_bhp256(&input.to_bits_le()),
+                    1 => A::hash_bhp512(&input.to_bits_le()),
+                    2 => A::hash_bhp768(&input.to_bits_le()),
+                    3 => A::hash_bhp1024(&input.to_bits_le()),
+                    4 => A::hash_ped64(&input.to_bits_le()),
+                    5 => A::hash_ped128(&input.to_bits_le()),
+                    6 => A::hash_psd2(&input.to_fields()),
+                    7 => A::hash_psd4(&input.to_fields()),
+                    8 => A::hash_psd8(&input.to_fields()),
+                    _ => bail!("Invalid 'hash' variant: {VARIANT}"),
+                };
+                // Truncate the field element into the destination type.
+                circuit::Literal::Field(output).cast_lossy(destination_type)?
+            }
         };
         // Convert the output to a stack value.
-        let output =
-            circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::Field(output), Default::default()));
+        let output = circuit::Value::Plaintext(circuit::Plaintext::Literal(output, Default::default()));
         // Store the output.
         registers.store_circuit(stack, &self.destination, output)
     }
@@ -176,9 +241,14 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
 
         // TODO (howardwu): If the operation is Pedersen, check that it is within the number of bits.
 
+        // Ensure the destination type is valid.
+        if !Self::is_valid_destination_type(self.destination_type()) {
+            bail!("Instruction '{}' cannot output a '{}'", Self::opcode(), self.destination_type())
+        }
+
         match VARIANT {
             0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 => {
-                Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field))])
+                Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(self.destination_type()))])
             }
             _ => bail!("Invalid 'hash' variant: {VARIANT}"),
         }
@@ -203,8 +273,12 @@ impl<N: Network, const VARIANT: u8> Parser for HashInstruction<N, VARIANT> {
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the destination register from the string.
         let (string, destination) = Register::parse(string)?;
-
-        Ok((string, Self { operands: vec![operand], destination }))
+        // Parse the destination register type from the string, if it is declared.
+        let (string, destination_type) = opt(map(
+            pair(pair(Sanitizer::parse_whitespaces, pair(tag("as"), Sanitizer::parse_whitespaces)), LiteralType::parse),
+            |(_, destination_type)| destination_type,
+        ))(string)?;
+        Ok((string, Self { operands: vec![operand], destination, destination_type }))
     }
 }
 
@@ -244,19 +318,30 @@ impl<N: Network, const VARIANT: u8> Display for HashInstruction<N, VARIANT> {
         // Print the operation.
         write!(f, "{} ", Self::opcode())?;
         self.operands.iter().try_for_each(|operand| write!(f, "{} ", operand))?;
-        write!(f, "into {}", self.destination)
+        write!(f, "into {}", self.destination)?;
+        // Print the destination register type, if it is declared.
+        match self.destination_type {
+            Some(destination_type) => write!(f, " as {destination_type}"),
+            None => Ok(()),
+        }
     }
 }
 
 impl<N: Network, const VARIANT: u8> FromBytes for HashInstruction<N, VARIANT> {
     /// Reads the operation from a buffer.
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
-        // Read the operand.
-        let operands = vec![Operand::read_le(&mut reader)?];
+        // Read the first byte, which is either the destination type marker or the operand variant.
+        let first_byte = u8::read_le(&mut reader)?;
+        // Read the destination register type, if it is declared, and the operand.
+        let (destination_type, operand) = match first_byte {
+            DESTINATION_TYPE_MARKER => (Some(LiteralType::read_le(&mut reader)?), Operand::read_le(&mut reader)?),
+            _ => (None, Operand::read_le([first_byte].as_slice().chain(&mut reader))?),
+        };
+        let operands = vec![operand];
         // Read the destination register.
         let destination = Register::read_le(&mut reader)?;
         // Return the operation.
-        Ok(Self { operands, destination })
+        Ok(Self { operands, destination, destination_type })
     }
 }
 
@@ -267,6 +352,11 @@ impl<N: Network, const VARIANT: u8> ToBytes for HashInstruction<N, VARIANT> {
         if self.operands.len() != 1 {
             return Err(error(format!("The number of operands must be 1, found {}", self.operands.len())));
         }
+        // Write the destination register type, if it is declared.
+        if let Some(destination_type) = self.destination_type {
+            DESTINATION_TYPE_MARKER.write_le(&mut writer)?;
+            destination_type.write_le(&mut writer)?;
+        }
         // Write the operand.
         self.operands[0].write_le(&mut writer)?;
         // Write the destination register.
@@ -277,10 +367,106 @@ impl<N: Network, const VARIANT: u8> ToBytes for HashInstruction<N, VARIANT> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{
+        program::instruction::operation::test_helpers::{sample_registers, CurrentAleo},
+        ProvingKey,
+        VerifyingKey,
+    };
     use console::network::Testnet3;
 
+    use std::collections::HashMap;
+
     type CurrentNetwork = Testnet3;
 
+    /// Samples the stack for a program that hashes `r0` into `r1`.
+    fn sample_stack(
+        opcode: Opcode,
+        input_type: LiteralType,
+        destination_type: LiteralType,
+        cache: &mut HashMap<String, (ProvingKey<CurrentNetwork>, VerifyingKey<CurrentNetwork>)>,
+    ) -> Result<Stack<CurrentNetwork>> {
+        crate::program::instruction::operation::test_helpers::sample_stack(
+            &format!(
+                "input r0 as {input_type}.private;
+                {opcode} r0 into r1 as {destination_type};
+                output r1 as {destination_type}.private;"
+            ),
+            cache,
+        )
+    }
+
+    /// Checks that hashing `input` into `destination_type` matches in console and circuit, and returns the output.
+    fn check_hash<const VARIANT: u8>(
+        input: &str,
+        destination_type: LiteralType,
+        cache: &mut HashMap<String, (ProvingKey<CurrentNetwork>, VerifyingKey<CurrentNetwork>)>,
+    ) -> Literal<CurrentNetwork> {
+        use circuit::Eject;
+
+        let input = Literal::<CurrentNetwork>::from_str(input).unwrap();
+        let opcode = HashInstruction::<CurrentNetwork, VARIANT>::opcode();
+
+        // Initialize the stack and the operation.
+        let stack = sample_stack(opcode, input.to_type(), destination_type, cache).unwrap();
+        let operation =
+            HashInstruction::<CurrentNetwork, VARIANT>::from_str(&format!("{opcode} r0 into r1 as {destination_type}"))
+                .unwrap();
+        let destination = Operand::Register(Register::Locator(1));
+
+        // Initialize the registers.
+        let mut registers = sample_registers(&stack, &[Value::Plaintext(Plaintext::from(input))]).unwrap();
+
+        // Compute the console output.
+        operation.evaluate(&stack, &mut registers).unwrap();
+        let expected = registers.load_literal(&stack, &destination).unwrap();
+        assert_eq!(expected.to_type(), destination_type);
+        // Ensure the circuit output matches.
+        operation.execute::<CurrentAleo>(&stack, &mut registers).unwrap();
+        assert_eq!(registers.load_literal_circuit(&stack, &destination).unwrap().eject_value(), expected);
+        assert!(<CurrentAleo as circuit::Environment>::is_satisfied());
+        <CurrentAleo as circuit::Environment>::reset();
+
+        expected
+    }
+
+    #[test]
+    fn test_hash_into_types() {
+        // Prepare the key cache.
+        let cache = &mut Default::default();
+
+        let input = Value::<CurrentNetwork>::from_str("1234field").unwrap();
+
+        // Ensure the Poseidon hashes output the group element and scalar from the network.
+        let expected = CurrentNetwork::hash_to_group_psd2(&input.to_fields().unwrap()).unwrap();
+        assert_eq!(check_hash::<6>("1234field", LiteralType::Group, cache), Literal::Group(expected));
+        let expected = CurrentNetwork::hash_to_scalar_psd4(&input.to_fields().unwrap()).unwrap();
+        assert_eq!(check_hash::<7>("1234field", LiteralType::Scalar, cache), Literal::Scalar(expected));
+
+        // Ensure the integer outputs are the lower bits of the field element.
+        let output = Literal::Field(CurrentNetwork::hash_psd8(&input.to_fields().unwrap()).unwrap());
+        assert_eq!(check_hash::<8>("1234field", LiteralType::U64, cache), output.cast_lossy(LiteralType::U64).unwrap());
+        let input = Value::<CurrentNetwork>::from_str("7u8").unwrap();
+        let output = Literal::Field(CurrentNetwork::hash_bhp256(&input.to_bits_le()).unwrap());
+        assert_eq!(check_hash::<0>("7u8", LiteralType::Field, cache), output);
+        assert_eq!(check_hash::<0>("7u8", LiteralType::I32, cache), output.cast_lossy(LiteralType::I32).unwrap());
+    }
+
+    #[test]
+    fn test_hash_type_check() {
+        // Prepare the key cache.
+        let cache = &mut Default::default();
+
+        // Ensure supported destination types type check.
+        assert!(sample_stack(Opcode::Hash("hash.psd2"), LiteralType::Field, LiteralType::Scalar, cache).is_ok());
+        assert!(sample_stack(Opcode::Hash("hash.psd8"), LiteralType::U8, LiteralType::Group, cache).is_ok());
+        assert!(sample_stack(Opcode::Hash("hash.bhp512"), LiteralType::Field, LiteralType::U128, cache).is_ok());
+        // Ensure unsupported destination types are rejected.
+        assert!(sample_stack(Opcode::Hash("hash.bhp256"), LiteralType::Field, LiteralType::Group, cache).is_err());
+        assert!(sample_stack(Opcode::Hash("hash.ped64"), LiteralType::U8, LiteralType::Scalar, cache).is_err());
+        assert!(sample_stack(Opcode::Hash("hash.psd2"), LiteralType::Field, LiteralType::Boolean, cache).is_err());
+        assert!(sample_stack(Opcode::Hash("hash.psd2"), LiteralType::Field, LiteralType::Address, cache).is_err());
+    }
+
     #[test]
     fn test_parse() {
         let (string, hash) = HashBHP512::<CurrentNetwork>::parse("hash.bhp512 r0 into r1").unwrap();
@@ -288,5 +474,31 @@ mod tests {
         assert_eq!(hash.operands.len(), 1, "The number of operands is incorrect");
         assert_eq!(hash.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
         assert_eq!(hash.destination, Register::Locator(1), "The destination register is incorrect");
+        assert_eq!(hash.destination_type, None, "The destination type is incorrect");
+        assert_eq!(hash.destination_type(), LiteralType::Field, "The destination type is incorrect");
+
+        let (string, hash) = HashPSD2::<CurrentNetwork>::parse("hash.psd2 r0 into r1 as scalar").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(hash.destination, Register::Locator(1), "The destination register is incorrect");
+        assert_eq!(hash.destination_type, Some(LiteralType::Scalar), "The destination type is incorrect");
+        assert_eq!(hash.to_string(), "hash.psd2 r0 into r1 as scalar");
+        assert_eq!(hash, HashPSD2::read_le(&hash.to_bytes_le().unwrap()[..]).unwrap());
+    }
+
+    #[test]
+    fn test_undeclared_destination_type_is_unchanged() {
+        let hash = HashBHP512::<CurrentNetwork>::from_str("hash.bhp512 r0 into r1").unwrap();
+        // Ensure the string is unchanged.
+        assert_eq!(hash.to_string(), "hash.bhp512 r0 into r1");
+        // Ensure the bytes are the operand followed by the destination register.
+        let mut expected = Operand::<CurrentNetwork>::Register(Register::Locator(0)).to_bytes_le().unwrap();
+        expected.extend(Register::<CurrentNetwork>::Locator(1).to_bytes_le().unwrap());
+        assert_eq!(hash.to_bytes_le().unwrap(), expected);
+        assert_eq!(hash, HashBHP512::read_le(&expected[..]).unwrap());
+
+        // Ensure a declared field type is preserved.
+        let hash = HashBHP512::<CurrentNetwork>::from_str("hash.bhp512 r0 into r1 as field").unwrap();
+        assert_eq!(hash.to_string(), "hash.bhp512 r0 into r1 as field");
+        assert_eq!(hash, HashBHP512::read_le(&hash.to_bytes_le().unwrap()[..]).unwrap());
     }
 }
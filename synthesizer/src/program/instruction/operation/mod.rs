@@ -35,6 +35,9 @@ pub use is::*;
 mod literals;
 pub use literals::*;
 
+mod sign_verify;
+pub use sign_verify::*;
+
 mod macros;
 
 use crate::Opcode;
@@ -769,3 +772,68 @@ crate::operation!(
         (U128, U128) => U128,
     }
 );
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use crate::{
+        Authorization,
+        CallStack,
+        Process,
+        Program,
+        ProvingKey,
+        Registers,
+        RegistersStore,
+        Stack,
+        VerifyingKey,
+    };
+    use console::{
+        network::{prelude::*, Testnet3},
+        program::{Identifier, Register, Value},
+    };
+
+    use std::collections::HashMap;
+
+    pub(crate) type CurrentNetwork = Testnet3;
+    pub(crate) type CurrentAleo = circuit::AleoV0;
+
+    /// Samples the stack for a program with a `run` function of the given statements.
+    /// Note: Do not replicate this for real program use, it is insecure.
+    pub(crate) fn sample_stack(
+        statements: &str,
+        cache: &mut HashMap<String, (ProvingKey<CurrentNetwork>, VerifyingKey<CurrentNetwork>)>,
+    ) -> Result<Stack<CurrentNetwork>> {
+        // Initialize the program.
+        let program = Program::from_str(&format!(
+            "program testing.aleo;
+            function run:
+                {statements}"
+        ))?;
+
+        // Initialize the stack.
+        Stack::new(&Process::load_with_cache(cache)?, &program)
+    }
+
+    /// Samples the registers for the `run` function, storing the given values from `r0` onwards as private inputs.
+    /// Note: Do not replicate this for real program use, it is insecure.
+    pub(crate) fn sample_registers(
+        stack: &Stack<CurrentNetwork>,
+        values: &[Value<CurrentNetwork>],
+    ) -> Result<Registers<CurrentNetwork, CurrentAleo>> {
+        use circuit::Inject;
+
+        // Initialize the registers.
+        let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+            CallStack::evaluate(Authorization::new(&[]))?,
+            stack.get_register_types(&Identifier::from_str("run")?)?.clone(),
+        );
+
+        // Store the values in the console and circuit registers.
+        for (index, value) in values.iter().enumerate() {
+            let register = Register::Locator(index as u64);
+            registers.store(stack, &register, value.clone())?;
+            registers.store_circuit(stack, &register, circuit::Value::new(circuit::Mode::Private, value.clone()))?;
+        }
+
+        Ok(registers)
+    }
+}
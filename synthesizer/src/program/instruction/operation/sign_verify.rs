@@ -0,0 +1,405 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{FinalizeRegisters, Opcode, Operand, Registers, RegistersLoad, RegistersStore, Stack};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::Boolean,
+};
+
+/// Computes whether `signature` is valid for the given `address` and `message`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct SignVerify<N: Network> {
+    /// The operands as `(signature, address, message)`.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network> SignVerify<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Sign("sign.verify")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that the operands is exactly three inputs.
+        debug_assert!(self.operands.len() == 3, "Instruction '{}' must have three operands", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+}
+
+impl<N: Network> SignVerify<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &Stack<N>,
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the signature.
+        let signature = match registers.load(stack, &self.operands[0])? {
+            Value::Plaintext(Plaintext::Literal(Literal::Signature(signature), ..)) => signature,
+            _ => bail!("Expected the first operand of '{}' to be a signature", Self::opcode()),
+        };
+        // Retrieve the address.
+        let address = match registers.load(stack, &self.operands[1])? {
+            Value::Plaintext(Plaintext::Literal(Literal::Address(address), ..)) => address,
+            _ => bail!("Expected the second operand of '{}' to be an address", Self::opcode()),
+        };
+        // Retrieve the message.
+        let message = match registers.load(stack, &self.operands[2])? {
+            Value::Plaintext(plaintext) => plaintext.to_fields()?,
+            Value::Record(..) => bail!("Expected the third operand of '{}' to be a plaintext", Self::opcode()),
+        };
+
+        // Verify the signature.
+        let output = Literal::Boolean(Boolean::new(signature.verify(&address, &message)));
+        // Store the output.
+        registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(output)))
+    }
+
+    /// Evaluates the instruction in a `finalize` scope.
+    #[inline]
+    pub fn evaluate_finalize(&self, stack: &Stack<N>, registers: &mut FinalizeRegisters<N>) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &Stack<N>,
+        registers: &mut Registers<N, A>,
+    ) -> Result<()> {
+        use circuit::ToFields;
+
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the signature.
+        let signature = match registers.load_circuit(stack, &self.operands[0])? {
+            circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::Signature(signature), ..)) => {
+                signature
+            }
+            _ => bail!("Expected the first operand of '{}' to be a signature", Self::opcode()),
+        };
+        // Retrieve the address.
+        let address = match registers.load_circuit(stack, &self.operands[1])? {
+            circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::Address(address), ..)) => address,
+            _ => bail!("Expected the second operand of '{}' to be an address", Self::opcode()),
+        };
+        // Retrieve the message.
+        let message = match registers.load_circuit(stack, &self.operands[2])? {
+            circuit::Value::Plaintext(plaintext) => plaintext.to_fields(),
+            circuit::Value::Record(..) => {
+                bail!("Expected the third operand of '{}' to be a plaintext", Self::opcode())
+            }
+        };
+
+        // Verify the signature.
+        let output = circuit::Literal::Boolean(signature.verify(&address, &message));
+        // Convert the output to a stack value.
+        let output = circuit::Value::Plaintext(circuit::Plaintext::Literal(output, Default::default()));
+        // Store the output.
+        registers.store_circuit(stack, &self.destination, output)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(&self, _stack: &Stack<N>, input_types: &[RegisterType<N>]) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 3 {
+            bail!("Instruction '{}' expects 3 inputs, found {} inputs", Self::opcode(), input_types.len())
+        }
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Ensure the first input is a signature.
+        if input_types[0] != RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Signature)) {
+            bail!(
+                "Instruction '{}' expects the first input to be a 'signature', found '{}'",
+                Self::opcode(),
+                input_types[0]
+            )
+        }
+        // Ensure the second input is an address.
+        if input_types[1] != RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address)) {
+            bail!(
+                "Instruction '{}' expects the second input to be an 'address', found '{}'",
+                Self::opcode(),
+                input_types[1]
+            )
+        }
+        // Ensure the third input is a plaintext.
+        if !matches!(input_types[2], RegisterType::Plaintext(..)) {
+            bail!(
+                "Instruction '{}' expects the third input to be a plaintext, found '{}'",
+                Self::opcode(),
+                input_types[2]
+            )
+        }
+
+        Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Boolean))])
+    }
+}
+
+impl<N: Network> Parser for SignVerify<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the signature operand from the string.
+        let (string, signature) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the address operand from the string.
+        let (string, address) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the message operand from the string.
+        let (string, message) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands: vec![signature, address, message], destination }))
+    }
+}
+
+impl<N: Network> FromStr for SignVerify<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for SignVerify<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for SignVerify<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            eprintln!("The number of operands must be 3, found {}", self.operands.len());
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{} ", operand))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network> FromBytes for SignVerify<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(3);
+        // Read the operands.
+        for _ in 0..3 {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network> ToBytes for SignVerify<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            return Err(error(format!("The number of operands must be 3, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        program::instruction::operation::test_helpers::{sample_registers, CurrentAleo},
+        ProvingKey,
+        VerifyingKey,
+    };
+    use console::{
+        account::{Address, PrivateKey, Signature},
+        network::Testnet3,
+        types::Field,
+    };
+
+    use std::collections::HashMap;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Samples the stack for a program that verifies the signature `r0` from `r1` over `r2`.
+    fn sample_stack(
+        signature_type: &str,
+        message_type: &str,
+        cache: &mut HashMap<String, (ProvingKey<CurrentNetwork>, VerifyingKey<CurrentNetwork>)>,
+    ) -> Result<Stack<CurrentNetwork>> {
+        crate::program::instruction::operation::test_helpers::sample_stack(
+            &format!(
+                "input r0 as {signature_type}.private;
+                input r1 as address.private;
+                input r2 as {message_type}.private;
+                sign.verify r0 r1 r2 into r3;
+                output r3 as boolean.private;"
+            ),
+            cache,
+        )
+    }
+
+    /// Checks that verifying `signature` matches in console and circuit, and returns the output.
+    fn check_sign_verify(
+        signature: Signature<CurrentNetwork>,
+        address: Address<CurrentNetwork>,
+        message: Value<CurrentNetwork>,
+        cache: &mut HashMap<String, (ProvingKey<CurrentNetwork>, VerifyingKey<CurrentNetwork>)>,
+    ) -> bool {
+        use circuit::Eject;
+
+        // Initialize the stack and the operation.
+        let stack = sample_stack("signature", "field", cache).unwrap();
+        let operation = SignVerify::<CurrentNetwork>::from_str("sign.verify r0 r1 r2 into r3").unwrap();
+        let destination = Operand::Register(Register::Locator(3));
+
+        // Initialize the registers.
+        let inputs = [
+            Value::Plaintext(Plaintext::from(Literal::Signature(Box::new(signature)))),
+            Value::Plaintext(Plaintext::from(Literal::Address(address))),
+            message,
+        ];
+        let mut registers = sample_registers(&stack, &inputs).unwrap();
+
+        // Compute the console output.
+        operation.evaluate(&stack, &mut registers).unwrap();
+        let expected = registers.load_literal(&stack, &destination).unwrap();
+        // Ensure the circuit output matches.
+        operation.execute::<CurrentAleo>(&stack, &mut registers).unwrap();
+        assert_eq!(registers.load_literal_circuit(&stack, &destination).unwrap().eject_value(), expected);
+        assert!(<CurrentAleo as circuit::Environment>::is_satisfied());
+        <CurrentAleo as circuit::Environment>::reset();
+
+        match expected {
+            Literal::Boolean(output) => *output,
+            _ => panic!("Expected a boolean output, found '{expected}'"),
+        }
+    }
+
+    #[test]
+    fn test_sign_verify() {
+        let rng = &mut TestRng::default();
+        // Prepare the key cache.
+        let cache = &mut Default::default();
+
+        // Sample a signer and a message.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+        let message = Value::Plaintext(Plaintext::from(Literal::Field(Field::rand(rng))));
+        // Sign the message.
+        let signature = Signature::sign(&private_key, &message.to_fields().unwrap(), rng).unwrap();
+
+        // Ensure the signature is valid for the signer and message.
+        assert!(check_sign_verify(signature, address, message.clone(), cache));
+
+        // Ensure the signature is invalid for a different signer.
+        let other = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        assert!(!check_sign_verify(signature, other, message, cache));
+
+        // Ensure the signature is invalid for a different message.
+        let message = Value::Plaintext(Plaintext::from(Literal::Field(Field::rand(rng))));
+        assert!(!check_sign_verify(signature, address, message, cache));
+    }
+
+    #[test]
+    fn test_sign_verify_type_check() {
+        // Prepare the key cache.
+        let cache = &mut Default::default();
+
+        // Ensure a signature over any plaintext type checks.
+        assert!(sample_stack("signature", "field", cache).is_ok());
+        assert!(sample_stack("signature", "u64", cache).is_ok());
+        // Ensure the signature operand must be a signature.
+        assert!(sample_stack("field", "field", cache).is_err());
+        assert!(sample_stack("address", "field", cache).is_err());
+    }
+
+    #[test]
+    fn test_parse() {
+        let (string, sign_verify) = SignVerify::<CurrentNetwork>::parse("sign.verify r0 r1 r2 into r3").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(sign_verify.operands.len(), 3, "The number of operands is incorrect");
+        assert_eq!(sign_verify.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(sign_verify.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(sign_verify.operands[2], Operand::Register(Register::Locator(2)), "The third operand is incorrect");
+        assert_eq!(sign_verify.destination, Register::Locator(3), "The destination register is incorrect");
+        assert_eq!(sign_verify.to_string(), "sign.verify r0 r1 r2 into r3");
+        assert_eq!(sign_verify, SignVerify::read_le(&sign_verify.to_bytes_le().unwrap()[..]).unwrap());
+    }
+}
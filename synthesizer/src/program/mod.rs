@@ -37,7 +37,7 @@ mod serialize;
 
 use console::{
     network::prelude::*,
-    program::{EntryType, Identifier, PlaintextType, ProgramID, RecordType, Struct},
+    program::{ArrayType, EntryType, FinalizeType, Identifier, PlaintextType, ProgramID, RecordType, Struct},
 };
 
 use indexmap::IndexMap;
@@ -227,6 +227,33 @@ function fee:
         Ok(struct_)
     }
 
+    /// Ensures a plaintext array of the given type fits within `u16::MAX` bits,
+    /// as it may be an element or member of another value.
+    pub fn check_array_size(&self, array_type: &ArrayType<N>) -> Result<()> {
+        array_type.check_size_in_bits(self.plaintext_size_in_bits(array_type.base_element_type())?)
+    }
+
+    /// Returns the maximum number of bits of a plaintext of the given type.
+    fn plaintext_size_in_bits(&self, plaintext_type: &PlaintextType<N>) -> Result<usize> {
+        match plaintext_type {
+            PlaintextType::Literal(literal_type) => Ok(ArrayType::<N>::literal_size_in_bits(*literal_type)),
+            PlaintextType::Struct(struct_name) => {
+                // The variant bits, and the number of members (u8).
+                let mut size_in_bits = 2usize + 8;
+                for (identifier, member_type) in self.get_struct(struct_name)?.members() {
+                    // The identifier with its size (u8), and the member with its size (u16).
+                    size_in_bits = size_in_bits
+                        .saturating_add(8 + identifier.size_in_bits() as usize + 16)
+                        .saturating_add(self.plaintext_size_in_bits(member_type)?);
+                }
+                Ok(size_in_bits)
+            }
+            PlaintextType::Array(array_type) => {
+                Ok(array_type.size_in_bits(self.plaintext_size_in_bits(array_type.base_element_type())?))
+            }
+        }
+    }
+
     /// Returns the record with the given name.
     pub fn get_record(&self, name: &Identifier<N>) -> Result<RecordType<N>> {
         // Attempt to retrieve the record.
@@ -308,6 +335,8 @@ impl<N: Network> Program<N> {
     /// # Errors
     /// This method will halt if the mapping name is already in use.
     /// This method will halt if the mapping name is a reserved opcode or keyword.
+    /// This method will halt if the mapping key or value is not a public plaintext type.
+    /// This method will halt if any structs in the mapping key or value are not already defined.
     #[inline]
     fn add_mapping(&mut self, mapping: Mapping<N>) -> Result<()> {
         // Retrieve the mapping name.
@@ -320,6 +349,32 @@ impl<N: Network> Program<N> {
         // Ensure the mapping name is not a reserved opcode.
         ensure!(!Self::is_reserved_opcode(&mapping_name.to_string()), "'{mapping_name}' is a reserved opcode.");
 
+        // Ensure the mapping key and value types are well-formed.
+        for finalize_type in [mapping.key().finalize_type(), mapping.value().finalize_type()] {
+            match finalize_type {
+                // Ensure the plaintext type is already defined.
+                FinalizeType::Public(plaintext_type) => match plaintext_type {
+                    PlaintextType::Literal(..) => continue,
+                    PlaintextType::Struct(identifier) => {
+                        if !self.structs.contains_key(identifier) {
+                            bail!("Struct '{identifier}' in mapping '{mapping_name}' is not defined.")
+                        }
+                    }
+                    PlaintextType::Array(array_type) => {
+                        if let PlaintextType::Struct(identifier) = array_type.base_element_type() {
+                            if !self.structs.contains_key(identifier) {
+                                bail!("Struct '{identifier}' in mapping '{mapping_name}' is not defined.")
+                            }
+                            self.check_array_size(array_type)?;
+                        }
+                    }
+                },
+                FinalizeType::Record(..) | FinalizeType::ExternalRecord(..) => {
+                    bail!("Mapping '{mapping_name}' cannot store a record as its key or value.")
+                }
+            }
+        }
+
         // Add the mapping name to the identifiers.
         if self.identifiers.insert(mapping_name, ProgramDefinition::Mapping).is_some() {
             bail!("'{mapping_name}' already exists in the program.")
@@ -367,6 +422,16 @@ impl<N: Network> Program<N> {
                         bail!("'{member_identifier}' in struct '{}' is not defined.", struct_name)
                     }
                 }
+                PlaintextType::Array(array_type) => {
+                    // Ensure the base element struct name exists in the program.
+                    if let PlaintextType::Struct(member_identifier) = array_type.base_element_type() {
+                        if !self.structs.contains_key(member_identifier) {
+                            bail!("'{member_identifier}' in struct '{}' is not defined.", struct_name)
+                        }
+                        // Ensure the array fits within `u16::MAX` bits.
+                        self.check_array_size(array_type)?;
+                    }
+                }
             }
         }
 
@@ -420,6 +485,14 @@ impl<N: Network> Program<N> {
                             bail!("Struct '{identifier}' in record '{record_name}' is not defined.")
                         }
                     }
+                    PlaintextType::Array(array_type) => {
+                        if let PlaintextType::Struct(identifier) = array_type.base_element_type() {
+                            if !self.structs.contains_key(identifier) {
+                                bail!("Struct '{identifier}' in record '{record_name}' is not defined.")
+                            }
+                            self.check_array_size(array_type)?;
+                        }
+                    }
                 },
             }
         }
@@ -672,6 +745,41 @@ mapping message:
         Ok(())
     }
 
+    #[test]
+    fn test_program_mapping_with_struct() -> Result<()> {
+        // Create a new mapping.
+        let mapping = Mapping::<CurrentNetwork>::from_str(
+            r"
+mapping messages:
+    key owner as address.public;
+    value message as message.public;",
+        )?;
+
+        // Initialize a new program.
+        let mut program = Program::<CurrentNetwork>::new(ProgramID::from_str("unknown.aleo")?)?;
+
+        // Ensure the mapping cannot be added before the struct is defined.
+        assert!(program.add_mapping(mapping.clone()).is_err());
+
+        // Add the struct to the program.
+        program.add_struct(Struct::from_str("struct message:\n    first as field;\n    second as field;")?)?;
+        // Add the mapping to the program.
+        program.add_mapping(mapping.clone())?;
+        // Ensure the retrieved mapping matches.
+        assert_eq!(mapping, program.get_mapping(&Identifier::from_str("messages")?)?);
+
+        // Ensure a mapping cannot store a record.
+        let mapping = Mapping::<CurrentNetwork>::from_str(
+            r"
+mapping tokens:
+    key owner as address.public;
+    value token as token.record;",
+        )?;
+        assert!(program.add_mapping(mapping).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_program_struct() -> Result<()> {
         // Create a new struct.
@@ -695,6 +803,34 @@ struct message:
         Ok(())
     }
 
+    #[test]
+    fn test_program_struct_array_size() -> Result<()> {
+        // Initialize a new program, with a struct of two fields.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"program unknown.aleo;
+struct message:
+    first as field;
+    second as field;
+struct inbox:
+    messages as [message; 32u32];",
+        )?;
+        // Ensure the struct was added.
+        assert!(program.contains_struct(&Identifier::from_str("inbox")?));
+
+        // Ensure an array of structs that exceeds `u16::MAX` bits is rejected.
+        let result = Program::<CurrentNetwork>::from_str(
+            r"program unknown.aleo;
+struct message:
+    first as field;
+    second as field;
+struct inbox:
+    messages as [[message; 32u32]; 32u32];",
+        );
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_program_record() -> Result<()> {
         // Create a new record.
@@ -0,0 +1,136 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+mod bytes;
+mod parse;
+mod serialize;
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct HeterogeneousProofs<N: Network> {
+    /// The proofs for several circuits, proven under a shared Fiat-Shamir transcript.
+    proofs: marlin::HeterogeneousProofs<N::PairingCurve>,
+}
+
+impl<N: Network> HeterogeneousProofs<N> {
+    /// Initializes new heterogeneous proofs.
+    pub(super) const fn new(proofs: marlin::HeterogeneousProofs<N::PairingCurve>) -> Self {
+        Self { proofs }
+    }
+}
+
+impl<N: Network> Deref for HeterogeneousProofs<N> {
+    type Target = marlin::HeterogeneousProofs<N::PairingCurve>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.proofs
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use console::{network::Testnet3, types::Field};
+
+    use once_cell::sync::OnceCell;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::AleoV0;
+
+    /// Returns the circuit keys and a batch of assignments and verifier inputs,
+    /// for a circuit that squares its public input `num_squarings` times.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn sample_keys_and_assignments(
+        num_squarings: usize,
+        batch_size: usize,
+    ) -> (
+        ProvingKey<CurrentNetwork>,
+        VerifyingKey<CurrentNetwork>,
+        Vec<circuit::Assignment<<CurrentNetwork as Environment>::Field>>,
+        Vec<Vec<<CurrentNetwork as Environment>::Field>>,
+    ) {
+        use circuit::{Environment, Inject, Mode};
+
+        let rng = &mut TestRng::default();
+
+        let (assignments, inputs): (Vec<_>, Vec<_>) = (0..batch_size)
+            .map(|_| {
+                let environment = CurrentAleo::enter();
+                let input = Field::<CurrentNetwork>::rand(rng);
+                let mut output = circuit::Field::<CurrentAleo>::new(Mode::Public, input);
+                for _ in 0..num_squarings {
+                    output = output.square();
+                }
+                assert!(CurrentAleo::is_satisfied());
+                (environment.eject_assignment(), vec![
+                    <CurrentNetwork as console::prelude::Environment>::Field::one(),
+                    *input,
+                ])
+            })
+            .unzip();
+
+        let function_name = Identifier::from_str("square").unwrap();
+        let (proving_key, verifying_key) =
+            UniversalSRS::<CurrentNetwork>::load().unwrap().to_circuit_key(&function_name, &assignments[0]).unwrap();
+        (proving_key, verifying_key, assignments, inputs)
+    }
+
+    pub(crate) fn sample_heterogeneous_proofs() -> HeterogeneousProofs<CurrentNetwork> {
+        static INSTANCE: OnceCell<HeterogeneousProofs<CurrentNetwork>> = OnceCell::new();
+        INSTANCE
+            .get_or_init(|| {
+                // Sample two different circuits.
+                let (proving_key_a, _, assignments_a, _) = sample_keys_and_assignments(1, 2);
+                let (proving_key_b, _, assignments_b, _) = sample_keys_and_assignments(8, 1);
+                // Return the heterogeneous proofs.
+                ProvingKey::prove_heterogeneous_batch(
+                    &[(&proving_key_a, &assignments_a[..]), (&proving_key_b, &assignments_b[..])],
+                    &mut TestRng::default(),
+                )
+                .unwrap()
+            })
+            .clone()
+    }
+
+    #[test]
+    fn test_verify_heterogeneous_batch() {
+        let rng = &mut TestRng::default();
+
+        // Sample two different circuits.
+        let (proving_key_a, verifying_key_a, assignments_a, inputs_a) = sample_keys_and_assignments(1, 2);
+        let (proving_key_b, verifying_key_b, assignments_b, inputs_b) = sample_keys_and_assignments(8, 1);
+
+        // Prove both batches under a shared transcript.
+        let proofs = ProvingKey::prove_heterogeneous_batch(
+            &[(&proving_key_a, &assignments_a[..]), (&proving_key_b, &assignments_b[..])],
+            rng,
+        )
+        .unwrap();
+        assert_eq!(proofs.num_circuits(), 2);
+
+        // Ensure the heterogeneous proofs are valid.
+        assert!(VerifyingKey::verify_heterogeneous_batch(
+            &[(&verifying_key_a, &inputs_a[..]), (&verifying_key_b, &inputs_b[..])],
+            &proofs
+        ));
+        // Ensure the heterogeneous proofs are invalid for the circuits in a different order.
+        assert!(!VerifyingKey::verify_heterogeneous_batch(
+            &[(&verifying_key_b, &inputs_b[..]), (&verifying_key_a, &inputs_a[..])],
+            &proofs
+        ));
+    }
+}
@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+static HETEROGENEOUS_PROOFS_PREFIX: &str = "heterogeneousproofs";
+
+impl<N: Network> Parser for HeterogeneousProofs<N> {
+    /// Parses a string into heterogeneous proofs.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Prepare a parser for the Aleo heterogeneous proofs.
+        let parse_heterogeneous_proofs = recognize(pair(
+            pair(tag(HETEROGENEOUS_PROOFS_PREFIX), tag("1")),
+            many1(terminated(one_of("qpzry9x8gf2tvdw0s3jn54khce6mua7l"), many0(char('_')))),
+        ));
+
+        // Parse the heterogeneous proofs from the string.
+        map_res(parse_heterogeneous_proofs, |proofs: &str| -> Result<_, Error> {
+            Self::from_str(&proofs.replace('_', ""))
+        })(string)
+    }
+}
+
+impl<N: Network> FromStr for HeterogeneousProofs<N> {
+    type Err = Error;
+
+    /// Reads in the heterogeneous proofs string.
+    fn from_str(proofs: &str) -> Result<Self, Self::Err> {
+        // Decode the heterogeneous proofs string from bech32m.
+        let (hrp, data, variant) = bech32::decode(proofs)?;
+        if hrp != HETEROGENEOUS_PROOFS_PREFIX {
+            bail!("Failed to decode heterogeneous proofs: '{hrp}' is an invalid prefix")
+        } else if data.is_empty() {
+            bail!("Failed to decode heterogeneous proofs: data field is empty")
+        } else if variant != bech32::Variant::Bech32m {
+            bail!("Found heterogeneous proofs that are not bech32m encoded: {proofs}");
+        }
+        // Decode the heterogeneous proofs data from u5 to u8, and into the heterogeneous proofs.
+        Ok(Self::read_le(&Vec::from_base32(&data)?[..])?)
+    }
+}
+
+impl<N: Network> Debug for HeterogeneousProofs<N> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for HeterogeneousProofs<N> {
+    /// Writes the heterogeneous proofs as a bech32m string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Convert the heterogeneous proofs to bytes.
+        let bytes = self.to_bytes_le().map_err(|_| fmt::Error)?;
+        // Encode the bytes into bech32m.
+        let string = bech32::encode(HETEROGENEOUS_PROOFS_PREFIX, bytes.to_base32(), bech32::Variant::Bech32m)
+            .map_err(|_| fmt::Error)?;
+        // Output the string.
+        Display::fmt(&string, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        // Ensure type and empty value fails.
+        assert!(HeterogeneousProofs::<CurrentNetwork>::parse(&format!("{HETEROGENEOUS_PROOFS_PREFIX}1")).is_err());
+        assert!(HeterogeneousProofs::<CurrentNetwork>::parse("").is_err());
+
+        // Sample the heterogeneous proofs.
+        let proofs = heterogeneous_proofs::tests::sample_heterogeneous_proofs();
+
+        // Check the parsing of the heterogeneous proofs.
+        let expected = format!("{proofs}");
+        let (remainder, candidate) = HeterogeneousProofs::<CurrentNetwork>::parse(&expected).unwrap();
+        assert_eq!(format!("{expected}"), candidate.to_string());
+        assert_eq!(HETEROGENEOUS_PROOFS_PREFIX, candidate.to_string().split('1').next().unwrap());
+        assert_eq!("", remainder);
+        Ok(())
+    }
+
+    #[test]
+    fn test_string() -> Result<()> {
+        // Sample the heterogeneous proofs.
+        let expected = heterogeneous_proofs::tests::sample_heterogeneous_proofs();
+
+        // Check the string representation.
+        let candidate = format!("{expected}");
+        assert_eq!(expected, HeterogeneousProofs::from_str(&candidate)?);
+        assert_eq!(HETEROGENEOUS_PROOFS_PREFIX, candidate.split('1').next().unwrap());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_display() -> Result<()> {
+        // Sample the heterogeneous proofs.
+        let expected = heterogeneous_proofs::tests::sample_heterogeneous_proofs();
+
+        let candidate = expected.to_string();
+        assert_eq!(format!("{expected}"), candidate);
+        assert_eq!(HETEROGENEOUS_PROOFS_PREFIX, candidate.split('1').next().unwrap());
+
+        let candidate_recovered = HeterogeneousProofs::<CurrentNetwork>::from_str(&candidate)?;
+        assert_eq!(expected, candidate_recovered);
+
+        Ok(())
+    }
+}
@@ -20,9 +20,11 @@ use console::{
     network::{prelude::*, FiatShamir},
     program::Identifier,
 };
-use snarkvm_algorithms::{snark::marlin, traits::SNARK};
+use snarkvm_algorithms::{snark::marlin, traits::SNARK, Prepare};
 
 use once_cell::sync::OnceCell;
+#[cfg(not(target_family = "wasm"))]
+use std::path::Path;
 use std::sync::Arc;
 
 #[cfg(feature = "aleo-cli")]
@@ -33,6 +35,9 @@ type Marlin<N> = marlin::MarlinSNARK<<N as Environment>::PairingCurve, FiatShami
 mod certificate;
 pub use certificate::Certificate;
 
+mod heterogeneous_proofs;
+pub use heterogeneous_proofs::HeterogeneousProofs;
+
 mod proof;
 pub use proof::Proof;
 
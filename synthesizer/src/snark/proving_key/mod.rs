@@ -34,6 +34,26 @@ impl<N: Network> ProvingKey<N> {
         Self { proving_key }
     }
 
+    /// Loads a proving key from the file at the given path, which was written by `write_mapped`.
+    /// The committer key is memory-mapped rather than read into memory.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn load_mapped<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Ok(Self::new(Arc::new(marlin::CircuitProvingKey::load_mapped(path)?)))
+    }
+
+    /// Loads a proving key from the file at the given path, which was already validated by an earlier `load_mapped`.
+    /// The committer key is memory-mapped, and is not revalidated.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn load_mapped_prevalidated<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Ok(Self::new(Arc::new(marlin::CircuitProvingKey::load_mapped_prevalidated(path)?)))
+    }
+
+    /// Writes the proving key to the file at the given path, in the zero-copy layout.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn write_mapped<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        Ok(self.proving_key.write_mapped(path)?)
+    }
+
     /// Returns a proof for the given assignment on the circuit.
     pub fn prove<R: Rng + CryptoRng>(
         &self,
@@ -74,6 +94,37 @@ impl<N: Network> ProvingKey<N> {
         println!("{}", format!(" • Executed '{function_name}' (in {} ms)", timer.elapsed().as_millis()).dimmed());
         Ok(batch_proof)
     }
+
+    /// Returns a proof for each of the given batches of assignments, each on the circuit of its proving key.
+    /// The proofs are computed under a shared Fiat-Shamir transcript, so they must be verified together.
+    /// Note: This does not compress the batches into a single proof, as each circuit is still opened separately.
+    pub fn prove_heterogeneous_batch<R: Rng + CryptoRng>(
+        keys_to_assignments: &[(&Self, &[circuit::Assignment<N::Field>])],
+        rng: &mut R,
+    ) -> Result<HeterogeneousProofs<N>> {
+        #[cfg(feature = "aleo-cli")]
+        let timer = std::time::Instant::now();
+
+        // Retrieve the circuit proving keys.
+        let keys_to_assignments = keys_to_assignments
+            .iter()
+            .map(|(proving_key, assignments)| (proving_key.proving_key.as_ref(), *assignments))
+            .collect::<Vec<_>>();
+        // Compute the proofs.
+        let proofs = HeterogeneousProofs::new(Marlin::<N>::prove_heterogeneous_batch(
+            N::marlin_fs_parameters(),
+            &keys_to_assignments,
+            rng,
+        )?);
+
+        #[cfg(feature = "aleo-cli")]
+        println!(
+            "{}",
+            format!(" • Executed {} circuits (in {} ms)", keys_to_assignments.len(), timer.elapsed().as_millis())
+                .dimmed()
+        );
+        Ok(proofs)
+    }
 }
 
 impl<N: Network> Deref for ProvingKey<N> {
@@ -83,3 +134,34 @@ impl<N: Network> Deref for ProvingKey<N> {
         &self.proving_key
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::snark::heterogeneous_proofs::tests::sample_keys_and_assignments;
+
+    #[test]
+    fn test_mapped_proving_key() {
+        let rng = &mut TestRng::default();
+
+        let (proving_key, verifying_key, assignments, inputs) = sample_keys_and_assignments(2, 1);
+
+        // Write the proving key in the zero-copy layout, and map it back in.
+        let path = std::env::temp_dir().join(format!("snarkvm_mapped_proving_key_{}", std::process::id()));
+        proving_key.write_mapped(&path).unwrap();
+        let mapped_proving_key = ProvingKey::load_mapped(&path).unwrap();
+        assert_eq!(proving_key.to_bytes_le().unwrap(), mapped_proving_key.to_bytes_le().unwrap());
+
+        // Ensure the mapped proving key produces valid proofs.
+        let function_name = Identifier::from_str("square").unwrap();
+        let proof = mapped_proving_key.prove(&function_name, &assignments[0], rng).unwrap();
+        assert!(verifying_key.verify(&function_name, &inputs[0], &proof));
+
+        // Ensure the proving key, mapped without revalidation, produces valid proofs.
+        let prevalidated_proving_key = ProvingKey::load_mapped_prevalidated(&path).unwrap();
+        let proof = prevalidated_proving_key.prove(&function_name, &assignments[0], rng).unwrap();
+        assert!(verifying_key.verify(&function_name, &inputs[0], &proof));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
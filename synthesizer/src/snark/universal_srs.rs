@@ -28,6 +28,20 @@ impl<N: Network> UniversalSRS<N> {
         Ok(Self { srs: Arc::new(OnceCell::new()) })
     }
 
+    /// Loads the universal SRS from the file at the given path, which was written by `write_mapped`.
+    /// The powers are memory-mapped rather than read into memory, and each degree of them
+    /// is only validated when a circuit key first requires it.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn load_mapped<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Ok(Self { srs: Arc::new(OnceCell::with_value(marlin::UniversalSRS::load_mapped(path)?)) })
+    }
+
+    /// Writes the universal SRS to the file at the given path, in the zero-copy layout.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn write_mapped<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        self.deref().write_mapped(path)
+    }
+
     /// Returns the circuit proving and verifying key.
     pub fn to_circuit_key(
         &self,
@@ -70,7 +84,7 @@ impl<N: Network> Deref for UniversalSRS<N> {
             let timer = std::time::Instant::now();
 
             // Load the universal SRS.
-            let universal_srs = marlin::UniversalSRS::load().expect("Failed to load the universal SRS");
+            let universal_srs = N::universal_srs().expect("Failed to load the universal SRS");
 
             #[cfg(feature = "aleo-cli")]
             println!("{}", format!(" • Loaded universal setup (in {} ms)", timer.elapsed().as_millis()).dimmed());
@@ -81,6 +81,86 @@ impl<N: Network> VerifyingKey<N> {
             }
         }
     }
+
+    /// Returns `true` if the proofs are valid for the given batches of public inputs, each under its verifying key.
+    pub fn verify_heterogeneous_batch(
+        keys_to_inputs: &[(&Self, &[Vec<N::Field>])],
+        proofs: &HeterogeneousProofs<N>,
+    ) -> bool {
+        #[cfg(feature = "aleo-cli")]
+        let timer = std::time::Instant::now();
+
+        // Retrieve the circuit verifying keys.
+        let keys_to_inputs = keys_to_inputs
+            .iter()
+            .map(|(verifying_key, inputs)| (verifying_key.verifying_key.as_ref(), *inputs))
+            .collect::<Vec<_>>();
+        // Verify the proofs.
+        let result = Marlin::<N>::verify_heterogeneous_batch(N::marlin_fs_parameters(), &keys_to_inputs, proofs);
+
+        #[cfg(feature = "aleo-cli")]
+        match &result {
+            Ok(_) => {
+                let elapsed = timer.elapsed().as_millis();
+                println!("{}", format!(" • Verified {} circuits (in {} ms)", keys_to_inputs.len(), elapsed).dimmed());
+            }
+            Err(error) => println!("{}", format!(" • Verifier failed: {error}").dimmed()),
+        }
+
+        result.unwrap_or_default()
+    }
+
+    /// Returns the index of the first proof or certificate that is invalid, or `None` if all of them are valid.
+    /// The certificates are indexed after the proofs. The proofs and certificates are verified together
+    /// with a single multi-pairing, and are only verified one at a time to locate the failure if that check fails.
+    #[allow(clippy::type_complexity)]
+    pub fn find_invalid_proof<R: Rng + CryptoRng>(
+        instances: &[(&Self, &[N::Field], &Proof<N>)],
+        certificates: &[(&circuit::Assignment<N::Field>, &Self, &Certificate<N>)],
+        rng: &mut R,
+    ) -> Option<usize> {
+        #[cfg(feature = "aleo-cli")]
+        let timer = std::time::Instant::now();
+
+        // Prepare the circuit verifying keys.
+        let prepared_verifying_keys =
+            instances.iter().map(|(verifying_key, ..)| verifying_key.verifying_key.prepare()).collect::<Vec<_>>();
+        let instances = prepared_verifying_keys
+            .iter()
+            .zip_eq(instances)
+            .map(|(verifying_key, (_, inputs, proof))| (verifying_key, std::slice::from_ref(inputs), &***proof))
+            .collect::<Vec<_>>();
+        // Retrieve the circuit verifying keys of the certificates.
+        let certificates = certificates
+            .iter()
+            .map(|(assignment, verifying_key, certificate)| {
+                (*assignment, verifying_key.verifying_key.as_ref(), &***certificate)
+            })
+            .collect::<Vec<_>>();
+        // Verify the proofs and certificates.
+        let invalid_index =
+            Marlin::<N>::find_invalid_proof_prepared(N::marlin_fs_parameters(), &instances, &certificates, rng);
+
+        #[cfg(feature = "aleo-cli")]
+        match invalid_index {
+            Some(index) => println!("{}", format!(" • Verifier failed on proof {index}").dimmed()),
+            None => {
+                let elapsed = timer.elapsed().as_millis();
+                println!(
+                    "{}",
+                    format!(
+                        " • Verified {} proofs and {} certificates (in {} ms)",
+                        instances.len(),
+                        certificates.len(),
+                        elapsed
+                    )
+                    .dimmed()
+                );
+            }
+        }
+
+        invalid_index
+    }
 }
 
 impl<N: Network> Deref for VerifyingKey<N> {
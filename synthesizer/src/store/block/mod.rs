@@ -14,6 +14,15 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod scan;
+pub use scan::*;
+
+#[cfg(feature = "persistent")]
+use crate::store::{
+    helpers::sled_map::{MapID, SledMap},
+    TransactionDB,
+    TransitionDB,
+};
 use crate::{
     atomic_write_batch,
     block::{Block, Header, Transactions},
@@ -640,6 +649,128 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
     }
 }
 
+/// A database-backed block storage.
+#[cfg(feature = "persistent")]
+#[derive(Clone)]
+pub struct BlockDB<N: Network> {
+    /// The mapping of `block height` to `state root`.
+    state_root_map: SledMap<u32, N::StateRoot>,
+    /// The mapping of `state root` to `block height`.
+    reverse_state_root_map: SledMap<N::StateRoot, u32>,
+    /// The mapping of `block height` to `block hash`.
+    id_map: SledMap<u32, N::BlockHash>,
+    /// The mapping of `block hash` to `block height`.
+    reverse_id_map: SledMap<N::BlockHash, u32>,
+    /// The header map.
+    header_map: SledMap<N::BlockHash, Header<N>>,
+    /// The transactions map.
+    transactions_map: SledMap<N::BlockHash, Vec<N::TransactionID>>,
+    /// The reverse transactions map.
+    reverse_transactions_map: SledMap<N::TransactionID, N::BlockHash>,
+    /// The transaction store.
+    transaction_store: TransactionStore<N, TransactionDB<N>>,
+    /// The coinbase solution map.
+    coinbase_solution_map: SledMap<N::BlockHash, Option<CoinbaseSolution<N>>>,
+    /// The coinbase puzzle commitment map.
+    coinbase_puzzle_commitment_map: SledMap<PuzzleCommitment<N>, N::BlockHash>,
+    /// The signature map.
+    signature_map: SledMap<N::BlockHash, Signature<N>>,
+}
+
+#[cfg(feature = "persistent")]
+#[rustfmt::skip]
+impl<N: Network> BlockStorage<N> for BlockDB<N> {
+    type StateRootMap = SledMap<u32, N::StateRoot>;
+    type ReverseStateRootMap = SledMap<N::StateRoot, u32>;
+    type IDMap = SledMap<u32, N::BlockHash>;
+    type ReverseIDMap = SledMap<N::BlockHash, u32>;
+    type HeaderMap = SledMap<N::BlockHash, Header<N>>;
+    type TransactionsMap = SledMap<N::BlockHash, Vec<N::TransactionID>>;
+    type ReverseTransactionsMap = SledMap<N::TransactionID, N::BlockHash>;
+    type TransactionStorage = TransactionDB<N>;
+    type TransitionStorage = TransitionDB<N>;
+    type CoinbaseSolutionMap = SledMap<N::BlockHash, Option<CoinbaseSolution<N>>>;
+    type CoinbasePuzzleCommitmentMap = SledMap<PuzzleCommitment<N>, N::BlockHash>;
+    type SignatureMap = SledMap<N::BlockHash, Signature<N>>;
+
+    /// Initializes the block storage.
+    fn open(dev: Option<u16>) -> Result<Self> {
+        // Initialize the transition store.
+        let transition_store = TransitionStore::<N, TransitionDB<N>>::open(dev)?;
+        // Initialize the transaction store.
+        let transaction_store = TransactionStore::<N, TransactionDB<N>>::open(transition_store)?;
+        // Return the block storage.
+        Ok(Self {
+            state_root_map: SledMap::open(N::ID, dev, MapID::BlockStateRoot)?,
+            reverse_state_root_map: SledMap::open(N::ID, dev, MapID::BlockReverseStateRoot)?,
+            id_map: SledMap::open(N::ID, dev, MapID::BlockID)?,
+            reverse_id_map: SledMap::open(N::ID, dev, MapID::BlockReverseID)?,
+            header_map: SledMap::open(N::ID, dev, MapID::BlockHeader)?,
+            transactions_map: SledMap::open(N::ID, dev, MapID::BlockTransactions)?,
+            reverse_transactions_map: SledMap::open(N::ID, dev, MapID::BlockReverseTransactions)?,
+            transaction_store,
+            coinbase_solution_map: SledMap::open(N::ID, dev, MapID::BlockCoinbaseSolution)?,
+            coinbase_puzzle_commitment_map: SledMap::open(N::ID, dev, MapID::BlockCoinbasePuzzleCommitment)?,
+            signature_map: SledMap::open(N::ID, dev, MapID::BlockSignature)?,
+        })
+    }
+
+    /// Returns the state root map.
+    fn state_root_map(&self) -> &Self::StateRootMap {
+        &self.state_root_map
+    }
+
+    /// Returns the reverse state root map.
+    fn reverse_state_root_map(&self) -> &Self::ReverseStateRootMap {
+        &self.reverse_state_root_map
+    }
+
+    /// Returns the ID map.
+    fn id_map(&self) -> &Self::IDMap {
+        &self.id_map
+    }
+
+    /// Returns the reverse ID map.
+    fn reverse_id_map(&self) -> &Self::ReverseIDMap {
+        &self.reverse_id_map
+    }
+
+    /// Returns the header map.
+    fn header_map(&self) -> &Self::HeaderMap {
+        &self.header_map
+    }
+
+    /// Returns the transactions map.
+    fn transactions_map(&self) -> &Self::TransactionsMap {
+        &self.transactions_map
+    }
+
+    /// Returns the reverse transactions map.
+    fn reverse_transactions_map(&self) -> &Self::ReverseTransactionsMap {
+        &self.reverse_transactions_map
+    }
+
+    /// Returns the transaction store.
+    fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage> {
+        &self.transaction_store
+    }
+
+    /// Returns the coinbase solution map.
+    fn coinbase_solution_map(&self) -> &Self::CoinbaseSolutionMap {
+        &self.coinbase_solution_map
+    }
+
+    /// Returns the coinbase puzzle commitment map.
+    fn coinbase_puzzle_commitment_map(&self) -> &Self::CoinbasePuzzleCommitmentMap {
+        &self.coinbase_puzzle_commitment_map
+    }
+
+    /// Returns the signature map.
+    fn signature_map(&self) -> &Self::SignatureMap {
+        &self.signature_map
+    }
+}
+
 /// The block store.
 #[derive(Clone)]
 pub struct BlockStore<N: Network, B: BlockStorage<N>> {
@@ -807,6 +938,11 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         (*self.tree.read().root()).into()
     }
 
+    /// Returns the current block height, or `None` if there are no blocks in storage.
+    pub fn current_block_height(&self) -> Option<u32> {
+        u32::try_from(self.tree.read().number_of_leaves()).ok()?.checked_sub(1)
+    }
+
     /// Returns the state root that contains the given `block height`.
     pub fn get_state_root(&self, block_height: u32) -> Result<Option<N::StateRoot>> {
         self.storage.get_state_root(block_height)
@@ -910,9 +1046,11 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use console::network::Testnet3;
 
-    #[test]
-    fn test_insert_get_remove() {
+    type CurrentNetwork = Testnet3;
+
+    fn check_insert_get_remove<S: BlockStorage<CurrentNetwork>>() {
         let mut rng = TestRng::default();
 
         // Sample the block.
@@ -920,7 +1058,7 @@ mod tests {
         let block_hash = block.hash();
 
         // Initialize a new block store.
-        let block_store = BlockStore::<_, BlockMemory<_>>::open(None).unwrap();
+        let block_store = BlockStore::<_, S>::open(crate::store::helpers::test_helpers::sample_dev()).unwrap();
 
         // Ensure the block does not exist.
         let candidate = block_store.get_block(&block_hash).unwrap();
@@ -941,8 +1079,7 @@ mod tests {
         assert_eq!(None, candidate);
     }
 
-    #[test]
-    fn test_find_block_hash() {
+    fn check_find_block_hash<S: BlockStorage<CurrentNetwork>>() {
         let mut rng = TestRng::default();
 
         // Sample the block.
@@ -951,7 +1088,7 @@ mod tests {
         assert!(block.transactions().len() > 0, "This test must be run with at least one transaction.");
 
         // Initialize a new block store.
-        let block_store = BlockStore::<_, BlockMemory<_>>::open(None).unwrap();
+        let block_store = BlockStore::<_, S>::open(crate::store::helpers::test_helpers::sample_dev()).unwrap();
 
         // Ensure the block does not exist.
         let candidate = block_store.get_block(&block_hash).unwrap();
@@ -981,4 +1118,26 @@ mod tests {
             assert_eq!(None, candidate);
         }
     }
+
+    #[test]
+    fn test_insert_get_remove() {
+        check_insert_get_remove::<BlockMemory<CurrentNetwork>>();
+    }
+
+    #[cfg(feature = "persistent")]
+    #[test]
+    fn test_insert_get_remove_db() {
+        check_insert_get_remove::<BlockDB<CurrentNetwork>>();
+    }
+
+    #[test]
+    fn test_find_block_hash() {
+        check_find_block_hash::<BlockMemory<CurrentNetwork>>();
+    }
+
+    #[cfg(feature = "persistent")]
+    #[test]
+    fn test_find_block_hash_db() {
+        check_find_block_hash::<BlockDB<CurrentNetwork>>();
+    }
 }
@@ -0,0 +1,292 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use console::{
+    account::{Address, GraphKey, PrivateKey, ViewKey},
+    program::{Plaintext, Record},
+};
+
+use core::ops::Range;
+
+/// The key used to determine whether a scanned record has been spent.
+#[derive(Clone)]
+pub enum SpendKey<N: Network> {
+    /// The private key, which derives the serial number of a record.
+    PrivateKey(PrivateKey<N>),
+    /// The graph key, which derives the tag of a record.
+    GraphKey(GraphKey<N>),
+}
+
+/// A record in the block store that belongs to a view key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScannedRecord<N: Network> {
+    /// The height of the block containing the record.
+    height: u32,
+    /// The commitment of the record.
+    commitment: Field<N>,
+    /// The decrypted record.
+    record: Record<N, Plaintext<N>>,
+    /// The serial number of the record, if it was scanned with a private key.
+    serial_number: Option<Field<N>>,
+    /// The tag of the record, if it was scanned with a graph key.
+    tag: Option<Field<N>>,
+    /// A boolean indicating whether the record has been spent.
+    is_spent: bool,
+}
+
+impl<N: Network> ScannedRecord<N> {
+    /// Returns the height of the block containing the record.
+    pub const fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the commitment of the record.
+    pub const fn commitment(&self) -> Field<N> {
+        self.commitment
+    }
+
+    /// Returns the decrypted record.
+    pub const fn record(&self) -> &Record<N, Plaintext<N>> {
+        &self.record
+    }
+
+    /// Returns the serial number of the record, if it was scanned with a private key.
+    pub const fn serial_number(&self) -> Option<Field<N>> {
+        self.serial_number
+    }
+
+    /// Returns the tag of the record, if it was scanned with a graph key.
+    pub const fn tag(&self) -> Option<Field<N>> {
+        self.tag
+    }
+
+    /// Returns `true` if the record has been spent.
+    pub const fn is_spent(&self) -> bool {
+        self.is_spent
+    }
+}
+
+impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
+    /// Returns the records in the given range of block heights that belong to the given view key,
+    /// and marks each record as spent if its serial number (or tag) exists in storage.
+    ///
+    /// Heights beyond the latest block are ignored.
+    pub fn scan_records(
+        &self,
+        heights: Range<u32>,
+        view_key: &ViewKey<N>,
+        spend_key: &SpendKey<N>,
+    ) -> Result<Vec<ScannedRecord<N>>> {
+        // Ensure the private key or graph key corresponds to the view key.
+        let address = view_key.to_address();
+        match spend_key {
+            SpendKey::PrivateKey(private_key) => {
+                ensure!(Address::try_from(private_key)? == address, "The private key does not match the view key")
+            }
+            SpendKey::GraphKey(graph_key) => {
+                ensure!(GraphKey::try_from(view_key)? == *graph_key, "The graph key does not match the view key")
+            }
+        }
+        // Compute the x-coordinate of the address, to check the ownership of each record.
+        let address_x_coordinate = address.to_x_coordinate();
+
+        // Clamp the range of heights to the latest block.
+        let end = match self.current_block_height() {
+            Some(latest_height) => heights.end.min(latest_height.saturating_add(1)),
+            None => return Ok(vec![]),
+        };
+
+        let mut scanned_records = Vec::new();
+        for height in heights.start..end {
+            // Retrieve the transactions in the block.
+            let transactions = match self.get_block_hash(height)? {
+                Some(block_hash) => match self.get_block_transactions(&block_hash)? {
+                    Some(transactions) => transactions,
+                    None => bail!("Missing transactions for block {height}"),
+                },
+                None => bail!("Missing block hash for block {height}"),
+            };
+
+            // Retrieve the records in the block.
+            let records = transactions.records().collect::<Vec<_>>();
+            // Decrypt the records that belong to the view key, and determine whether each record is spent.
+            let block_records = cfg_into_iter!(records)
+                .filter(|(_, record)| record.is_owner_with_address_x_coordinate(view_key, &address_x_coordinate))
+                .map(|(commitment, record)| {
+                    // Decrypt the record.
+                    let record = record.decrypt(view_key)?;
+                    // Compute the serial number or tag, and check if it exists in storage.
+                    let (serial_number, tag, is_spent) = match spend_key {
+                        SpendKey::PrivateKey(private_key) => {
+                            let serial_number = Record::<N, Plaintext<N>>::serial_number(*private_key, *commitment)?;
+                            let is_spent = self.transition_store().contains_serial_number(&serial_number)?;
+                            (Some(serial_number), None, is_spent)
+                        }
+                        SpendKey::GraphKey(graph_key) => {
+                            let tag = Record::<N, Plaintext<N>>::tag(graph_key.sk_tag(), *commitment)?;
+                            let is_spent = self.transition_store().contains_tag(&tag)?;
+                            (None, Some(tag), is_spent)
+                        }
+                    };
+                    Ok(ScannedRecord { height, commitment: *commitment, record, serial_number, tag, is_spent })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            scanned_records.extend(block_records);
+        }
+        Ok(scanned_records)
+    }
+
+    /// Returns the unspent records in the given range of block heights that belong to the given view key.
+    pub fn scan_unspent_records(
+        &self,
+        heights: Range<u32>,
+        view_key: &ViewKey<N>,
+        spend_key: &SpendKey<N>,
+    ) -> Result<Vec<ScannedRecord<N>>> {
+        Ok(self.scan_records(heights, view_key, spend_key)?.into_iter().filter(|record| !record.is_spent()).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_scan_records() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis block and its private key.
+        let block = crate::vm::test_helpers::sample_genesis_block(rng);
+        let private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        let graph_key = GraphKey::try_from(&view_key).unwrap();
+
+        let by_private_key = SpendKey::PrivateKey(private_key);
+        let by_graph_key = SpendKey::GraphKey(graph_key);
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        // Ensure an empty block store has no records.
+        assert!(block_store.scan_records(0..u32::MAX, &view_key, &by_private_key).unwrap().is_empty());
+
+        // Insert the block.
+        block_store.insert(&block).unwrap();
+
+        // Compute the expected records.
+        let expected = block
+            .records()
+            .filter(|(_, record)| record.is_owner(&view_key))
+            .map(|(commitment, record)| (*commitment, record.decrypt(&view_key).unwrap()))
+            .collect::<Vec<_>>();
+        assert!(!expected.is_empty());
+
+        // Ensure the records are found with the private key.
+        let records = block_store.scan_records(0..u32::MAX, &view_key, &by_private_key).unwrap();
+        assert_eq!(records.len(), expected.len());
+        for (scanned, (commitment, record)) in records.iter().zip_eq(&expected) {
+            assert_eq!(scanned.height(), 0);
+            assert_eq!(scanned.commitment(), *commitment);
+            assert_eq!(scanned.record(), record);
+            assert_eq!(
+                scanned.serial_number(),
+                Some(
+                    Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::serial_number(private_key, *commitment)
+                        .unwrap()
+                )
+            );
+            assert_eq!(scanned.tag(), None);
+            assert!(!scanned.is_spent());
+        }
+
+        // Ensure the records are found with the graph key.
+        let records = block_store.scan_records(0..1, &view_key, &by_graph_key).unwrap();
+        assert_eq!(records.len(), expected.len());
+        for (scanned, (commitment, record)) in records.iter().zip_eq(&expected) {
+            assert_eq!(scanned.commitment(), *commitment);
+            assert_eq!(scanned.record(), record);
+            assert_eq!(scanned.serial_number(), None);
+            assert_eq!(
+                scanned.tag(),
+                Some(
+                    Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::tag(graph_key.sk_tag(), *commitment).unwrap()
+                )
+            );
+            assert!(!scanned.is_spent());
+        }
+
+        // Ensure a range beyond the latest block has no records.
+        assert!(block_store.scan_records(1..u32::MAX, &view_key, &by_private_key).unwrap().is_empty());
+
+        // Ensure another view key has no records.
+        let other_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let other_view_key = ViewKey::try_from(&other_private_key).unwrap();
+        let other_spend_key = SpendKey::PrivateKey(other_private_key);
+        assert!(block_store.scan_records(0..1, &other_view_key, &other_spend_key).unwrap().is_empty());
+
+        // Ensure a spend key that does not match the view key is rejected.
+        assert!(block_store.scan_records(0..1, &view_key, &other_spend_key).is_err());
+        let other_graph_key = SpendKey::GraphKey(GraphKey::try_from(&other_view_key).unwrap());
+        assert!(block_store.scan_records(0..1, &view_key, &other_graph_key).is_err());
+    }
+
+    #[test]
+    fn test_scan_unspent_records() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis block, its private key, and a transaction that spends a genesis record.
+        let block = crate::vm::test_helpers::sample_genesis_block(rng);
+        let private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        let graph_key = GraphKey::try_from(&view_key).unwrap();
+        let transaction = crate::vm::test_helpers::sample_execution_transaction(rng);
+
+        // Initialize a new block store, and insert the block.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        block_store.insert(&block).unwrap();
+
+        let num_records = block_store.scan_records(0..1, &view_key, &SpendKey::PrivateKey(private_key)).unwrap().len();
+
+        // Insert the transitions that spend the genesis record.
+        for transition in transaction.transitions() {
+            block_store.transition_store().insert(transition).unwrap();
+        }
+        let serial_numbers = transaction.serial_numbers().collect::<Vec<_>>();
+        let tags = transaction.tags().collect::<Vec<_>>();
+
+        for spend_key in [SpendKey::PrivateKey(private_key), SpendKey::GraphKey(graph_key)] {
+            // Ensure the spent record is marked as spent.
+            let records = block_store.scan_records(0..1, &view_key, &spend_key).unwrap();
+            assert_eq!(records.len(), num_records);
+            for record in &records {
+                let is_spent = match &spend_key {
+                    SpendKey::PrivateKey(_) => serial_numbers.contains(&&record.serial_number().unwrap()),
+                    SpendKey::GraphKey(_) => tags.contains(&&record.tag().unwrap()),
+                };
+                assert_eq!(record.is_spent(), is_spent);
+            }
+            assert_eq!(records.iter().filter(|record| record.is_spent()).count(), 1);
+
+            // Ensure the unspent records exclude the spent record.
+            let unspent_records = block_store.scan_unspent_records(0..1, &view_key, &spend_key).unwrap();
+            assert_eq!(unspent_records.len(), num_records - 1);
+            assert!(unspent_records.iter().all(|record| !record.is_spent()));
+        }
+    }
+}
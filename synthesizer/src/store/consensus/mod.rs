@@ -14,6 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+#[cfg(feature = "persistent")]
+use crate::store::{BlockDB, ProgramDB, TransactionDB, TransitionDB};
 use crate::store::{
     BlockMemory,
     BlockStorage,
@@ -134,6 +136,48 @@ impl<N: Network> ConsensusStorage<N> for ConsensusMemory<N> {
     }
 }
 
+/// A database-backed consensus storage.
+#[cfg(feature = "persistent")]
+#[derive(Clone)]
+pub struct ConsensusDB<N: Network> {
+    /// The program store.
+    program_store: ProgramStore<N, ProgramDB<N>>,
+    /// The block store.
+    block_store: BlockStore<N, BlockDB<N>>,
+}
+
+#[cfg(feature = "persistent")]
+#[rustfmt::skip]
+impl<N: Network> ConsensusStorage<N> for ConsensusDB<N> {
+    type ProgramStorage = ProgramDB<N>;
+    type BlockStorage = BlockDB<N>;
+    type TransactionStorage = TransactionDB<N>;
+    type TransitionStorage = TransitionDB<N>;
+
+    /// Initializes the consensus storage.
+    fn open(dev: Option<u16>) -> Result<Self> {
+        // Initialize the program store.
+        let program_store = ProgramStore::<N, ProgramDB<N>>::open(dev)?;
+        // Initialize the block store.
+        let block_store = BlockStore::<N, BlockDB<N>>::open(dev)?;
+        // Return the consensus storage.
+        Ok(Self {
+            program_store,
+            block_store,
+        })
+    }
+
+    /// Returns the program store.
+    fn program_store(&self) -> &ProgramStore<N, Self::ProgramStorage> {
+        &self.program_store
+    }
+
+    /// Returns the block store.
+    fn block_store(&self) -> &BlockStore<N, Self::BlockStorage> {
+        &self.block_store
+    }
+}
+
 /// The consensus store.
 #[derive(Clone)]
 pub struct ConsensusStore<N: Network, C: ConsensusStorage<N>> {
@@ -15,6 +15,8 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 pub mod memory_map;
+#[cfg(feature = "persistent")]
+pub mod sled_map;
 
 use console::network::prelude::*;
 
@@ -175,3 +177,18 @@ macro_rules! atomic_write_batch {
         }
     };
 }
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    #[cfg(feature = "persistent")]
+    use console::network::{Network, Testnet3};
+
+    /// Returns the development ID of a new, empty ledger.
+    pub(crate) fn sample_dev() -> Option<u16> {
+        // Reserve a temporary ledger database, so that the persistent stores do not open the ledger on disk.
+        #[cfg(feature = "persistent")]
+        return Some(super::sled_map::reserve_temporary(Testnet3::ID));
+        #[cfg(not(feature = "persistent"))]
+        None
+    }
+}
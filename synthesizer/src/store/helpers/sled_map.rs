@@ -0,0 +1,769 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::store::helpers::{Map, MapRead};
+use console::network::prelude::*;
+use indexmap::IndexMap;
+
+use bincode::Options;
+use core::{borrow::Borrow, hash::Hash, marker::PhantomData};
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use sled::{transaction::TransactionError, Transactional};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    sync::{
+        atomic::{AtomicBool, AtomicU16, Ordering},
+        Arc,
+        Weak,
+    },
+};
+
+/// The identifiers of the maps in the ledger database. Each map is stored in its own tree.
+/// Note: The discriminants are persisted on disk and **must not** be changed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum MapID {
+    BlockStateRoot = 0,
+    BlockReverseStateRoot = 1,
+    BlockID = 2,
+    BlockReverseID = 3,
+    BlockHeader = 4,
+    BlockTransactions = 5,
+    BlockReverseTransactions = 6,
+    BlockCoinbaseSolution = 7,
+    BlockCoinbasePuzzleCommitment = 8,
+    BlockSignature = 9,
+
+    TransactionID = 100,
+
+    DeploymentID = 200,
+    DeploymentEdition = 201,
+    DeploymentReverseID = 202,
+    DeploymentProgram = 203,
+    DeploymentVerifyingKey = 204,
+    DeploymentCertificate = 205,
+    DeploymentFee = 206,
+
+    ExecutionID = 300,
+    ExecutionReverseID = 301,
+    ExecutionInclusion = 302,
+    ExecutionFee = 303,
+
+    TransitionLocator = 400,
+    TransitionFinalize = 401,
+    TransitionProof = 402,
+    TransitionTPK = 403,
+    TransitionReverseTPK = 404,
+    TransitionTCM = 405,
+    TransitionReverseTCM = 406,
+    TransitionFee = 407,
+    TransitionFinalizeOutput = 408,
+
+    InputID = 500,
+    InputReverseID = 501,
+    InputConstant = 502,
+    InputPublic = 503,
+    InputPrivate = 504,
+    InputRecord = 505,
+    InputRecordTag = 506,
+    InputExternalRecord = 507,
+
+    OutputID = 600,
+    OutputReverseID = 601,
+    OutputConstant = 602,
+    OutputPublic = 603,
+    OutputPrivate = 604,
+    OutputRecord = 605,
+    OutputRecordNonce = 606,
+    OutputExternalRecord = 607,
+
+    ProgramID = 700,
+    ProgramMappingID = 701,
+    ProgramKeyValueID = 702,
+    ProgramKey = 703,
+    ProgramValue = 704,
+}
+
+/// The ledger databases that are currently open, indexed by their network ID and optional development ID.
+/// Note: A database may only be opened once per process, so every map of a ledger shares one handle,
+/// which is closed once all of its maps are dropped.
+#[allow(clippy::type_complexity)]
+static DATABASES: Lazy<Mutex<HashMap<(u16, Option<u16>), Weak<Database>>>> = Lazy::new(Default::default);
+
+/// The development IDs that are reserved for temporary ledger databases, indexed by their network ID.
+static TEMPORARY: Lazy<Mutex<HashSet<(u16, u16)>>> = Lazy::new(Default::default);
+
+/// Reserves a development ID for a temporary ledger database of the given network ID.
+/// Stores that are opened with the returned ID share a database that is deleted once all of them are dropped,
+/// instead of the ledger in the Aleo directory.
+pub fn reserve_temporary(network: u16) -> u16 {
+    // The temporary development IDs are assigned downwards, to stay clear of the ones in use.
+    static NEXT_DEV: AtomicU16 = AtomicU16::new(u16::MAX);
+
+    let dev = NEXT_DEV.fetch_sub(1, Ordering::SeqCst);
+    TEMPORARY.lock().insert((network, dev));
+    dev
+}
+
+/// Returns the ledger database for the given network ID and optional development ID.
+fn open_database(network: u16, dev: Option<u16>) -> Result<Arc<Database>> {
+    let mut databases = DATABASES.lock();
+
+    // Retrieve the database, if it is already open.
+    if let Some(database) = databases.get(&(network, dev)).and_then(Weak::upgrade) {
+        return Ok(database);
+    }
+
+    // Otherwise, open the database, either as a temporary one, or from the path to the ledger.
+    let is_temporary = matches!(dev, Some(dev) if TEMPORARY.lock().contains(&(network, dev)));
+    let database = match is_temporary {
+        true => sled::Config::new().temporary(true).open()?,
+        false => sled::open(aleo_std::aleo_ledger_dir(network, dev))?,
+    };
+
+    let database = Arc::new(Database::new(database));
+    databases.insert((network, dev), Arc::downgrade(&database));
+    Ok(database)
+}
+
+/// The queued operations of a tree, as pairs of a key and its new value, or `None` if the key is removed.
+type Operations = Vec<(Vec<u8>, Option<Vec<u8>>)>;
+
+/// A ledger database, whose trees hold the maps of the ledger.
+struct Database {
+    /// The database handle.
+    database: sled::Db,
+    /// The atomic batches that are in progress on the maps of the database.
+    atomic_writes: Mutex<AtomicWrites>,
+}
+
+/// The atomic batches that are in progress on the maps of a ledger database.
+///
+/// The batches of all maps that are in an atomic operation at the same time are committed together,
+/// in a single transaction across their trees, once the last of them finishes. This ensures that a
+/// write spanning several maps, such as the insertion of a block, is never partially applied.
+#[derive(Default)]
+struct AtomicWrites {
+    /// The number of maps with an atomic batch in progress.
+    in_progress: usize,
+    /// The queued operations of the maps that have finished their atomic batch, indexed by tree name.
+    operations: IndexMap<sled::IVec, (sled::Tree, Operations)>,
+}
+
+impl Database {
+    /// Initializes a new ledger database from the given handle.
+    fn new(database: sled::Db) -> Self {
+        Self { database, atomic_writes: Default::default() }
+    }
+
+    /// Registers the start of an atomic batch on one of the maps.
+    fn start_atomic(&self) {
+        self.atomic_writes.lock().in_progress += 1;
+    }
+
+    /// Registers the abort of an atomic batch on one of the maps.
+    fn abort_atomic(&self) -> Result<()> {
+        self.finish_atomic(None)
+    }
+
+    /// Registers the finish of an atomic batch on the given tree, and commits all of the queued
+    /// operations if there are no more atomic batches in progress.
+    fn finish_atomic(&self, operations: Option<(&sled::Tree, Operations)>) -> Result<()> {
+        let mut atomic_writes = self.atomic_writes.lock();
+
+        // Queue the operations of the tree.
+        if let Some((tree, operations)) = operations.filter(|(_, operations)| !operations.is_empty()) {
+            atomic_writes
+                .operations
+                .entry(tree.name())
+                .or_insert_with(|| (tree.clone(), Vec::new()))
+                .1
+                .extend(operations);
+        }
+        atomic_writes.in_progress = atomic_writes.in_progress.saturating_sub(1);
+
+        // Return early if there are atomic batches still in progress.
+        if atomic_writes.in_progress > 0 {
+            return Ok(());
+        }
+
+        // Prepare the write batch of each tree.
+        let (trees, batches): (Vec<_>, Vec<_>) = core::mem::take(&mut atomic_writes.operations)
+            .into_values()
+            .map(|(tree, operations)| {
+                let mut batch = sled::Batch::default();
+                for (key, value) in operations {
+                    match value {
+                        Some(value) => batch.insert(key, value),
+                        None => batch.remove(key),
+                    }
+                }
+                (tree, batch)
+            })
+            .unzip();
+
+        // Perform all the queued operations in a single transaction across the trees.
+        if !trees.is_empty() {
+            trees
+                .as_slice()
+                .transaction(|trees| {
+                    for (tree, batch) in trees.iter().zip_eq(&batches) {
+                        tree.apply_batch(batch)?;
+                    }
+                    Ok(())
+                })
+                .map_err(|error: TransactionError| anyhow!("Failed to commit the atomic batch: {error:?}"))?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the encoding for keys and values. Integers are encoded in big-endian with a fixed length,
+/// so that the on-disk order of integer keys (such as block heights) matches their numeric order.
+fn encoding() -> impl Options {
+    bincode::DefaultOptions::new().with_big_endian().with_fixint_encoding()
+}
+
+#[derive(Clone)]
+pub struct SledMap<
+    K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> {
+    database: Arc<Database>,
+    tree: sled::Tree,
+    batch_in_progress: Arc<AtomicBool>,
+    atomic_batch: Arc<Mutex<IndexMap<K, Option<V>>>>,
+    _phantom: PhantomData<(K, V)>,
+}
+
+impl<
+    K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> SledMap<K, V>
+{
+    /// Opens the map with the given ID in the ledger database of the given network ID and optional development ID.
+    pub fn open(network: u16, dev: Option<u16>, map_id: MapID) -> Result<Self> {
+        Self::open_tree(&open_database(network, dev)?, map_id)
+    }
+
+    /// Opens the map with the given ID in the given database.
+    fn open_tree(database: &Arc<Database>, map_id: MapID) -> Result<Self> {
+        Ok(Self {
+            database: database.clone(),
+            tree: database.database.open_tree((map_id as u16).to_be_bytes())?,
+            batch_in_progress: Default::default(),
+            atomic_batch: Default::default(),
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<
+    'a,
+    K: 'a + Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: 'a + Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> Map<'a, K, V> for SledMap<K, V>
+{
+    ///
+    /// Inserts the given key-value pair into the map.
+    ///
+    fn insert(&self, key: K, value: V) -> Result<()> {
+        // Determine if an atomic batch is in progress.
+        let is_batch = self.batch_in_progress.load(Ordering::SeqCst);
+
+        match is_batch {
+            // If a batch is in progress, add the key-value pair to the batch.
+            true => {
+                self.atomic_batch.lock().insert(key, Some(value));
+            }
+            // Otherwise, insert the key-value pair directly into the map.
+            false => {
+                self.tree.insert(encoding().serialize(&key)?, encoding().serialize(&value)?)?;
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// Removes the key-value pair for the given key from the map.
+    ///
+    fn remove(&self, key: &K) -> Result<()> {
+        // Determine if an atomic batch is in progress.
+        let is_batch = self.batch_in_progress.load(Ordering::SeqCst);
+
+        match is_batch {
+            // If a batch is in progress, add the key-None pair to the batch.
+            true => {
+                self.atomic_batch.lock().insert(*key, None);
+            }
+            // Otherwise, remove the key-value pair directly from the map.
+            false => {
+                self.tree.remove(encoding().serialize(key)?)?;
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// Begins an atomic operation. Any further calls to `insert` and `remove` will be queued
+    /// without an actual write taking place until `finish_atomic` is called.
+    ///
+    fn start_atomic(&self) {
+        // Set the atomic batch flag to `true`.
+        self.batch_in_progress.store(true, Ordering::SeqCst);
+        // Ensure that the atomic batch is empty.
+        assert!(self.atomic_batch.lock().is_empty());
+        // Register the atomic batch with the database.
+        self.database.start_atomic();
+    }
+
+    ///
+    /// Checks whether an atomic operation is currently in progress. This can be done to ensure
+    /// that lower-level operations don't start and finish their individual atomic write batch
+    /// if they are already part of a larger one.
+    ///
+    fn is_atomic_in_progress(&self) -> bool {
+        self.batch_in_progress.load(Ordering::SeqCst)
+    }
+
+    ///
+    /// Aborts the current atomic operation.
+    ///
+    fn abort_atomic(&self) {
+        // Clear the atomic batch.
+        *self.atomic_batch.lock() = Default::default();
+        // Set the atomic batch flag to `false`, and deregister the atomic batch from the database.
+        if self.batch_in_progress.swap(false, Ordering::SeqCst) {
+            if let Err(error) = self.database.abort_atomic() {
+                error!("SledMap atomic batch error: {error}");
+            }
+        }
+    }
+
+    ///
+    /// Finishes an atomic operation, performing all the queued writes.
+    ///
+    /// Note: The writes are performed once the atomic operations of all the maps in the same
+    /// ledger database have finished, in a single transaction across the maps.
+    ///
+    fn finish_atomic(&self) -> Result<()> {
+        // Retrieve the atomic batch.
+        let operations = core::mem::take(&mut *self.atomic_batch.lock());
+
+        // Prepare the queued operations.
+        let operations = operations
+            .into_iter()
+            .map(|(key, value)| {
+                Ok((encoding().serialize(&key)?, value.map(|value| encoding().serialize(&value)).transpose()?))
+            })
+            .collect::<Result<Vec<_>>>()
+            .map_err(|error| {
+                self.abort_atomic();
+                error
+            })?;
+
+        // Set the atomic batch flag to `false`.
+        self.batch_in_progress.store(false, Ordering::SeqCst);
+
+        // Finish the atomic batch in the database, which performs the queued writes once all maps are done.
+        self.database.finish_atomic(Some((&self.tree, operations)))
+    }
+}
+
+impl<
+    'a,
+    K: 'a + Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: 'a + Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> MapRead<'a, K, V> for SledMap<K, V>
+{
+    type Iterator = Iter<'a, K, V>;
+    type Keys = Keys<'a, K>;
+    type Values = Values<'a, V>;
+
+    ///
+    /// Returns `true` if the given key exists in the map.
+    ///
+    fn contains_key<Q>(&self, key: &Q) -> Result<bool>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        Ok(self.tree.contains_key(encoding().serialize(key)?)?)
+    }
+
+    ///
+    /// Returns the value for the given key from the map, if it exists.
+    ///
+    fn get<Q>(&'a self, key: &Q) -> Result<Option<Cow<'a, V>>>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        match self.tree.get(encoding().serialize(key)?)? {
+            Some(bytes) => Ok(Some(Cow::Owned(encoding().deserialize(&bytes)?))),
+            None => Ok(None),
+        }
+    }
+
+    ///
+    /// Returns the current value for the given key if it is scheduled
+    /// to be inserted as part of an atomic batch.
+    ///
+    /// If the key does not exist, returns `None`.
+    /// If the key is removed in the batch, returns `Some(None)`.
+    /// If the key is inserted in the batch, returns `Some(Some(value))`.
+    ///
+    fn get_batched<Q>(&self, key: &Q) -> Option<Option<V>>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        // Return early if there is no atomic batch in progress.
+        if self.batch_in_progress.load(Ordering::SeqCst) { self.atomic_batch.lock().get(key).cloned() } else { None }
+    }
+
+    ///
+    /// Returns an iterator visiting each key-value pair in the map.
+    ///
+    fn iter(&'a self) -> Self::Iterator {
+        Iter { inner: self.tree.iter(), _phantom: PhantomData }
+    }
+
+    ///
+    /// Returns an iterator over each key in the map.
+    ///
+    fn keys(&'a self) -> Self::Keys {
+        Keys { inner: self.tree.iter(), _phantom: PhantomData }
+    }
+
+    ///
+    /// Returns an iterator over each value in the map.
+    ///
+    fn values(&'a self) -> Self::Values {
+        Values { inner: self.tree.iter(), _phantom: PhantomData }
+    }
+}
+
+/// An iterator over the key-value pairs of a `SledMap`.
+pub struct Iter<'a, K: 'a + for<'de> Deserialize<'de>, V: 'a + for<'de> Deserialize<'de>> {
+    inner: sled::Iter,
+    _phantom: PhantomData<(&'a K, &'a V)>,
+}
+
+impl<'a, K: 'a + Clone + for<'de> Deserialize<'de>, V: 'a + Clone + for<'de> Deserialize<'de>> Iterator
+    for Iter<'a, K, V>
+{
+    type Item = (Cow<'a, K>, Cow<'a, V>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Retrieve the next entry, halting the iteration on a storage error.
+        let (key, value) = self.inner.next()?.map_err(|error| error!("SledMap iterator error: {error}")).ok()?;
+        // Deserialize the key and value.
+        let key = encoding().deserialize(&key).map_err(|error| error!("SledMap key decoding error: {error}")).ok()?;
+        let value =
+            encoding().deserialize(&value).map_err(|error| error!("SledMap value decoding error: {error}")).ok()?;
+        Some((Cow::Owned(key), Cow::Owned(value)))
+    }
+}
+
+/// An iterator over the keys of a `SledMap`.
+pub struct Keys<'a, K: 'a + for<'de> Deserialize<'de>> {
+    inner: sled::Iter,
+    _phantom: PhantomData<&'a K>,
+}
+
+impl<'a, K: 'a + Clone + for<'de> Deserialize<'de>> Iterator for Keys<'a, K> {
+    type Item = Cow<'a, K>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Retrieve the next key, halting the iteration on a storage error.
+        let (key, _) = self.inner.next()?.map_err(|error| error!("SledMap iterator error: {error}")).ok()?;
+        // Deserialize the key.
+        let key = encoding().deserialize(&key).map_err(|error| error!("SledMap key decoding error: {error}")).ok()?;
+        Some(Cow::Owned(key))
+    }
+}
+
+/// An iterator over the values of a `SledMap`.
+pub struct Values<'a, V: 'a + for<'de> Deserialize<'de>> {
+    inner: sled::Iter,
+    _phantom: PhantomData<&'a V>,
+}
+
+impl<'a, V: 'a + Clone + for<'de> Deserialize<'de>> Iterator for Values<'a, V> {
+    type Item = Cow<'a, V>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Retrieve the next value, halting the iteration on a storage error.
+        let (_, value) = self.inner.next()?.map_err(|error| error!("SledMap iterator error: {error}")).ok()?;
+        // Deserialize the value.
+        let value =
+            encoding().deserialize(&value).map_err(|error| error!("SledMap value decoding error: {error}")).ok()?;
+        Some(Cow::Owned(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::Address, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns a new map, backed by a temporary database.
+    fn sample_map<
+        K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+        V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    >() -> SledMap<K, V> {
+        SledMap::open(CurrentNetwork::ID, Some(reserve_temporary(CurrentNetwork::ID)), MapID::BlockID).unwrap()
+    }
+
+    #[test]
+    fn test_contains_key() {
+        // Initialize an address.
+        let address =
+            Address::<CurrentNetwork>::from_str("aleo1q6qstg8q8shwqf5m6q5fcenuwsdqsvp4hhsgfnx5chzjm3secyzqt9mxm8")
+                .unwrap();
+
+        // Initialize a map.
+        let map: SledMap<Address<CurrentNetwork>, ()> = sample_map();
+        map.insert(address, ()).unwrap();
+        assert!(map.contains_key(&address).unwrap());
+    }
+
+    #[test]
+    fn test_keys_are_ordered() {
+        // Initialize a map.
+        let map: SledMap<u32, String> = sample_map();
+
+        // Insert the items out of order.
+        for i in [256u32, 1, 65536, 0, 255] {
+            map.insert(i, i.to_string()).unwrap();
+        }
+
+        // Check that the keys are iterated in numeric order.
+        assert_eq!(map.keys().map(|k| *k).collect::<Vec<_>>(), vec![0, 1, 255, 256, 65536]);
+        // Check that the values are iterated in key order.
+        assert_eq!(map.values().map(|v| v.into_owned()).collect::<Vec<_>>(), vec!["0", "1", "255", "256", "65536"]);
+    }
+
+    #[test]
+    fn test_insert_and_get_speculative() {
+        // Initialize a map.
+        let map: SledMap<usize, String> = sample_map();
+
+        // Sanity check.
+        assert!(map.iter().next().is_none());
+
+        /* test atomic insertions */
+
+        // Start an atomic write batch.
+        map.start_atomic();
+
+        // Insert an item into the map.
+        map.insert(0, "0".to_string()).unwrap();
+
+        // Check that the item is not yet in the map.
+        assert!(map.get(&0).unwrap().is_none());
+        // Check that the item is in the batch.
+        assert_eq!(map.get_batched(&0), Some(Some("0".to_string())));
+        // Check that the item can be speculatively retrieved.
+        assert_eq!(map.get_speculative(&0).unwrap(), Some(Cow::Owned("0".to_string())));
+
+        // Queue (since a batch is in progress) NUM_ITEMS insertions.
+        for i in 1..10 {
+            // Update the item in the map.
+            map.insert(0, i.to_string()).unwrap();
+
+            // Check that the item is not yet in the map.
+            assert!(map.get(&0).unwrap().is_none());
+            // Check that the updated item is in the batch.
+            assert_eq!(map.get_batched(&0), Some(Some(i.to_string())));
+            // Check that the updated item can be speculatively retrieved.
+            assert_eq!(map.get_speculative(&0).unwrap(), Some(Cow::Owned(i.to_string())));
+        }
+
+        // The map should still contain no items.
+        assert!(map.iter().next().is_none());
+
+        // Finish the current atomic write batch.
+        map.finish_atomic().unwrap();
+
+        // Check that the item is present in the map now.
+        assert_eq!(map.get(&0).unwrap(), Some(Cow::Owned("9".to_string())));
+        // Check that the item is not in the batch.
+        assert_eq!(map.get_batched(&0), None);
+        // Check that the item can be speculatively retrieved.
+        assert_eq!(map.get_speculative(&0).unwrap(), Some(Cow::Owned("9".to_string())));
+    }
+
+    #[test]
+    fn test_remove_and_get_speculative() {
+        // Initialize a map.
+        let map: SledMap<usize, String> = sample_map();
+
+        // Insert an item into the map.
+        map.insert(0, "0".to_string()).unwrap();
+
+        // Check that the item is present in the map .
+        assert_eq!(map.get(&0).unwrap(), Some(Cow::Owned("0".to_string())));
+
+        /* test atomic removals */
+
+        // Start an atomic write batch.
+        map.start_atomic();
+
+        // Remove the item from the map.
+        map.remove(&0).unwrap();
+
+        // Check that the item still exists in the map.
+        assert_eq!(map.get(&0).unwrap(), Some(Cow::Owned("0".to_string())));
+        // Check that the item is removed in the batch.
+        assert_eq!(map.get_batched(&0), Some(None));
+        // Check that the item is removed when speculatively retrieved.
+        assert_eq!(map.get_speculative(&0).unwrap(), None);
+
+        // Finish the current atomic write batch.
+        map.finish_atomic().unwrap();
+
+        // Check that the item is not present in the map now.
+        assert!(map.get(&0).unwrap().is_none());
+        // Check that the item is not in the batch.
+        assert_eq!(map.get_batched(&0), None);
+        // Check that the map is empty now.
+        assert!(map.iter().next().is_none());
+    }
+
+    #[test]
+    fn test_atomic_writes_can_be_aborted() {
+        // The number of items that will be queued to be inserted into the map.
+        const NUM_ITEMS: usize = 10;
+
+        // Initialize a map.
+        let map: SledMap<usize, String> = sample_map();
+
+        // Start an atomic write batch.
+        map.start_atomic();
+
+        // Queue (since a batch is in progress) NUM_ITEMS insertions.
+        for i in 0..NUM_ITEMS {
+            map.insert(i, i.to_string()).unwrap();
+        }
+
+        // Abort the current atomic write batch.
+        map.abort_atomic();
+
+        // The map should still contain no items.
+        assert!(map.iter().next().is_none());
+
+        // Start another atomic write batch.
+        map.start_atomic();
+
+        // Queue (since a batch is in progress) NUM_ITEMS insertions.
+        for i in 0..NUM_ITEMS {
+            map.insert(i, i.to_string()).unwrap();
+        }
+
+        // Finish the current atomic write batch.
+        map.finish_atomic().unwrap();
+
+        // The map should contain NUM_ITEMS items now.
+        assert_eq!(map.iter().count(), NUM_ITEMS);
+    }
+
+    #[test]
+    fn test_atomic_writes_span_maps() {
+        // Initialize two maps in the same database.
+        let dev = Some(reserve_temporary(CurrentNetwork::ID));
+        let map_a: SledMap<u32, String> = SledMap::open(CurrentNetwork::ID, dev, MapID::BlockID).unwrap();
+        let map_b: SledMap<u32, String> = SledMap::open(CurrentNetwork::ID, dev, MapID::BlockReverseID).unwrap();
+
+        // Start an atomic write batch on both maps.
+        map_a.start_atomic();
+        map_b.start_atomic();
+
+        // Queue an insertion in each map.
+        map_a.insert(0, "a".to_string()).unwrap();
+        map_b.insert(0, "b".to_string()).unwrap();
+
+        // Finish the atomic write batch of the first map.
+        map_a.finish_atomic().unwrap();
+
+        // Check that no write is performed while the second map is still in its atomic write batch.
+        assert!(map_a.get(&0).unwrap().is_none());
+        assert!(map_b.get(&0).unwrap().is_none());
+
+        // Finish the atomic write batch of the second map.
+        map_b.finish_atomic().unwrap();
+
+        // Check that both writes are performed now.
+        assert_eq!(map_a.get(&0).unwrap(), Some(Cow::Owned("a".to_string())));
+        assert_eq!(map_b.get(&0).unwrap(), Some(Cow::Owned("b".to_string())));
+
+        // Start an atomic write batch on both maps, and abort one of them.
+        map_a.start_atomic();
+        map_b.start_atomic();
+        map_a.insert(1, "a".to_string()).unwrap();
+        map_b.abort_atomic();
+        map_a.finish_atomic().unwrap();
+
+        // Check that the writes of the finished map are performed.
+        assert_eq!(map_a.get(&1).unwrap(), Some(Cow::Owned("a".to_string())));
+        assert!(map_b.get(&1).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_temporary_databases_are_separate() {
+        // Initialize two maps with the same ID in different temporary databases.
+        let map_a: SledMap<u32, String> = sample_map();
+        let map_b: SledMap<u32, String> = sample_map();
+
+        // Insert an item into the first map.
+        map_a.insert(0, "0".to_string()).unwrap();
+
+        // Check that the item is not visible from the second map.
+        assert!(map_b.get(&0).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_reopen_persists() {
+        // Initialize a path for the database.
+        let path = std::env::temp_dir().join(format!(".ledger-test-reopen-{}", std::process::id()));
+
+        {
+            // Open the database, and insert an item into a map.
+            let database = Arc::new(Database::new(sled::open(&path).unwrap()));
+            let map: SledMap<u32, String> = SledMap::open_tree(&database, MapID::BlockID).unwrap();
+            map.insert(0, "0".to_string()).unwrap();
+            database.database.flush().unwrap();
+        }
+
+        {
+            // Reopen the database, and check that the item persisted.
+            let database = Arc::new(Database::new(sled::open(&path).unwrap()));
+            let map: SledMap<u32, String> = SledMap::open_tree(&database, MapID::BlockID).unwrap();
+            assert_eq!(map.get(&0).unwrap(), Some(Cow::Owned("0".to_string())));
+            // Check that the item is not visible from a different map.
+            let map: SledMap<u32, String> = SledMap::open_tree(&database, MapID::BlockReverseID).unwrap();
+            assert!(map.get(&0).unwrap().is_none());
+        }
+
+        // Remove the database.
+        std::fs::remove_dir_all(&path).unwrap();
+    }
+}
@@ -14,6 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+#[cfg(feature = "persistent")]
+use crate::store::helpers::sled_map::{MapID, SledMap};
 use crate::{
     atomic_write_batch,
     cow_to_cloned,
@@ -568,6 +570,76 @@ impl<N: Network> ProgramStorage<N> for ProgramMemory<N> {
     }
 }
 
+/// A database-backed program state storage.
+#[cfg(feature = "persistent")]
+#[derive(Clone)]
+pub struct ProgramDB<N: Network> {
+    /// The program ID map.
+    program_id_map: SledMap<ProgramID<N>, IndexSet<Identifier<N>>>,
+    /// The mapping ID map.
+    mapping_id_map: SledMap<(ProgramID<N>, Identifier<N>), Field<N>>,
+    /// The key-value ID map.
+    key_value_id_map: SledMap<Field<N>, IndexMap<Field<N>, Field<N>>>,
+    /// The key map.
+    key_map: SledMap<Field<N>, Plaintext<N>>,
+    /// The value map.
+    value_map: SledMap<Field<N>, Value<N>>,
+    /// The optional development ID.
+    dev: Option<u16>,
+}
+
+#[cfg(feature = "persistent")]
+#[rustfmt::skip]
+impl<N: Network> ProgramStorage<N> for ProgramDB<N> {
+    type ProgramIDMap = SledMap<ProgramID<N>, IndexSet<Identifier<N>>>;
+    type MappingIDMap = SledMap<(ProgramID<N>, Identifier<N>), Field<N>>;
+    type KeyValueIDMap = SledMap<Field<N>, IndexMap<Field<N>, Field<N>>>;
+    type KeyMap = SledMap<Field<N>, Plaintext<N>>;
+    type ValueMap = SledMap<Field<N>, Value<N>>;
+
+    /// Initializes the program state storage.
+    fn open(dev: Option<u16>) -> Result<Self> {
+        Ok(Self {
+            program_id_map: SledMap::open(N::ID, dev, MapID::ProgramID)?,
+            mapping_id_map: SledMap::open(N::ID, dev, MapID::ProgramMappingID)?,
+            key_value_id_map: SledMap::open(N::ID, dev, MapID::ProgramKeyValueID)?,
+            key_map: SledMap::open(N::ID, dev, MapID::ProgramKey)?,
+            value_map: SledMap::open(N::ID, dev, MapID::ProgramValue)?,
+            dev,
+        })
+    }
+
+    /// Returns the program ID map.
+    fn program_id_map(&self) -> &Self::ProgramIDMap {
+        &self.program_id_map
+    }
+
+    /// Returns the mapping ID map.
+    fn mapping_id_map(&self) -> &Self::MappingIDMap {
+        &self.mapping_id_map
+    }
+
+    /// Returns the key-value ID map.
+    fn key_value_id_map(&self) -> &Self::KeyValueIDMap {
+        &self.key_value_id_map
+    }
+
+    /// Returns the key map.
+    fn key_map(&self) -> &Self::KeyMap {
+        &self.key_map
+    }
+
+    /// Returns the value map.
+    fn value_map(&self) -> &Self::ValueMap {
+        &self.value_map
+    }
+
+    /// Returns the optional development ID.
+    fn dev(&self) -> Option<u16> {
+        self.dev
+    }
+}
+
 /// The program store.
 #[derive(Clone)]
 pub struct ProgramStore<N: Network, P: ProgramStorage<N>> {
@@ -714,7 +786,7 @@ mod tests {
 
     /// Checks `initialize_mapping`, `insert_key_value`, `remove_key_value`, and `remove_mapping`.
     fn check_initialize_insert_remove<N: Network>(
-        program_store: &ProgramMemory<N>,
+        program_store: &impl ProgramStorage<N>,
         program_id: ProgramID<N>,
         mapping_name: Identifier<N>,
     ) {
@@ -787,7 +859,7 @@ mod tests {
 
     /// Checks `initialize_mapping`, `update_key_value`, `remove_key_value`, and `remove_mapping`.
     fn check_initialize_update_remove<N: Network>(
-        program_store: &ProgramMemory<N>,
+        program_store: &impl ProgramStorage<N>,
         program_id: ProgramID<N>,
         mapping_name: Identifier<N>,
     ) {
@@ -912,6 +984,19 @@ mod tests {
         check_initialize_insert_remove(&program_store, program_id, mapping_name);
     }
 
+    #[cfg(feature = "persistent")]
+    #[test]
+    fn test_initialize_insert_remove_db() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Initialize a new program store.
+        let program_store = ProgramDB::open(crate::store::helpers::test_helpers::sample_dev()).unwrap();
+        // Check the operations.
+        check_initialize_insert_remove(&program_store, program_id, mapping_name);
+    }
+
     #[test]
     fn test_initialize_update_remove() {
         // Initialize a program ID and mapping name.
@@ -924,14 +1009,26 @@ mod tests {
         check_initialize_update_remove(&program_store, program_id, mapping_name);
     }
 
+    #[cfg(feature = "persistent")]
     #[test]
-    fn test_remove_key_value() {
+    fn test_initialize_update_remove_db() {
         // Initialize a program ID and mapping name.
         let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
         let mapping_name = Identifier::from_str("account").unwrap();
 
         // Initialize a new program store.
-        let program_store = ProgramMemory::open(None).unwrap();
+        let program_store = ProgramDB::open(crate::store::helpers::test_helpers::sample_dev()).unwrap();
+        // Check the operations.
+        check_initialize_update_remove(&program_store, program_id, mapping_name);
+    }
+
+    fn check_remove_key_value<S: ProgramStorage<CurrentNetwork>>() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Initialize a new program store.
+        let program_store = S::open(crate::store::helpers::test_helpers::sample_dev()).unwrap();
         // Ensure the program ID does not exist.
         assert!(!program_store.contains_program(&program_id).unwrap());
         // Ensure the mapping name does not exist.
@@ -991,14 +1088,13 @@ mod tests {
         }
     }
 
-    #[test]
-    fn test_remove_mapping() {
+    fn check_remove_mapping<S: ProgramStorage<CurrentNetwork>>() {
         // Initialize a program ID and mapping name.
         let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
         let mapping_name = Identifier::from_str("account").unwrap();
 
         // Initialize a new program store.
-        let program_store = ProgramMemory::open(None).unwrap();
+        let program_store = S::open(crate::store::helpers::test_helpers::sample_dev()).unwrap();
         // Ensure the program ID does not exist.
         assert!(!program_store.contains_program(&program_id).unwrap());
         // Ensure the mapping name does not exist.
@@ -1054,14 +1150,13 @@ mod tests {
         }
     }
 
-    #[test]
-    fn test_remove_program() {
+    fn check_remove_program<S: ProgramStorage<CurrentNetwork>>() {
         // Initialize a program ID and mapping name.
         let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
         let mapping_name = Identifier::from_str("account").unwrap();
 
         // Initialize a new program store.
-        let program_store = ProgramMemory::open(None).unwrap();
+        let program_store = S::open(crate::store::helpers::test_helpers::sample_dev()).unwrap();
         // Ensure the program ID does not exist.
         assert!(!program_store.contains_program(&program_id).unwrap());
         // Ensure the mapping name does not exist.
@@ -1117,14 +1212,13 @@ mod tests {
         }
     }
 
-    #[test]
-    fn test_must_initialize_first() {
+    fn check_must_initialize_first<S: ProgramStorage<CurrentNetwork>>() {
         // Initialize a program ID and mapping name.
         let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
         let mapping_name = Identifier::from_str("account").unwrap();
 
         // Initialize a new program store.
-        let program_store = ProgramMemory::open(None).unwrap();
+        let program_store = S::open(crate::store::helpers::test_helpers::sample_dev()).unwrap();
         // Ensure the program ID does not exist.
         assert!(!program_store.contains_program(&program_id).unwrap());
         // Ensure the mapping name does not exist.
@@ -1175,4 +1269,48 @@ mod tests {
         check_initialize_insert_remove(&program_store, program_id, mapping_name);
         check_initialize_update_remove(&program_store, program_id, mapping_name);
     }
+
+    #[test]
+    fn test_remove_key_value() {
+        check_remove_key_value::<ProgramMemory<CurrentNetwork>>();
+    }
+
+    #[cfg(feature = "persistent")]
+    #[test]
+    fn test_remove_key_value_db() {
+        check_remove_key_value::<ProgramDB<CurrentNetwork>>();
+    }
+
+    #[test]
+    fn test_remove_mapping() {
+        check_remove_mapping::<ProgramMemory<CurrentNetwork>>();
+    }
+
+    #[cfg(feature = "persistent")]
+    #[test]
+    fn test_remove_mapping_db() {
+        check_remove_mapping::<ProgramDB<CurrentNetwork>>();
+    }
+
+    #[test]
+    fn test_remove_program() {
+        check_remove_program::<ProgramMemory<CurrentNetwork>>();
+    }
+
+    #[cfg(feature = "persistent")]
+    #[test]
+    fn test_remove_program_db() {
+        check_remove_program::<ProgramDB<CurrentNetwork>>();
+    }
+
+    #[test]
+    fn test_must_initialize_first() {
+        check_must_initialize_first::<ProgramMemory<CurrentNetwork>>();
+    }
+
+    #[cfg(feature = "persistent")]
+    #[test]
+    fn test_must_initialize_first_db() {
+        check_must_initialize_first::<ProgramDB<CurrentNetwork>>();
+    }
 }
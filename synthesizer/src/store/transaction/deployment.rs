@@ -14,6 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+#[cfg(feature = "persistent")]
+use crate::store::{
+    helpers::sled_map::{MapID, SledMap},
+    TransitionDB,
+};
 use crate::{
     atomic_write_batch,
     block::Transaction,
@@ -492,6 +497,98 @@ impl<N: Network> DeploymentStorage<N> for DeploymentMemory<N> {
     }
 }
 
+/// A database-backed deployment storage.
+#[cfg(feature = "persistent")]
+#[derive(Clone)]
+#[allow(clippy::type_complexity)]
+pub struct DeploymentDB<N: Network> {
+    /// The ID map.
+    id_map: SledMap<N::TransactionID, ProgramID<N>>,
+    /// The edition map.
+    edition_map: SledMap<ProgramID<N>, u16>,
+    /// The reverse ID map.
+    reverse_id_map: SledMap<(ProgramID<N>, u16), N::TransactionID>,
+    /// The program map.
+    program_map: SledMap<(ProgramID<N>, u16), Program<N>>,
+    /// The verifying key map.
+    verifying_key_map: SledMap<(ProgramID<N>, Identifier<N>, u16), VerifyingKey<N>>,
+    /// The certificate map.
+    certificate_map: SledMap<(ProgramID<N>, Identifier<N>, u16), Certificate<N>>,
+    /// The fee map.
+    fee_map: SledMap<N::TransactionID, (N::TransitionID, N::StateRoot, Option<Proof<N>>)>,
+    /// The transition store.
+    transition_store: TransitionStore<N, TransitionDB<N>>,
+}
+
+#[cfg(feature = "persistent")]
+#[rustfmt::skip]
+impl<N: Network> DeploymentStorage<N> for DeploymentDB<N> {
+    type IDMap = SledMap<N::TransactionID, ProgramID<N>>;
+    type EditionMap = SledMap<ProgramID<N>, u16>;
+    type ReverseIDMap = SledMap<(ProgramID<N>, u16), N::TransactionID>;
+    type ProgramMap = SledMap<(ProgramID<N>, u16), Program<N>>;
+    type VerifyingKeyMap = SledMap<(ProgramID<N>, Identifier<N>, u16), VerifyingKey<N>>;
+    type CertificateMap = SledMap<(ProgramID<N>, Identifier<N>, u16), Certificate<N>>;
+    type FeeMap = SledMap<N::TransactionID, (N::TransitionID, N::StateRoot, Option<Proof<N>>)>;
+    type TransitionStorage = TransitionDB<N>;
+
+    /// Initializes the deployment storage.
+    fn open(transition_store: TransitionStore<N, Self::TransitionStorage>) -> Result<Self> {
+        // Retrieve the optional development ID.
+        let dev = transition_store.dev();
+        Ok(Self {
+            id_map: SledMap::open(N::ID, dev, MapID::DeploymentID)?,
+            edition_map: SledMap::open(N::ID, dev, MapID::DeploymentEdition)?,
+            reverse_id_map: SledMap::open(N::ID, dev, MapID::DeploymentReverseID)?,
+            program_map: SledMap::open(N::ID, dev, MapID::DeploymentProgram)?,
+            verifying_key_map: SledMap::open(N::ID, dev, MapID::DeploymentVerifyingKey)?,
+            certificate_map: SledMap::open(N::ID, dev, MapID::DeploymentCertificate)?,
+            fee_map: SledMap::open(N::ID, dev, MapID::DeploymentFee)?,
+            transition_store,
+        })
+    }
+
+    /// Returns the ID map.
+    fn id_map(&self) -> &Self::IDMap {
+        &self.id_map
+    }
+
+    /// Returns the edition map.
+    fn edition_map(&self) -> &Self::EditionMap {
+        &self.edition_map
+    }
+
+    /// Returns the reverse ID map.
+    fn reverse_id_map(&self) -> &Self::ReverseIDMap {
+        &self.reverse_id_map
+    }
+
+    /// Returns the program map.
+    fn program_map(&self) -> &Self::ProgramMap {
+        &self.program_map
+    }
+
+    /// Returns the verifying key map.
+    fn verifying_key_map(&self) -> &Self::VerifyingKeyMap {
+        &self.verifying_key_map
+    }
+
+    /// Returns the certificate map.
+    fn certificate_map(&self) -> &Self::CertificateMap {
+        &self.certificate_map
+    }
+
+    /// Returns the fee map.
+    fn fee_map(&self) -> &Self::FeeMap {
+        &self.fee_map
+    }
+
+    /// Returns the transition store.
+    fn transition_store(&self) -> &TransitionStore<N, Self::TransitionStorage> {
+        &self.transition_store
+    }
+}
+
 /// The deployment store.
 #[derive(Clone)]
 pub struct DeploymentStore<N: Network, D: DeploymentStorage<N>> {
@@ -655,9 +752,11 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use console::network::Testnet3;
 
-    #[test]
-    fn test_insert_get_remove() {
+    type CurrentNetwork = Testnet3;
+
+    fn check_insert_get_remove<S: DeploymentStorage<CurrentNetwork>>() {
         let rng = &mut TestRng::default();
 
         // Sample the deployment transaction.
@@ -665,9 +764,11 @@ mod tests {
         let transaction_id = transaction.id();
 
         // Initialize a new transition store.
-        let transition_store = TransitionStore::open(None).unwrap();
+        let transition_store =
+            TransitionStore::<_, S::TransitionStorage>::open(crate::store::helpers::test_helpers::sample_dev())
+                .unwrap();
         // Initialize a new deployment store.
-        let deployment_store = DeploymentMemory::open(transition_store).unwrap();
+        let deployment_store = S::open(transition_store).unwrap();
 
         // Ensure the deployment transaction does not exist.
         let candidate = deployment_store.get_transaction(&transaction_id).unwrap();
@@ -688,8 +789,7 @@ mod tests {
         assert_eq!(None, candidate);
     }
 
-    #[test]
-    fn test_find_transaction_id() {
+    fn check_find_transaction_id<S: DeploymentStorage<CurrentNetwork>>() {
         let rng = &mut TestRng::default();
 
         // Sample the deployment transaction.
@@ -701,9 +801,11 @@ mod tests {
         };
 
         // Initialize a new transition store.
-        let transition_store = TransitionStore::open(None).unwrap();
+        let transition_store =
+            TransitionStore::<_, S::TransitionStorage>::open(crate::store::helpers::test_helpers::sample_dev())
+                .unwrap();
         // Initialize a new deployment store.
-        let deployment_store = DeploymentMemory::open(transition_store).unwrap();
+        let deployment_store = S::open(transition_store).unwrap();
 
         // Ensure the deployment transaction does not exist.
         let candidate = deployment_store.get_transaction(&transaction_id).unwrap();
@@ -727,4 +829,26 @@ mod tests {
         let candidate = deployment_store.find_transaction_id(&program_id).unwrap();
         assert_eq!(None, candidate);
     }
+
+    #[test]
+    fn test_insert_get_remove() {
+        check_insert_get_remove::<DeploymentMemory<CurrentNetwork>>();
+    }
+
+    #[cfg(feature = "persistent")]
+    #[test]
+    fn test_insert_get_remove_db() {
+        check_insert_get_remove::<DeploymentDB<CurrentNetwork>>();
+    }
+
+    #[test]
+    fn test_find_transaction_id() {
+        check_find_transaction_id::<DeploymentMemory<CurrentNetwork>>();
+    }
+
+    #[cfg(feature = "persistent")]
+    #[test]
+    fn test_find_transaction_id_db() {
+        check_find_transaction_id::<DeploymentDB<CurrentNetwork>>();
+    }
 }
@@ -14,6 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+#[cfg(feature = "persistent")]
+use crate::store::{
+    helpers::sled_map::{MapID, SledMap},
+    TransitionDB,
+};
 use crate::{
     atomic_write_batch,
     block::{Transaction, Transition},
@@ -355,6 +360,71 @@ impl<N: Network> ExecutionStorage<N> for ExecutionMemory<N> {
     }
 }
 
+/// A database-backed execution storage.
+#[cfg(feature = "persistent")]
+#[derive(Clone)]
+#[allow(clippy::type_complexity)]
+pub struct ExecutionDB<N: Network> {
+    /// The ID map.
+    id_map: SledMap<N::TransactionID, (Vec<N::TransitionID>, Option<N::TransitionID>)>,
+    /// The reverse ID map.
+    reverse_id_map: SledMap<N::TransitionID, N::TransactionID>,
+    /// The transition store.
+    transition_store: TransitionStore<N, TransitionDB<N>>,
+    /// The inclusion map.
+    inclusion_map: SledMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>,
+    /// The fee map.
+    fee_map: SledMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>,
+}
+
+#[cfg(feature = "persistent")]
+#[rustfmt::skip]
+impl<N: Network> ExecutionStorage<N> for ExecutionDB<N> {
+    type IDMap = SledMap<N::TransactionID, (Vec<N::TransitionID>, Option<N::TransitionID>)>;
+    type ReverseIDMap = SledMap<N::TransitionID, N::TransactionID>;
+    type TransitionStorage = TransitionDB<N>;
+    type InclusionMap = SledMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>;
+    type FeeMap = SledMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>;
+
+    /// Initializes the execution storage.
+    fn open(transition_store: TransitionStore<N, Self::TransitionStorage>) -> Result<Self> {
+        // Retrieve the optional development ID.
+        let dev = transition_store.dev();
+        Ok(Self {
+            id_map: SledMap::open(N::ID, dev, MapID::ExecutionID)?,
+            reverse_id_map: SledMap::open(N::ID, dev, MapID::ExecutionReverseID)?,
+            transition_store,
+            inclusion_map: SledMap::open(N::ID, dev, MapID::ExecutionInclusion)?,
+            fee_map: SledMap::open(N::ID, dev, MapID::ExecutionFee)?,
+        })
+    }
+
+    /// Returns the ID map.
+    fn id_map(&self) -> &Self::IDMap {
+        &self.id_map
+    }
+
+    /// Returns the reverse ID map.
+    fn reverse_id_map(&self) -> &Self::ReverseIDMap {
+        &self.reverse_id_map
+    }
+
+    /// Returns the transition store.
+    fn transition_store(&self) -> &TransitionStore<N, Self::TransitionStorage> {
+        &self.transition_store
+    }
+
+    /// Returns the inclusion map.
+    fn inclusion_map(&self) -> &Self::InclusionMap {
+        &self.inclusion_map
+    }
+
+    /// Returns the fee map.
+    fn fee_map(&self) -> &Self::FeeMap {
+        &self.fee_map
+    }
+}
+
 /// The execution store.
 #[derive(Clone)]
 pub struct ExecutionStore<N: Network, E: ExecutionStorage<N>> {
@@ -448,9 +518,11 @@ impl<N: Network, E: ExecutionStorage<N>> ExecutionStore<N, E> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use console::network::Testnet3;
 
-    #[test]
-    fn test_insert_get_remove() {
+    type CurrentNetwork = Testnet3;
+
+    fn check_insert_get_remove<S: ExecutionStorage<CurrentNetwork>>() {
         let rng = &mut TestRng::default();
 
         // Sample the execution transaction.
@@ -458,9 +530,11 @@ mod tests {
         let transaction_id = transaction.id();
 
         // Initialize a new transition store.
-        let transition_store = TransitionStore::open(None).unwrap();
+        let transition_store =
+            TransitionStore::<_, S::TransitionStorage>::open(crate::store::helpers::test_helpers::sample_dev())
+                .unwrap();
         // Initialize a new execution store.
-        let execution_store = ExecutionMemory::open(transition_store).unwrap();
+        let execution_store = S::open(transition_store).unwrap();
 
         // Ensure the execution transaction does not exist.
         let candidate = execution_store.get_transaction(&transaction_id).unwrap();
@@ -481,8 +555,7 @@ mod tests {
         assert_eq!(None, candidate);
     }
 
-    #[test]
-    fn test_find_transaction_id() {
+    fn check_find_transaction_id<S: ExecutionStorage<CurrentNetwork>>() {
         let rng = &mut TestRng::default();
 
         // Sample the execution transaction.
@@ -496,9 +569,11 @@ mod tests {
         };
 
         // Initialize a new transition store.
-        let transition_store = TransitionStore::open(None).unwrap();
+        let transition_store =
+            TransitionStore::<_, S::TransitionStorage>::open(crate::store::helpers::test_helpers::sample_dev())
+                .unwrap();
         // Initialize a new execution store.
-        let execution_store = ExecutionMemory::open(transition_store).unwrap();
+        let execution_store = S::open(transition_store).unwrap();
 
         // Ensure the execution transaction does not exist.
         let candidate = execution_store.get_transaction(&transaction_id).unwrap();
@@ -524,4 +599,26 @@ mod tests {
             assert_eq!(None, candidate);
         }
     }
+
+    #[test]
+    fn test_insert_get_remove() {
+        check_insert_get_remove::<ExecutionMemory<CurrentNetwork>>();
+    }
+
+    #[cfg(feature = "persistent")]
+    #[test]
+    fn test_insert_get_remove_db() {
+        check_insert_get_remove::<ExecutionDB<CurrentNetwork>>();
+    }
+
+    #[test]
+    fn test_find_transaction_id() {
+        check_find_transaction_id::<ExecutionMemory<CurrentNetwork>>();
+    }
+
+    #[cfg(feature = "persistent")]
+    #[test]
+    fn test_find_transaction_id_db() {
+        check_find_transaction_id::<ExecutionDB<CurrentNetwork>>();
+    }
 }
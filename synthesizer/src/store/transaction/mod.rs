@@ -20,6 +20,11 @@ pub use deployment::*;
 mod execution;
 pub use execution::*;
 
+#[cfg(feature = "persistent")]
+use crate::store::{
+    helpers::sled_map::{MapID, SledMap},
+    TransitionDB,
+};
 use crate::{
     atomic_write_batch,
     block::Transaction,
@@ -230,6 +235,54 @@ impl<N: Network> TransactionStorage<N> for TransactionMemory<N> {
     }
 }
 
+/// A database-backed transaction storage.
+#[cfg(feature = "persistent")]
+#[derive(Clone)]
+pub struct TransactionDB<N: Network> {
+    /// The mapping of `transaction ID` to `transaction type`.
+    id_map: SledMap<N::TransactionID, TransactionType>,
+    /// The deployment store.
+    deployment_store: DeploymentStore<N, DeploymentDB<N>>,
+    /// The execution store.
+    execution_store: ExecutionStore<N, ExecutionDB<N>>,
+}
+
+#[cfg(feature = "persistent")]
+#[rustfmt::skip]
+impl<N: Network> TransactionStorage<N> for TransactionDB<N> {
+    type IDMap = SledMap<N::TransactionID, TransactionType>;
+    type DeploymentStorage = DeploymentDB<N>;
+    type ExecutionStorage = ExecutionDB<N>;
+    type TransitionStorage = TransitionDB<N>;
+
+    /// Initializes the transaction storage.
+    fn open(transition_store: TransitionStore<N, Self::TransitionStorage>) -> Result<Self> {
+        // Retrieve the optional development ID.
+        let dev = transition_store.dev();
+        // Initialize the deployment store.
+        let deployment_store = DeploymentStore::<N, DeploymentDB<N>>::open(transition_store.clone())?;
+        // Initialize the execution store.
+        let execution_store = ExecutionStore::<N, ExecutionDB<N>>::open(transition_store)?;
+        // Return the transaction storage.
+        Ok(Self { id_map: SledMap::open(N::ID, dev, MapID::TransactionID)?, deployment_store, execution_store })
+    }
+
+    /// Returns the ID map.
+    fn id_map(&self) -> &Self::IDMap {
+        &self.id_map
+    }
+
+    /// Returns the deployment store.
+    fn deployment_store(&self) -> &DeploymentStore<N, Self::DeploymentStorage> {
+        &self.deployment_store
+    }
+
+    /// Returns the execution store.
+    fn execution_store(&self) -> &ExecutionStore<N, Self::ExecutionStorage> {
+        &self.execution_store
+    }
+}
+
 /// The transaction store.
 #[derive(Clone)]
 pub struct TransactionStore<N: Network, T: TransactionStorage<N>> {
@@ -452,9 +505,11 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use console::network::Testnet3;
 
-    #[test]
-    fn test_insert_get_remove() {
+    type CurrentNetwork = Testnet3;
+
+    fn check_insert_get_remove<S: TransactionStorage<CurrentNetwork>>() {
         let rng = &mut TestRng::default();
 
         // Sample the transactions.
@@ -465,9 +520,11 @@ mod tests {
             let transaction_id = transaction.id();
 
             // Initialize a new transition store.
-            let transition_store = TransitionStore::<_, TransitionMemory<_>>::open(None).unwrap();
+            let transition_store =
+                TransitionStore::<_, S::TransitionStorage>::open(crate::store::helpers::test_helpers::sample_dev())
+                    .unwrap();
             // Initialize a new transaction store.
-            let transaction_store = TransactionStore::<_, TransactionMemory<_>>::open(transition_store).unwrap();
+            let transaction_store = TransactionStore::<_, S>::open(transition_store).unwrap();
 
             // Ensure the transaction does not exist.
             let candidate = transaction_store.get_transaction(&transaction_id).unwrap();
@@ -489,8 +546,7 @@ mod tests {
         }
     }
 
-    #[test]
-    fn test_find_transaction_id() {
+    fn check_find_transaction_id<S: TransactionStorage<CurrentNetwork>>() {
         let rng = &mut TestRng::default();
 
         // Sample the execution transaction.
@@ -504,9 +560,11 @@ mod tests {
         };
 
         // Initialize a new transition store.
-        let transition_store = TransitionStore::<_, TransitionMemory<_>>::open(None).unwrap();
+        let transition_store =
+            TransitionStore::<_, S::TransitionStorage>::open(crate::store::helpers::test_helpers::sample_dev())
+                .unwrap();
         // Initialize a new transaction store.
-        let transaction_store = TransactionStore::<_, TransactionMemory<_>>::open(transition_store).unwrap();
+        let transaction_store = TransactionStore::<_, S>::open(transition_store).unwrap();
 
         // Ensure the execution transaction does not exist.
         let candidate = transaction_store.get_transaction(&transaction_id).unwrap();
@@ -532,4 +590,26 @@ mod tests {
             assert_eq!(None, candidate);
         }
     }
+
+    #[test]
+    fn test_insert_get_remove() {
+        check_insert_get_remove::<TransactionMemory<CurrentNetwork>>();
+    }
+
+    #[cfg(feature = "persistent")]
+    #[test]
+    fn test_insert_get_remove_db() {
+        check_insert_get_remove::<TransactionDB<CurrentNetwork>>();
+    }
+
+    #[test]
+    fn test_find_transaction_id() {
+        check_find_transaction_id::<TransactionMemory<CurrentNetwork>>();
+    }
+
+    #[cfg(feature = "persistent")]
+    #[test]
+    fn test_find_transaction_id_db() {
+        check_find_transaction_id::<TransactionDB<CurrentNetwork>>();
+    }
 }
@@ -14,6 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+#[cfg(feature = "persistent")]
+use crate::store::helpers::sled_map::{MapID, SledMap};
 use crate::{
     atomic_write_batch,
     block::Input,
@@ -349,6 +351,103 @@ impl<N: Network> InputStorage<N> for InputMemory<N> {
     }
 }
 
+/// A database-backed transition input storage.
+#[cfg(feature = "persistent")]
+#[derive(Clone)]
+pub struct InputDB<N: Network> {
+    /// The mapping of `transition ID` to `input IDs`.
+    id_map: SledMap<N::TransitionID, Vec<Field<N>>>,
+    /// The mapping of `input ID` to `transition ID`.
+    reverse_id_map: SledMap<Field<N>, N::TransitionID>,
+    /// The mapping of `plaintext hash` to `(optional) plaintext`.
+    constant: SledMap<Field<N>, Option<Plaintext<N>>>,
+    /// The mapping of `plaintext hash` to `(optional) plaintext`.
+    public: SledMap<Field<N>, Option<Plaintext<N>>>,
+    /// The mapping of `ciphertext hash` to `(optional) ciphertext`.
+    private: SledMap<Field<N>, Option<Ciphertext<N>>>,
+    /// The mapping of `serial number` to `tag`.
+    record: SledMap<Field<N>, Field<N>>,
+    /// The mapping of `record tag` to `serial number`.
+    record_tag: SledMap<Field<N>, Field<N>>,
+    /// The mapping of `external hash` to `()`. Note: This is **not** the record commitment.
+    external_record: SledMap<Field<N>, ()>,
+    /// The optional development ID.
+    dev: Option<u16>,
+}
+
+#[cfg(feature = "persistent")]
+#[rustfmt::skip]
+impl<N: Network> InputStorage<N> for InputDB<N> {
+    type IDMap = SledMap<N::TransitionID, Vec<Field<N>>>;
+    type ReverseIDMap = SledMap<Field<N>, N::TransitionID>;
+    type ConstantMap = SledMap<Field<N>, Option<Plaintext<N>>>;
+    type PublicMap = SledMap<Field<N>, Option<Plaintext<N>>>;
+    type PrivateMap = SledMap<Field<N>, Option<Ciphertext<N>>>;
+    type RecordMap = SledMap<Field<N>, Field<N>>;
+    type RecordTagMap = SledMap<Field<N>, Field<N>>;
+    type ExternalRecordMap = SledMap<Field<N>, ()>;
+
+    /// Initializes the transition input storage.
+    fn open(dev: Option<u16>) -> Result<Self> {
+        Ok(Self {
+            id_map: SledMap::open(N::ID, dev, MapID::InputID)?,
+            reverse_id_map: SledMap::open(N::ID, dev, MapID::InputReverseID)?,
+            constant: SledMap::open(N::ID, dev, MapID::InputConstant)?,
+            public: SledMap::open(N::ID, dev, MapID::InputPublic)?,
+            private: SledMap::open(N::ID, dev, MapID::InputPrivate)?,
+            record: SledMap::open(N::ID, dev, MapID::InputRecord)?,
+            record_tag: SledMap::open(N::ID, dev, MapID::InputRecordTag)?,
+            external_record: SledMap::open(N::ID, dev, MapID::InputExternalRecord)?,
+            dev,
+        })
+    }
+
+    /// Returns the ID map.
+    fn id_map(&self) -> &Self::IDMap {
+        &self.id_map
+    }
+
+    /// Returns the reverse ID map.
+    fn reverse_id_map(&self) -> &Self::ReverseIDMap {
+        &self.reverse_id_map
+    }
+
+    /// Returns the constant map.
+    fn constant_map(&self) -> &Self::ConstantMap {
+        &self.constant
+    }
+
+    /// Returns the public map.
+    fn public_map(&self) -> &Self::PublicMap {
+        &self.public
+    }
+
+    /// Returns the private map.
+    fn private_map(&self) -> &Self::PrivateMap {
+        &self.private
+    }
+
+    /// Returns the record map.
+    fn record_map(&self) -> &Self::RecordMap {
+        &self.record
+    }
+
+    /// Returns the record tag map.
+    fn record_tag_map(&self) -> &Self::RecordTagMap {
+        &self.record_tag
+    }
+
+    /// Returns the external record map.
+    fn external_record_map(&self) -> &Self::ExternalRecordMap {
+        &self.external_record
+    }
+
+    /// Returns the optional development ID.
+    fn dev(&self) -> Option<u16> {
+        self.dev
+    }
+}
+
 /// The transition input store.
 #[derive(Clone)]
 pub struct InputStore<N: Network, I: InputStorage<N>> {
@@ -539,13 +638,15 @@ impl<N: Network, I: InputStorage<N>> InputStore<N, I> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use console::network::Testnet3;
 
-    #[test]
-    fn test_insert_get_remove() {
+    type CurrentNetwork = Testnet3;
+
+    fn check_insert_get_remove<S: InputStorage<CurrentNetwork>>() {
         // Sample the transition inputs.
         for (transition_id, input) in crate::block::transition::input::test_helpers::sample_inputs() {
             // Initialize a new input store.
-            let input_store = InputMemory::open(None).unwrap();
+            let input_store = S::open(crate::store::helpers::test_helpers::sample_dev()).unwrap();
 
             // Ensure the transition input does not exist.
             let candidate = input_store.get(&transition_id).unwrap();
@@ -567,12 +668,11 @@ mod tests {
         }
     }
 
-    #[test]
-    fn test_find_transition_id() {
+    fn check_find_transition_id<S: InputStorage<CurrentNetwork>>() {
         // Sample the transition inputs.
         for (transition_id, input) in crate::block::transition::input::test_helpers::sample_inputs() {
             // Initialize a new input store.
-            let input_store = InputMemory::open(None).unwrap();
+            let input_store = S::open(crate::store::helpers::test_helpers::sample_dev()).unwrap();
 
             // Ensure the transition input does not exist.
             let candidate = input_store.get(&transition_id).unwrap();
@@ -597,4 +697,26 @@ mod tests {
             assert!(candidate.is_none());
         }
     }
+
+    #[test]
+    fn test_insert_get_remove() {
+        check_insert_get_remove::<InputMemory<CurrentNetwork>>();
+    }
+
+    #[cfg(feature = "persistent")]
+    #[test]
+    fn test_insert_get_remove_db() {
+        check_insert_get_remove::<InputDB<CurrentNetwork>>();
+    }
+
+    #[test]
+    fn test_find_transition_id() {
+        check_find_transition_id::<InputMemory<CurrentNetwork>>();
+    }
+
+    #[cfg(feature = "persistent")]
+    #[test]
+    fn test_find_transition_id_db() {
+        check_find_transition_id::<InputDB<CurrentNetwork>>();
+    }
 }
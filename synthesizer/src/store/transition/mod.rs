@@ -20,6 +20,8 @@ pub use input::*;
 mod output;
 pub use output::*;
 
+#[cfg(feature = "persistent")]
+use crate::store::helpers::sled_map::{MapID, SledMap};
 use crate::{
     block::{Input, Output, Transition},
     cow_to_cloned,
@@ -46,6 +48,8 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
     type OutputStorage: OutputStorage<N>;
     /// The transition finalize inputs.
     type FinalizeMap: for<'a> Map<'a, N::TransitionID, Option<Vec<Value<N>>>>;
+    /// The transition finalize outputs.
+    type FinalizeOutputMap: for<'a> Map<'a, N::TransitionID, Vec<Value<N>>>;
     /// The transition proofs.
     type ProofMap: for<'a> Map<'a, N::TransitionID, Proof<N>>;
     /// The transition public keys.
@@ -70,6 +74,8 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
     fn output_store(&self) -> &OutputStore<N, Self::OutputStorage>;
     /// Returns the transition finalize inputs map.
     fn finalize_map(&self) -> &Self::FinalizeMap;
+    /// Returns the transition finalize outputs map.
+    fn finalize_output_map(&self) -> &Self::FinalizeOutputMap;
     /// Returns the transition proofs map.
     fn proof_map(&self) -> &Self::ProofMap;
     /// Returns the transition public keys map.
@@ -95,6 +101,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.input_store().start_atomic();
         self.output_store().start_atomic();
         self.finalize_map().start_atomic();
+        self.finalize_output_map().start_atomic();
         self.proof_map().start_atomic();
         self.tpk_map().start_atomic();
         self.reverse_tpk_map().start_atomic();
@@ -109,6 +116,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
             || self.input_store().is_atomic_in_progress()
             || self.output_store().is_atomic_in_progress()
             || self.finalize_map().is_atomic_in_progress()
+            || self.finalize_output_map().is_atomic_in_progress()
             || self.proof_map().is_atomic_in_progress()
             || self.tpk_map().is_atomic_in_progress()
             || self.reverse_tpk_map().is_atomic_in_progress()
@@ -123,6 +131,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.input_store().abort_atomic();
         self.output_store().abort_atomic();
         self.finalize_map().abort_atomic();
+        self.finalize_output_map().abort_atomic();
         self.proof_map().abort_atomic();
         self.tpk_map().abort_atomic();
         self.reverse_tpk_map().abort_atomic();
@@ -137,6 +146,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.input_store().finish_atomic()?;
         self.output_store().finish_atomic()?;
         self.finalize_map().finish_atomic()?;
+        self.finalize_output_map().finish_atomic()?;
         self.proof_map().finish_atomic()?;
         self.tpk_map().finish_atomic()?;
         self.reverse_tpk_map().finish_atomic()?;
@@ -226,6 +236,8 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
             self.output_store().remove(transition_id)?;
             // Remove the finalize inputs.
             self.finalize_map().remove(transition_id)?;
+            // Remove the finalize outputs.
+            self.finalize_output_map().remove(transition_id)?;
             // Remove the proof.
             self.proof_map().remove(transition_id)?;
             // Remove `tpk`.
@@ -256,6 +268,16 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         Ok(())
     }
 
+    /// Stores the given finalize `outputs` for the given `transition ID`.
+    fn insert_finalize_outputs(&self, transition_id: &N::TransitionID, outputs: Vec<Value<N>>) -> Result<()> {
+        // Ensure the transition exists.
+        if !self.locator_map().contains_key(transition_id)? {
+            bail!("Missing transition '{transition_id}' - cannot store finalize outputs")
+        }
+        // Store the finalize outputs.
+        self.finalize_output_map().insert(*transition_id, outputs)
+    }
+
     /// Returns the transition for the given `transition ID`.
     fn get(&self, transition_id: &N::TransitionID) -> Result<Option<Transition<N>>> {
         // Retrieve the program ID and function name.
@@ -314,6 +336,8 @@ pub struct TransitionMemory<N: Network> {
     output_store: OutputStore<N, OutputMemory<N>>,
     /// The transition finalize inputs.
     finalize_map: MemoryMap<N::TransitionID, Option<Vec<Value<N>>>>,
+    /// The transition finalize outputs.
+    finalize_output_map: MemoryMap<N::TransitionID, Vec<Value<N>>>,
     /// The transition proofs.
     proof_map: MemoryMap<N::TransitionID, Proof<N>>,
     /// The transition public keys.
@@ -334,6 +358,7 @@ impl<N: Network> TransitionStorage<N> for TransitionMemory<N> {
     type InputStorage = InputMemory<N>;
     type OutputStorage = OutputMemory<N>;
     type FinalizeMap = MemoryMap<N::TransitionID, Option<Vec<Value<N>>>>;
+    type FinalizeOutputMap = MemoryMap<N::TransitionID, Vec<Value<N>>>;
     type ProofMap = MemoryMap<N::TransitionID, Proof<N>>;
     type TPKMap = MemoryMap<N::TransitionID, Group<N>>;
     type ReverseTPKMap = MemoryMap<Group<N>, N::TransitionID>;
@@ -348,6 +373,7 @@ impl<N: Network> TransitionStorage<N> for TransitionMemory<N> {
             input_store: InputStore::open(dev)?,
             output_store: OutputStore::open(dev)?,
             finalize_map: MemoryMap::default(),
+            finalize_output_map: MemoryMap::default(),
             proof_map: MemoryMap::default(),
             tpk_map: MemoryMap::default(),
             reverse_tpk_map: MemoryMap::default(),
@@ -377,6 +403,127 @@ impl<N: Network> TransitionStorage<N> for TransitionMemory<N> {
         &self.finalize_map
     }
 
+    /// Returns the transition finalize outputs.
+    fn finalize_output_map(&self) -> &Self::FinalizeOutputMap {
+        &self.finalize_output_map
+    }
+
+    /// Returns the transition proofs.
+    fn proof_map(&self) -> &Self::ProofMap {
+        &self.proof_map
+    }
+
+    /// Returns the transition public keys.
+    fn tpk_map(&self) -> &Self::TPKMap {
+        &self.tpk_map
+    }
+
+    /// Returns the reverse `tpk` map.
+    fn reverse_tpk_map(&self) -> &Self::ReverseTPKMap {
+        &self.reverse_tpk_map
+    }
+
+    /// Returns the transition commitments.
+    fn tcm_map(&self) -> &Self::TCMMap {
+        &self.tcm_map
+    }
+
+    /// Returns the reverse `tcm` map.
+    fn reverse_tcm_map(&self) -> &Self::ReverseTCMMap {
+        &self.reverse_tcm_map
+    }
+
+    /// Returns the transition fees.
+    fn fee_map(&self) -> &Self::FeeMap {
+        &self.fee_map
+    }
+}
+
+/// A database-backed transition storage.
+#[cfg(feature = "persistent")]
+#[derive(Clone)]
+pub struct TransitionDB<N: Network> {
+    /// The transition program IDs and function names.
+    locator_map: SledMap<N::TransitionID, (ProgramID<N>, Identifier<N>)>,
+    /// The transition input store.
+    input_store: InputStore<N, InputDB<N>>,
+    /// The transition output store.
+    output_store: OutputStore<N, OutputDB<N>>,
+    /// The transition finalize inputs.
+    finalize_map: SledMap<N::TransitionID, Option<Vec<Value<N>>>>,
+    /// The transition finalize outputs.
+    finalize_output_map: SledMap<N::TransitionID, Vec<Value<N>>>,
+    /// The transition proofs.
+    proof_map: SledMap<N::TransitionID, Proof<N>>,
+    /// The transition public keys.
+    tpk_map: SledMap<N::TransitionID, Group<N>>,
+    /// The reverse `tpk` map.
+    reverse_tpk_map: SledMap<Group<N>, N::TransitionID>,
+    /// The transition commitments.
+    tcm_map: SledMap<N::TransitionID, Field<N>>,
+    /// The reverse `tcm` map.
+    reverse_tcm_map: SledMap<Field<N>, N::TransitionID>,
+    /// The transition fees.
+    fee_map: SledMap<N::TransitionID, i64>,
+}
+
+#[cfg(feature = "persistent")]
+#[rustfmt::skip]
+impl<N: Network> TransitionStorage<N> for TransitionDB<N> {
+    type LocatorMap = SledMap<N::TransitionID, (ProgramID<N>, Identifier<N>)>;
+    type InputStorage = InputDB<N>;
+    type OutputStorage = OutputDB<N>;
+    type FinalizeMap = SledMap<N::TransitionID, Option<Vec<Value<N>>>>;
+    type FinalizeOutputMap = SledMap<N::TransitionID, Vec<Value<N>>>;
+    type ProofMap = SledMap<N::TransitionID, Proof<N>>;
+    type TPKMap = SledMap<N::TransitionID, Group<N>>;
+    type ReverseTPKMap = SledMap<Group<N>, N::TransitionID>;
+    type TCMMap = SledMap<N::TransitionID, Field<N>>;
+    type ReverseTCMMap = SledMap<Field<N>, N::TransitionID>;
+    type FeeMap = SledMap<N::TransitionID, i64>;
+
+    /// Initializes the transition storage.
+    fn open(dev: Option<u16>) -> Result<Self> {
+        Ok(Self {
+            locator_map: SledMap::open(N::ID, dev, MapID::TransitionLocator)?,
+            input_store: InputStore::open(dev)?,
+            output_store: OutputStore::open(dev)?,
+            finalize_map: SledMap::open(N::ID, dev, MapID::TransitionFinalize)?,
+            finalize_output_map: SledMap::open(N::ID, dev, MapID::TransitionFinalizeOutput)?,
+            proof_map: SledMap::open(N::ID, dev, MapID::TransitionProof)?,
+            tpk_map: SledMap::open(N::ID, dev, MapID::TransitionTPK)?,
+            reverse_tpk_map: SledMap::open(N::ID, dev, MapID::TransitionReverseTPK)?,
+            tcm_map: SledMap::open(N::ID, dev, MapID::TransitionTCM)?,
+            reverse_tcm_map: SledMap::open(N::ID, dev, MapID::TransitionReverseTCM)?,
+            fee_map: SledMap::open(N::ID, dev, MapID::TransitionFee)?,
+        })
+    }
+
+    /// Returns the transition program IDs and function names.
+    fn locator_map(&self) -> &Self::LocatorMap {
+        &self.locator_map
+    }
+
+    /// Returns the transition input store.
+    fn input_store(&self) -> &InputStore<N, Self::InputStorage> {
+        &self.input_store
+    }
+
+    /// Returns the transition output store.
+    fn output_store(&self) -> &OutputStore<N, Self::OutputStorage> {
+        &self.output_store
+    }
+
+    /// Returns the transition finalize inputs.
+    fn finalize_map(&self) -> &Self::FinalizeMap {
+        &self.finalize_map
+    }
+
+    /// Returns the transition finalize outputs.
+    fn finalize_output_map(&self) -> &Self::FinalizeOutputMap {
+        &self.finalize_output_map
+    }
+
     /// Returns the transition proofs.
     fn proof_map(&self) -> &Self::ProofMap {
         &self.proof_map
@@ -419,6 +566,8 @@ pub struct TransitionStore<N: Network, T: TransitionStorage<N>> {
     outputs: OutputStore<N, T::OutputStorage>,
     /// The map of transition finalize inputs.
     finalize: T::FinalizeMap,
+    /// The map of transition finalize outputs.
+    finalize_outputs: T::FinalizeOutputMap,
     /// The map of transition proofs.
     proof: T::ProofMap,
     /// The map of transition public keys.
@@ -446,6 +595,7 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
             inputs: (*storage.input_store()).clone(),
             outputs: (*storage.output_store()).clone(),
             finalize: storage.finalize_map().clone(),
+            finalize_outputs: storage.finalize_output_map().clone(),
             proof: storage.proof_map().clone(),
             tpk: storage.tpk_map().clone(),
             reverse_tpk: storage.reverse_tpk_map().clone(),
@@ -463,6 +613,7 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
             inputs: (*storage.input_store()).clone(),
             outputs: (*storage.output_store()).clone(),
             finalize: storage.finalize_map().clone(),
+            finalize_outputs: storage.finalize_output_map().clone(),
             proof: storage.proof_map().clone(),
             tpk: storage.tpk_map().clone(),
             reverse_tpk: storage.reverse_tpk_map().clone(),
@@ -478,6 +629,11 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
         self.storage.insert(transition)
     }
 
+    /// Stores the given finalize `outputs` for the given `transition ID`.
+    pub fn insert_finalize_outputs(&self, transition_id: &N::TransitionID, outputs: Vec<Value<N>>) -> Result<()> {
+        self.storage.insert_finalize_outputs(transition_id, outputs)
+    }
+
     /// Removes the input for the given `transition ID`.
     pub fn remove(&self, transition_id: &N::TransitionID) -> Result<()> {
         self.storage.remove(transition_id)
@@ -575,6 +731,23 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
         }
     }
 
+    /// Returns the finalize outputs for the given `transition ID`.
+    ///
+    /// If the transition was finalized, `Ok(Some(outputs))` is returned.
+    /// If the transition has not been finalized, `Ok(None)` is returned.
+    /// If the transition does not exist, `Err(error)` is returned.
+    pub fn get_finalize_outputs(&self, transition_id: &N::TransitionID) -> Result<Option<Vec<Value<N>>>> {
+        // Ensure the transition exists.
+        if !self.locator.contains_key(transition_id)? {
+            bail!("Missing transition '{transition_id}' - cannot get finalize outputs")
+        }
+        // Retrieve the finalize outputs.
+        match self.finalize_outputs.get(transition_id)? {
+            Some(outputs) => Ok(Some(cow_to_cloned!(outputs))),
+            None => Ok(None),
+        }
+    }
+
     /// Returns the record for the given `commitment`.
     ///
     /// If the record exists, `Ok(Some(record))` is returned.
@@ -637,6 +810,11 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
         self.reverse_tpk.contains_key(tpk)
     }
 
+    /// Returns `true` if the given transition has finalize outputs.
+    pub fn contains_finalize_outputs(&self, transition_id: &N::TransitionID) -> Result<bool> {
+        self.finalize_outputs.contains_key(transition_id)
+    }
+
     /// Returns `true` if the given transition commitment exists.
     pub fn contains_tcm(&self, tcm: &Field<N>) -> Result<bool> {
         self.reverse_tcm.contains_key(tcm)
@@ -771,6 +949,11 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
 
     /* Metadata */
 
+    /// Returns an iterator over the finalize outputs, for all transitions that were finalized.
+    pub fn finalize_outputs(&self) -> impl '_ + Iterator<Item = (Cow<'_, N::TransitionID>, Cow<'_, Vec<Value<N>>>)> {
+        self.finalize_outputs.iter()
+    }
+
     /// Returns an iterator over the proofs, for all transitions.
     pub fn proofs(&self) -> impl '_ + Iterator<Item = Cow<'_, Proof<N>>> {
         self.proof.values()
@@ -795,9 +978,11 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use console::network::Testnet3;
 
-    #[test]
-    fn test_insert_get_remove() {
+    type CurrentNetwork = Testnet3;
+
+    fn check_insert_get_remove<S: TransitionStorage<CurrentNetwork>>() {
         let rng = &mut TestRng::default();
 
         // Sample the transitions.
@@ -813,7 +998,7 @@ mod tests {
         assert!(transitions.len() > 1, "\n\nNumber of transitions: {}\n", transitions.len());
 
         // Initialize a new transition store.
-        let transition_store = TransitionMemory::open(None).unwrap();
+        let transition_store = S::open(crate::store::helpers::test_helpers::sample_dev()).unwrap();
 
         // Test each transition in isolation.
         for transition in transitions.iter() {
@@ -879,4 +1064,61 @@ mod tests {
             assert_eq!(None, candidate);
         }
     }
+
+    fn check_insert_get_remove_finalize_outputs<S: TransitionStorage<CurrentNetwork>>() {
+        // Sample the transition.
+        let transition = crate::process::test_helpers::sample_transition();
+        let transition_id = *transition.id();
+        // Sample the finalize outputs.
+        let outputs = vec![Value::from_str("1u64").unwrap(), Value::from_str("2u64").unwrap()];
+
+        // Initialize a new transition store.
+        let transition_store =
+            TransitionStore::<CurrentNetwork, S>::open(crate::store::helpers::test_helpers::sample_dev()).unwrap();
+
+        // Ensure the finalize outputs cannot be stored for a missing transition.
+        assert!(transition_store.insert_finalize_outputs(&transition_id, outputs.clone()).is_err());
+        assert!(transition_store.get_finalize_outputs(&transition_id).is_err());
+
+        // Insert the transition.
+        transition_store.insert(&transition).unwrap();
+        // Ensure the transition has no finalize outputs yet.
+        assert_eq!(None, transition_store.get_finalize_outputs(&transition_id).unwrap());
+        assert!(!transition_store.contains_finalize_outputs(&transition_id).unwrap());
+
+        // Store the finalize outputs.
+        transition_store.insert_finalize_outputs(&transition_id, outputs.clone()).unwrap();
+        // Ensure the finalize outputs exist.
+        assert_eq!(Some(outputs), transition_store.get_finalize_outputs(&transition_id).unwrap());
+        assert!(transition_store.contains_finalize_outputs(&transition_id).unwrap());
+        assert_eq!(1, transition_store.finalize_outputs().count());
+
+        // Remove the transition.
+        transition_store.remove(&transition_id).unwrap();
+        // Ensure the finalize outputs were removed.
+        assert!(!transition_store.contains_finalize_outputs(&transition_id).unwrap());
+        assert_eq!(0, transition_store.finalize_outputs().count());
+    }
+
+    #[test]
+    fn test_insert_get_remove() {
+        check_insert_get_remove::<TransitionMemory<CurrentNetwork>>();
+    }
+
+    #[cfg(feature = "persistent")]
+    #[test]
+    fn test_insert_get_remove_db() {
+        check_insert_get_remove::<TransitionDB<CurrentNetwork>>();
+    }
+
+    #[test]
+    fn test_insert_get_remove_finalize_outputs() {
+        check_insert_get_remove_finalize_outputs::<TransitionMemory<CurrentNetwork>>();
+    }
+
+    #[cfg(feature = "persistent")]
+    #[test]
+    fn test_insert_get_remove_finalize_outputs_db() {
+        check_insert_get_remove_finalize_outputs::<TransitionDB<CurrentNetwork>>();
+    }
 }
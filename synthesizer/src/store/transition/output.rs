@@ -14,6 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+#[cfg(feature = "persistent")]
+use crate::store::helpers::sled_map::{MapID, SledMap};
 use crate::{
     block::Output,
     store::helpers::{memory_map::MemoryMap, Map, MapRead},
@@ -391,6 +393,104 @@ impl<N: Network> OutputStorage<N> for OutputMemory<N> {
     }
 }
 
+/// A database-backed transition output storage.
+#[cfg(feature = "persistent")]
+#[derive(Clone)]
+#[allow(clippy::type_complexity)]
+pub struct OutputDB<N: Network> {
+    /// The mapping of `transition ID` to `output IDs`.
+    id_map: SledMap<N::TransitionID, Vec<Field<N>>>,
+    /// The mapping of `output ID` to `transition ID`.
+    reverse_id_map: SledMap<Field<N>, N::TransitionID>,
+    /// The mapping of `plaintext hash` to `(optional) plaintext`.
+    constant: SledMap<Field<N>, Option<Plaintext<N>>>,
+    /// The mapping of `plaintext hash` to `(optional) plaintext`.
+    public: SledMap<Field<N>, Option<Plaintext<N>>>,
+    /// The mapping of `ciphertext hash` to `(optional) ciphertext`.
+    private: SledMap<Field<N>, Option<Ciphertext<N>>>,
+    /// The mapping of `commitment` to `(checksum, (optional) record ciphertext)`.
+    record: SledMap<Field<N>, (Field<N>, Option<Record<N, Ciphertext<N>>>)>,
+    /// The mapping of `record nonce` to `commitment`.
+    record_nonce: SledMap<Group<N>, Field<N>>,
+    /// The mapping of `external hash` to `()`. Note: This is **not** the record commitment.
+    external_record: SledMap<Field<N>, ()>,
+    /// The optional development ID.
+    dev: Option<u16>,
+}
+
+#[cfg(feature = "persistent")]
+#[rustfmt::skip]
+impl<N: Network> OutputStorage<N> for OutputDB<N> {
+    type IDMap = SledMap<N::TransitionID, Vec<Field<N>>>;
+    type ReverseIDMap = SledMap<Field<N>, N::TransitionID>;
+    type ConstantMap = SledMap<Field<N>, Option<Plaintext<N>>>;
+    type PublicMap = SledMap<Field<N>, Option<Plaintext<N>>>;
+    type PrivateMap = SledMap<Field<N>, Option<Ciphertext<N>>>;
+    type RecordMap = SledMap<Field<N>, (Field<N>, Option<Record<N, Ciphertext<N>>>)>;
+    type RecordNonceMap = SledMap<Group<N>, Field<N>>;
+    type ExternalRecordMap = SledMap<Field<N>, ()>;
+
+    /// Initializes the transition output storage.
+    fn open(dev: Option<u16>) -> Result<Self> {
+        Ok(Self {
+            id_map: SledMap::open(N::ID, dev, MapID::OutputID)?,
+            reverse_id_map: SledMap::open(N::ID, dev, MapID::OutputReverseID)?,
+            constant: SledMap::open(N::ID, dev, MapID::OutputConstant)?,
+            public: SledMap::open(N::ID, dev, MapID::OutputPublic)?,
+            private: SledMap::open(N::ID, dev, MapID::OutputPrivate)?,
+            record: SledMap::open(N::ID, dev, MapID::OutputRecord)?,
+            record_nonce: SledMap::open(N::ID, dev, MapID::OutputRecordNonce)?,
+            external_record: SledMap::open(N::ID, dev, MapID::OutputExternalRecord)?,
+            dev,
+        })
+    }
+
+    /// Returns the ID map.
+    fn id_map(&self) -> &Self::IDMap {
+        &self.id_map
+    }
+
+    /// Returns the reverse ID map.
+    fn reverse_id_map(&self) -> &Self::ReverseIDMap {
+        &self.reverse_id_map
+    }
+
+    /// Returns the constant map.
+    fn constant_map(&self) -> &Self::ConstantMap {
+        &self.constant
+    }
+
+    /// Returns the public map.
+    fn public_map(&self) -> &Self::PublicMap {
+        &self.public
+    }
+
+    /// Returns the private map.
+    fn private_map(&self) -> &Self::PrivateMap {
+        &self.private
+    }
+
+    /// Returns the record map.
+    fn record_map(&self) -> &Self::RecordMap {
+        &self.record
+    }
+
+    /// Returns the record nonce map.
+    fn record_nonce_map(&self) -> &Self::RecordNonceMap {
+        &self.record_nonce
+    }
+
+    /// Returns the external record map.
+    fn external_record_map(&self) -> &Self::ExternalRecordMap {
+        &self.external_record
+    }
+
+    /// Returns the optional development ID.
+    fn dev(&self) -> Option<u16> {
+        self.dev
+    }
+}
+
 /// The transition output store.
 #[derive(Clone)]
 pub struct OutputStore<N: Network, O: OutputStorage<N>> {
@@ -619,13 +719,15 @@ impl<N: Network, I: OutputStorage<N>> OutputStore<N, I> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use console::network::Testnet3;
 
-    #[test]
-    fn test_insert_get_remove() {
+    type CurrentNetwork = Testnet3;
+
+    fn check_insert_get_remove<S: OutputStorage<CurrentNetwork>>() {
         // Sample the transition outputs.
         for (transition_id, output) in crate::block::transition::output::test_helpers::sample_outputs() {
             // Initialize a new output store.
-            let output_store = OutputMemory::open(None).unwrap();
+            let output_store = S::open(crate::store::helpers::test_helpers::sample_dev()).unwrap();
 
             // Ensure the transition output does not exist.
             let candidate = output_store.get(&transition_id).unwrap();
@@ -647,12 +749,11 @@ mod tests {
         }
     }
 
-    #[test]
-    fn test_find_transition_id() {
+    fn check_find_transition_id<S: OutputStorage<CurrentNetwork>>() {
         // Sample the transition outputs.
         for (transition_id, output) in crate::block::transition::output::test_helpers::sample_outputs() {
             // Initialize a new output store.
-            let output_store = OutputMemory::open(None).unwrap();
+            let output_store = S::open(crate::store::helpers::test_helpers::sample_dev()).unwrap();
 
             // Ensure the transition output does not exist.
             let candidate = output_store.get(&transition_id).unwrap();
@@ -677,4 +778,26 @@ mod tests {
             assert!(candidate.is_none());
         }
     }
+
+    #[test]
+    fn test_insert_get_remove() {
+        check_insert_get_remove::<OutputMemory<CurrentNetwork>>();
+    }
+
+    #[cfg(feature = "persistent")]
+    #[test]
+    fn test_insert_get_remove_db() {
+        check_insert_get_remove::<OutputDB<CurrentNetwork>>();
+    }
+
+    #[test]
+    fn test_find_transition_id() {
+        check_find_transition_id::<OutputMemory<CurrentNetwork>>();
+    }
+
+    #[cfg(feature = "persistent")]
+    #[test]
+    fn test_find_transition_id_db() {
+        check_find_transition_id::<OutputDB<CurrentNetwork>>();
+    }
 }
@@ -34,7 +34,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                         lap!(timer, "Finalize deployment");
                     }
                     Transaction::Execute(_, execution, _) => {
-                        process.finalize_execution(self.program_store(), execution)?;
+                        // Finalize the execution, and store the finalize outputs of each transition.
+                        for (transition_id, outputs) in process.finalize_execution(self.program_store(), execution)? {
+                            self.transition_store().insert_finalize_outputs(&transition_id, outputs)?;
+                        }
                         lap!(timer, "Finalize execution");
                     }
                 }
@@ -0,0 +1,136 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::coinbase_puzzle::PuzzleCommitment;
+use console::{network::prelude::*, program::ProgramID, types::Field};
+
+use thiserror::Error;
+
+/// The reason a transaction was rejected by `VM::check_transaction`.
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum VerificationError<N: Network> {
+    #[error("Failed to compute the Merkle root of the transaction: {0}")]
+    InvalidMerkleRoot(String),
+
+    #[error("Incorrect transaction ID ({0})")]
+    IncorrectTransactionID(N::TransactionID),
+
+    #[error("Found duplicate transition in the transactions list")]
+    DuplicateTransitionID,
+
+    #[error("Found duplicate transition public keys in the transactions list")]
+    DuplicateTransitionPublicKey,
+
+    #[error("Found duplicate serial numbers in the transactions list")]
+    DuplicateSerialNumber,
+
+    #[error("Found duplicate commitments in the transactions list")]
+    DuplicateCommitment,
+
+    #[error("Found duplicate nonces in the transactions list")]
+    DuplicateNonce,
+
+    #[error("Invalid transaction size (deployment): {0}")]
+    InvalidDeploymentSize(String),
+
+    #[error("Invalid transaction size (execution): {0}")]
+    InvalidExecutionSize(String),
+
+    #[error("Deployment verification failed: {0}")]
+    InvalidDeployment(String),
+
+    #[error("Execution verification failed: {0}")]
+    InvalidExecution(String),
+
+    #[error("Fee verification failed: {0}")]
+    InvalidFee(String),
+
+    #[error("Transition '{0}' has an invalid proof")]
+    InvalidTransitionProof(N::TransitionID),
+
+    #[error("Global state root '{0}' not found")]
+    UnknownStateRoot(N::StateRoot),
+
+    #[error("Program '{0}' does not exist")]
+    UnknownProgram(ProgramID<N>),
+}
+
+/// The reason a block was rejected by `VM::check_next_block`.
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum BlockError<N: Network> {
+    #[error("Failed to read the ledger state: {0}")]
+    Storage(String),
+
+    #[error("The block is not a valid genesis block")]
+    InvalidGenesisBlock,
+
+    #[error("Block '{0}' already exists in the ledger")]
+    DuplicateBlockHash(N::BlockHash),
+
+    #[error("The block signature is invalid")]
+    InvalidSignature,
+
+    #[error("Incorrect previous block hash. Expected '{expected}', found '{found}'")]
+    IncorrectPreviousHash { expected: N::BlockHash, found: N::BlockHash },
+
+    #[error("Incorrect block height. Expected {expected}, found {found}")]
+    IncorrectHeight { expected: u32, found: u32 },
+
+    #[error("Block round {found} does not increase the latest round {latest}")]
+    IncorrectRound { latest: u64, found: u64 },
+
+    #[error("Block timestamp {found} does not increase the latest timestamp {latest}")]
+    IncorrectTimestamp { latest: i64, found: i64 },
+
+    #[error("The block header is malformed")]
+    InvalidHeader,
+
+    #[error("Failed to retarget from the latest block: {0}")]
+    InvalidRetarget(String),
+
+    #[error("Incorrect coinbase target. Expected {expected}, found {found}")]
+    IncorrectCoinbaseTarget { expected: u64, found: u64 },
+
+    #[error("Incorrect proof target. Expected {expected}, found {found}")]
+    IncorrectProofTarget { expected: u64, found: u64 },
+
+    #[error("Incorrect last coinbase target. Expected {expected}, found {found}")]
+    IncorrectLastCoinbaseTarget { expected: u64, found: u64 },
+
+    #[error("Incorrect last coinbase timestamp. Expected {expected}, found {found}")]
+    IncorrectLastCoinbaseTimestamp { expected: i64, found: i64 },
+
+    #[error("Incorrect transactions root. Expected '{expected}', found '{found}'")]
+    IncorrectTransactionsRoot { expected: Field<N>, found: Field<N> },
+
+    #[error("Incorrect previous state root. Expected '{expected}', found '{found}'")]
+    IncorrectStateRoot { expected: N::StateRoot, found: Field<N> },
+
+    #[error("Transaction '{0}' already exists in the ledger")]
+    DuplicateTransactionID(N::TransactionID),
+
+    #[error("Transaction '{0}' is invalid: {1}")]
+    InvalidTransaction(N::TransactionID, VerificationError<N>),
+
+    #[error("Serial number '{0}' is already spent")]
+    DoubleSpend(Field<N>),
+
+    #[error("Puzzle commitment '{0}' already exists in the ledger")]
+    DuplicatePuzzleCommitment(PuzzleCommitment<N>),
+
+    #[error("Coinbase solution verification failed: {0}")]
+    InvalidCoinbaseSolution(String),
+}
@@ -53,6 +53,15 @@ macro_rules! process {
 
                 $logic!(process.read(), console::network::Testnet3, circuit::AleoV0)
             }
+            console::network::Devnet::ID => {
+                // Cast the process.
+                let process = (&$self.process as &dyn std::any::Any)
+                    .downcast_ref::<Arc<RwLock<Process<console::network::Devnet>>>>()
+                    .ok_or_else(|| anyhow!("Failed to downcast {}", stringify!($self.process)))
+                    .unwrap();
+
+                $logic!(process.read(), console::network::Devnet, circuit::AleoDevnet)
+            }
             _ => Err(anyhow!("Unsupported VM configuration for network: {}", N::ID)),
         }
     }};
@@ -15,3 +15,6 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 mod macros;
+
+mod error;
+pub use error::*;
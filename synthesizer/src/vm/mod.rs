@@ -15,6 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 mod helpers;
+pub use helpers::*;
 
 mod authorize;
 mod deploy;
@@ -26,9 +27,22 @@ use crate::{
     atomic_write_batch,
     block::{Block, Transaction, Transactions, Transition},
     cast_ref,
+    coinbase_puzzle::{CoinbasePuzzle, EpochChallenge},
     process,
-    process::{Authorization, Deployment, Execution, Fee, Inclusion, InclusionAssignment, Process, Query},
+    process::{
+        Authorization,
+        DeferredCertificate,
+        DeferredProof,
+        Deployment,
+        Execution,
+        Fee,
+        Inclusion,
+        InclusionAssignment,
+        Process,
+        Query,
+    },
     program::Program,
+    snark::VerifyingKey,
     store::{BlockStore, ConsensusStorage, ConsensusStore, ProgramStore, TransactionStore, TransitionStore},
 };
 use console::{
@@ -39,7 +53,7 @@ use console::{
 
 use aleo_std::prelude::{finish, lap, timer};
 use parking_lot::RwLock;
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
 
 #[derive(Clone)]
 pub struct VM<N: Network, C: ConsensusStorage<N>> {
@@ -56,16 +70,26 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         // Initialize a new process.
         let mut process = Process::load()?;
 
-        // Retrieve the transaction store.
-        let transaction_store = store.transaction_store();
-        // Load the deployments from the store.
-        for transaction_id in transaction_store.deployment_ids() {
-            // Retrieve the deployment.
-            match transaction_store.get_deployment(&transaction_id)? {
-                // Load the deployment.
-                Some(deployment) => process.load_deployment(&deployment)?,
-                None => bail!("Deployment transaction '{transaction_id}' is not found in storage."),
+        // Retrieve the block store.
+        let block_store = store.block_store();
+        // Load the deployments from the store, in the order they were included in blocks.
+        // Note: The deployments are not loaded from the transaction store directly, as a persistent
+        // storage does not preserve the insertion order, and a program must be loaded after its imports.
+        for height in block_store.heights() {
+            // Retrieve the block hash.
+            let block_hash = match block_store.get_block_hash(*height)? {
+                Some(block_hash) => block_hash,
+                None => bail!("Block {} is not found in storage.", *height),
             };
+            // Retrieve the block transactions.
+            let transactions = match block_store.get_block_transactions(&block_hash)? {
+                Some(transactions) => transactions,
+                None => bail!("Transactions for block {} are not found in storage.", *height),
+            };
+            // Load the deployments.
+            for deployment in transactions.deployments() {
+                process.load_deployment(deployment)?;
+            }
         }
 
         // Return the new VM.
@@ -20,192 +20,541 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Verifies the transaction in the VM.
     #[inline]
     pub fn verify(&self, transaction: &Transaction<N>) -> bool {
-        let timer = timer!("VM::verify");
+        match self.check_transaction(transaction) {
+            Ok(()) => true,
+            Err(error) => {
+                warn!("{error}");
+                false
+            }
+        }
+    }
+
+    /// Verifies the transactions in the VM, verifying their transition proofs in batch.
+    #[inline]
+    pub fn verify_transactions(&self, transactions: &Transactions<N>) -> bool {
+        match self.check_transactions(transactions) {
+            Ok(()) => true,
+            Err(error) => {
+                warn!("{error}");
+                false
+            }
+        }
+    }
+
+    /// Checks the transaction in the VM, returning the reason it was rejected on failure.
+    /// The transition proofs and deployment certificates are verified in batch, after all other checks,
+    /// with a single multi-pairing.
+    #[inline]
+    pub fn check_transaction(&self, transaction: &Transaction<N>) -> Result<(), VerificationError<N>> {
+        // Check the transaction, deferring its transition proofs and deployment certificates.
+        let mut deferred_proofs = Vec::new();
+        let mut deferred_certificates = Vec::new();
+        self.check_transaction_internal(transaction, &mut deferred_proofs, &mut deferred_certificates)?;
+        // Verify the transition proofs and deployment certificates in batch.
+        self.verify_deferred(&deferred_proofs, &deferred_certificates).map_err(|(_, error)| error)
+    }
+
+    /// Checks the transactions in the VM, returning the reason the first invalid transaction was rejected on failure.
+    /// The transition proofs and deployment certificates are verified in batch, after all other checks,
+    /// with a single multi-pairing.
+    #[inline]
+    pub fn check_transactions(&self, transactions: &Transactions<N>) -> Result<(), BlockError<N>> {
+        let timer = timer!("VM::check_transactions");
+
+        // Check each transaction, deferring its transition proofs and deployment certificates.
+        let mut deferred_proofs = Vec::new();
+        let mut deferred_certificates = Vec::new();
+        // Track the ID of the transaction each deferred proof and certificate belongs to.
+        let mut proof_transaction_ids = Vec::new();
+        let mut certificate_transaction_ids = Vec::new();
+        for transaction in transactions.iter() {
+            self.check_transaction_internal(transaction, &mut deferred_proofs, &mut deferred_certificates)
+                .map_err(|error| BlockError::InvalidTransaction(transaction.id(), error))?;
+            proof_transaction_ids.resize(deferred_proofs.len(), transaction.id());
+            certificate_transaction_ids.resize(deferred_certificates.len(), transaction.id());
+        }
+        lap!(timer, "Check the transactions");
+
+        // Verify the transition proofs and deployment certificates in batch.
+        if let Err((index, error)) = self.verify_deferred(&deferred_proofs, &deferred_certificates) {
+            // Note: The certificates are indexed after the proofs.
+            let transaction_ids =
+                proof_transaction_ids.into_iter().chain(certificate_transaction_ids).collect::<Vec<_>>();
+            return Err(BlockError::InvalidTransaction(transaction_ids[index], error));
+        }
+        lap!(timer, "Verify the transition proofs and deployment certificates");
+
+        finish!(timer);
+
+        Ok(())
+    }
+
+    /// Verifies the deferred transition proofs and deployment certificates with a single multi-pairing,
+    /// returning the index of the first invalid one, with the certificates indexed after the proofs,
+    /// and the reason it was rejected on failure.
+    fn verify_deferred(
+        &self,
+        deferred_proofs: &[DeferredProof<N>],
+        deferred_certificates: &[DeferredCertificate<N>],
+    ) -> Result<(), (usize, VerificationError<N>)> {
+        let instances = deferred_proofs
+            .iter()
+            .map(|(_, verifying_key, inputs, proof)| (verifying_key, &inputs[..], proof))
+            .collect::<Vec<_>>();
+        let certificates = deferred_certificates
+            .iter()
+            .map(|(_, _, verifying_key, assignment, certificate)| (assignment, verifying_key, certificate))
+            .collect::<Vec<_>>();
+        match VerifyingKey::find_invalid_proof(&instances, &certificates, &mut rand::thread_rng()) {
+            None => Ok(()),
+            Some(index) => match deferred_proofs.get(index) {
+                Some((transition_id, ..)) => Err((index, VerificationError::InvalidTransitionProof(*transition_id))),
+                None => {
+                    let (program_id, function_name, ..) = &deferred_certificates[index - deferred_proofs.len()];
+                    let error = format!("The certificate for function '{function_name}' is invalid in '{program_id}'");
+                    Err((index, VerificationError::InvalidDeployment(error)))
+                }
+            },
+        }
+    }
+
+    /// Checks the transaction in the VM, deferring its transition proofs and deployment certificates.
+    fn check_transaction_internal(
+        &self,
+        transaction: &Transaction<N>,
+        deferred_proofs: &mut Vec<DeferredProof<N>>,
+        deferred_certificates: &mut Vec<DeferredCertificate<N>>,
+    ) -> Result<(), VerificationError<N>> {
+        let timer = timer!("VM::check_transaction");
+
+        // Ensure the transaction is within the size bounds.
+        // Note: This is checked before the Merkle root, as the Merkle tree is bounded by the same size.
+        match transaction {
+            Transaction::Deploy(_, deployment, _) => {
+                if let Err(error) = Transaction::check_deployment_size(deployment) {
+                    return Err(VerificationError::InvalidDeploymentSize(error.to_string()));
+                }
+            }
+            Transaction::Execute(_, execution, _) => {
+                if let Err(error) = Transaction::check_execution_size(execution) {
+                    return Err(VerificationError::InvalidExecutionSize(error.to_string()));
+                }
+            }
+        };
 
         // Compute the Merkle root of the transaction.
         match transaction.to_root() {
             // Ensure the transaction ID is correct.
             Ok(root) => {
                 if *transaction.id() != root {
-                    warn!("Incorrect transaction ID ({})", transaction.id());
-                    return false;
+                    return Err(VerificationError::IncorrectTransactionID(transaction.id()));
                 }
             }
-            Err(error) => {
-                warn!("Failed to compute the Merkle root of the transaction: {error}\n{transaction}");
-                return false;
-            }
+            Err(error) => return Err(VerificationError::InvalidMerkleRoot(error.to_string())),
         };
         lap!(timer, "Verify the transaction id");
 
         // Ensure there are no duplicate transition IDs.
         if has_duplicates(transaction.transition_ids()) {
-            warn!("Found duplicate transition in the transactions list");
-            return false;
+            return Err(VerificationError::DuplicateTransitionID);
         }
 
         // Ensure there are no duplicate transition public keys.
         if has_duplicates(transaction.transition_public_keys()) {
-            warn!("Found duplicate transition public keys in the transactions list");
-            return false;
+            return Err(VerificationError::DuplicateTransitionPublicKey);
         }
 
         // Ensure there are no duplicate serial numbers.
         if has_duplicates(transaction.serial_numbers()) {
-            warn!("Found duplicate serial numbers in the transactions list");
-            return false;
+            return Err(VerificationError::DuplicateSerialNumber);
         }
 
         // Ensure there are no duplicate commitments.
         if has_duplicates(transaction.commitments()) {
-            warn!("Found duplicate commitments in the transactions list");
-            return false;
+            return Err(VerificationError::DuplicateCommitment);
         }
 
         // Ensure there are no duplicate nonces.
         if has_duplicates(transaction.nonces()) {
-            warn!("Found duplicate nonces in the transactions list");
-            return false;
+            return Err(VerificationError::DuplicateNonce);
         }
         lap!(timer, "Check for duplicate elements");
 
-        let verification = match transaction {
+        match transaction {
             Transaction::Deploy(_, deployment, fee) => {
-                // Check the deployment size.
-                if let Err(error) = Transaction::check_deployment_size(deployment) {
-                    warn!("Invalid transaction size (deployment): {error}");
-                    return false;
-                }
                 // Verify the deployment.
-                self.verify_deployment(deployment)
-                    // Verify the fee.
-                    && self.verify_fee(fee)
+                self.check_deployment(deployment, deferred_certificates)?;
+                // Verify the fee.
+                self.check_fee(fee, deferred_proofs)?;
             }
             Transaction::Execute(_, execution, additional_fee) => {
-                // Check the deployment size.
-                if let Err(error) = Transaction::check_execution_size(execution) {
-                    warn!("Invalid transaction size (execution): {error}");
-                    return false;
+                // Verify the execution.
+                self.check_execution(execution, deferred_proofs)?;
+                // Verify the additional fee, if it exists.
+                if let Some(additional_fee) = additional_fee {
+                    self.check_fee(additional_fee, deferred_proofs)?;
                 }
+            }
+        };
 
-                // Verify the additional fee, if it exists.
-                let check_additional_fee = match additional_fee {
-                    Some(additional_fee) => self.verify_fee(additional_fee),
-                    None => true,
-                };
+        lap!(timer, "Verify the transaction");
 
-                // Verify the execution.
-                self.verify_execution(execution)
-                    // Verify the additional fee.
-                    && check_additional_fee
+        finish!(timer);
+
+        Ok(())
+    }
+
+    /// Checks the given block is a valid next block for the ledger, returning the reason it was rejected on failure.
+    /// If the ledger is empty, the block must be a genesis block.
+    #[inline]
+    pub fn check_next_block(&self, block: &Block<N>, coinbase_puzzle: &CoinbasePuzzle<N>) -> Result<(), BlockError<N>> {
+        let timer = timer!("VM::check_next_block");
+
+        // Ensure the block does not already exist.
+        if self.block_store().contains_block_hash(&block.hash()).map_err(storage_error)? {
+            return Err(BlockError::DuplicateBlockHash(block.hash()));
+        }
+
+        // Ensure the block signature is valid for the block hash.
+        let signer = block.signature().to_address();
+        if !block.signature().verify(&signer, &[*block.hash()]) {
+            return Err(BlockError::InvalidSignature);
+        }
+
+        // Retrieve the latest block height.
+        let latest_height = match self.block_store().current_block_height() {
+            Some(height) => height,
+            // If the ledger is empty, ensure the block is a genesis block with valid transactions.
+            None => {
+                if !block.is_genesis() {
+                    return Err(BlockError::InvalidGenesisBlock);
+                }
+                self.check_transactions(block.transactions())?;
+                finish!(timer);
+                return Ok(());
             }
         };
 
-        lap!(timer, "Verify the transaction");
+        // Retrieve the latest block hash.
+        let latest_hash = match self.block_store().get_block_hash(latest_height).map_err(storage_error)? {
+            Some(hash) => hash,
+            None => return Err(BlockError::Storage(format!("Block {latest_height} is missing its hash"))),
+        };
+        // Retrieve the latest block header.
+        let latest_header = match self.block_store().get_block_header(&latest_hash).map_err(storage_error)? {
+            Some(header) => header,
+            None => return Err(BlockError::Storage(format!("Block {latest_height} is missing its header"))),
+        };
+
+        // Ensure the previous block hash is the latest block hash.
+        if block.previous_hash() != latest_hash {
+            return Err(BlockError::IncorrectPreviousHash { expected: latest_hash, found: block.previous_hash() });
+        }
+        // Ensure the block height increments the latest block height.
+        if block.height() != latest_height.saturating_add(1) {
+            return Err(BlockError::IncorrectHeight {
+                expected: latest_height.saturating_add(1),
+                found: block.height(),
+            });
+        }
+        // Ensure the block round increases the latest round.
+        if block.round() <= latest_header.round() {
+            return Err(BlockError::IncorrectRound { latest: latest_header.round(), found: block.round() });
+        }
+        // Ensure the block timestamp increases the latest timestamp.
+        if block.timestamp() <= latest_header.timestamp() {
+            return Err(BlockError::IncorrectTimestamp { latest: latest_header.timestamp(), found: block.timestamp() });
+        }
+        // Ensure the block header is well-formed.
+        if !block.header().is_valid() {
+            return Err(BlockError::InvalidHeader);
+        }
+        lap!(timer, "Check the block against the latest block");
+
+        // Compute the expected coinbase target and proof target for the block.
+        let (expected_coinbase_target, expected_proof_target) = latest_header
+            .next_targets(block.timestamp())
+            .map_err(|error| BlockError::InvalidRetarget(error.to_string()))?;
+        // Ensure the coinbase target and proof target are retargeted from the latest block.
+        if block.coinbase_target() != expected_coinbase_target {
+            return Err(BlockError::IncorrectCoinbaseTarget {
+                expected: expected_coinbase_target,
+                found: block.coinbase_target(),
+            });
+        }
+        if block.proof_target() != expected_proof_target {
+            return Err(BlockError::IncorrectProofTarget {
+                expected: expected_proof_target,
+                found: block.proof_target(),
+            });
+        }
+        // Ensure the last coinbase target and timestamp are updated iff the block contains a coinbase solution.
+        let (expected_last_coinbase_target, expected_last_coinbase_timestamp) = match block.coinbase() {
+            Some(_) => (block.coinbase_target(), block.timestamp()),
+            None => (latest_header.last_coinbase_target(), latest_header.last_coinbase_timestamp()),
+        };
+        if block.last_coinbase_target() != expected_last_coinbase_target {
+            return Err(BlockError::IncorrectLastCoinbaseTarget {
+                expected: expected_last_coinbase_target,
+                found: block.last_coinbase_target(),
+            });
+        }
+        if block.last_coinbase_timestamp() != expected_last_coinbase_timestamp {
+            return Err(BlockError::IncorrectLastCoinbaseTimestamp {
+                expected: expected_last_coinbase_timestamp,
+                found: block.last_coinbase_timestamp(),
+            });
+        }
+        lap!(timer, "Check the coinbase and proof targets");
+
+        // Ensure the transactions root matches the block transactions.
+        let transactions_root = block
+            .transactions()
+            .to_root()
+            .map_err(|error| BlockError::Storage(format!("Failed to compute the transactions root: {error}")))?;
+        if block.transactions_root() != transactions_root {
+            return Err(BlockError::IncorrectTransactionsRoot {
+                expected: transactions_root,
+                found: block.transactions_root(),
+            });
+        }
+        // Ensure the previous state root is the latest state root.
+        let state_root = self.block_store().current_state_root();
+        if block.previous_state_root() != *state_root {
+            return Err(BlockError::IncorrectStateRoot { expected: state_root, found: block.previous_state_root() });
+        }
+        lap!(timer, "Check the transactions root and state root");
+
+        // Ensure each transaction is new.
+        for transaction in block.transactions().iter() {
+            if self.transaction_store().contains_transaction_id(&transaction.id()).map_err(storage_error)? {
+                return Err(BlockError::DuplicateTransactionID(transaction.id()));
+            }
+        }
+        // Ensure each transaction is valid.
+        self.check_transactions(block.transactions())?;
+        // Ensure each serial number is spent exactly once.
+        let mut serial_numbers = HashSet::new();
+        for serial_number in block.serial_numbers() {
+            if !serial_numbers.insert(serial_number)
+                || self.transition_store().contains_serial_number(serial_number).map_err(storage_error)?
+            {
+                return Err(BlockError::DoubleSpend(*serial_number));
+            }
+        }
+        lap!(timer, "Check the transactions");
+
+        // Ensure the coinbase solution is valid, if it exists.
+        if let Some(coinbase) = block.coinbase() {
+            // Ensure the puzzle commitments are new.
+            for puzzle_commitment in coinbase.puzzle_commitments() {
+                if self.block_store().contains_puzzle_commitment(&puzzle_commitment).map_err(storage_error)? {
+                    return Err(BlockError::DuplicatePuzzleCommitment(puzzle_commitment));
+                }
+            }
+
+            // Retrieve the epoch block hash, defined as the block hash right before the epoch started.
+            let epoch_number = block.epoch_number();
+            let epoch_starting_height = epoch_number.saturating_mul(N::NUM_BLOCKS_PER_EPOCH);
+            let epoch_block_hash =
+                match self.block_store().get_previous_block_hash(epoch_starting_height).map_err(storage_error)? {
+                    Some(hash) => hash,
+                    None => return Err(BlockError::Storage(format!("Epoch {epoch_number} is missing its block hash"))),
+                };
+            // Construct the epoch challenge.
+            let epoch_challenge = EpochChallenge::new(epoch_number, epoch_block_hash, N::COINBASE_PUZZLE_DEGREE)
+                .map_err(|error| BlockError::InvalidCoinbaseSolution(error.to_string()))?;
+
+            // Verify the coinbase solution.
+            match coinbase_puzzle.verify(coinbase, &epoch_challenge, block.coinbase_target(), block.proof_target()) {
+                Ok(true) => (),
+                Ok(false) => return Err(BlockError::InvalidCoinbaseSolution("Invalid coinbase proof".to_string())),
+                Err(error) => return Err(BlockError::InvalidCoinbaseSolution(error.to_string())),
+            }
+            lap!(timer, "Verify the coinbase solution");
+        }
 
         finish!(timer);
 
-        verification
+        Ok(())
     }
 
-    /// Verifies the given deployment.
+    /// Verifies the given deployment, deferring its certificates to `deferred_certificates`.
     #[inline]
-    fn verify_deployment(&self, deployment: &Deployment<N>) -> bool {
-        let timer = timer!("VM::verify_deployment");
+    fn check_deployment(
+        &self,
+        deployment: &Deployment<N>,
+        deferred_certificates: &mut Vec<DeferredCertificate<N>>,
+    ) -> Result<(), VerificationError<N>> {
+        let timer = timer!("VM::check_deployment");
 
         // Compute the core logic.
         macro_rules! logic {
             ($process:expr, $network:path, $aleo:path) => {{
-                let task = || {
+                let mut task = || {
                     // Prepare the deployment.
                     let deployment = cast_ref!(&deployment as Deployment<$network>);
+                    // Prepare the deferred certificates.
+                    let deferred_certificates = (&mut *deferred_certificates as &mut dyn std::any::Any)
+                        .downcast_mut::<Vec<DeferredCertificate<$network>>>()
+                        .ok_or_else(|| anyhow!("Failed to downcast {}", stringify!(deferred_certificates)))?;
                     // Initialize an RNG.
                     let rng = &mut rand::thread_rng();
                     // Verify the deployment.
-                    $process.verify_deployment::<$aleo, _>(&deployment, rng)
+                    $process.verify_deployment_deferred::<$aleo, _>(&deployment, deferred_certificates, rng)
                 };
                 task()
             }};
         }
 
         // Process the logic.
-        match process!(self, logic) {
-            Ok(()) => {
-                finish!(timer);
-                true
-            }
-            Err(error) => {
-                warn!("Deployment verification failed: {error}");
-                finish!(timer);
-                false
-            }
-        }
+        let verification = process!(self, logic);
+        finish!(timer);
+
+        verification.map_err(|error| VerificationError::InvalidDeployment(error.to_string()))
     }
 
-    /// Verifies the given execution.
+    /// Verifies the given execution, deferring its transition proofs to `deferred_proofs`.
     #[inline]
-    fn verify_execution(&self, execution: &Execution<N>) -> bool {
-        let timer = timer!("VM::verify_execution");
+    fn check_execution(
+        &self,
+        execution: &Execution<N>,
+        deferred_proofs: &mut Vec<DeferredProof<N>>,
+    ) -> Result<(), VerificationError<N>> {
+        let timer = timer!("VM::check_execution");
+
+        // Ensure the programs of the transitions exist.
+        for transition in execution.transitions() {
+            if !self.contains_program(transition.program_id()) {
+                return Err(VerificationError::UnknownProgram(*transition.program_id()));
+            }
+        }
 
         // Verify the execution.
-        let verification = self.process.read().verify_execution::<true>(execution);
+        let verification = self.process.read().verify_execution_deferred::<true>(execution, deferred_proofs);
         finish!(timer);
 
-        match verification {
-            // Ensure the global state root exists in the block store.
-            Ok(()) => match self.block_store().contains_state_root(&execution.global_state_root()) {
-                Ok(true) => true,
-                Ok(false) => {
-                    warn!("Execution verification failed: global state root not found");
-                    false
-                }
-                Err(error) => {
-                    warn!("Execution verification failed: {error}");
-                    false
-                }
-            },
-            Err(error) => {
-                warn!("Execution verification failed: {error}");
-                false
-            }
+        if let Err(error) = verification {
+            return Err(VerificationError::InvalidExecution(error.to_string()));
         }
+
+        // Ensure the global state root exists in the block store.
+        self.check_state_root(execution.global_state_root(), VerificationError::InvalidExecution)
     }
 
-    /// Verifies the given fee.
+    /// Verifies the given fee, deferring its transition proof to `deferred_proofs`.
     #[inline]
-    fn verify_fee(&self, fee: &Fee<N>) -> bool {
-        let timer = timer!("VM::verify_fee");
+    fn check_fee(&self, fee: &Fee<N>, deferred_proofs: &mut Vec<DeferredProof<N>>) -> Result<(), VerificationError<N>> {
+        let timer = timer!("VM::check_fee");
 
         // Verify the fee.
-        let verification = self.process.read().verify_fee(fee);
+        let verification = self.process.read().verify_fee_deferred(fee, deferred_proofs);
         finish!(timer);
 
-        match verification {
-            // Ensure the global state root exists in the block store.
-            Ok(()) => match self.block_store().contains_state_root(&fee.global_state_root()) {
-                Ok(true) => true,
-                Ok(false) => {
-                    warn!("Fee verification failed: global state root not found");
-                    false
-                }
-                Err(error) => {
-                    warn!("Fee verification failed: {error}");
-                    false
-                }
-            },
-            Err(error) => {
-                warn!("Fee verification failed: {error}");
-                false
-            }
+        if let Err(error) = verification {
+            return Err(VerificationError::InvalidFee(error.to_string()));
+        }
+
+        // Ensure the global state root exists in the block store.
+        self.check_state_root(fee.global_state_root(), VerificationError::InvalidFee)
+    }
+
+    /// Ensures the given global state root exists in the block store.
+    #[inline]
+    fn check_state_root(
+        &self,
+        global_state_root: N::StateRoot,
+        on_error: fn(String) -> VerificationError<N>,
+    ) -> Result<(), VerificationError<N>> {
+        // Note: A zero global state root denotes that no records are consumed,
+        // which is checked against the inclusion proof by the process.
+        if global_state_root == N::StateRoot::default() {
+            return Ok(());
+        }
+        match self.block_store().contains_state_root(&global_state_root) {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(VerificationError::UnknownStateRoot(global_state_root)),
+            Err(error) => Err(on_error(error.to_string())),
         }
     }
 }
 
+/// Converts the given storage error into a block error.
+fn storage_error<N: Network>(error: Error) -> BlockError<N> {
+    BlockError::Storage(error.to_string())
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{vm::test_helpers::sample_program, Inclusion, Transaction};
+    use crate::{
+        coinbase_puzzle::{CoinbasePuzzle, PuzzleConfig},
+        vm::{
+            test_helpers::{sample_program, CurrentNetwork},
+            BlockError,
+            VerificationError,
+        },
+        Block,
+        Deployment,
+        Execution,
+        Fee,
+        Header,
+        Inclusion,
+        Input,
+        Metadata,
+        Output,
+        Transaction,
+        Transactions,
+        Transition,
+    };
+    use console::{
+        account::PrivateKey,
+        network::prelude::*,
+        program::{ProgramID, TRANSACTION_DEPTH},
+        types::{Field, Group},
+    };
     use snarkvm_utilities::TestRng;
 
+    use indexmap::IndexMap;
+
+    /// Samples a block on top of the genesis block with the given height and timestamp.
+    fn sample_next_block(
+        vm: &crate::vm::VM<CurrentNetwork, crate::store::ConsensusMemory<CurrentNetwork>>,
+        private_key: &PrivateKey<CurrentNetwork>,
+        height: u32,
+        timestamp: i64,
+        rng: &mut TestRng,
+    ) -> Block<CurrentNetwork> {
+        // Fetch the genesis block.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+
+        // Construct the transactions.
+        let transactions = Transactions::from(&[crate::vm::test_helpers::sample_execution_transaction(rng)]);
+        // Compute the targets for the block.
+        let (coinbase_target, proof_target) = genesis.header().next_targets(timestamp).unwrap();
+        // Construct the metadata.
+        let metadata = Metadata::new(
+            CurrentNetwork::ID,
+            genesis.round() + 1,
+            height,
+            coinbase_target,
+            proof_target,
+            genesis.last_coinbase_target(),
+            genesis.last_coinbase_timestamp(),
+            timestamp,
+        )
+        .unwrap();
+        // Construct the header.
+        let header = Header::from(
+            *vm.block_store().current_state_root(),
+            transactions.to_root().unwrap(),
+            Field::zero(),
+            metadata,
+        )
+        .unwrap();
+        // Construct the block.
+        Block::new(private_key, genesis.hash(), header, transactions, None, rng).unwrap()
+    }
+
     #[test]
     fn test_verify() {
         let rng = &mut TestRng::default();
@@ -222,6 +571,316 @@ mod tests {
         assert!(vm.verify(&execution_transaction));
     }
 
+    #[test]
+    fn test_verify_transactions() {
+        let rng = &mut TestRng::default();
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Fetch a deployment and an execution transaction.
+        let transactions = Transactions::from(&[
+            crate::vm::test_helpers::sample_deployment_transaction(rng),
+            crate::vm::test_helpers::sample_execution_transaction(rng),
+        ]);
+        // Ensure the transactions verify, with their transition proofs in batch.
+        assert!(vm.verify_transactions(&transactions));
+
+        // Initialize a VM without the genesis block.
+        let vm = crate::vm::test_helpers::sample_vm();
+        // Ensure the transactions are rejected, as their global state roots are unknown.
+        match vm.check_transactions(&transactions) {
+            Err(BlockError::InvalidTransaction(id, VerificationError::UnknownStateRoot(_))) => {
+                assert_eq!(id, transactions.iter().next().unwrap().id())
+            }
+            result => panic!("Expected an unknown state root error, found {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_check_transaction_unknown_state_root() {
+        let rng = &mut TestRng::default();
+        // Initialize a VM without the genesis block.
+        let vm = crate::vm::test_helpers::sample_vm();
+
+        // Fetch an execution transaction.
+        let transaction = crate::vm::test_helpers::sample_execution_transaction(rng);
+
+        // Ensure the transaction is rejected, as its global state root is unknown.
+        match vm.check_transaction(&transaction) {
+            Err(VerificationError::UnknownStateRoot(_)) => (),
+            result => panic!("Expected an unknown state root error, found {result:?}"),
+        }
+    }
+
+    /// Returns the execution of the sample execution transaction.
+    fn sample_execution(rng: &mut TestRng) -> Execution<CurrentNetwork> {
+        match crate::vm::test_helpers::sample_execution_transaction(rng) {
+            Transaction::Execute(_, execution, _) => execution,
+            _ => panic!("Expected an execution transaction"),
+        }
+    }
+
+    /// Returns a copy of the given transition, with the given inputs, outputs, and transition public key.
+    fn sample_transition_with(
+        transition: &Transition<CurrentNetwork>,
+        inputs: Vec<Input<CurrentNetwork>>,
+        outputs: Vec<Output<CurrentNetwork>>,
+        tpk: Group<CurrentNetwork>,
+    ) -> Transition<CurrentNetwork> {
+        Transition::new(
+            *transition.program_id(),
+            *transition.function_name(),
+            inputs,
+            outputs,
+            transition.finalize().cloned(),
+            transition.proof().clone(),
+            tpk,
+            *transition.tcm(),
+            *transition.fee(),
+        )
+        .unwrap()
+    }
+
+    /// Returns an execution transaction with the given transitions, and the global state root of the given execution.
+    fn sample_execution_transaction_with(
+        execution: &Execution<CurrentNetwork>,
+        transitions: Vec<Transition<CurrentNetwork>>,
+    ) -> Transaction<CurrentNetwork> {
+        let execution = Execution::from(transitions.into_iter(), execution.global_state_root(), None).unwrap();
+        Transaction::from_execution(execution, None).unwrap()
+    }
+
+    #[test]
+    fn test_check_transaction_incorrect_transaction_id() {
+        let rng = &mut TestRng::default();
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Construct an execution transaction with an incorrect transaction ID.
+        let execution = sample_execution(rng);
+        let transaction = Transaction::Execute(Field::<CurrentNetwork>::zero().into(), execution, None);
+
+        // Ensure the transaction is rejected.
+        match vm.check_transaction(&transaction) {
+            Err(VerificationError::IncorrectTransactionID(id)) => assert_eq!(id, transaction.id()),
+            result => panic!("Expected an incorrect transaction ID error, found {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_check_transaction_invalid_size() {
+        let rng = &mut TestRng::default();
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Construct a deployment transaction without verifying keys.
+        let transaction = match crate::vm::test_helpers::sample_deployment_transaction(rng) {
+            Transaction::Deploy(id, deployment, fee) => {
+                let deployment =
+                    Deployment::new(deployment.edition(), deployment.program().clone(), IndexMap::new()).unwrap();
+                Transaction::Deploy(id, Box::new(deployment), fee)
+            }
+            _ => panic!("Expected a deployment transaction"),
+        };
+        // Ensure the transaction is rejected.
+        match vm.check_transaction(&transaction) {
+            Err(VerificationError::InvalidDeploymentSize(_)) => (),
+            result => panic!("Expected an invalid deployment size error, found {result:?}"),
+        }
+
+        // Construct an execution transaction with too many transitions.
+        let execution = sample_execution(rng);
+        let transition = execution.peek().unwrap();
+        let transitions = (0..usize::pow(2, TRANSACTION_DEPTH as u32)).map(|index| {
+            let inputs = vec![Input::ExternalRecord(Field::from_u32(index as u32))];
+            sample_transition_with(transition, inputs, vec![], *transition.tpk())
+        });
+        let execution = Execution::from(transitions, execution.global_state_root(), None).unwrap();
+        let transaction = Transaction::Execute(Field::<CurrentNetwork>::zero().into(), execution, None);
+        // Ensure the transaction is rejected.
+        match vm.check_transaction(&transaction) {
+            Err(VerificationError::InvalidExecutionSize(_)) => (),
+            result => panic!("Expected an invalid execution size error, found {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_check_transaction_duplicates() {
+        let rng = &mut TestRng::default();
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Fetch the transition of the execution.
+        let execution = sample_execution(rng);
+        let transition = execution.peek().unwrap().clone();
+
+        // Ensure a transaction with a duplicate transition ID is rejected.
+        let fee = Fee::from(transition.clone(), execution.global_state_root(), None);
+        let transaction = Transaction::from_execution(execution.clone(), Some(fee)).unwrap();
+        match vm.check_transaction(&transaction) {
+            Err(VerificationError::DuplicateTransitionID) => (),
+            result => panic!("Expected a duplicate transition ID error, found {result:?}"),
+        }
+
+        // Ensure a transaction with a duplicate transition public key is rejected.
+        let other = sample_transition_with(&transition, transition.inputs().to_vec(), vec![], *transition.tpk());
+        let transaction = sample_execution_transaction_with(&execution, vec![transition.clone(), other]);
+        match vm.check_transaction(&transaction) {
+            Err(VerificationError::DuplicateTransitionPublicKey) => (),
+            result => panic!("Expected a duplicate transition public key error, found {result:?}"),
+        }
+
+        // Ensure a transaction with a duplicate serial number is rejected.
+        let other = sample_transition_with(&transition, transition.inputs().to_vec(), vec![], Uniform::rand(rng));
+        let transaction = sample_execution_transaction_with(&execution, vec![transition.clone(), other]);
+        match vm.check_transaction(&transaction) {
+            Err(VerificationError::DuplicateSerialNumber) => (),
+            result => panic!("Expected a duplicate serial number error, found {result:?}"),
+        }
+
+        // Ensure a transaction with a duplicate commitment is rejected.
+        let other = sample_transition_with(&transition, vec![], transition.outputs().to_vec(), Uniform::rand(rng));
+        let transaction = sample_execution_transaction_with(&execution, vec![transition.clone(), other]);
+        match vm.check_transaction(&transaction) {
+            Err(VerificationError::DuplicateCommitment) => (),
+            result => panic!("Expected a duplicate commitment error, found {result:?}"),
+        }
+
+        // Ensure a transaction with a duplicate nonce is rejected.
+        let outputs = transition
+            .outputs()
+            .iter()
+            .map(|output| match output {
+                Output::Record(_, checksum, record) => Output::Record(Uniform::rand(rng), *checksum, record.clone()),
+                output => output.clone(),
+            })
+            .collect();
+        let other = sample_transition_with(&transition, vec![], outputs, Uniform::rand(rng));
+        let transaction = sample_execution_transaction_with(&execution, vec![transition, other]);
+        match vm.check_transaction(&transaction) {
+            Err(VerificationError::DuplicateNonce) => (),
+            result => panic!("Expected a duplicate nonce error, found {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_check_transaction_invalid_deployment() {
+        let rng = &mut TestRng::default();
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Fetch a deployment transaction.
+        let transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        // Add the program to the process.
+        vm.process().write().add_program(&sample_program()).unwrap();
+
+        // Ensure the transaction is rejected, as the program already exists.
+        match vm.check_transaction(&transaction) {
+            Err(VerificationError::InvalidDeployment(_)) => (),
+            result => panic!("Expected an invalid deployment error, found {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_check_transaction_invalid_fee() {
+        let rng = &mut TestRng::default();
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Construct a deployment transaction with a fee that is missing its inclusion proof.
+        let transaction = match crate::vm::test_helpers::sample_deployment_transaction(rng) {
+            Transaction::Deploy(_, deployment, fee) => {
+                let fee = Fee::from(fee.transition().clone(), fee.global_state_root(), None);
+                Transaction::from_deployment(*deployment, fee).unwrap()
+            }
+            _ => panic!("Expected a deployment transaction"),
+        };
+
+        // Ensure the transaction is rejected.
+        match vm.check_transaction(&transaction) {
+            Err(VerificationError::InvalidFee(_)) => (),
+            result => panic!("Expected an invalid fee error, found {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_check_transaction_invalid_execution() {
+        let rng = &mut TestRng::default();
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Construct an execution transaction that is missing its inclusion proof.
+        let execution = sample_execution(rng);
+        let transaction = sample_execution_transaction_with(&execution, execution.transitions().cloned().collect());
+
+        // Ensure the transaction is rejected.
+        match vm.check_transaction(&transaction) {
+            Err(VerificationError::InvalidExecution(_)) => (),
+            result => panic!("Expected an invalid execution error, found {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_check_transaction_invalid_transition_proof() {
+        let rng = &mut TestRng::default();
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Fetch the proof of a fee.
+        let proof = crate::vm::test_helpers::sample_fee().proof().clone();
+
+        // Construct an execution transaction with the transition proof replaced by the fee proof.
+        let execution = sample_execution(rng);
+        let transition = execution.peek().unwrap();
+        let transition = Transition::new(
+            *transition.program_id(),
+            *transition.function_name(),
+            transition.inputs().to_vec(),
+            transition.outputs().to_vec(),
+            transition.finalize().cloned(),
+            proof,
+            *transition.tpk(),
+            *transition.tcm(),
+            *transition.fee(),
+        )
+        .unwrap();
+        let execution = Execution::from(
+            [transition].into_iter(),
+            execution.global_state_root(),
+            execution.inclusion_proof().cloned(),
+        )
+        .unwrap();
+        let transaction = Transaction::from_execution(execution.clone(), None).unwrap();
+
+        // Ensure the transaction is rejected.
+        match vm.check_transaction(&transaction) {
+            Err(VerificationError::InvalidTransitionProof(id)) => assert_eq!(id, *execution.peek().unwrap().id()),
+            result => panic!("Expected an invalid transition proof error, found {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_check_transaction_unknown_program() {
+        let rng = &mut TestRng::default();
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Construct an execution transaction for a program that does not exist.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("unknown.aleo").unwrap();
+        let execution = sample_execution(rng);
+        let transition = execution.peek().unwrap();
+        let transition = Transition::new(
+            program_id,
+            *transition.function_name(),
+            transition.inputs().to_vec(),
+            transition.outputs().to_vec(),
+            transition.finalize().cloned(),
+            transition.proof().clone(),
+            *transition.tpk(),
+            *transition.tcm(),
+            *transition.fee(),
+        )
+        .unwrap();
+        let transaction = sample_execution_transaction_with(&execution, vec![transition]);
+
+        // Ensure the transaction is rejected.
+        match vm.check_transaction(&transaction) {
+            Err(VerificationError::UnknownProgram(id)) => assert_eq!(id, program_id),
+            result => panic!("Expected an unknown program error, found {result:?}"),
+        }
+    }
+
     #[test]
     fn test_verify_deployment() {
         let rng = &mut TestRng::default();
@@ -233,8 +892,12 @@ mod tests {
         // Deploy the program.
         let deployment = vm.deploy(&program, rng).unwrap();
 
-        // Ensure the deployment is valid.
-        assert!(vm.verify_deployment(&deployment));
+        // Ensure the deployment is valid, deferring its certificates.
+        let mut deferred_certificates = Vec::new();
+        assert!(vm.check_deployment(&deployment, &mut deferred_certificates).is_ok());
+        assert_eq!(deployment.verifying_keys().len(), deferred_certificates.len());
+        // Ensure the certificates are valid.
+        assert!(vm.verify_deferred(&[], &deferred_certificates).is_ok());
     }
 
     #[test]
@@ -251,10 +914,54 @@ mod tests {
                 assert!(execution.inclusion_proof().is_some());
                 // Verify the inclusion.
                 assert!(Inclusion::verify_execution(&execution).is_ok());
-                // Verify the execution.
-                assert!(vm.verify_execution(&execution));
+                // Verify the execution, deferring its transition proofs.
+                let mut deferred_proofs = Vec::new();
+                assert!(vm.check_execution(&execution, &mut deferred_proofs).is_ok());
+                // Verify the transition proofs.
+                assert!(vm.verify_deferred(&deferred_proofs, &[]).is_ok());
             }
             _ => panic!("Expected an execution transaction"),
         }
     }
+
+    #[test]
+    fn test_check_next_block() {
+        let rng = &mut TestRng::default();
+        // Initialize a VM without the genesis block.
+        let vm = crate::vm::test_helpers::sample_vm();
+        // Initialize a coinbase puzzle.
+        let srs = CoinbasePuzzle::<CurrentNetwork>::setup(PuzzleConfig { degree: 31 }).unwrap();
+        let coinbase_puzzle = CoinbasePuzzle::<CurrentNetwork>::trim(&srs, PuzzleConfig { degree: 31 }).unwrap();
+
+        // Ensure the genesis block is accepted by an empty ledger.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+        assert_eq!(vm.check_next_block(&genesis, &coinbase_puzzle), Ok(()));
+        vm.add_next_block(&genesis).unwrap();
+
+        // Ensure the genesis block is rejected once it is in the ledger.
+        match vm.check_next_block(&genesis, &coinbase_puzzle) {
+            Err(BlockError::DuplicateBlockHash(hash)) => assert_eq!(hash, genesis.hash()),
+            result => panic!("Expected a duplicate block hash error, found {result:?}"),
+        }
+
+        // Ensure a valid next block is accepted.
+        let private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let timestamp = genesis.timestamp() + 1;
+        let block = sample_next_block(&vm, &private_key, 1, timestamp, rng);
+        assert_eq!(vm.check_next_block(&block, &coinbase_puzzle), Ok(()));
+
+        // Ensure a block with the wrong height is rejected.
+        let block = sample_next_block(&vm, &private_key, 2, timestamp, rng);
+        match vm.check_next_block(&block, &coinbase_puzzle) {
+            Err(BlockError::IncorrectHeight { expected: 1, found: 2 }) => (),
+            result => panic!("Expected an incorrect height error, found {result:?}"),
+        }
+
+        // Ensure a block with a stale timestamp is rejected.
+        let block = sample_next_block(&vm, &private_key, 1, genesis.timestamp(), rng);
+        match vm.check_next_block(&block, &coinbase_puzzle) {
+            Err(BlockError::IncorrectTimestamp { .. }) => (),
+            result => panic!("Expected an incorrect timestamp error, found {result:?}"),
+        }
+    }
 }
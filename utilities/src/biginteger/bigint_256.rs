@@ -22,6 +22,7 @@ use crate::{
     FromBytes,
     ToBits,
     ToBytes,
+    ZeroCopy,
 };
 
 use anyhow::Result;
@@ -33,6 +34,7 @@ use rand::{
 };
 
 #[derive(Copy, Clone, PartialEq, Eq, Default, Hash)]
+#[repr(transparent)]
 pub struct BigInteger256(pub [u64; 4]);
 
 impl BigInteger256 {
@@ -278,6 +280,19 @@ impl FromBytes for BigInteger256 {
     }
 }
 
+// SAFETY: `BigInteger256` is a transparent wrapper around its limbs.
+unsafe impl ZeroCopy for BigInteger256 {
+    #[inline]
+    fn write_raw<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.0.write_raw(writer)
+    }
+
+    #[inline]
+    fn is_valid_raw(bytes: &[u8]) -> bool {
+        <[u64; 4]>::is_valid_raw(bytes)
+    }
+}
+
 impl Debug for BigInteger256 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for i in self.0.iter().rev() {
@@ -22,6 +22,7 @@ use crate::{
     FromBytes,
     ToBits,
     ToBytes,
+    ZeroCopy,
 };
 
 use anyhow::Result;
@@ -33,6 +34,7 @@ use rand::{
 };
 
 #[derive(Copy, Clone, PartialEq, Eq, Default, Hash)]
+#[repr(transparent)]
 pub struct BigInteger384(pub [u64; 6]);
 
 impl BigInteger384 {
@@ -280,6 +282,19 @@ impl FromBytes for BigInteger384 {
         <[u64; 6]>::read_le(reader).map(Self::new)
     }
 }
+// SAFETY: `BigInteger384` is a transparent wrapper around its limbs.
+unsafe impl ZeroCopy for BigInteger384 {
+    #[inline]
+    fn write_raw<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.0.write_raw(writer)
+    }
+
+    #[inline]
+    fn is_valid_raw(bytes: &[u8]) -> bool {
+        <[u64; 6]>::is_valid_raw(bytes)
+    }
+}
+
 impl Debug for BigInteger384 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for i in self.0.iter().rev() {
@@ -73,6 +73,9 @@ pub use self::rand::*;
 pub mod serialize;
 pub use serialize::*;
 
+pub mod zero_copy;
+pub use zero_copy::*;
+
 #[cfg(not(feature = "std"))]
 pub mod io;
 
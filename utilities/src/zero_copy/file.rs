@@ -0,0 +1,345 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{is_valid_raw_layout_slice, is_valid_raw_slice, write_padding, MappedSlice, MappedVec, ZeroCopy};
+use crate::{error, FromBytes, ToBytes};
+
+use memmap2::Mmap;
+use std::{
+    fs::File,
+    io::{self, Read, Write},
+    mem::{align_of, size_of},
+    path::Path,
+    sync::Arc,
+};
+
+/// The magic bytes at the start of a zero-copy file.
+const MAGIC: [u8; 8] = *b"SVMZCOPY";
+/// The version of the zero-copy layout.
+const VERSION: u64 = 1;
+/// A value whose in-memory representation differs on every supported byte order.
+const BYTE_ORDER: u64 = 0x0102_0304_0506_0708;
+/// The number of bytes in the header of a zero-copy file.
+const HEADER_SIZE: usize = 24;
+/// The alignment of every zero-copy section in the file.
+const ALIGNMENT: usize = 64;
+
+/// Returns the number of padding bytes needed to align `position` to `ALIGNMENT`.
+const fn padding_for(position: usize) -> usize {
+    (ALIGNMENT - position % ALIGNMENT) % ALIGNMENT
+}
+
+/// A read-only, memory-mapped file in the zero-copy layout.
+///
+/// The file must not be modified or truncated while it is mapped.
+#[derive(Clone)]
+pub struct MappedFile {
+    mmap: Arc<Mmap>,
+}
+
+impl MappedFile {
+    /// Maps the file at the given path into memory, and checks its header.
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let file = File::open(path)?;
+        // SAFETY: The file is mapped read-only, and callers must not modify it while it is mapped.
+        let mmap = unsafe { Mmap::map(&file)? };
+
+        // Ensure the header is valid.
+        let header = mmap.get(..HEADER_SIZE).ok_or_else(|| error("The zero-copy file is missing its header"))?;
+        if header[..8] != MAGIC {
+            return Err(error("The file is not in the zero-copy layout"));
+        }
+        if header[8..16] != BYTE_ORDER.to_ne_bytes() {
+            return Err(error("The zero-copy file was written on a platform with a different byte order"));
+        }
+        if header[16..24] != VERSION.to_le_bytes() {
+            return Err(error("The zero-copy file has an unsupported version"));
+        }
+
+        Ok(Self { mmap: Arc::new(mmap) })
+    }
+
+    /// Returns the contents of the file.
+    pub(super) fn as_bytes(&self) -> &[u8] {
+        &self.mmap
+    }
+
+    /// Returns a reader positioned at the first section of the file.
+    pub fn reader(&self) -> MappedReader {
+        MappedReader { file: self.clone(), position: HEADER_SIZE, is_prevalidated: false }
+    }
+
+    /// Returns a reader positioned at the first section of the file, for a file whose values were already
+    /// validated by an earlier read. The reader only checks the layout of each value (see `is_valid_raw_layout`).
+    pub fn prevalidated_reader(&self) -> MappedReader {
+        MappedReader { file: self.clone(), position: HEADER_SIZE, is_prevalidated: true }
+    }
+}
+
+/// A writer for the zero-copy layout.
+///
+/// Values that implement `ToBytes` are written in place, as usual, while slices of `ZeroCopy`
+/// values are written in their in-memory representation, so they can later be mapped from disk.
+pub struct ZeroCopyWriter<W: Write> {
+    writer: W,
+    position: usize,
+}
+
+impl<W: Write> ZeroCopyWriter<W> {
+    /// Initializes a new writer, and writes the header of the file.
+    pub fn new(mut writer: W) -> io::Result<Self> {
+        writer.write_all(&MAGIC)?;
+        writer.write_all(&BYTE_ORDER.to_ne_bytes())?;
+        writer.write_all(&VERSION.to_le_bytes())?;
+        Ok(Self { writer, position: HEADER_SIZE })
+    }
+
+    /// Writes the given values in their in-memory representation.
+    pub fn write_slice<T: ZeroCopy>(&mut self, values: &[T]) -> io::Result<()> {
+        debug_assert!(align_of::<T>() <= ALIGNMENT);
+        // Write the number of values and the size of each value.
+        (values.len() as u64).write_le(&mut *self)?;
+        (size_of::<T>() as u64).write_le(&mut *self)?;
+        // Align the values.
+        let padding = padding_for(self.position);
+        write_padding(&mut *self, padding)?;
+        // Write the values.
+        values.iter().try_for_each(|value| value.write_raw(&mut *self))
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: Write> Write for ZeroCopyWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let num_bytes = self.writer.write(buf)?;
+        self.position += num_bytes;
+        Ok(num_bytes)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+/// A reader for a memory-mapped file in the zero-copy layout.
+///
+/// Values that implement `FromBytes` are read (and copied) as usual, while slices of `ZeroCopy`
+/// values are returned in place, without copying them out of the file.
+pub struct MappedReader {
+    file: MappedFile,
+    position: usize,
+    /// If `true`, the values were already validated, and only their layout is checked.
+    is_prevalidated: bool,
+}
+
+impl MappedReader {
+    /// Maps the file at the given path into memory, and returns a reader positioned at its first section.
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Ok(MappedFile::open(path)?.reader())
+    }
+
+    /// Maps the file at the given path into memory, and returns a reader positioned at its first section,
+    /// for a file whose values were already validated by an earlier read.
+    pub fn open_prevalidated<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Ok(MappedFile::open(path)?.prevalidated_reader())
+    }
+
+    /// Returns `true` if the reader has consumed the entire file.
+    pub fn is_empty(&self) -> bool {
+        self.position == self.file.as_bytes().len()
+    }
+
+    /// Reads the next slice of values, and ensures every value is valid.
+    /// If the reader is prevalidated, only the layout of each value is checked.
+    pub fn read_slice<T: ZeroCopy>(&mut self) -> io::Result<MappedSlice<T>> {
+        let (offset, len) = self.read_section::<T>()?;
+        // Ensure the values are valid.
+        let bytes = &self.file.as_bytes()[offset..offset + len * size_of::<T>()];
+        let is_valid = match self.is_prevalidated {
+            true => is_valid_raw_layout_slice::<T>(bytes),
+            false => is_valid_raw_slice::<T>(bytes),
+        };
+        if !is_valid {
+            return Err(error("The zero-copy file contains an invalid value"));
+        }
+        // SAFETY: The section is aligned, lies within the file, and each of its values is valid.
+        Ok(unsafe { MappedSlice::from_mapped_unchecked(self.file.clone(), offset, len) })
+    }
+
+    /// Reads the next slice of values, deferring their validation until they are first accessed.
+    /// The values are validated in chunks, where the first chunk contains `first_chunk_len` values,
+    /// and each following chunk is twice the size of the previous.
+    pub fn read_vec<T: ZeroCopy>(&mut self, first_chunk_len: usize) -> io::Result<MappedVec<T>> {
+        let (offset, len) = self.read_section::<T>()?;
+        // SAFETY: The section is aligned and lies within the file.
+        Ok(unsafe { MappedVec::from_mapped_unchecked(self.file.clone(), offset, len, first_chunk_len) })
+    }
+
+    /// Reads the header of the next section, and returns the offset and number of its values.
+    fn read_section<T: ZeroCopy>(&mut self) -> io::Result<(usize, usize)> {
+        if align_of::<T>() > ALIGNMENT {
+            return Err(error("The zero-copy layout does not support the alignment of the values"));
+        }
+        // Read the number of values and the size of each value.
+        let len = usize::try_from(u64::read_le(&mut *self)?).map_err(|_| error("Invalid section length"))?;
+        let size = u64::read_le(&mut *self)?;
+        if size != size_of::<T>() as u64 {
+            return Err(error("The zero-copy file has a different memory layout"));
+        }
+        // Skip the padding.
+        let offset = self.position + padding_for(self.position);
+        // Ensure the values lie within the file.
+        let num_bytes = len.checked_mul(size_of::<T>()).ok_or_else(|| error("Invalid section length"))?;
+        let end = offset.checked_add(num_bytes).ok_or_else(|| error("Invalid section length"))?;
+        if end > self.file.as_bytes().len() {
+            return Err(error("The zero-copy file is truncated"));
+        }
+        self.position = end;
+        Ok((offset, len))
+    }
+}
+
+impl Read for MappedReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut remaining = &self.file.as_bytes()[self.position..];
+        let num_bytes = remaining.read(buf)?;
+        self.position += num_bytes;
+        Ok(num_bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zero_copy_file() {
+        let path = std::env::temp_dir().join(format!("snarkvm_zero_copy_test_{}", std::process::id()));
+
+        // Write a file with values of each kind.
+        let values = (0..1000u64).map(|i| [i, i * i]).collect::<Vec<_>>();
+        let mut writer = ZeroCopyWriter::new(File::create(&path).unwrap()).unwrap();
+        7u32.write_le(&mut writer).unwrap();
+        writer.write_slice(&values).unwrap();
+        writer.write_slice(&values[..10]).unwrap();
+        writer.into_inner().sync_all().unwrap();
+
+        // Read the values back.
+        let mut reader = MappedReader::open(&path).unwrap();
+        assert_eq!(u32::read_le(&mut reader).unwrap(), 7);
+        let slice = reader.read_slice::<[u64; 2]>().unwrap();
+        assert!(slice.is_mapped());
+        assert_eq!(&*slice, &values[..]);
+        assert_eq!(&*slice.slice(10..20), &values[10..20]);
+        let mut vec = reader.read_vec::<[u64; 2]>(4).unwrap();
+        assert_eq!(&*vec.get(2..7).unwrap(), &values[2..7]);
+        assert!(reader.is_empty());
+
+        // Ensure a section with a different memory layout is rejected.
+        let mut reader = MappedReader::open(&path).unwrap();
+        assert_eq!(u32::read_le(&mut reader).unwrap(), 7);
+        assert!(reader.read_slice::<u64>().is_err());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    /// A value whose only valid representations are `0` and `1`.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    #[repr(transparent)]
+    struct Flag(u8);
+
+    unsafe impl ZeroCopy for Flag {
+        fn write_raw<W: Write>(&self, writer: W) -> io::Result<()> {
+            self.0.write_raw(writer)
+        }
+
+        fn is_valid_raw(bytes: &[u8]) -> bool {
+            bytes.len() == 1 && bytes[0] <= 1
+        }
+    }
+
+    #[test]
+    fn test_lazy_validation() {
+        let path = std::env::temp_dir().join(format!("snarkvm_zero_copy_lazy_test_{}", std::process::id()));
+
+        // Write a file where only the value at index 70 is invalid.
+        let mut values = (0..100).map(|i| Flag(i % 2)).collect::<Vec<_>>();
+        values[70] = Flag(2);
+        let mut writer = ZeroCopyWriter::new(File::create(&path).unwrap()).unwrap();
+        writer.write_slice(&values).unwrap();
+        writer.write_slice(&values).unwrap();
+        writer.into_inner().sync_all().unwrap();
+
+        let mut reader = MappedReader::open(&path).unwrap();
+        // The chunks are [0, 8), [8, 16), [16, 32), [32, 64), and [64, 100).
+        let mut vec = reader.read_vec::<Flag>(8).unwrap();
+        assert_eq!(&*vec.get(0..64).unwrap(), &values[..64]);
+        assert!(vec.get(60..65).is_err());
+        assert!(vec.get_all().is_err());
+        // Ensure an eagerly-validated slice is rejected as a whole.
+        assert!(reader.read_slice::<Flag>().is_err());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    /// A value whose only valid representations are `0` and `1`, and whose layout allows any byte.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    #[repr(transparent)]
+    struct Checked(u8);
+
+    unsafe impl ZeroCopy for Checked {
+        fn write_raw<W: Write>(&self, writer: W) -> io::Result<()> {
+            self.0.write_raw(writer)
+        }
+
+        fn is_valid_raw(bytes: &[u8]) -> bool {
+            bytes.len() == 1 && bytes[0] <= 1
+        }
+
+        fn is_valid_raw_layout(bytes: &[u8]) -> bool {
+            bytes.len() == 1
+        }
+    }
+
+    #[test]
+    fn test_prevalidated_reader() {
+        let path = std::env::temp_dir().join(format!("snarkvm_zero_copy_prevalidated_test_{}", std::process::id()));
+
+        // Write a file where only the value at index 70 is invalid.
+        let mut values = (0..100).map(|i| Checked(i % 2)).collect::<Vec<_>>();
+        values[70] = Checked(2);
+        let mut writer = ZeroCopyWriter::new(File::create(&path).unwrap()).unwrap();
+        writer.write_slice(&values).unwrap();
+        writer.write_slice(&values.iter().map(|value| Flag(value.0)).collect::<Vec<_>>()).unwrap();
+        writer.into_inner().sync_all().unwrap();
+
+        // Ensure the invalid value is rejected by a validating reader.
+        let mut reader = MappedReader::open(&path).unwrap();
+        assert!(reader.read_slice::<Checked>().is_err());
+
+        // Ensure a prevalidated reader only checks the layout of each value.
+        let mut reader = MappedReader::open_prevalidated(&path).unwrap();
+        assert_eq!(&*reader.read_slice::<Checked>().unwrap(), &values[..]);
+        // Ensure a value with an invalid layout is still rejected.
+        assert!(reader.read_slice::<Flag>().is_err());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,161 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(all(feature = "std", not(target_family = "wasm")))]
+mod file;
+#[cfg(all(feature = "std", not(target_family = "wasm")))]
+pub use file::*;
+
+mod slice;
+pub use slice::*;
+
+mod vec;
+pub use vec::*;
+
+use crate::io::{Result as IoResult, Write};
+
+use core::mem::size_of;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// A type whose in-memory representation can be written to disk as-is, and read back
+/// in place from a memory-mapped file.
+///
+/// # Safety
+///
+/// Implementors must have a fixed layout (i.e. `#[repr(C)]` or `#[repr(transparent)]`),
+/// and every byte string of length `size_of::<Self>()` for which `is_valid_raw` or
+/// `is_valid_raw_layout` returns `true` must be a valid value of `Self`.
+pub unsafe trait ZeroCopy: Copy + Send + Sync + 'static {
+    /// Writes the in-memory representation of `self`, with every padding byte set to zero.
+    fn write_raw<W: Write>(&self, writer: W) -> IoResult<()>;
+
+    /// Returns `true` if `bytes` is the in-memory representation of a valid value of `Self`.
+    fn is_valid_raw(bytes: &[u8]) -> bool;
+
+    /// Returns `true` if `bytes` is the in-memory representation of a value of `Self`,
+    /// skipping any check that is only needed for values that were not validated before (e.g. subgroup checks).
+    #[inline]
+    fn is_valid_raw_layout(bytes: &[u8]) -> bool {
+        Self::is_valid_raw(bytes)
+    }
+}
+
+/// Returns the value represented by `bytes`, if it is valid.
+pub fn read_raw<T: ZeroCopy>(bytes: &[u8]) -> Option<T> {
+    match bytes.len() == size_of::<T>() && T::is_valid_raw(bytes) {
+        // SAFETY: `bytes` has the size of `T`, and is a valid representation of `T`.
+        true => Some(unsafe { core::ptr::read_unaligned(bytes.as_ptr() as *const T) }),
+        false => None,
+    }
+}
+
+/// Returns `true` if every element of `bytes`, read as consecutive values of `T`, is valid.
+pub fn is_valid_raw_slice<T: ZeroCopy>(bytes: &[u8]) -> bool {
+    all_raw_values::<T>(bytes, T::is_valid_raw)
+}
+
+/// Returns `true` if every element of `bytes`, read as consecutive values of `T`, has a valid layout.
+pub fn is_valid_raw_layout_slice<T: ZeroCopy>(bytes: &[u8]) -> bool {
+    all_raw_values::<T>(bytes, T::is_valid_raw_layout)
+}
+
+/// Returns `true` if `is_valid` holds for every element of `bytes`, read as consecutive values of `T`.
+fn all_raw_values<T: ZeroCopy>(bytes: &[u8], is_valid: fn(&[u8]) -> bool) -> bool {
+    // The number of elements to validate in each batch.
+    const BATCH_SIZE: usize = 1 << 10;
+
+    match size_of::<T>() {
+        0 => bytes.is_empty(),
+        size => {
+            bytes.len() % size == 0
+                && cfg_chunks!(bytes, size * BATCH_SIZE).all(|batch| batch.chunks_exact(size).all(is_valid))
+        }
+    }
+}
+
+/// Writes the given number of zero bytes, for use as padding.
+pub fn write_padding<W: Write>(mut writer: W, num_bytes: usize) -> IoResult<()> {
+    const ZEROS: [u8; 64] = [0u8; 64];
+    let mut remaining = num_bytes;
+    while remaining > 0 {
+        let length = remaining.min(ZEROS.len());
+        writer.write_all(&ZEROS[..length])?;
+        remaining -= length;
+    }
+    Ok(())
+}
+
+macro_rules! impl_zero_copy_for_primitive {
+    ($($type:ty),*) => {
+        $(
+            unsafe impl ZeroCopy for $type {
+                #[inline]
+                fn write_raw<W: Write>(&self, mut writer: W) -> IoResult<()> {
+                    writer.write_all(&self.to_ne_bytes())
+                }
+
+                #[inline]
+                fn is_valid_raw(bytes: &[u8]) -> bool {
+                    bytes.len() == size_of::<Self>()
+                }
+            }
+        )*
+    };
+}
+
+impl_zero_copy_for_primitive!(u8, u16, u32, u64, u128);
+
+unsafe impl<T: ZeroCopy, const N: usize> ZeroCopy for [T; N] {
+    #[inline]
+    fn write_raw<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.iter().try_for_each(|element| element.write_raw(&mut writer))
+    }
+
+    #[inline]
+    fn is_valid_raw(bytes: &[u8]) -> bool {
+        bytes.len() == size_of::<Self>()
+            && (size_of::<T>() == 0 || bytes.chunks_exact(size_of::<T>()).all(T::is_valid_raw))
+    }
+
+    #[inline]
+    fn is_valid_raw_layout(bytes: &[u8]) -> bool {
+        bytes.len() == size_of::<Self>()
+            && (size_of::<T>() == 0 || bytes.chunks_exact(size_of::<T>()).all(T::is_valid_raw_layout))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the in-memory representation of `value`.
+    pub(crate) fn to_raw_bytes<T: ZeroCopy>(value: &T) -> crate::Vec<u8> {
+        let mut bytes = crate::Vec::with_capacity(size_of::<T>());
+        value.write_raw(&mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_primitive_round_trip() {
+        let value = [0x0102030405060708u64, u64::MAX, 0];
+        let bytes = to_raw_bytes(&value);
+        assert_eq!(bytes.len(), size_of::<[u64; 3]>());
+        assert_eq!(read_raw::<[u64; 3]>(&bytes), Some(value));
+        assert_eq!(read_raw::<[u64; 3]>(&bytes[1..]), None);
+    }
+}
@@ -0,0 +1,189 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(all(feature = "std", not(target_family = "wasm")))]
+use super::MappedFile;
+use super::ZeroCopy;
+use crate::{
+    io::{Read, Result as IoResult, Write},
+    serialize::*,
+    ToBytes,
+    Vec,
+};
+
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+    ops::{Deref, Range},
+};
+
+/// A read-only sequence of values, which is either owned, or which resides in a memory-mapped file.
+///
+/// Mapped values are never copied; cloning or slicing a mapped `MappedSlice` shares the underlying file.
+/// A `MappedSlice` can only be constructed from values that have already been validated.
+#[derive(Clone)]
+pub struct MappedSlice<T: ZeroCopy> {
+    inner: Inner<T>,
+}
+
+#[derive(Clone)]
+enum Inner<T: ZeroCopy> {
+    /// The values are owned.
+    Owned(Vec<T>),
+    /// The values are the `len` elements of `T` at byte offset `offset` in the file.
+    #[cfg(all(feature = "std", not(target_family = "wasm")))]
+    Mapped { file: MappedFile, offset: usize, len: usize },
+}
+
+impl<T: ZeroCopy> MappedSlice<T> {
+    /// Initializes a `MappedSlice` over the `len` elements at byte offset `offset` in the given file.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure `offset` is aligned for `T`, the elements lie within the file,
+    /// and every element is valid, i.e. satisfies `T::is_valid_raw`.
+    #[cfg(all(feature = "std", not(target_family = "wasm")))]
+    pub(super) unsafe fn from_mapped_unchecked(file: MappedFile, offset: usize, len: usize) -> Self {
+        Self { inner: Inner::Mapped { file, offset, len } }
+    }
+
+    /// Returns the values as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        match &self.inner {
+            Inner::Owned(values) => values,
+            // SAFETY: The values were validated, aligned, and bounds-checked on construction,
+            // and the file is kept mapped for as long as `self` is alive.
+            #[cfg(all(feature = "std", not(target_family = "wasm")))]
+            Inner::Mapped { file, offset, len } => unsafe {
+                core::slice::from_raw_parts(file.as_bytes().as_ptr().add(*offset) as *const T, *len)
+            },
+        }
+    }
+
+    /// Returns `true` if the values reside in a memory-mapped file.
+    pub fn is_mapped(&self) -> bool {
+        !matches!(self.inner, Inner::Owned(_))
+    }
+
+    /// Returns the values in `range`. If `self` is mapped, the values are not copied.
+    pub fn slice(&self, range: Range<usize>) -> Self {
+        match &self.inner {
+            Inner::Owned(values) => Self::from(values[range].to_vec()),
+            #[cfg(all(feature = "std", not(target_family = "wasm")))]
+            Inner::Mapped { file, offset, len } => {
+                assert!(range.start <= range.end && range.end <= *len, "Slice index out of bounds");
+                let offset = offset + range.start * core::mem::size_of::<T>();
+                // SAFETY: The values in `range` are a subset of the (already-validated) values in `self`.
+                unsafe { Self::from_mapped_unchecked(file.clone(), offset, range.end - range.start) }
+            }
+        }
+    }
+
+    /// Returns the values as an owned vector, copying them if they are mapped.
+    pub fn into_vec(self) -> Vec<T> {
+        match self.inner {
+            Inner::Owned(values) => values,
+            #[cfg(all(feature = "std", not(target_family = "wasm")))]
+            Inner::Mapped { .. } => self.as_slice().to_vec(),
+        }
+    }
+}
+
+impl<T: ZeroCopy> Deref for MappedSlice<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T: ZeroCopy> From<Vec<T>> for MappedSlice<T> {
+    fn from(values: Vec<T>) -> Self {
+        Self { inner: Inner::Owned(values) }
+    }
+}
+
+impl<T: ZeroCopy> Default for MappedSlice<T> {
+    fn default() -> Self {
+        Self::from(Vec::new())
+    }
+}
+
+impl<'a, T: ZeroCopy> IntoIterator for &'a MappedSlice<T> {
+    type IntoIter = core::slice::Iter<'a, T>;
+    type Item = &'a T;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_slice().iter()
+    }
+}
+
+impl<T: ZeroCopy + fmt::Debug> fmt::Debug for MappedSlice<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+impl<T: ZeroCopy + PartialEq> PartialEq for MappedSlice<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: ZeroCopy + Eq> Eq for MappedSlice<T> {}
+
+impl<T: ZeroCopy + Hash> Hash for MappedSlice<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
+impl<T: ZeroCopy + ToBytes> ToBytes for MappedSlice<T> {
+    #[inline]
+    fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.as_slice().write_le(writer)
+    }
+}
+
+impl<T: ZeroCopy + CanonicalSerialize> CanonicalSerialize for MappedSlice<T> {
+    #[inline]
+    fn serialize_with_mode<W: Write>(&self, writer: W, compress: Compress) -> Result<(), SerializationError> {
+        self.as_slice().serialize_with_mode(writer, compress)
+    }
+
+    #[inline]
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.as_slice().serialized_size(compress)
+    }
+}
+
+impl<T: ZeroCopy + Valid> Valid for MappedSlice<T> {
+    #[inline]
+    fn check(&self) -> Result<(), SerializationError> {
+        T::batch_check(self.iter())
+    }
+}
+
+impl<T: ZeroCopy + CanonicalDeserialize> CanonicalDeserialize for MappedSlice<T> {
+    #[inline]
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        Vec::deserialize_with_mode(reader, compress, validate).map(Self::from)
+    }
+}
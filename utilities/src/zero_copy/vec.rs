@@ -0,0 +1,237 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(all(feature = "std", not(target_family = "wasm")))]
+use super::{is_valid_raw_slice, MappedFile};
+use super::{MappedSlice, ZeroCopy};
+use crate::{
+    io::{Read, Result as IoResult, Write},
+    serialize::*,
+    Vec,
+};
+
+use core::{fmt, ops::Range};
+
+/// A growable sequence of values, which is either owned, or which resides in a memory-mapped file.
+///
+/// Unlike a `MappedSlice`, the values in a mapped `MappedVec` are validated lazily, one chunk at a time,
+/// when they are first accessed. The first chunk contains a fixed number of values, and each following
+/// chunk is twice the size of the previous, so every chunk is validated at most once.
+#[derive(Clone)]
+pub struct MappedVec<T: ZeroCopy> {
+    inner: Inner<T>,
+}
+
+#[derive(Clone)]
+enum Inner<T: ZeroCopy> {
+    /// The values are owned.
+    Owned(Vec<T>),
+    /// The values are the `len` elements of `T` at byte offset `offset` in the file.
+    #[cfg(all(feature = "std", not(target_family = "wasm")))]
+    Mapped {
+        file: MappedFile,
+        offset: usize,
+        len: usize,
+        /// The number of values in the first chunk.
+        first_chunk_len: usize,
+        /// For each chunk, whether it has been validated.
+        validated: Vec<bool>,
+    },
+}
+
+impl<T: ZeroCopy> MappedVec<T> {
+    /// Initializes a `MappedVec` over the `len` elements at byte offset `offset` in the given file.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure `offset` is aligned for `T`, and the elements lie within the file.
+    #[cfg(all(feature = "std", not(target_family = "wasm")))]
+    pub(super) unsafe fn from_mapped_unchecked(
+        file: MappedFile,
+        offset: usize,
+        len: usize,
+        first_chunk_len: usize,
+    ) -> Self {
+        let first_chunk_len = first_chunk_len.max(1);
+        let num_chunks = chunk_of(len.saturating_sub(1), first_chunk_len) + 1;
+        Self { inner: Inner::Mapped { file, offset, len, first_chunk_len, validated: vec![false; num_chunks] } }
+    }
+
+    /// Returns the number of values.
+    pub fn len(&self) -> usize {
+        match &self.inner {
+            Inner::Owned(values) => values.len(),
+            #[cfg(all(feature = "std", not(target_family = "wasm")))]
+            Inner::Mapped { len, .. } => *len,
+        }
+    }
+
+    /// Returns `true` if there are no values.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the values reside in a memory-mapped file.
+    pub fn is_mapped(&self) -> bool {
+        !matches!(self.inner, Inner::Owned(_))
+    }
+
+    /// Returns the values in `range`, validating them first if they have not been accessed before.
+    /// If `self` is mapped, the values are not copied.
+    pub fn get(&mut self, range: Range<usize>) -> IoResult<MappedSlice<T>> {
+        if range.start > range.end || range.end > self.len() {
+            return Err(crate::error("The requested range is out of bounds"));
+        }
+        match &mut self.inner {
+            Inner::Owned(values) => Ok(MappedSlice::from(values[range].to_vec())),
+            #[cfg(all(feature = "std", not(target_family = "wasm")))]
+            Inner::Mapped { file, offset, len, first_chunk_len, validated } => {
+                let size = core::mem::size_of::<T>();
+                if !range.is_empty() {
+                    // Validate each chunk that overlaps the range, if it has not been validated yet.
+                    let (first_chunk, last_chunk) =
+                        (chunk_of(range.start, *first_chunk_len), chunk_of(range.end - 1, *first_chunk_len));
+                    for (chunk, is_validated) in validated.iter_mut().enumerate().take(last_chunk + 1).skip(first_chunk)
+                    {
+                        if !*is_validated {
+                            let chunk_range = chunk_range(chunk, *first_chunk_len, *len);
+                            let chunk_bytes =
+                                &file.as_bytes()[*offset + chunk_range.start * size..*offset + chunk_range.end * size];
+                            if !is_valid_raw_slice::<T>(chunk_bytes) {
+                                return Err(crate::error("The memory-mapped file contains an invalid value"));
+                            }
+                            *is_validated = true;
+                        }
+                    }
+                }
+                let offset = *offset + range.start * size;
+                // SAFETY: Every value in the range lies within the file, and has been validated.
+                Ok(unsafe { MappedSlice::from_mapped_unchecked(file.clone(), offset, range.end - range.start) })
+            }
+        }
+    }
+
+    /// Returns all of the values, validating them first if they have not been accessed before.
+    pub fn get_all(&mut self) -> IoResult<MappedSlice<T>> {
+        self.get(0..self.len())
+    }
+
+    /// Returns all of the values as a slice, validating them first if they have not been accessed before.
+    pub fn as_slice(&mut self) -> IoResult<&[T]> {
+        if self.is_mapped() {
+            self.get_all()?;
+        }
+        match &self.inner {
+            Inner::Owned(values) => Ok(values),
+            // SAFETY: Every value lies within the file, and has been validated above.
+            #[cfg(all(feature = "std", not(target_family = "wasm")))]
+            Inner::Mapped { file, offset, len, .. } => {
+                Ok(unsafe { core::slice::from_raw_parts(file.as_bytes().as_ptr().add(*offset) as *const T, *len) })
+            }
+        }
+    }
+
+    /// Returns a mutable reference to the values. If `self` is mapped, the values are
+    /// validated and copied out of the file, and `self` no longer refers to the file.
+    pub fn to_mut(&mut self) -> IoResult<&mut Vec<T>> {
+        if self.is_mapped() {
+            let values = self.get_all()?.into_vec();
+            self.inner = Inner::Owned(values);
+        }
+        match &mut self.inner {
+            Inner::Owned(values) => Ok(values),
+            #[cfg(all(feature = "std", not(target_family = "wasm")))]
+            Inner::Mapped { .. } => unreachable!("The values were copied out of the file"),
+        }
+    }
+
+    /// Applies `f` to all of the values, validating any chunk that has not been accessed before.
+    /// Unlike `get_all`, this method does not record the validated chunks.
+    fn with_validated<R>(&self, f: impl FnOnce(&[T]) -> R) -> IoResult<R> {
+        match &self.inner {
+            Inner::Owned(values) => Ok(f(values)),
+            #[cfg(all(feature = "std", not(target_family = "wasm")))]
+            Inner::Mapped { .. } => Ok(f(&self.clone().get_all()?)),
+        }
+    }
+}
+
+/// Returns the index of the chunk containing the value at `index`.
+#[cfg(all(feature = "std", not(target_family = "wasm")))]
+fn chunk_of(index: usize, first_chunk_len: usize) -> usize {
+    match index < first_chunk_len {
+        true => 0,
+        false => (usize::BITS - (index / first_chunk_len).leading_zeros()) as usize,
+    }
+}
+
+/// Returns the range of values in the given chunk. The final chunk is truncated to the `len` values.
+#[cfg(all(feature = "std", not(target_family = "wasm")))]
+fn chunk_range(chunk: usize, first_chunk_len: usize, len: usize) -> Range<usize> {
+    let start = match chunk {
+        0 => 0,
+        _ => first_chunk_len << (chunk - 1),
+    };
+    start..(first_chunk_len << chunk).min(len)
+}
+
+impl<T: ZeroCopy> From<Vec<T>> for MappedVec<T> {
+    fn from(values: Vec<T>) -> Self {
+        Self { inner: Inner::Owned(values) }
+    }
+}
+
+impl<T: ZeroCopy> Default for MappedVec<T> {
+    fn default() -> Self {
+        Self::from(Vec::new())
+    }
+}
+
+impl<T: ZeroCopy> fmt::Debug for MappedVec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MappedVec").field("len", &self.len()).field("is_mapped", &self.is_mapped()).finish()
+    }
+}
+
+impl<T: ZeroCopy + CanonicalSerialize> CanonicalSerialize for MappedVec<T> {
+    #[inline]
+    fn serialize_with_mode<W: Write>(&self, writer: W, compress: Compress) -> Result<(), SerializationError> {
+        self.with_validated(|values| values.serialize_with_mode(writer, compress))?
+    }
+
+    #[inline]
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.with_validated(|values| values.serialized_size(compress)).unwrap_or_default()
+    }
+}
+
+impl<T: ZeroCopy + Valid> Valid for MappedVec<T> {
+    #[inline]
+    fn check(&self) -> Result<(), SerializationError> {
+        self.with_validated(|values| T::batch_check(values.iter()))?
+    }
+}
+
+impl<T: ZeroCopy + CanonicalDeserialize> CanonicalDeserialize for MappedVec<T> {
+    #[inline]
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        Vec::deserialize_with_mode(reader, compress, validate).map(Self::from)
+    }
+}
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::cli::Updater;
+use crate::cli::commands::{Build, Clean, Deploy, Execute, New, Run, Update, Verify};
 
 use anyhow::Result;
 use clap::Parser;
@@ -25,6 +25,9 @@ pub struct CLI {
     /// Specify the verbosity [options: 0, 1, 2, 3]
     #[clap(default_value = "2", short, long)]
     pub verbosity: u8,
+    /// Print the output as JSON
+    #[clap(long, global = true)]
+    pub json: bool,
     /// Specify a subcommand.
     #[clap(subcommand)]
     pub command: Command,
@@ -32,46 +35,68 @@ pub struct CLI {
 
 #[derive(Debug, Parser)]
 pub enum Command {
+    /// Create a new Aleo program package
+    New(New),
+    /// Compile the proving and verifying keys of the package
+    Build(Build),
+    /// Evaluate a function in the package, without producing a proof
+    Run(Run),
+    /// Execute a function in the package, producing an execution
+    Execute(Execute),
+    /// Deploy the program in the package
+    Deploy(Deploy),
+    /// Remove the build directory of the package
+    Clean(Clean),
+    /// Verify an execution of a function in the package
+    Verify(Verify),
     /// Update snarkVM to the latest version
-    Update {
-        /// Lists all available versions of snarkVM
-        #[clap(short = 'l', long)]
-        list: bool,
-        /// Suppress outputs to terminal
-        #[clap(short = 'q', long)]
-        quiet: bool,
-    },
+    Update(Update),
 }
 
 impl Command {
-    /// Parse the command.
-    pub fn start(&self) -> Result<String> {
+    /// Parse the command, returning the output as JSON iff `json` is `true`.
+    pub fn start(&self, json: bool) -> Result<String> {
         match self {
-            Command::Update { list, quiet } => match list {
-                true => match Updater::show_available_releases() {
-                    Ok(output) => Ok(output),
-                    Err(error) => Ok(format!("Failed to list the available versions of snarkVM\n{error}\n")),
-                },
-                false => {
-                    let result = Updater::update_to_latest_release(!quiet);
-                    if !quiet {
-                        match result {
-                            Ok(status) => {
-                                if status.uptodate() {
-                                    Ok("\nsnarkVM is already on the latest version".to_string())
-                                } else if status.updated() {
-                                    Ok(format!("\nsnarkVM has updated to version {}", status.version()))
-                                } else {
-                                    Ok("".to_string())
-                                }
-                            }
-                            Err(e) => Ok(format!("\nFailed to update snarkVM to the latest version\n{}\n", e)),
-                        }
-                    } else {
-                        Ok("".to_string())
-                    }
-                }
-            }, // _ => Err(anyhow!("\nUnknown command\n")),
+            Self::New(command) => command.parse(json),
+            Self::Build(command) => command.parse(json),
+            Self::Run(command) => command.parse(json),
+            Self::Execute(command) => command.parse(json),
+            Self::Deploy(command) => command.parse(json),
+            Self::Clean(command) => command.parse(json),
+            Self::Verify(command) => command.parse(json),
+            Self::Update(command) => command.parse(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_commands() {
+        // Ensure the package commands parse.
+        let cli = CLI::try_parse_from(["snarkvm", "run", "hello", "1u32", "2u32"]).unwrap();
+        assert!(!cli.json);
+        assert!(matches!(cli.command, Command::Run(..)));
+
+        let cli =
+            CLI::try_parse_from(["snarkvm", "execute", "hello", "1u32", "--json", "--store", "out.json"]).unwrap();
+        assert!(cli.json);
+        assert!(matches!(cli.command, Command::Execute(..)));
+
+        let cli = CLI::try_parse_from(["snarkvm", "--json", "verify", "out.json"]).unwrap();
+        assert!(cli.json);
+        assert!(matches!(cli.command, Command::Verify(..)));
+
+        for command in ["new hello", "build", "deploy", "clean", "update --list"] {
+            let args = std::iter::once("snarkvm").chain(command.split_whitespace());
+            assert!(CLI::try_parse_from(args).is_ok(), "Failed to parse '{command}'");
+        }
+
+        // Ensure malformed inputs are rejected.
+        assert!(CLI::try_parse_from(["snarkvm", "run", "hello", "1u32x"]).is_err());
+        assert!(CLI::try_parse_from(["snarkvm", "run", "1hello"]).is_err());
+        assert!(CLI::try_parse_from(["snarkvm", "verify"]).is_err());
+    }
+}
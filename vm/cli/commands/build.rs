@@ -0,0 +1,55 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use clap::Parser;
+use colored::Colorize;
+use std::path::PathBuf;
+
+/// Compiles the proving and verifying keys for each function in the package.
+#[derive(Debug, Parser)]
+pub struct Build {
+    /// The endpoint of a remote build service.
+    #[clap(long)]
+    endpoint: Option<String>,
+    /// The directory of the package.
+    #[clap(long, default_value = ".")]
+    path: PathBuf,
+}
+
+impl Build {
+    /// Builds the package, returning the output for the terminal.
+    pub fn parse(&self, json: bool) -> Result<String> {
+        // Open the package.
+        let package = open_package(&self.path)?;
+        // Build the package.
+        package.build::<CurrentAleo>(self.endpoint.clone())?;
+
+        match json {
+            true => Ok(serde_json::json!({
+                "program_id": package.program_id().to_string(),
+                "build_directory": package.build_directory().display().to_string(),
+            })
+            .to_string()),
+            false => Ok(format!(
+                "✅ Built '{}' (in \"{}\")",
+                package.program_id().to_string().bold(),
+                package.build_directory().display()
+            )),
+        }
+    }
+}
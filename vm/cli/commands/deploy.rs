@@ -0,0 +1,65 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use clap::Parser;
+use colored::Colorize;
+use std::path::PathBuf;
+
+/// Deploys the program in the package.
+#[derive(Debug, Parser)]
+pub struct Deploy {
+    /// The endpoint to send the deployment to.
+    #[clap(long)]
+    endpoint: Option<String>,
+    /// The file to store the deployment in.
+    #[clap(long)]
+    store: Option<PathBuf>,
+    /// The directory of the package.
+    #[clap(long, default_value = ".")]
+    path: PathBuf,
+}
+
+impl Deploy {
+    /// Deploys the program, returning the output for the terminal.
+    pub fn parse(&self, json: bool) -> Result<String> {
+        // Open the package.
+        let package = open_package(&self.path)?;
+        // Deploy the program.
+        let deployment = package.deploy::<CurrentAleo>(self.endpoint.clone())?;
+
+        // Store the deployment, if requested.
+        if let Some(store) = &self.store {
+            std::fs::write(store, deployment.to_string())?;
+        }
+
+        match json {
+            true => Ok(serde_json::json!({
+                "program_id": package.program_id().to_string(),
+                "deployment": serde_json::to_value(&deployment)?,
+            })
+            .to_string()),
+            false => {
+                let mut output = format!("✅ Deployed '{}'", package.program_id().to_string().bold());
+                if let Some(store) = &self.store {
+                    output += &format!(" (stored in \"{}\")", store.display());
+                }
+                Ok(output)
+            }
+        }
+    }
+}
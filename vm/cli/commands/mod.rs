@@ -0,0 +1,80 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod build;
+pub use build::*;
+
+mod clean;
+pub use clean::*;
+
+mod deploy;
+pub use deploy::*;
+
+mod execute;
+pub use execute::*;
+
+mod new;
+pub use new::*;
+
+mod run;
+pub use run::*;
+
+mod update;
+pub use update::*;
+
+mod verify;
+pub use verify::*;
+
+use crate::{
+    circuit::AleoV0,
+    console::network::Testnet3,
+    package::Package,
+    prelude::{Identifier, Value},
+};
+
+use anyhow::Result;
+use std::path::Path;
+
+/// The network used by the CLI.
+pub(crate) type CurrentNetwork = Testnet3;
+/// The circuit environment used by the CLI.
+pub(crate) type CurrentAleo = AleoV0;
+
+/// Opens the package at the given directory.
+pub(crate) fn open_package(directory: &Path) -> Result<Package<CurrentNetwork>> {
+    Package::open(&directory.canonicalize()?)
+}
+
+/// Formats the given function outputs for the terminal.
+pub(crate) fn format_outputs(outputs: &[Value<CurrentNetwork>]) -> String {
+    match outputs.is_empty() {
+        true => String::new(),
+        false => {
+            let outputs = outputs.iter().map(|output| format!(" • {output}")).collect::<Vec<_>>();
+            format!("\n➡️  Output\n\n{}\n", outputs.join("\n"))
+        }
+    }
+}
+
+/// Formats the given function outputs as a JSON array.
+pub(crate) fn json_outputs(outputs: &[Value<CurrentNetwork>]) -> serde_json::Value {
+    serde_json::Value::from(outputs.iter().map(|output| output.to_string()).collect::<Vec<_>>())
+}
+
+/// Returns the locator string for the given function in the package.
+pub(crate) fn locator(package: &Package<CurrentNetwork>, function_name: &Identifier<CurrentNetwork>) -> String {
+    format!("{}/{function_name}", package.program_id())
+}
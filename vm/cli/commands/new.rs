@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use clap::Parser;
+use colored::Colorize;
+use std::path::PathBuf;
+
+/// Creates a new Aleo program package.
+#[derive(Debug, Parser)]
+pub struct New {
+    /// The name of the program (without the `.aleo` suffix).
+    name: String,
+    /// The directory in which to create the package.
+    #[clap(long, default_value = ".")]
+    path: PathBuf,
+}
+
+impl New {
+    /// Creates the package, returning the output for the terminal.
+    pub fn parse(&self, json: bool) -> Result<String> {
+        // Parse the program ID.
+        let program_id = format!("{}.aleo", self.name).parse()?;
+        // Prepare the package directory.
+        let directory = self.path.canonicalize()?.join(&self.name);
+        // Create the package.
+        let package = Package::<CurrentNetwork>::create(&directory, &program_id)?;
+
+        match json {
+            true => Ok(serde_json::json!({
+                "program_id": package.program_id().to_string(),
+                "directory": package.directory().display().to_string(),
+            })
+            .to_string()),
+            false => Ok(format!(
+                "✅ Created an Aleo program '{}' (in \"{}\")",
+                package.program_id().to_string().bold(),
+                package.directory().display()
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        // Initialize a temporary directory.
+        let directory = tempfile::tempdir().unwrap().into_path();
+
+        // Create a new package.
+        let new = New::try_parse_from(["new", "hello", "--path", directory.to_str().unwrap()]).unwrap();
+        let output = new.parse(true).unwrap();
+        // Ensure the output is valid JSON for the package.
+        let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(json["program_id"], "hello.aleo");
+
+        // Ensure the package can be opened.
+        let package = open_package(&directory.join("hello")).unwrap();
+        assert_eq!(package.program_id().to_string(), "hello.aleo");
+
+        // Ensure creating the package again fails.
+        assert!(new.parse(false).is_err());
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::prelude::PrivateKey;
+
+use clap::Parser;
+use colored::Colorize;
+use std::path::PathBuf;
+
+/// Evaluates a function in the package, without producing a proof.
+#[derive(Debug, Parser)]
+pub struct Run {
+    /// The name of the function to run.
+    function: Identifier<CurrentNetwork>,
+    /// The inputs to the function.
+    inputs: Vec<Value<CurrentNetwork>>,
+    /// The private key of the caller (defaults to the development private key in the manifest).
+    #[clap(long)]
+    private_key: Option<PrivateKey<CurrentNetwork>>,
+    /// The directory of the package.
+    #[clap(long, default_value = ".")]
+    path: PathBuf,
+}
+
+impl Run {
+    /// Runs the function, returning the output for the terminal.
+    pub fn parse(&self, json: bool) -> Result<String> {
+        // Open the package.
+        let package = open_package(&self.path)?;
+        // Retrieve the private key of the caller.
+        let private_key = self.private_key.unwrap_or(*package.manifest_file().development_private_key());
+
+        // Evaluate the function.
+        let response =
+            package.evaluate::<CurrentAleo, _>(&private_key, self.function, &self.inputs, &mut rand::thread_rng())?;
+
+        let locator = locator(&package, &self.function);
+        match json {
+            true => Ok(serde_json::json!({
+                "locator": locator,
+                "outputs": json_outputs(response.outputs()),
+            })
+            .to_string()),
+            false => Ok(format!("{}\n✅ Finished '{}'", format_outputs(response.outputs()), locator.bold())),
+        }
+    }
+}
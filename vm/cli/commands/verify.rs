@@ -0,0 +1,57 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::synthesizer::Execution;
+
+use clap::Parser;
+use colored::Colorize;
+use core::str::FromStr;
+use std::path::PathBuf;
+
+/// Verifies an execution of a function in the package.
+#[derive(Debug, Parser)]
+pub struct Verify {
+    /// The file containing the execution (as produced by `execute --store`).
+    execution: PathBuf,
+    /// The directory of the package.
+    #[clap(long, default_value = ".")]
+    path: PathBuf,
+}
+
+impl Verify {
+    /// Verifies the execution, returning the output for the terminal.
+    pub fn parse(&self, json: bool) -> Result<String> {
+        // Open the package.
+        let package = open_package(&self.path)?;
+        // Read the execution.
+        let execution = Execution::<CurrentNetwork>::from_str(&std::fs::read_to_string(&self.execution)?)?;
+
+        // Verify the execution.
+        package.verify(&execution)?;
+
+        let locator = locator(&package, execution.peek()?.function_name());
+        match json {
+            true => Ok(serde_json::json!({
+                "locator": locator,
+                "verified": true,
+                "num_transitions": execution.len(),
+            })
+            .to_string()),
+            false => Ok(format!("✅ Verified the execution of '{}'", locator.bold())),
+        }
+    }
+}
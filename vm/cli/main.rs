@@ -21,10 +21,12 @@ use clap::Parser;
 fn main() -> anyhow::Result<()> {
     // Parse the given arguments.
     let cli = CLI::parse();
-    // Run the updater.
-    println!("{}", Updater::print_cli());
+    // Run the updater, if the output is not JSON.
+    if !cli.json {
+        println!("{}", Updater::print_cli());
+    }
     // Run the CLI.
-    println!("{}", cli.command.start()?);
+    println!("{}", cli.command.start(cli.json)?);
 
     Ok(())
 }
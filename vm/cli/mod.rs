@@ -17,6 +17,8 @@
 mod cli;
 pub use cli::*;
 
+pub mod commands;
+
 mod errors;
 pub use errors::*;
 
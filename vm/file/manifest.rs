@@ -15,12 +15,13 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    prelude::{Address, Network, PrivateKey, ProgramID},
+    prelude::{Address, Field, Network, PrivateKey, ProgramID},
     synthesizer::Program,
 };
 
-use anyhow::{anyhow, ensure, Result};
+use anyhow::{anyhow, bail, ensure, Result};
 use core::str::FromStr;
+use indexmap::IndexMap;
 use std::{
     fs::{self, File},
     io::Write,
@@ -29,6 +30,86 @@ use std::{
 
 const MANIFEST_FILE_NAME: &str = "program.json";
 
+/// The source from which a dependency is resolved.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DependencySource {
+    /// The dependency is resolved from a local path, relative to the package directory.
+    /// The path is either an Aleo program file, or a package directory containing a main program file.
+    Path(PathBuf),
+    /// The dependency is resolved from the content-addressed cache, using its checksum.
+    Version(String),
+}
+
+/// A dependency declared in the `dependencies` section of the manifest file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Dependency<N: Network> {
+    /// The program ID of the dependency.
+    program_id: ProgramID<N>,
+    /// The source of the dependency.
+    source: DependencySource,
+    /// The expected checksum of the dependency program, if one is specified.
+    checksum: Option<Field<N>>,
+}
+
+impl<N: Network> Dependency<N> {
+    /// Initializes a new dependency.
+    pub fn new(program_id: ProgramID<N>, source: DependencySource, checksum: Option<Field<N>>) -> Result<Self> {
+        // Ensure the program name is valid.
+        ensure!(
+            !Program::is_reserved_keyword(program_id.name()),
+            "Dependency name is invalid (reserved): {program_id}"
+        );
+        // Ensure a dependency from the content-addressed cache has a checksum.
+        if let DependencySource::Version(version) = &source {
+            ensure!(checksum.is_some(), "Dependency '{program_id}' (version {version}) is missing a checksum");
+        }
+        Ok(Self { program_id, source, checksum })
+    }
+
+    /// Returns the checksum of the given program, which is the BHP-1024 hash of its bytes.
+    pub fn checksum_of(program: &Program<N>) -> Result<Field<N>> {
+        use snarkvm_console::network::prelude::*;
+
+        N::hash_bhp1024(&program.to_bytes_le()?.to_bits_le())
+    }
+
+    /// Ensures the given program matches the program ID and checksum of this dependency.
+    pub fn verify(&self, program: &Program<N>) -> Result<()> {
+        // Ensure the program ID matches.
+        ensure!(
+            program.id() == &self.program_id,
+            "Dependency '{}' resolved to a program with a mismatching ID: '{}'",
+            self.program_id,
+            program.id()
+        );
+        // Ensure the checksum matches, if one is specified.
+        if let Some(expected) = self.checksum {
+            let candidate = Self::checksum_of(program)?;
+            ensure!(
+                candidate == expected,
+                "Dependency '{}' has a mismatching checksum: expected '{expected}', found '{candidate}'",
+                self.program_id
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the source.
+    pub const fn source(&self) -> &DependencySource {
+        &self.source
+    }
+
+    /// Returns the expected checksum, if one is specified.
+    pub const fn checksum(&self) -> Option<&Field<N>> {
+        self.checksum.as_ref()
+    }
+}
+
 pub struct Manifest<N: Network> {
     /// The file path.
     path: PathBuf,
@@ -38,6 +119,8 @@ pub struct Manifest<N: Network> {
     development_private_key: PrivateKey<N>,
     /// The development address.
     development_address: Address<N>,
+    /// The dependencies, in the order they are declared.
+    dependencies: IndexMap<ProgramID<N>, Dependency<N>>,
 }
 
 impl<N: Network> Manifest<N> {
@@ -79,7 +162,13 @@ impl<N: Network> Manifest<N> {
         File::create(&path)?.write_all(manifest_string.as_bytes())?;
 
         // Return the manifest file.
-        Ok(Self { path, program_id: *id, development_private_key: private_key, development_address: address })
+        Ok(Self {
+            path,
+            program_id: *id,
+            development_private_key: private_key,
+            development_address: address,
+            dependencies: Default::default(),
+        })
     }
 
     /// Opens the manifest file for reading.
@@ -117,8 +206,46 @@ impl<N: Network> Manifest<N> {
             "Development address does not match development private key."
         );
 
+        // Retrieve the dependencies, if any.
+        let dependencies = match &json["dependencies"] {
+            serde_json::Value::Null => Default::default(),
+            serde_json::Value::Object(dependencies) => dependencies
+                .iter()
+                .map(|(name, dependency)| {
+                    let dependency = Self::parse_dependency(name, dependency)?;
+                    // Ensure the dependency is not the program itself.
+                    ensure!(dependency.program_id() != &id, "Program '{id}' cannot depend on itself");
+                    Ok((*dependency.program_id(), dependency))
+                })
+                .collect::<Result<_>>()?,
+            _ => bail!("Dependencies must be an object of program IDs to dependency declarations."),
+        };
+
         // Return the manifest file.
-        Ok(Self { path, program_id: id, development_private_key, development_address })
+        Ok(Self { path, program_id: id, development_private_key, development_address, dependencies })
+    }
+
+    /// Parses a dependency declaration of the form `{ "path": .. | "version": .., "checksum": .. }`.
+    fn parse_dependency(name: &str, json: &serde_json::Value) -> Result<Dependency<N>> {
+        // Retrieve the program ID.
+        let program_id = ProgramID::from_str(name)?;
+
+        // Retrieve the source.
+        let source = match (json["path"].as_str(), json["version"].as_str()) {
+            (Some(path), None) => DependencySource::Path(PathBuf::from(path)),
+            (None, Some(version)) => DependencySource::Version(version.to_string()),
+            _ => bail!("Dependency '{program_id}' must specify exactly one of 'path' or 'version'."),
+        };
+
+        // Retrieve the checksum, if one is specified.
+        let checksum = match &json["checksum"] {
+            serde_json::Value::Null => None,
+            checksum => Some(Field::from_str(
+                checksum.as_str().ok_or_else(|| anyhow!("Dependency '{program_id}' has an invalid checksum."))?,
+            )?),
+        };
+
+        Dependency::new(program_id, source, checksum)
     }
 
     /// Returns `true` if the manifest file exists at the given path.
@@ -153,4 +280,9 @@ impl<N: Network> Manifest<N> {
     pub const fn development_address(&self) -> &Address<N> {
         &self.development_address
     }
+
+    /// Returns the dependencies.
+    pub const fn dependencies(&self) -> &IndexMap<ProgramID<N>, Dependency<N>> {
+        &self.dependencies
+    }
 }
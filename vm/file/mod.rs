@@ -21,7 +21,7 @@ mod avm;
 pub use avm::AVMFile;
 
 mod manifest;
-pub use manifest::Manifest;
+pub use manifest::{Dependency, DependencySource, Manifest};
 
 mod prover;
 pub use prover::ProverFile;
@@ -0,0 +1,291 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{
+    file::{Dependency, DependencySource},
+    prelude::Field,
+};
+
+use indexmap::{IndexMap, IndexSet};
+
+impl<N: Network> Package<N> {
+    /// Returns the directory of the content-addressed program cache.
+    pub fn cache_directory() -> PathBuf {
+        aleo_std::aleo_dir().join("programs")
+    }
+
+    /// Writes the given program to the content-addressed cache, and returns its checksum.
+    pub fn cache_program(cache_directory: &Path, program: &Program<N>) -> Result<Field<N>> {
+        // Compute the checksum of the program.
+        let checksum = Dependency::checksum_of(program)?;
+
+        // Create the cache directory if it does not exist.
+        if !cache_directory.exists() {
+            std::fs::create_dir_all(cache_directory)?;
+        }
+
+        // Write the program to the cache, if it is not already cached.
+        let path = Self::cache_path(cache_directory, &checksum);
+        if !path.exists() {
+            std::fs::write(path, program.to_string())?;
+        }
+        Ok(checksum)
+    }
+
+    /// Returns the imports of the main program, resolved from the default cache directory.
+    /// The imports are in topological order, such that each program is preceded by its own imports.
+    pub fn resolve_imports(&self) -> Result<Vec<Program<N>>> {
+        self.resolve_imports_from(&Self::cache_directory())
+    }
+
+    /// Returns the imports of the main program, resolved from the given cache directory.
+    /// The imports are in topological order, such that each program is preceded by its own imports.
+    ///
+    /// Each import is resolved from its declaration in the manifest, if one exists,
+    /// and otherwise from the imports directory.
+    pub fn resolve_imports_from(&self, cache_directory: &Path) -> Result<Vec<Program<N>>> {
+        let mut visiting = IndexSet::new();
+        let mut resolved = IndexMap::new();
+        for program_id in self.program().imports().keys() {
+            self.visit_import(program_id, cache_directory, &mut visiting, &mut resolved)?;
+        }
+        Ok(resolved.into_values().collect())
+    }
+
+    /// Resolves the given import and its imports (depth-first), appending them to `resolved` in topological order.
+    fn visit_import(
+        &self,
+        program_id: &ProgramID<N>,
+        cache_directory: &Path,
+        visiting: &mut IndexSet<ProgramID<N>>,
+        resolved: &mut IndexMap<ProgramID<N>, Program<N>>,
+    ) -> Result<()> {
+        // If the import is already resolved, skip it.
+        if resolved.contains_key(program_id) {
+            return Ok(());
+        }
+        // Ensure the import is not part of a cycle.
+        ensure!(visiting.insert(*program_id), "Found a cyclic import of '{program_id}'");
+
+        // Resolve the import, and then its own imports.
+        let program = self.resolve_import(program_id, cache_directory)?;
+        for import_id in program.imports().keys() {
+            self.visit_import(import_id, cache_directory, visiting, resolved)?;
+        }
+
+        visiting.remove(program_id);
+        resolved.insert(*program_id, program);
+        Ok(())
+    }
+
+    /// Returns the program for the given import.
+    fn resolve_import(&self, program_id: &ProgramID<N>, cache_directory: &Path) -> Result<Program<N>> {
+        // If the import is not declared as a dependency, open it from the imports directory.
+        let dependency = match self.manifest_file.dependencies().get(program_id) {
+            Some(dependency) => dependency,
+            None => return Ok(AleoFile::open(&self.imports_directory(), program_id, false)?.program().clone()),
+        };
+
+        let program = match dependency.source() {
+            DependencySource::Path(path) => {
+                // Construct the path, relative to the package directory.
+                let path = self.directory.join(path);
+                match path.is_dir() {
+                    // Open the main program file of the package.
+                    true => AleoFile::open(&path, program_id, true)?.program().clone(),
+                    // Open the program file.
+                    false => {
+                        ensure!(path.exists(), "Dependency '{program_id}' is missing: '{}'", path.display());
+                        Program::from_str(&std::fs::read_to_string(&path)?)?
+                    }
+                }
+            }
+            DependencySource::Version(version) => {
+                // Retrieve the checksum.
+                let checksum = match dependency.checksum() {
+                    Some(checksum) => checksum,
+                    None => bail!("Dependency '{program_id}' (version {version}) is missing a checksum"),
+                };
+                // Construct the path in the cache.
+                let path = Self::cache_path(cache_directory, checksum);
+                ensure!(
+                    path.exists(),
+                    "Dependency '{program_id}' (version {version}) is missing from the cache: '{}'",
+                    path.display()
+                );
+                Program::from_str(&std::fs::read_to_string(&path)?)?
+            }
+        };
+
+        // Ensure the program matches the dependency.
+        dependency.verify(&program)?;
+        Ok(program)
+    }
+
+    /// Returns the path of the program with the given checksum in the cache.
+    fn cache_path(cache_directory: &Path, checksum: &Field<N>) -> PathBuf {
+        cache_directory.join(format!("{checksum}.aleo"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    fn temp_dir() -> PathBuf {
+        tempfile::tempdir().expect("Failed to open temporary directory").into_path()
+    }
+
+    /// Returns a program with the given ID and imports,
+    /// which calls the `transfer` function of its first import, if any.
+    fn sample_program(program_id: &str, imports: &[&str]) -> Program<CurrentNetwork> {
+        let mut program_string = String::new();
+        for import in imports {
+            program_string += &format!("import {import};\n");
+        }
+        program_string += &format!("\nprogram {program_id};\n\nfunction transfer:\n    input r0 as u64.private;\n");
+        match imports.first() {
+            Some(import) => {
+                program_string += &format!("    call {import}/transfer r0 into r1;\n    output r1 as u64.private;\n")
+            }
+            None => program_string += "    add r0 1u64 into r1;\n    output r1 as u64.private;\n",
+        }
+        Program::from_str(&program_string).unwrap()
+    }
+
+    /// Samples a (temporary) package with the given main program and `dependencies` section.
+    fn sample_package(
+        directory: &Path,
+        program: &Program<CurrentNetwork>,
+        dependencies: serde_json::Value,
+    ) -> Result<Package<CurrentNetwork>> {
+        std::fs::create_dir_all(directory)?;
+        std::fs::write(directory.join("main.aleo"), program.to_string())?;
+
+        // Create the manifest file, and add the dependencies section.
+        let manifest = Manifest::<CurrentNetwork>::create(directory, program.id())?;
+        let mut json: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(manifest.path())?)?;
+        json["dependencies"] = dependencies;
+        std::fs::write(manifest.path(), json.to_string())?;
+
+        Package::open(directory)
+    }
+
+    #[test]
+    fn test_resolve_imports() {
+        let directory = temp_dir();
+        let cache_directory = directory.join("cache");
+
+        // Cache `token.aleo`, which is declared by version.
+        let token = sample_program("token.aleo", &[]);
+        let token_checksum = Package::cache_program(&cache_directory, &token).unwrap();
+
+        // Write `wallet.aleo` as a package, which is declared by path.
+        let wallet = sample_program("wallet.aleo", &["token.aleo"]);
+        let _ = sample_package(&directory.join("wallet"), &wallet, serde_json::Value::Null).unwrap();
+        let wallet_checksum = Dependency::checksum_of(&wallet).unwrap();
+
+        // Write `bank.aleo` as a program file, which is declared by path without a checksum.
+        let bank = sample_program("bank.aleo", &["wallet.aleo", "token.aleo"]);
+        std::fs::write(directory.join("bank.aleo"), bank.to_string()).unwrap();
+
+        // Create the main package, which imports `bank.aleo` and `token.aleo`.
+        let main = sample_program("main.aleo", &["bank.aleo", "token.aleo"]);
+        let package = sample_package(
+            &directory.join("main"),
+            &main,
+            serde_json::json!({
+                "token.aleo": { "version": "0.1.0", "checksum": token_checksum.to_string() },
+                "wallet.aleo": { "path": "../wallet", "checksum": wallet_checksum.to_string() },
+                "bank.aleo": { "path": "../bank.aleo" },
+            }),
+        )
+        .unwrap();
+        assert_eq!(3, package.manifest_file().dependencies().len());
+
+        // Ensure the imports are resolved in topological order.
+        let imports = package.resolve_imports_from(&cache_directory).unwrap();
+        let import_ids = imports.iter().map(|program| program.id().to_string()).collect::<Vec<_>>();
+        assert_eq!(vec!["token.aleo", "wallet.aleo", "bank.aleo"], import_ids);
+        assert_eq!(vec![token, wallet, bank], imports);
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_resolve_imports_fails() {
+        let directory = temp_dir();
+        let cache_directory = directory.join("cache");
+
+        let token = sample_program("token.aleo", &[]);
+        let main = sample_program("main.aleo", &["token.aleo"]);
+        let token_checksum = Package::cache_program(&cache_directory, &token).unwrap();
+        std::fs::write(directory.join("token.aleo"), token.to_string()).unwrap();
+
+        // Ensure a mismatching checksum fails.
+        let checksum = Dependency::checksum_of(&main).unwrap().to_string();
+        let package = sample_package(
+            &directory.join("a"),
+            &main,
+            serde_json::json!({ "token.aleo": { "path": "../token.aleo", "checksum": checksum } }),
+        )
+        .unwrap();
+        assert!(package.resolve_imports_from(&cache_directory).is_err());
+
+        // Ensure a dependency that is missing from the cache fails.
+        let package = sample_package(
+            &directory.join("b"),
+            &main,
+            serde_json::json!({ "token.aleo": { "version": "0.1.0", "checksum": checksum } }),
+        )
+        .unwrap();
+        assert!(package.resolve_imports_from(&cache_directory).is_err());
+
+        // Ensure a dependency with a mismatching program ID fails.
+        std::fs::write(directory.join("wallet.aleo"), sample_program("wallet.aleo", &[]).to_string()).unwrap();
+        let package = sample_package(
+            &directory.join("c"),
+            &main,
+            serde_json::json!({ "token.aleo": { "path": "../wallet.aleo" } }),
+        )
+        .unwrap();
+        assert!(package.resolve_imports_from(&cache_directory).is_err());
+
+        // Ensure invalid declarations fail.
+        let checksum = token_checksum.to_string();
+        for (i, dependencies) in [
+            serde_json::json!({ "token.aleo": { "path": "../token.aleo", "version": "0.1.0" } }),
+            serde_json::json!({ "token.aleo": {} }),
+            serde_json::json!({ "token.aleo": { "version": "0.1.0" } }),
+            serde_json::json!({ "token.aleo": { "path": "../token.aleo", "checksum": 1 } }),
+            serde_json::json!({ "main.aleo": { "path": "../token.aleo", "checksum": checksum } }),
+            serde_json::json!(["token.aleo"]),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            assert!(sample_package(&directory.join(format!("invalid_{i}")), &main, dependencies).is_err());
+        }
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Package<N> {
+    /// Evaluates a program function with the given inputs, without producing a proof.
+    pub fn evaluate<A: crate::circuit::Aleo<Network = N, BaseField = N::Field>, R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        function_name: Identifier<N>,
+        inputs: &[Value<N>],
+        rng: &mut R,
+    ) -> Result<Response<N>> {
+        // Retrieve the main program.
+        let program = self.program();
+        // Retrieve the program ID.
+        let program_id = program.id();
+        // Ensure that the function exists.
+        if !program.contains_function(&function_name) {
+            bail!("Function '{function_name}' does not exist.")
+        }
+
+        // Prepare the locator (even if logging is disabled, to sanity check the locator is well-formed).
+        let _locator = Locator::<N>::from_str(&format!("{program_id}/{function_name}"))?;
+
+        #[cfg(feature = "aleo-cli")]
+        println!("🚀 Evaluating '{}'...\n", _locator.to_string().bold());
+
+        // Construct the process.
+        let process = self.get_process()?;
+        // Authorize the function call.
+        let authorization = process.authorize::<A, R>(private_key, program_id, function_name, inputs.iter(), rng)?;
+        // Evaluate the function.
+        process.evaluate::<A>(authorization)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use snarkvm_utilities::TestRng;
+
+    type CurrentAleo = snarkvm_circuit::network::AleoV0;
+
+    #[test]
+    fn test_evaluate() {
+        // Samples a new package at a temporary directory.
+        let (directory, package) = crate::package::test_helpers::sample_package();
+
+        // Initialize an RNG.
+        let rng = &mut TestRng::default();
+        // Sample the function inputs.
+        let (private_key, function_name, inputs) =
+            crate::package::test_helpers::sample_package_run(package.program_id());
+        // Evaluate the program function.
+        let response = package.evaluate::<CurrentAleo, _>(&private_key, function_name, &inputs, rng).unwrap();
+        // Ensure the function produced one output.
+        assert_eq!(response.outputs().len(), 1);
+
+        // Ensure the package was not built.
+        assert!(!package.build_directory().exists());
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_evaluate_with_import() {
+        // Samples a new package at a temporary directory.
+        let (directory, package) = crate::package::test_helpers::sample_package_with_import();
+
+        // Initialize an RNG.
+        let rng = &mut TestRng::default();
+        // Sample the function inputs.
+        let (private_key, function_name, inputs) =
+            crate::package::test_helpers::sample_package_run(package.program_id());
+        // Evaluate the program function.
+        let response = package.evaluate::<CurrentAleo, _>(&private_key, function_name, &inputs, rng).unwrap();
+        // Ensure the function produced two outputs.
+        assert_eq!(response.outputs().len(), 2);
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -16,9 +16,12 @@
 
 mod build;
 mod clean;
+mod dependencies;
 mod deploy;
+mod evaluate;
 mod is_build_required;
 mod run;
+mod verify;
 
 pub use build::{BuildRequest, BuildResponse};
 pub use deploy::{DeployRequest, DeployResponse};
@@ -43,7 +46,7 @@ use crate::{
     synthesizer::{CallOperator, Execution, Inclusion, Instruction, Process, Program, ProvingKey, VerifyingKey},
 };
 
-use anyhow::{bail, ensure, Error, Result};
+use anyhow::{bail, ensure, Result};
 use core::str::FromStr;
 use rand::{CryptoRng, Rng};
 use std::path::{Path, PathBuf};
@@ -157,17 +160,10 @@ impl<N: Network> Package<N> {
         // Create the process.
         let mut process = Process::load()?;
 
-        // Prepare the imports directory.
-        let imports_directory = self.imports_directory();
-
-        // Add all import programs (in order) to the process.
-        self.program().imports().keys().try_for_each(|program_id| {
-            // Open the Aleo program file.
-            let import_program_file = AleoFile::open(&imports_directory, program_id, false)?;
-            // Add the import program.
-            process.add_program(import_program_file.program())?;
-            Ok::<_, Error>(())
-        })?;
+        // Add all import programs (in topological order) to the process.
+        for import_program in self.resolve_imports()? {
+            process.add_program(&import_program)?;
+        }
 
         // Add the program to the process.
         process.add_program(self.program())?;
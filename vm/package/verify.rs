@@ -0,0 +1,113 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Package<N> {
+    /// Verifies the given execution of a program function against the verifying keys in the build directory.
+    pub fn verify(&self, execution: &Execution<N>) -> Result<()> {
+        // Retrieve the program ID.
+        let program_id = self.program().id();
+
+        // Ensure the execution is for the main program.
+        let transition = execution.peek()?;
+        ensure!(
+            transition.program_id() == program_id,
+            "Execution is for '{}', not for '{program_id}'",
+            transition.program_id()
+        );
+
+        #[cfg(feature = "aleo-cli")]
+        println!("⏳ Verifying '{}'...\n", format!("{program_id}/{}", transition.function_name()).bold());
+
+        // Construct the process.
+        let process = self.get_process()?;
+
+        // Load the verifier for each transition in the execution.
+        for transition in execution.transitions() {
+            // Prepare the build directory for the program of the transition.
+            let build_directory = match transition.program_id() == program_id {
+                true => self.build_directory(),
+                false => self.build_directory().join(format!(
+                    "{}-{}",
+                    transition.program_id().name(),
+                    transition.program_id().network()
+                )),
+            };
+
+            // Load the verifier.
+            let verifier = VerifierFile::open(&build_directory, transition.function_name())?;
+            // Adds the verifying key to the process.
+            process.insert_verifying_key(
+                transition.program_id(),
+                transition.function_name(),
+                verifier.verifying_key().clone(),
+            )?;
+        }
+
+        // Verify the execution.
+        process.verify_execution::<false>(execution)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use snarkvm_utilities::TestRng;
+
+    type CurrentAleo = snarkvm_circuit::network::AleoV0;
+
+    #[test]
+    fn test_verify() {
+        // Samples a new package at a temporary directory.
+        let (directory, package) = crate::package::test_helpers::sample_package();
+
+        // Initialize an RNG.
+        let rng = &mut TestRng::default();
+        // Sample the function inputs.
+        let (private_key, function_name, inputs) =
+            crate::package::test_helpers::sample_package_run(package.program_id());
+        // Run the program function.
+        let (_response, execution, _inclusion) =
+            package.run::<CurrentAleo, _>(None, &private_key, function_name, &inputs, rng).unwrap();
+
+        // Verify the execution.
+        package.verify(&execution).unwrap();
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_verify_with_import() {
+        // Samples a new package at a temporary directory.
+        let (directory, package) = crate::package::test_helpers::sample_package_with_import();
+
+        // Initialize an RNG.
+        let rng = &mut TestRng::default();
+        // Sample the function inputs.
+        let (private_key, function_name, inputs) =
+            crate::package::test_helpers::sample_package_run(package.program_id());
+        // Run the program function.
+        let (_response, execution, _inclusion) =
+            package.run::<CurrentAleo, _>(None, &private_key, function_name, &inputs, rng).unwrap();
+
+        // Verify the execution.
+        package.verify(&execution).unwrap();
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -0,0 +1,66 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The address of an Aleo account.
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Address(account::Address<CurrentNetwork>);
+
+#[wasm_bindgen]
+impl Address {
+    /// Returns the address for the given private key.
+    #[wasm_bindgen(js_name = fromPrivateKey)]
+    pub fn from_private_key(private_key: &PrivateKey) -> Result<Address, String> {
+        let address = account::Address::try_from(&**private_key).map_err(|error| error.to_string())?;
+        Ok(Self(address))
+    }
+
+    /// Returns the address for the given view key.
+    #[wasm_bindgen(js_name = fromViewKey)]
+    pub fn from_view_key(view_key: &ViewKey) -> Result<Address, String> {
+        let address = account::Address::try_from(&**view_key).map_err(|error| error.to_string())?;
+        Ok(Self(address))
+    }
+
+    /// Returns the address from the given string.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(address: &str) -> Result<Address, String> {
+        let address = account::Address::from_str(address).map_err(|error| error.to_string())?;
+        Ok(Self(address))
+    }
+
+    /// Returns the address as a string.
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns `true` if the given signature is valid for the given message and the address.
+    pub fn verify(&self, message: &[u8], signature: &Signature) -> bool {
+        signature.verify(self, message)
+    }
+}
+
+impl Deref for Address {
+    type Target = account::Address<CurrentNetwork>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
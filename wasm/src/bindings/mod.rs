@@ -0,0 +1,50 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod address;
+pub use address::*;
+
+mod private_key;
+pub use private_key::*;
+
+#[cfg(feature = "synthesizer")]
+mod program;
+#[cfg(feature = "synthesizer")]
+pub use program::*;
+
+mod record_ciphertext;
+pub use record_ciphertext::*;
+
+mod record_plaintext;
+pub use record_plaintext::*;
+
+mod signature;
+pub use signature::*;
+
+mod view_key;
+pub use view_key::*;
+
+use snarkvm_console::{
+    account,
+    network::Testnet3,
+    program::{Ciphertext, Identifier, Plaintext, ProgramID, Record},
+};
+
+use core::{ops::Deref, str::FromStr};
+use wasm_bindgen::prelude::*;
+
+/// The network used by the WebAssembly bindings.
+type CurrentNetwork = Testnet3;
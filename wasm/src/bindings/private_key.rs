@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use rand::{rngs::StdRng, SeedableRng};
+
+/// The private key of an Aleo account.
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrivateKey(account::PrivateKey<CurrentNetwork>);
+
+#[wasm_bindgen]
+impl PrivateKey {
+    /// Samples a new private key.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Result<PrivateKey, String> {
+        let private_key = account::PrivateKey::new(&mut StdRng::from_entropy()).map_err(|error| error.to_string())?;
+        Ok(Self(private_key))
+    }
+
+    /// Returns the private key from the given string.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(private_key: &str) -> Result<PrivateKey, String> {
+        let private_key = account::PrivateKey::from_str(private_key).map_err(|error| error.to_string())?;
+        Ok(Self(private_key))
+    }
+
+    /// Returns the private key as a string.
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns the view key for the private key.
+    #[wasm_bindgen(js_name = toViewKey)]
+    pub fn to_view_key(&self) -> Result<ViewKey, String> {
+        ViewKey::from_private_key(self)
+    }
+
+    /// Returns the address for the private key.
+    #[wasm_bindgen(js_name = toAddress)]
+    pub fn to_address(&self) -> Result<Address, String> {
+        Address::from_private_key(self)
+    }
+
+    /// Returns a signature for the given message, signed by the private key.
+    pub fn sign(&self, message: &[u8]) -> Result<Signature, String> {
+        Signature::sign(self, message)
+    }
+}
+
+impl Deref for PrivateKey {
+    type Target = account::PrivateKey<CurrentNetwork>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
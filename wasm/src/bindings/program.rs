@@ -0,0 +1,110 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use snarkvm_circuit_network::AleoV0;
+use snarkvm_synthesizer::Process;
+
+use rand::{rngs::StdRng, SeedableRng};
+use std::cell::RefCell;
+
+thread_local! {
+    /// The process used to evaluate programs, which is loaded on first use.
+    static PROCESS: RefCell<Option<Process<CurrentNetwork>>> = RefCell::new(None);
+}
+
+/// An Aleo program.
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Program(snarkvm_synthesizer::Program<CurrentNetwork>);
+
+#[wasm_bindgen]
+impl Program {
+    /// Returns the program from the given string.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(program: &str) -> Result<Program, String> {
+        let program = snarkvm_synthesizer::Program::from_str(program).map_err(|error| error.to_string())?;
+        Ok(Self(program))
+    }
+
+    /// Returns the program as a string.
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns the program ID as a string.
+    pub fn id(&self) -> String {
+        self.0.id().to_string()
+    }
+
+    /// Returns the names of the functions in the program.
+    pub fn functions(&self) -> Vec<String> {
+        self.0.functions().keys().map(|name| name.to_string()).collect()
+    }
+
+    /// Evaluates the given function with the given inputs, as the given private key, without producing a proof.
+    /// Returns the outputs of the function as strings.
+    pub fn evaluate(
+        &self,
+        private_key: &PrivateKey,
+        function_name: &str,
+        inputs: Vec<String>,
+    ) -> Result<Vec<String>, String> {
+        PROCESS.with(|process| {
+            let mut process = process.borrow_mut();
+            // Initialize the process, if it is not already loaded.
+            if process.is_none() {
+                *process = Some(Process::<CurrentNetwork>::load_web().map_err(|error| error.to_string())?);
+            }
+            let process = process.as_mut().ok_or_else(|| "Failed to load the process".to_string())?;
+
+            // Add the program to the process, if it is not already present.
+            if !process.contains_program(self.0.id()) {
+                process.add_program(&self.0).map_err(|error| error.to_string())?;
+            }
+            // Ensure the loaded program matches this program.
+            if process.get_program(self.0.id()).map_err(|error| error.to_string())? != &self.0 {
+                return Err(format!("A different program with ID '{}' is already loaded", self.0.id()));
+            }
+
+            // Authorize the function call.
+            let authorization = process
+                .authorize::<AleoV0, _>(
+                    private_key,
+                    self.0.id(),
+                    function_name,
+                    inputs.iter(),
+                    &mut StdRng::from_entropy(),
+                )
+                .map_err(|error| error.to_string())?;
+            // Evaluate the function.
+            let response = process.evaluate::<AleoV0>(authorization).map_err(|error| error.to_string())?;
+            // Return the outputs.
+            Ok(response.outputs().iter().map(|output| output.to_string()).collect())
+        })
+    }
+}
+
+impl Deref for Program {
+    type Target = snarkvm_synthesizer::Program<CurrentNetwork>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// An encrypted record, owned by an Aleo account.
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecordCiphertext(Record<CurrentNetwork, Ciphertext<CurrentNetwork>>);
+
+#[wasm_bindgen]
+impl RecordCiphertext {
+    /// Returns the record ciphertext from the given string.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(record: &str) -> Result<RecordCiphertext, String> {
+        let record = Record::<CurrentNetwork, Ciphertext<CurrentNetwork>>::from_str(record)
+            .map_err(|error| error.to_string())?;
+        Ok(Self(record))
+    }
+
+    /// Returns the record ciphertext as a string.
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns the record plaintext, decrypted with the given view key.
+    pub fn decrypt(&self, view_key: &ViewKey) -> Result<RecordPlaintext, String> {
+        let record = self.0.decrypt(view_key).map_err(|error| error.to_string())?;
+        Ok(RecordPlaintext::from(record))
+    }
+
+    /// Returns `true` if the given view key belongs to the owner of the record.
+    #[wasm_bindgen(js_name = isOwner)]
+    pub fn is_owner(&self, view_key: &ViewKey) -> bool {
+        self.0.is_owner(view_key)
+    }
+}
+
+impl Deref for RecordCiphertext {
+    type Target = Record<CurrentNetwork, Ciphertext<CurrentNetwork>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
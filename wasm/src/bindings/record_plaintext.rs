@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A decrypted record, owned by an Aleo account.
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecordPlaintext(Record<CurrentNetwork, Plaintext<CurrentNetwork>>);
+
+#[wasm_bindgen]
+impl RecordPlaintext {
+    /// Returns the record plaintext from the given string.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(record: &str) -> Result<RecordPlaintext, String> {
+        let record =
+            Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(record).map_err(|error| error.to_string())?;
+        Ok(Self(record))
+    }
+
+    /// Returns the record plaintext as a string.
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns the serial number of the record, given the private key of the owner,
+    /// and the program ID and record name that the record belongs to.
+    #[wasm_bindgen(js_name = serialNumber)]
+    pub fn serial_number(
+        &self,
+        private_key: &PrivateKey,
+        program_id: &str,
+        record_name: &str,
+    ) -> Result<String, String> {
+        let program_id = ProgramID::from_str(program_id).map_err(|error| error.to_string())?;
+        let record_name = Identifier::from_str(record_name).map_err(|error| error.to_string())?;
+        // Compute the commitment of the record.
+        let commitment = self.0.to_commitment(&program_id, &record_name).map_err(|error| error.to_string())?;
+        // Compute the serial number of the record.
+        let serial_number =
+            Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::serial_number(**private_key, commitment)
+                .map_err(|error| error.to_string())?;
+        Ok(serial_number.to_string())
+    }
+}
+
+impl From<Record<CurrentNetwork, Plaintext<CurrentNetwork>>> for RecordPlaintext {
+    fn from(record: Record<CurrentNetwork, Plaintext<CurrentNetwork>>) -> Self {
+        Self(record)
+    }
+}
+
+impl Deref for RecordPlaintext {
+    type Target = Record<CurrentNetwork, Plaintext<CurrentNetwork>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use rand::{rngs::StdRng, SeedableRng};
+
+/// A signature on a message, from an Aleo account.
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Signature(account::Signature<CurrentNetwork>);
+
+#[wasm_bindgen]
+impl Signature {
+    /// Returns a signature for the given message, signed by the given private key.
+    pub fn sign(private_key: &PrivateKey, message: &[u8]) -> Result<Signature, String> {
+        let signature =
+            private_key.sign_bytes(message, &mut StdRng::from_entropy()).map_err(|error| error.to_string())?;
+        Ok(Self(signature))
+    }
+
+    /// Returns the signature from the given string.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(signature: &str) -> Result<Signature, String> {
+        let signature = account::Signature::from_str(signature).map_err(|error| error.to_string())?;
+        Ok(Self(signature))
+    }
+
+    /// Returns the signature as a string.
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns `true` if the signature is valid for the given address and message.
+    pub fn verify(&self, address: &Address, message: &[u8]) -> bool {
+        self.0.verify_bytes(address, message)
+    }
+}
+
+impl Deref for Signature {
+    type Target = account::Signature<CurrentNetwork>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
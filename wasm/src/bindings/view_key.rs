@@ -0,0 +1,66 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The view key of an Aleo account.
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ViewKey(account::ViewKey<CurrentNetwork>);
+
+#[wasm_bindgen]
+impl ViewKey {
+    /// Returns the view key for the given private key.
+    #[wasm_bindgen(js_name = fromPrivateKey)]
+    pub fn from_private_key(private_key: &PrivateKey) -> Result<ViewKey, String> {
+        let view_key = account::ViewKey::try_from(&**private_key).map_err(|error| error.to_string())?;
+        Ok(Self(view_key))
+    }
+
+    /// Returns the view key from the given string.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(view_key: &str) -> Result<ViewKey, String> {
+        let view_key = account::ViewKey::from_str(view_key).map_err(|error| error.to_string())?;
+        Ok(Self(view_key))
+    }
+
+    /// Returns the view key as a string.
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns the address for the view key.
+    #[wasm_bindgen(js_name = toAddress)]
+    pub fn to_address(&self) -> Result<Address, String> {
+        Address::from_view_key(self)
+    }
+
+    /// Returns the record plaintext of the given record ciphertext string, decrypted with the view key.
+    pub fn decrypt(&self, ciphertext: &str) -> Result<String, String> {
+        let ciphertext = RecordCiphertext::from_string(ciphertext)?;
+        Ok(ciphertext.decrypt(self)?.to_string())
+    }
+}
+
+impl Deref for ViewKey {
+    type Target = account::ViewKey<CurrentNetwork>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
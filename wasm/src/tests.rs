@@ -14,11 +14,14 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::bindings;
 use snarkvm_console::{
     account::{Address, PrivateKey, ViewKey},
-    network::Testnet3,
+    network::{Network, Testnet3},
+    program::{Identifier, Plaintext, ProgramID, Record},
+    types::Scalar,
 };
-use snarkvm_utilities::TestRng;
+use snarkvm_utilities::{TestRng, Uniform};
 
 use core::str::FromStr;
 use wasm_bindgen_test::*;
@@ -60,3 +63,120 @@ fn test_account_sign() {
         assert!(result, "Failed to execute signature verification");
     }
 }
+
+#[wasm_bindgen_test]
+fn test_bindings_account() {
+    const ALEO_PRIVATE_KEY: &str = "APrivateKey1zkp8cC4jgHEBnbtu3xxs1Ndja2EMizcvTRDq5Nikdkukg1p";
+    const ALEO_VIEW_KEY: &str = "AViewKey1n1n3ZbnVEtXVe3La2xWkUvY3EY7XaCG6RZJJ3tbvrrrD";
+    const ALEO_ADDRESS: &str = "aleo1wvgwnqvy46qq0zemj0k6sfp3zv0mp77rw97khvwuhac05yuwscxqmfyhwf";
+
+    let private_key = bindings::PrivateKey::from_string(ALEO_PRIVATE_KEY).unwrap();
+    assert_eq!(ALEO_PRIVATE_KEY, private_key.to_string());
+
+    let view_key = private_key.to_view_key().unwrap();
+    assert_eq!(ALEO_VIEW_KEY, view_key.to_string());
+    assert_eq!(view_key, bindings::ViewKey::from_string(ALEO_VIEW_KEY).unwrap());
+
+    let address = view_key.to_address().unwrap();
+    assert_eq!(ALEO_ADDRESS, address.to_string());
+    assert_eq!(address, private_key.to_address().unwrap());
+    assert_eq!(address, bindings::Address::from_string(ALEO_ADDRESS).unwrap());
+
+    // Ensure a freshly sampled private key differs from the fixed one.
+    let private_key = bindings::PrivateKey::new().unwrap();
+    assert_ne!(ALEO_PRIVATE_KEY, private_key.to_string());
+
+    // Ensure malformed strings are rejected.
+    assert!(bindings::PrivateKey::from_string(ALEO_VIEW_KEY).is_err());
+    assert!(bindings::ViewKey::from_string(ALEO_ADDRESS).is_err());
+    assert!(bindings::Address::from_string(ALEO_PRIVATE_KEY).is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_bindings_sign_and_verify() {
+    let private_key = bindings::PrivateKey::new().unwrap();
+    let address = private_key.to_address().unwrap();
+    let message = "hello world!".as_bytes();
+
+    // Sign the message, and ensure the signature verifies.
+    let signature = private_key.sign(message).unwrap();
+    assert!(signature.verify(&address, message));
+    assert!(address.verify(message, &signature));
+
+    // Ensure the signature round-trips through its string representation.
+    let candidate = bindings::Signature::from_string(&signature.to_string()).unwrap();
+    assert_eq!(signature, candidate);
+    assert!(candidate.verify(&address, message));
+
+    // Ensure the signature does not verify for a different message or address.
+    assert!(!signature.verify(&address, "hello world?".as_bytes()));
+    let other_address = bindings::PrivateKey::new().unwrap().to_address().unwrap();
+    assert!(!signature.verify(&other_address, message));
+}
+
+#[wasm_bindgen_test]
+fn test_bindings_record() {
+    let mut rng = TestRng::default();
+
+    let private_key = bindings::PrivateKey::new().unwrap();
+    let view_key = private_key.to_view_key().unwrap();
+    let address = private_key.to_address().unwrap();
+
+    // Construct a record for the address, and encrypt it.
+    let randomizer = Scalar::<Testnet3>::rand(&mut rng);
+    let nonce = Testnet3::g_scalar_multiply(&randomizer);
+    let record = Record::<Testnet3, Plaintext<Testnet3>>::from_str(&format!(
+        "{{ owner: {}.private, gates: 5u64.private, token_amount: 100u64.private, _nonce: {nonce}.public }}",
+        address.to_string()
+    ))
+    .unwrap();
+    let ciphertext = record.encrypt(randomizer).unwrap();
+
+    // Ensure the record decrypts with the view key of the owner.
+    let record_ciphertext = bindings::RecordCiphertext::from_string(&ciphertext.to_string()).unwrap();
+    assert!(record_ciphertext.is_owner(&view_key));
+    let record_plaintext = record_ciphertext.decrypt(&view_key).unwrap();
+    assert_eq!(record.to_string(), record_plaintext.to_string());
+    assert_eq!(record.to_string(), view_key.decrypt(&ciphertext.to_string()).unwrap());
+
+    // Ensure the record does not decrypt with another view key.
+    let other_view_key = bindings::PrivateKey::new().unwrap().to_view_key().unwrap();
+    assert!(!record_ciphertext.is_owner(&other_view_key));
+
+    // Ensure the serial number matches the one computed from the record commitment.
+    let program_id = ProgramID::<Testnet3>::from_str("token.aleo").unwrap();
+    let record_name = Identifier::<Testnet3>::from_str("token").unwrap();
+    let commitment = record.to_commitment(&program_id, &record_name).unwrap();
+    let expected = Record::<Testnet3, Plaintext<Testnet3>>::serial_number(*private_key, commitment).unwrap();
+    let candidate = record_plaintext.serial_number(&private_key, "token.aleo", "token").unwrap();
+    assert_eq!(expected.to_string(), candidate);
+
+    // Ensure a malformed program ID is rejected.
+    assert!(record_plaintext.serial_number(&private_key, "token", "token").is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_bindings_program_evaluate() {
+    const PROGRAM: &str = r"program hello.aleo;
+
+function hello:
+    input r0 as u32.public;
+    input r1 as u32.private;
+    add r0 r1 into r2;
+    output r2 as u32.private;
+";
+
+    let program = bindings::Program::from_string(PROGRAM).unwrap();
+    assert_eq!(program.id(), "hello.aleo");
+    assert_eq!(program.functions(), vec!["hello".to_string()]);
+
+    // Evaluate the function.
+    let private_key = bindings::PrivateKey::new().unwrap();
+    let outputs = program.evaluate(&private_key, "hello", vec!["5u32".to_string(), "3u32".to_string()]).unwrap();
+    assert_eq!(outputs, vec!["8u32".to_string()]);
+
+    // Ensure an unknown function or mismatched inputs are rejected.
+    assert!(program.evaluate(&private_key, "goodbye", vec!["5u32".to_string(), "3u32".to_string()]).is_err());
+    assert!(program.evaluate(&private_key, "hello", vec!["5u32".to_string()]).is_err());
+    assert!(program.evaluate(&private_key, "hello", vec!["5u32".to_string(), "3u64".to_string()]).is_err());
+}